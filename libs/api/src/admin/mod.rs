@@ -1,4 +1,5 @@
 pub mod routes;
+pub mod service_accounts;
 pub mod user_management;
 
 pub use routes::admin_config;
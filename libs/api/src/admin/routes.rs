@@ -1,9 +1,10 @@
 use actix_web::web;
 
-use crate::admin::user_management;
+use crate::admin::{service_accounts, user_management};
 
 pub fn admin_config(cfg: &mut web::ServiceConfig) {
-    cfg.configure(user_management::user_management_config);
+    cfg.configure(user_management::user_management_config)
+        .configure(service_accounts::service_accounts_config);
 }
 
 #[cfg(feature = "api-docs")]
@@ -23,6 +24,7 @@ pub mod openapi {
             let mut doc = AdminApi::openapi();
 
             doc.merge(user_management::UserManagementApi::openapi());
+            doc.merge(service_accounts::ServiceAccountsApi::openapi());
             // Add more merges here as needed
 
             doc
@@ -33,12 +35,14 @@ pub mod openapi {
     #[strum(serialize_all = "snake_case")]
     pub(crate) enum AdminApiTag {
         UserManagement,
+        ServiceAccounts,
     }
 
     impl AdminApiTag {
         pub fn as_ref(&self) -> &'static str {
             match self {
                 AdminApiTag::UserManagement => "admin/user_management",
+                AdminApiTag::ServiceAccounts => "admin/service_accounts",
             }
         }
     }
@@ -0,0 +1,41 @@
+use actix_web::{Responder, post, web};
+use usecase::service_account::service::ServiceAccountService;
+
+use super::{CreateServiceAccountRequest, CreateServiceAccountResponse};
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = CreateServiceAccountRequest,
+        responses(
+            (status = 201, description = "サービスアカウントの作成成功 (クライアントシークレットはこの応答でのみ取得できる)", body = CreateServiceAccountResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::ServiceAccounts).as_ref(),
+    )
+)]
+#[post("/admin/service-accounts")]
+#[tracing::instrument(skip(service))]
+pub async fn create_service_account_handler(
+    admin: AdminContext,
+    body: web::Json<CreateServiceAccountRequest>,
+    service: web::Data<dyn ServiceAccountService>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into();
+
+    let output = service.create_service_account(admin.into(), input).await?;
+
+    Ok(CreateServiceAccountResponse::from(output))
+}
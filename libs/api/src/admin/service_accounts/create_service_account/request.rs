@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use usecase::{service_account::dto::CreateServiceAccountInput, shared::identity::UserRoleData};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct CreateServiceAccountRequest {
+    /// 一覧で見分けるためのサービスアカウントの名前
+    #[cfg_attr(feature = "api-docs", schema(examples("billing-service")))]
+    pub name: String,
+
+    /// サービスアカウントに割り当てるロール
+    pub role: ServiceAccountRoleRequest,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceAccountRoleRequest {
+    Admin,
+    User,
+}
+
+impl From<ServiceAccountRoleRequest> for UserRoleData {
+    fn from(role: ServiceAccountRoleRequest) -> Self {
+        match role {
+            ServiceAccountRoleRequest::Admin => UserRoleData::Admin,
+            ServiceAccountRoleRequest::User => UserRoleData::User,
+        }
+    }
+}
+
+impl From<CreateServiceAccountRequest> for CreateServiceAccountInput {
+    fn from(req: CreateServiceAccountRequest) -> Self {
+        CreateServiceAccountInput {
+            name: req.name,
+            role: req.role.into(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::service_account::dto::CreateServiceAccountOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+use crate::admin::service_accounts::list_service_accounts::ServiceAccountResponse;
+
+#[derive(derive_more::Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct CreateServiceAccountResponse {
+    /// クライアントシークレットの平文。`client_id` とともに `POST /oauth/token` で提示する
+    ///
+    /// サーバー側には保存しないため、この応答でのみ取得できる
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("Zk3yQy1m0b7vVnq2k7bS0Jm5uQy1l9rTg8cXo2dHf4E"))
+    )]
+    #[debug(skip)]
+    pub client_secret: String,
+
+    #[serde(flatten)]
+    pub details: ServiceAccountResponse,
+}
+
+impl From<CreateServiceAccountOutput> for CreateServiceAccountResponse {
+    fn from(output: CreateServiceAccountOutput) -> Self {
+        CreateServiceAccountResponse {
+            client_secret: output.client_secret,
+            details: output.details.into(),
+        }
+    }
+}
+
+crate::impl_responder_for!(CreateServiceAccountResponse, StatusCode::CREATED);
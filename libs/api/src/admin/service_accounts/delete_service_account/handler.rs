@@ -0,0 +1,45 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::service_account::{dto::DeleteServiceAccountInput, service::ServiceAccountService};
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("service_account_id" = uuid::Uuid, Path, description = "削除するサービスアカウントのID")
+        ),
+        responses(
+            (status = 204, description = "サービスアカウントの削除成功"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "サービスアカウントが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::ServiceAccounts).as_ref(),
+    )
+)]
+#[delete("/admin/service-accounts/{service_account_id}")]
+#[tracing::instrument(skip(service))]
+pub async fn delete_service_account_handler(
+    admin: AdminContext,
+    service_account_id: web::Path<Uuid>,
+    service: web::Data<dyn ServiceAccountService>,
+) -> Result<impl Responder, ApiError> {
+    let input = DeleteServiceAccountInput {
+        service_account_id: *service_account_id,
+    };
+
+    service.delete_service_account(admin.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,3 @@
+pub mod handler;
+
+pub use handler::*;
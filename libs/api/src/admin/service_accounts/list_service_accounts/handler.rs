@@ -0,0 +1,36 @@
+use actix_web::{Responder, get, web};
+use usecase::service_account::service::ServiceAccountService;
+
+use super::ListServiceAccountsResponse;
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        get,
+        responses(
+            (status = 200, description = "サービスアカウント一覧の取得成功", body = ListServiceAccountsResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::ServiceAccounts).as_ref(),
+    )
+)]
+#[get("/admin/service-accounts")]
+#[tracing::instrument(skip(service))]
+pub async fn list_service_accounts_handler(
+    admin: AdminContext,
+    service: web::Data<dyn ServiceAccountService>,
+) -> Result<impl Responder, ApiError> {
+    let output = service.list_service_accounts(admin.into()).await?;
+
+    Ok(ListServiceAccountsResponse::from(output))
+}
@@ -0,0 +1,85 @@
+use actix_web::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::service_account::dto::{ListServiceAccountsOutput, ServiceAccountItem};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ListServiceAccountsResponse {
+    pub service_accounts: Vec<ServiceAccountResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ServiceAccountResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019cbd1a-4f2e-7c3b-8a91-5d6e7f801234"))
+    )]
+    pub service_account_id: Uuid,
+
+    /// `POST /oauth/token` で提示するクライアント ID
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019cbd1a-4f2e-7c3b-8a91-5d6e7f801234"))
+    )]
+    pub client_id: String,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("billing-service")))]
+    pub name: String,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("admin", "user")))]
+    pub role: String,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-03-05T09:31:20Z"))
+    )]
+    pub created_at: DateTime<Utc>,
+
+    /// 最後にトークンを発行した日時。未使用の場合は `null`
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = Option<String>, format = DateTime, examples("2026-03-06T08:00:00Z"))
+    )]
+    pub last_authenticated_at: Option<DateTime<Utc>>,
+}
+
+impl From<ServiceAccountItem> for ServiceAccountResponse {
+    fn from(item: ServiceAccountItem) -> Self {
+        let ServiceAccountItem {
+            service_account_id,
+            client_id,
+            name,
+            role,
+            created_at,
+            last_authenticated_at,
+        } = item;
+
+        ServiceAccountResponse {
+            service_account_id,
+            client_id,
+            name,
+            role: role.to_string(),
+            created_at,
+            last_authenticated_at,
+        }
+    }
+}
+
+impl From<ListServiceAccountsOutput> for ListServiceAccountsResponse {
+    fn from(output: ListServiceAccountsOutput) -> Self {
+        ListServiceAccountsResponse {
+            service_accounts: output
+                .service_accounts
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+crate::impl_responder_for!(ListServiceAccountsResponse, StatusCode::OK);
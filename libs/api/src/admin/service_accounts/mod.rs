@@ -0,0 +1,9 @@
+pub mod create_service_account;
+pub mod delete_service_account;
+pub mod list_service_accounts;
+pub mod routes;
+
+pub use self::routes::service_accounts_config;
+
+#[cfg(feature = "api-docs")]
+pub use self::routes::ServiceAccountsApi;
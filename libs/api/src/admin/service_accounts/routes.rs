@@ -0,0 +1,42 @@
+use actix_web::web;
+
+use super::{create_service_account, delete_service_account, list_service_accounts};
+
+pub fn service_accounts_config(cfg: &mut web::ServiceConfig) {
+    cfg.service(create_service_account::create_service_account_handler)
+        .service(list_service_accounts::list_service_accounts_handler)
+        .service(delete_service_account::delete_service_account_handler);
+}
+
+#[cfg(feature = "api-docs")]
+pub use openapi::*;
+
+#[cfg(feature = "api-docs")]
+pub mod openapi {
+    use super::*;
+    use crate::{admin::routes::AdminApiTag, openapi::OpenApiTag};
+    use utoipa::OpenApi;
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(
+            create_service_account::create_service_account_handler,
+            list_service_accounts::list_service_accounts_handler,
+            delete_service_account::delete_service_account_handler,
+        ),
+        components(
+            schemas(
+                create_service_account::CreateServiceAccountRequest,
+                create_service_account::ServiceAccountRoleRequest,
+                create_service_account::CreateServiceAccountResponse,
+                list_service_accounts::ListServiceAccountsResponse,
+                list_service_accounts::ServiceAccountResponse,
+            )
+        ),
+        tags((
+                name = OpenApiTag::Admin(AdminApiTag::ServiceAccounts).as_ref(),
+                description = "管理者用サービスアカウント管理API"
+        ))
+    )]
+    pub struct ServiceAccountsApi;
+}
@@ -0,0 +1,46 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::user::{dto::ClearLoginLockoutInput, service::UserService};
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "ロックアウトを解除するユーザーID")
+        ),
+        responses(
+            (status = 204, description = "ロックアウト解除成功"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "ユーザーがロックアウトされていない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::UserManagement).as_ref(),
+    )
+)]
+#[delete("/admin/users/{user_id}/lockout")]
+#[tracing::instrument(skip(service))]
+pub async fn clear_login_lockout_handler(
+    admin: AdminContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = ClearLoginLockoutInput {
+        target_id: *user_id,
+    };
+
+    service.clear_login_lockout(admin.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,47 @@
+use actix_web::{Responder, patch, web};
+use usecase::user::{dto::DemoteUserInput, service::UserService};
+use uuid::Uuid;
+
+use super::DemoteUserResponse;
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        patch,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "一般ユーザーに降格する管理者のユーザーID")
+        ),
+        responses(
+            (status = 200, description = "一般ユーザーへの降格成功", body = DemoteUserResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "最後の管理者は降格できない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::UserManagement).as_ref(),
+    )
+)]
+#[patch("/admin/users/{user_id}/role/demote")]
+#[tracing::instrument(skip(service))]
+pub async fn demote_user_handler(
+    admin: AdminContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = DemoteUserInput {
+        target_id: *user_id,
+    };
+
+    let output = service.demote_user(admin.into(), input).await?;
+
+    Ok(DemoteUserResponse::from(output))
+}
@@ -0,0 +1,31 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::DemoteUserOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct DemoteUserResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("123e4567-e89b-12d3-a456-426614174000"))
+    )]
+    user_id: Uuid,
+    #[cfg_attr(feature = "api-docs", schema(examples("user")))]
+    role: String,
+}
+
+impl From<DemoteUserOutput> for DemoteUserResponse {
+    fn from(output: DemoteUserOutput) -> Self {
+        let DemoteUserOutput { user_id, role } = output;
+
+        DemoteUserResponse {
+            user_id,
+            role: role.to_string(),
+        }
+    }
+}
+
+crate::impl_responder_for!(DemoteUserResponse, StatusCode::OK);
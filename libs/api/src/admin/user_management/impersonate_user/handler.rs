@@ -0,0 +1,47 @@
+use actix_web::{Responder, post, web};
+use usecase::user::service::UserService;
+use uuid::Uuid;
+
+use super::{ImpersonateUserRequest, ImpersonateUserResponse};
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "なりすます対象のユーザーID")
+        ),
+        request_body = ImpersonateUserRequest,
+        responses(
+            (status = 200, description = "なりすましのアクセストークンの発行成功", body = ImpersonateUserResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー (管理者・自分自身が対象の場合や、利用停止中・退会済みのアカウントを含む)"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::UserManagement).as_ref(),
+    )
+)]
+#[post("/admin/users/{user_id}/impersonate")]
+#[tracing::instrument(skip(service))]
+pub async fn impersonate_user_handler(
+    admin: AdminContext,
+    user_id: web::Path<Uuid>,
+    body: web::Json<ImpersonateUserRequest>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    let output = service.impersonate_user(admin.into(), input).await?;
+
+    Ok(ImpersonateUserResponse::from(output))
+}
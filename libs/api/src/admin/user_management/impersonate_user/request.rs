@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use usecase::user::dto::ImpersonateUserInput;
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct ImpersonateUserRequest {
+    /// なりすましを行う理由 (監査記録に残す)
+    #[cfg_attr(feature = "api-docs", schema(examples("問い合わせ #1234 の再現調査")))]
+    pub reason: String,
+}
+
+impl ImpersonateUserRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> ImpersonateUserInput {
+        ImpersonateUserInput {
+            target_id,
+            reason: self.reason,
+        }
+    }
+}
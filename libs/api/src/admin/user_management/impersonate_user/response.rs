@@ -0,0 +1,43 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::ImpersonateUserOutput;
+
+use crate::auth::login::AccessScopeResponse;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ImpersonateUserResponse {
+    /// 対象のユーザーとして操作するためのアクセストークン。なりすましを行った管理者を `act` クレームに含む
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."))
+    )]
+    access_token: String,
+
+    /// アクセストークンの有効期間(秒)。リフレッシュトークンは発行しないため、期限切れでなりすましは終了する
+    #[cfg_attr(feature = "api-docs", schema(examples(1800)))]
+    expires_in: i64,
+
+    /// アクセストークンで許可する操作の範囲
+    scope: AccessScopeResponse,
+}
+
+impl From<ImpersonateUserOutput> for ImpersonateUserResponse {
+    fn from(output: ImpersonateUserOutput) -> Self {
+        let ImpersonateUserOutput {
+            access_token,
+            expires_in,
+            scope,
+        } = output;
+
+        ImpersonateUserResponse {
+            access_token,
+            expires_in,
+            scope: scope.into(),
+        }
+    }
+}
+
+crate::impl_responder_for!(ImpersonateUserResponse, StatusCode::OK);
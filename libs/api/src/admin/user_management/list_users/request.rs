@@ -1,20 +1,215 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
-use usecase::user::dto::ListUsersInput;
+use usecase::{
+    shared::identity::UserRoleData,
+    user::dto::{ListUsersInput, SortDirectionData, UserListSortKeyData, UserStateData},
+};
 #[cfg(feature = "api-docs")]
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
 #[derive(derive_more::Debug, Deserialize)]
 #[cfg_attr(feature = "api-docs", derive(IntoParams, ToSchema))]
+#[cfg_attr(feature = "api-docs", into_params(parameter_in = Query))]
 pub struct ListUsersRequest {
-    // Define query parameters here, e.g.:
-    // pub page: Option<u32>,
-    // pub per_page: Option<u32>,
+    /// ユーザーの状態で絞り込む
+    #[cfg_attr(feature = "api-docs", param(inline))]
+    pub state: Option<UserStateRequest>,
+
+    /// ロールで絞り込む
+    #[cfg_attr(feature = "api-docs", param(inline))]
+    pub role: Option<UserRoleRequest>,
+
+    /// 登録日時の下限 (この日時を含む)
+    #[cfg_attr(
+        feature = "api-docs",
+        param(value_type = Option<String>, format = DateTime, example = "2026-01-01T00:00:00Z"),
+        schema(value_type = Option<String>, format = DateTime)
+    )]
+    pub created_from: Option<DateTime<Utc>>,
+
+    /// 登録日時の上限 (この日時を含まない)
+    #[cfg_attr(
+        feature = "api-docs",
+        param(value_type = Option<String>, format = DateTime, example = "2026-04-01T00:00:00Z"),
+        schema(value_type = Option<String>, format = DateTime)
+    )]
+    pub created_until: Option<DateTime<Utc>>,
+
+    /// メールアドレスの前方一致で絞り込む
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", param(example = "admin@"))]
+    pub email_prefix: Option<String>,
+
+    /// ユーザー名の前方一致で絞り込む
+    #[cfg_attr(feature = "api-docs", param(example = "example"))]
+    pub username_prefix: Option<String>,
+
+    /// 並び替えの基準 (デフォルトは登録日時)
+    #[serde(default)]
+    #[cfg_attr(feature = "api-docs", param(inline))]
+    pub sort_by: UserListSortKeyRequest,
+
+    /// 並び順 (デフォルトは昇順)
+    #[serde(default)]
+    #[cfg_attr(feature = "api-docs", param(inline))]
+    pub sort_order: SortOrderRequest,
+
+    /// 前のページの応答の `next_cursor`。省略すると先頭のページを取得する
+    #[cfg_attr(
+        feature = "api-docs",
+        param(example = "019ca5d2-7e41-7b8a-9c3d-2f1e0a9b8c7d")
+    )]
+    pub cursor: Option<Uuid>,
+
+    /// 1ページあたりの件数 (1～100、デフォルトは20)
+    #[cfg_attr(feature = "api-docs", param(example = 20))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UserStateRequest {
+    /// 利用中
+    Active,
+    /// 管理者による利用停止中
+    SuspendedByAdmin,
+    /// ユーザーによる退会済み
+    DeactivatedByUser,
+    /// メールアドレスの確認待ち
+    PendingVerification,
+    /// メールアドレスの変更を確認中
+    ActiveWithUnverifiedEmail,
+}
+
+impl From<UserStateRequest> for UserStateData {
+    fn from(state: UserStateRequest) -> Self {
+        match state {
+            UserStateRequest::Active => UserStateData::Active,
+            UserStateRequest::SuspendedByAdmin => UserStateData::SuspendedByAdmin,
+            UserStateRequest::DeactivatedByUser => UserStateData::DeactivatedByUser,
+            UserStateRequest::PendingVerification => UserStateData::PendingVerification,
+            UserStateRequest::ActiveWithUnverifiedEmail => UserStateData::ActiveWithUnverifiedEmail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UserRoleRequest {
+    Admin,
+    User,
+}
+
+impl From<UserRoleRequest> for UserRoleData {
+    fn from(role: UserRoleRequest) -> Self {
+        match role {
+            UserRoleRequest::Admin => UserRoleData::Admin,
+            UserRoleRequest::User => UserRoleData::User,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UserListSortKeyRequest {
+    #[default]
+    CreatedAt,
+    Username,
+}
+
+impl From<UserListSortKeyRequest> for UserListSortKeyData {
+    fn from(key: UserListSortKeyRequest) -> Self {
+        match key {
+            UserListSortKeyRequest::CreatedAt => UserListSortKeyData::CreatedAt,
+            UserListSortKeyRequest::Username => UserListSortKeyData::Username,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrderRequest {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortOrderRequest> for SortDirectionData {
+    fn from(order: SortOrderRequest) -> Self {
+        match order {
+            SortOrderRequest::Asc => SortDirectionData::Asc,
+            SortOrderRequest::Desc => SortDirectionData::Desc,
+        }
+    }
 }
 
 impl From<ListUsersRequest> for ListUsersInput {
-    fn from(_req: ListUsersRequest) -> Self {
+    fn from(req: ListUsersRequest) -> Self {
+        let ListUsersRequest {
+            state,
+            role,
+            created_from,
+            created_until,
+            email_prefix,
+            username_prefix,
+            sort_by,
+            sort_order,
+            cursor,
+            limit,
+        } = req;
+
         ListUsersInput {
-            // Map fields from ListUsersRequest to ListUsersInput here
+            state: state.map(Into::into),
+            role: role.map(Into::into),
+            created_from,
+            created_until,
+            email_prefix,
+            username_prefix,
+            sort_by: sort_by.into(),
+            sort_order: sort_order.into(),
+            cursor,
+            limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::web;
+
+    use super::*;
+
+    #[test]
+    fn test_parses_query_into_input() {
+        let cursor = Uuid::now_v7();
+        let query = web::Query::<ListUsersRequest>::from_query(&format!(
+            "state=suspended_by_admin&role=admin&username_prefix=ad&sort_by=username&sort_order=desc&cursor={cursor}&limit=50"
+        ))
+        .unwrap();
+
+        let input = ListUsersInput::from(query.into_inner());
+
+        assert!(matches!(input.state, Some(UserStateData::SuspendedByAdmin)));
+        assert!(matches!(input.role, Some(UserRoleData::Admin)));
+        assert_eq!(input.username_prefix.as_deref(), Some("ad"));
+        assert!(matches!(input.sort_by, UserListSortKeyData::Username));
+        assert!(matches!(input.sort_order, SortDirectionData::Desc));
+        assert_eq!(input.cursor, Some(cursor));
+        assert_eq!(input.limit, Some(50));
+    }
+
+    #[test]
+    fn test_rejects_malformed_cursor() {
+        for query in ["cursor=not-a-uuid", "cursor=", "cursor=019ca5d2-7e41"] {
+            assert!(
+                web::Query::<ListUsersRequest>::from_query(query).is_err(),
+                "query: {query}"
+            );
         }
     }
 }
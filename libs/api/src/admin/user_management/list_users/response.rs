@@ -1,4 +1,5 @@
 use actix_web::http::StatusCode;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use usecase::user::dto::{ListUsersOutput, UserItem};
 #[cfg(feature = "api-docs")]
@@ -18,25 +19,46 @@ pub(crate) struct ListUsersResponse {
                         "user_id": "550e8400-e29b-41d4-a716-446655440000",
                         "username": "exampleuser",
                         "email": "exampleuser@example.com",
-                        "role": "user"
+                        "role": "user",
+                        "created_at": "2026-01-10T09:00:00Z"
                     },
                     {
                         "user_id": "550e8400-e29b-41d4-a716-446655440001",
                         "username": "adminuser",
                         "email": "adminuser@example.com",
-                        "role": "admin"
+                        "role": "admin",
+                        "created_at": "2026-01-12T15:30:00Z"
                     }
                 ])
             )
         )
     )]
     pub users: Vec<UserInfo>,
+
+    /// 次のページを取得する際に `cursor` に指定する値。最後のページの場合は `null`
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("550e8400-e29b-41d4-a716-446655440001"))
+    )]
+    pub next_cursor: Option<Uuid>,
+
+    /// 絞り込み条件に一致するユーザーの総数
+    #[cfg_attr(feature = "api-docs", schema(examples(42)))]
+    pub total: u64,
 }
 
 impl From<ListUsersOutput> for ListUsersResponse {
     fn from(output: ListUsersOutput) -> Self {
+        let ListUsersOutput {
+            users,
+            next_cursor,
+            total,
+        } = output;
+
         ListUsersResponse {
-            users: output.users.into_iter().map(|user| user.into()).collect(),
+            users: users.into_iter().map(|user| user.into()).collect(),
+            next_cursor,
+            total,
         }
     }
 }
@@ -48,6 +70,11 @@ pub(crate) struct UserInfo {
     pub username: String,
     pub email: String,
     pub role: String,
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-01-10T09:00:00Z"))
+    )]
+    pub created_at: DateTime<Utc>,
 }
 
 impl From<UserItem> for UserInfo {
@@ -57,6 +84,7 @@ impl From<UserItem> for UserInfo {
             username,
             email,
             role,
+            created_at,
         } = user;
 
         UserInfo {
@@ -64,6 +92,7 @@ impl From<UserItem> for UserInfo {
             username,
             email,
             role: role.to_string(),
+            created_at,
         }
     }
 }
@@ -1,6 +1,11 @@
+pub mod clear_login_lockout;
+pub mod demote_user;
+pub mod impersonate_user;
 pub mod list_users;
+pub mod promote_user;
 pub mod routes;
 pub mod suspend_user;
+pub mod unlock_user;
 
 pub use self::routes::user_management_config;
 
@@ -0,0 +1,47 @@
+use actix_web::{Responder, patch, web};
+use usecase::user::{dto::PromoteUserInput, service::UserService};
+use uuid::Uuid;
+
+use super::PromoteUserResponse;
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        patch,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "管理者に昇格するユーザーID")
+        ),
+        responses(
+            (status = 200, description = "管理者への昇格成功", body = PromoteUserResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "メールアドレスが確認済みでない、または利用中でないユーザー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::UserManagement).as_ref(),
+    )
+)]
+#[patch("/admin/users/{user_id}/role/promote")]
+#[tracing::instrument(skip(service))]
+pub async fn promote_user_handler(
+    admin: AdminContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = PromoteUserInput {
+        target_id: *user_id,
+    };
+
+    let output = service.promote_user(admin.into(), input).await?;
+
+    Ok(PromoteUserResponse::from(output))
+}
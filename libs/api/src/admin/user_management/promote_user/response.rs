@@ -0,0 +1,31 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::PromoteUserOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct PromoteUserResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("123e4567-e89b-12d3-a456-426614174000"))
+    )]
+    user_id: Uuid,
+    #[cfg_attr(feature = "api-docs", schema(examples("admin")))]
+    role: String,
+}
+
+impl From<PromoteUserOutput> for PromoteUserResponse {
+    fn from(output: PromoteUserOutput) -> Self {
+        let PromoteUserOutput { user_id, role } = output;
+
+        PromoteUserResponse {
+            user_id,
+            role: role.to_string(),
+        }
+    }
+}
+
+crate::impl_responder_for!(PromoteUserResponse, StatusCode::OK);
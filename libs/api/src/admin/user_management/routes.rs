@@ -1,10 +1,18 @@
 use actix_web::web;
 
-use super::{list_users, suspend_user};
+use super::{
+    clear_login_lockout, demote_user, impersonate_user, list_users, promote_user, suspend_user,
+    unlock_user,
+};
 
 pub fn user_management_config(cfg: &mut web::ServiceConfig) {
     cfg.service(list_users::list_users_handler)
-        .service(suspend_user::suspend_user_handler);
+        .service(suspend_user::suspend_user_handler)
+        .service(unlock_user::unlock_user_handler)
+        .service(promote_user::promote_user_handler)
+        .service(demote_user::demote_user_handler)
+        .service(clear_login_lockout::clear_login_lockout_handler)
+        .service(impersonate_user::impersonate_user_handler);
 }
 
 #[cfg(feature = "api-docs")]
@@ -21,13 +29,28 @@ pub mod openapi {
         paths(
             list_users::list_users_handler,
             suspend_user::suspend_user_handler,
+            unlock_user::unlock_user_handler,
+            promote_user::promote_user_handler,
+            demote_user::demote_user_handler,
+            clear_login_lockout::clear_login_lockout_handler,
+            impersonate_user::impersonate_user_handler,
         ),
         components(
             schemas(
                 list_users::ListUsersRequest,
+                list_users::request::UserStateRequest,
+                list_users::request::UserRoleRequest,
+                list_users::request::UserListSortKeyRequest,
+                list_users::request::SortOrderRequest,
                 list_users::ListUsersResponse,
                 suspend_user::SuspendUserRequest,
                 suspend_user::SuspendUserResponse,
+                unlock_user::UnlockUserRequest,
+                unlock_user::UnlockUserResponse,
+                promote_user::PromoteUserResponse,
+                demote_user::DemoteUserResponse,
+                impersonate_user::ImpersonateUserRequest,
+                impersonate_user::ImpersonateUserResponse,
             )
         ),
         tags((
@@ -0,0 +1,48 @@
+use actix_web::{Responder, patch, web};
+use usecase::user::service::UserService;
+use uuid::Uuid;
+
+use super::{UnlockUserRequest, UnlockUserResponse};
+#[cfg(feature = "api-docs")]
+use crate::admin::routes::AdminApiTag;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AdminContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        patch,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "利用再開の対象のユーザーID")
+        ),
+        request_body = UnlockUserRequest,
+        responses(
+            (status = 200, description = "ユーザーの利用再開成功", body = UnlockUserResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "ユーザーが停止されていない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Admin(AdminApiTag::UserManagement).as_ref(),
+    )
+)]
+#[patch("/admin/users/{user_id}/unlock")]
+#[tracing::instrument(skip(service))]
+pub async fn unlock_user_handler(
+    admin: AdminContext,
+    user_id: web::Path<Uuid>,
+    body: web::Json<UnlockUserRequest>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    let output = service.unlock_user(admin.into(), input).await?;
+
+    Ok(UnlockUserResponse::from(output))
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use usecase::user::dto::UnlockUserInput;
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct UnlockUserRequest {
+    #[cfg_attr(feature = "api-docs", schema(examples("異議申し立てを受理したため")))]
+    pub reason: String,
+}
+
+impl UnlockUserRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> UnlockUserInput {
+        UnlockUserInput {
+            target_id,
+            reason: self.reason,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::UnlockUserOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct UnlockUserResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("123e4567-e89b-12d3-a456-426614174000"))
+    )]
+    user_id: Uuid,
+    #[cfg_attr(feature = "api-docs", schema(examples(true, false)))]
+    suspended: bool,
+}
+
+impl From<UnlockUserOutput> for UnlockUserResponse {
+    fn from(output: UnlockUserOutput) -> Self {
+        let UnlockUserOutput { user_id, suspended } = output;
+
+        UnlockUserResponse { user_id, suspended }
+    }
+}
+
+crate::impl_responder_for!(UnlockUserResponse, StatusCode::OK);
@@ -0,0 +1,37 @@
+use actix_web::{Responder, post, web};
+use usecase::auth::{dto::BeginOidcLoginInput, service::AuthService};
+
+use super::BeginOidcLoginResponse;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("provider" = String, Path, description = "設定した ID プロバイダーの識別子")
+        ),
+        responses(
+            (status = 200, description = "外部 ID プロバイダーによるログインの開始", body = BeginOidcLoginResponse),
+            (status = 404, description = "ID プロバイダーが設定されていない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/oidc/{provider}/authorize")]
+#[tracing::instrument(skip(service))]
+pub async fn begin_oidc_login_handler(
+    path: web::Path<String>,
+    service: web::Data<dyn AuthService>,
+) -> Result<impl Responder, ApiError> {
+    let input = BeginOidcLoginInput {
+        provider: path.into_inner(),
+    };
+
+    let output = service.begin_oidc_login(input).await?;
+
+    Ok(BeginOidcLoginResponse::from(output))
+}
@@ -0,0 +1,42 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::auth::dto::BeginOidcLoginOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct BeginOidcLoginResponse {
+    /// ユーザーをリダイレクトさせる ID プロバイダーの認可エンドポイントの URL
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples(
+            "https://accounts.google.com/o/oauth2/v2/auth?response_type=code&client_id=..."
+        ))
+    )]
+    pub authorization_url: String,
+
+    /// リダイレクト後に `/auth/oidc/{provider}/callback` へ認可コードとともに送信する状態トークン。
+    /// ID プロバイダーには渡さず、クライアント側で保持する
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6Im9pZGMtc3RhdGUrand0In0..."))
+    )]
+    pub state_token: String,
+}
+
+impl From<BeginOidcLoginOutput> for BeginOidcLoginResponse {
+    fn from(output: BeginOidcLoginOutput) -> Self {
+        let BeginOidcLoginOutput {
+            authorization_url,
+            state_token,
+        } = output;
+
+        BeginOidcLoginResponse {
+            authorization_url,
+            state_token,
+        }
+    }
+}
+
+crate::impl_responder_for!(BeginOidcLoginResponse, StatusCode::OK);
@@ -0,0 +1,35 @@
+use actix_web::{Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::{BeginPasskeyLoginRequest, BeginPasskeyLoginResponse};
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = BeginPasskeyLoginRequest,
+        responses(
+            (status = 200, description = "パスキーによるログインの開始", body = BeginPasskeyLoginResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "チャレンジトークンが正しくない"),
+            (status = 409, description = "パスキーが登録されていない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/passkey/options")]
+#[tracing::instrument(skip(service))]
+pub async fn begin_passkey_login_handler(
+    service: web::Data<dyn AuthService>,
+    body: web::Json<BeginPasskeyLoginRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input();
+
+    let output = service.begin_passkey_login(input).await?;
+
+    Ok(BeginPasskeyLoginResponse::from(output))
+}
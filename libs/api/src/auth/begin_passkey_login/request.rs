@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use usecase::auth::dto::BeginPasskeyLoginInput;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct BeginPasskeyLoginRequest {
+    /// 2 段階目の認証としてパスキーを使用する場合に、`/auth/login` が返したチャレンジトークンを指定する。
+    /// 省略した場合はパスキーのみでログインする
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6Im1mYS1jaGFsbGVuZ2Urand0In0..."))
+    )]
+    #[debug(skip)]
+    #[serde(default)]
+    pub mfa_token: Option<String>,
+}
+
+impl BeginPasskeyLoginRequest {
+    pub(super) fn into_input(self) -> BeginPasskeyLoginInput {
+        BeginPasskeyLoginInput {
+            mfa_token: self.mfa_token,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::auth::{
+    dto::BeginPasskeyLoginOutput, webauthn_service::PublicKeyCredentialRequestOptions,
+};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct BeginPasskeyLoginResponse {
+    /// `/auth/passkey` に認証器の応答とともに送信するセレモニートークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6IndlYmF1dGhuLWNlcmVtb255K2p3dCJ9..."))
+    )]
+    pub ceremony_token: String,
+
+    /// `PublicKeyCredential.parseRequestOptionsFromJSON()` で読み込み、
+    /// `navigator.credentials.get()` に渡すオプション
+    #[cfg_attr(feature = "api-docs", schema(value_type = Object))]
+    pub public_key: PublicKeyCredentialRequestOptions,
+}
+
+impl From<BeginPasskeyLoginOutput> for BeginPasskeyLoginResponse {
+    fn from(output: BeginPasskeyLoginOutput) -> Self {
+        let BeginPasskeyLoginOutput {
+            ceremony_token,
+            options,
+        } = output;
+
+        BeginPasskeyLoginResponse {
+            ceremony_token,
+            public_key: options,
+        }
+    }
+}
+
+crate::impl_responder_for!(BeginPasskeyLoginResponse, StatusCode::OK);
@@ -0,0 +1,35 @@
+use actix_web::{HttpResponse, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::ConfirmPasswordResetRequest;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = ConfirmPasswordResetRequest,
+        responses(
+            (status = 204, description = "パスワードリセット成功"),
+            (status = 400, description = "トークンが不正・期限切れ・使用済み、またはパスワードの形式が不正"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "パスワードを変更できない状態のユーザー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/password-reset/confirm")]
+#[tracing::instrument(skip(service))]
+pub async fn confirm_password_reset_handler(
+    service: web::Data<dyn AuthService>,
+    body: web::Json<ConfirmPasswordResetRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into();
+
+    service.confirm_password_reset(input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
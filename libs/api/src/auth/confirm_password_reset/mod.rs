@@ -0,0 +1,5 @@
+pub mod handler;
+pub mod request;
+
+pub use handler::*;
+pub(crate) use request::ConfirmPasswordResetRequest;
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use usecase::auth::dto::ConfirmPasswordResetInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct ConfirmPasswordResetRequest {
+    /// リセットメールのリンクに含まれるトークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("q8Xn2v0Yf3pL7cR1tWm9bK4sJ6hD5gA0eZ2uN8yV1oI"))
+    )]
+    #[debug(skip)]
+    pub token: String,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("newpassword123")))]
+    #[debug(skip)]
+    pub new_password: String,
+}
+
+impl From<ConfirmPasswordResetRequest> for ConfirmPasswordResetInput {
+    fn from(req: ConfirmPasswordResetRequest) -> Self {
+        Self {
+            token: req.token,
+            new_password: req.new_password,
+        }
+    }
+}
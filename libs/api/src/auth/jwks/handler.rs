@@ -0,0 +1,25 @@
+use actix_web::{Responder, get, web};
+use usecase::auth::token_service::TokenService;
+
+use super::JwksResponse;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        get,
+        responses(
+            (status = 200, description = "アクセストークン検証用の公開鍵一覧", body = JwksResponse),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[get("/.well-known/jwks.json")]
+#[tracing::instrument(skip(service))]
+pub async fn jwks_handler(
+    service: web::Data<dyn TokenService>,
+) -> Result<impl Responder, ApiError> {
+    Ok(JwksResponse::from(service.jwks()))
+}
@@ -0,0 +1,5 @@
+pub mod handler;
+pub mod response;
+
+pub use handler::*;
+pub(crate) use response::JwksResponse;
@@ -0,0 +1,23 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::auth::token_service::{Jwk, JwkSet};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct JwksResponse {
+    /// RFC 7517 形式の公開鍵の一覧
+    #[cfg_attr(feature = "api-docs", schema(value_type = Vec<Object>))]
+    keys: Vec<Jwk>,
+}
+
+impl From<JwkSet> for JwksResponse {
+    fn from(jwks: JwkSet) -> Self {
+        let JwkSet { keys } = jwks;
+
+        JwksResponse { keys }
+    }
+}
+
+crate::impl_responder_for!(JwksResponse, StatusCode::OK);
@@ -1,10 +1,11 @@
-use actix_web::{Responder, post, web};
+use actix_web::{HttpRequest, Responder, post, web};
 use usecase::auth::service::AuthService;
 
 use super::{LoginRequest, LoginResponse};
 use crate::error::ApiError;
 #[cfg(feature = "api-docs")]
 use crate::openapi::OpenApiTag;
+use crate::shared::user_agent;
 
 #[cfg_attr(
     feature = "api-docs",
@@ -12,21 +13,26 @@ use crate::openapi::OpenApiTag;
         post,
         request_body = LoginRequest,
         responses(
-            (status = 200, description = "ログイン成功", body = LoginResponse),
+            (status = 200, description = "ログイン成功、または 2 段階認証の認証コードの入力が必要", body = LoginResponse),
             (status = 400, description = "リクエストエラー"),
             (status = 401, description = "認証エラー"),
+            (status = 403, description = "利用停止中・退会済みのアカウント (`reason` が `account_suspended` または `account_deactivated`)"),
+            (status = 429, description = "ログインの失敗が多すぎるため一時的にロックされている"),
             (status = 500, description = "サーバーエラー"),
         ),
         tag = OpenApiTag::Auth.as_ref(),
     )
 )]
 #[post("/auth/login")]
-#[tracing::instrument(skip(service))]
+#[tracing::instrument(skip(req, service))]
 pub async fn login_handler(
+    req: HttpRequest,
     service: web::Data<dyn AuthService>,
     body: web::Json<LoginRequest>,
 ) -> Result<impl Responder, ApiError> {
-    let input = body.into_inner().into();
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body.into_inner().into_input(ip_address, user_agent(&req));
 
     let output = service.login(input).await?;
 
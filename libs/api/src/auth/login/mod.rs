@@ -4,4 +4,4 @@ pub mod response;
 
 pub use handler::*;
 pub(crate) use request::LoginRequest;
-pub(crate) use response::LoginResponse;
+pub(crate) use response::*;
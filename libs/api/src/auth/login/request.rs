@@ -15,11 +15,17 @@ pub struct LoginRequest {
     pub password: String,
 }
 
-impl From<LoginRequest> for usecase::auth::dto::LoginInput {
-    fn from(req: LoginRequest) -> Self {
-        Self {
-            email: req.email,
-            password: req.password,
+impl LoginRequest {
+    pub(super) fn into_input(
+        self,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> usecase::auth::dto::LoginInput {
+        usecase::auth::dto::LoginInput {
+            email: self.email,
+            password: self.password,
+            ip_address,
+            user_agent,
         }
     }
 }
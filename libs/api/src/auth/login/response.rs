@@ -1,25 +1,179 @@
-use actix_web::http::StatusCode;
+use actix_web::{HttpRequest, HttpResponse, Responder, body::BoxBody};
 use serde::Serialize;
-use usecase::auth::dto::LoginOutput;
+use usecase::{
+    auth::dto::{LoginOutput, LoginTokens, MfaChallengeOutput, MfaMethod},
+    shared::identity::AccessScopeData,
+};
 #[cfg(feature = "api-docs")]
 use utoipa::ToSchema;
 
+use crate::session_cookie::move_tokens_to_cookies;
+
+/// ログインの結果
+///
+/// 2 段階認証が有効なユーザーの場合はトークンの代わりにチャレンジトークンを返す。
+/// チャレンジトークンと認証コードを `/auth/login/mfa` に送信するか、
+/// チャレンジトークンを指定して `/auth/passkey` でパスキーによる認証を行うとログインが完了する。
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub(crate) enum LoginResponse {
+    /// ログインが完了した
+    Authenticated(LoginTokensResponse),
+
+    /// 2 段階認証の認証コードの入力が必要
+    MfaRequired(MfaRequiredResponse),
+}
+
 #[derive(Serialize)]
 #[cfg_attr(feature = "api-docs", derive(ToSchema))]
-pub(crate) struct LoginResponse {
+pub(crate) struct LoginTokensResponse {
+    /// Cookie モードでは本文に含めず、`HttpOnly` の Cookie で返す
     #[cfg_attr(
         feature = "api-docs",
         schema(examples("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."))
     )]
-    token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_token: Option<String>,
+
+    /// Cookie モードでは本文に含めず、`HttpOnly` の Cookie で返す
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("q5b0X3Jd9m6wZk1yT2uVn8cE4aH7sL0pR3fG6jK9xQw"))
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+
+    /// アクセストークンの有効期間(秒)
+    #[cfg_attr(feature = "api-docs", schema(examples(900)))]
+    expires_in: i64,
+
+    /// アクセストークンで許可する操作の範囲
+    scope: AccessScopeResponse,
+}
+
+/// アクセストークンで許可する操作の範囲
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AccessScopeResponse {
+    /// 制限なし
+    Full,
+    /// メールアドレスの認証前のため、確認メールの再送・ログアウト・プロフィールの閲覧のみ可能
+    PendingVerification,
+}
+
+impl From<AccessScopeData> for AccessScopeResponse {
+    fn from(scope: AccessScopeData) -> Self {
+        match scope {
+            AccessScopeData::Full => AccessScopeResponse::Full,
+            AccessScopeData::PendingVerification => AccessScopeResponse::PendingVerification,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct MfaRequiredResponse {
+    /// `/auth/login/mfa` に認証コードとともに送信するチャレンジトークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6Im1mYS1jaGFsbGVuZ2Urand0In0..."))
+    )]
+    mfa_token: String,
+
+    /// チャレンジトークンの有効期間(秒)
+    #[cfg_attr(feature = "api-docs", schema(examples(300)))]
+    expires_in: i64,
+
+    /// 2 段階目の認証に使用できる方法
+    methods: Vec<MfaMethodResponse>,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MfaMethodResponse {
+    /// 認証アプリの認証コード、またはリカバリーコード (`/auth/login/mfa`)
+    Totp,
+    /// 登録済みのパスキー (`/auth/passkey`)
+    Passkey,
+}
+
+impl From<MfaMethod> for MfaMethodResponse {
+    fn from(method: MfaMethod) -> Self {
+        match method {
+            MfaMethod::Totp => MfaMethodResponse::Totp,
+            MfaMethod::Passkey => MfaMethodResponse::Passkey,
+        }
+    }
+}
+
+impl From<LoginTokens> for LoginTokensResponse {
+    fn from(tokens: LoginTokens) -> Self {
+        let LoginTokens {
+            access_token,
+            refresh_token,
+            expires_in,
+            scope,
+        } = tokens;
+
+        LoginTokensResponse {
+            access_token: Some(access_token),
+            refresh_token: Some(refresh_token),
+            expires_in,
+            scope: scope.into(),
+        }
+    }
 }
 
 impl From<LoginOutput> for LoginResponse {
     fn from(output: LoginOutput) -> Self {
-        let LoginOutput { token } = output;
+        match output {
+            LoginOutput::Authenticated(tokens) => LoginResponse::Authenticated(tokens.into()),
+            LoginOutput::MfaRequired(MfaChallengeOutput {
+                mfa_token,
+                expires_in,
+                methods,
+            }) => LoginResponse::MfaRequired(MfaRequiredResponse {
+                mfa_token,
+                expires_in,
+                methods: methods.into_iter().map(Into::into).collect(),
+            }),
+        }
+    }
+}
+
+impl Responder for LoginResponse {
+    type Body = BoxBody;
 
-        LoginResponse { token }
+    fn respond_to(mut self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let mut response = HttpResponse::Ok();
+        if let LoginResponse::Authenticated(tokens) = &mut self {
+            move_tokens_to_cookies(
+                req,
+                &mut response,
+                &mut tokens.access_token,
+                &mut tokens.refresh_token,
+                tokens.expires_in,
+            );
+        }
+        response.json(self)
     }
 }
 
-crate::impl_responder_for!(LoginResponse, StatusCode::OK);
+impl Responder for LoginTokensResponse {
+    type Body = BoxBody;
+
+    fn respond_to(mut self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let mut response = HttpResponse::Ok();
+        move_tokens_to_cookies(
+            req,
+            &mut response,
+            &mut self.access_token,
+            &mut self.refresh_token,
+            self.expires_in,
+        );
+        response.json(self)
+    }
+}
@@ -0,0 +1,39 @@
+use actix_web::{HttpRequest, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::LoginMagicLinkRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{auth::login::LoginResponse, error::ApiError, shared::user_agent};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = LoginMagicLinkRequest,
+        responses(
+            (status = 200, description = "ログイン成功、または 2 段階認証の認証コードの入力が必要", body = LoginResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "ログインリンクが不正・期限切れ・使用済み、またはメールアドレスが変更されている"),
+            (status = 403, description = "利用停止中・退会済みのアカウント"),
+            (status = 429, description = "ログインの失敗が多すぎるため一時的にロックされている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/magic-link/login")]
+#[tracing::instrument(skip(req, service))]
+pub async fn login_magic_link_handler(
+    req: HttpRequest,
+    service: web::Data<dyn AuthService>,
+    body: web::Json<LoginMagicLinkRequest>,
+) -> Result<impl Responder, ApiError> {
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body.into_inner().into_input(ip_address, user_agent(&req));
+
+    let output = service.complete_magic_link_login(input).await?;
+
+    Ok(LoginResponse::from(output))
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use usecase::auth::dto::MagicLinkLoginInput;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct LoginMagicLinkRequest {
+    /// ログインメールのリンクのクエリに含まれるトークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFZERTQSIsInR5cCI6Im1hZ2ljLWxpbmsrand0In0..."))
+    )]
+    #[debug(skip)]
+    pub token: String,
+}
+
+impl LoginMagicLinkRequest {
+    pub(super) fn into_input(
+        self,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> MagicLinkLoginInput {
+        MagicLinkLoginInput {
+            token: self.token,
+            ip_address,
+            user_agent,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use actix_web::{HttpRequest, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::LoginMfaRequest;
+use crate::auth::login::LoginTokensResponse;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::shared::user_agent;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = LoginMfaRequest,
+        responses(
+            (status = 200, description = "ログイン成功", body = LoginTokensResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "チャレンジトークンまたは認証コードが正しくない"),
+            (status = 403, description = "利用停止中・退会済みのアカウント (`reason` が `account_suspended` または `account_deactivated`)"),
+            (status = 429, description = "ログインの失敗が多すぎるため一時的にロックされている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/login/mfa")]
+#[tracing::instrument(skip(req, service))]
+pub async fn login_mfa_handler(
+    req: HttpRequest,
+    service: web::Data<dyn AuthService>,
+    body: web::Json<LoginMfaRequest>,
+) -> Result<impl Responder, ApiError> {
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body.into_inner().into_input(ip_address, user_agent(&req));
+
+    let tokens = service.complete_mfa_login(input).await?;
+
+    Ok(LoginTokensResponse::from(tokens))
+}
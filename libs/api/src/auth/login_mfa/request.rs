@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct LoginMfaRequest {
+    /// `/auth/login` が返したチャレンジトークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6Im1mYS1jaGFsbGVuZ2Urand0In0..."))
+    )]
+    #[debug(skip)]
+    pub mfa_token: String,
+
+    /// 認証アプリに表示された 6 桁の認証コード、またはリカバリーコード
+    #[cfg_attr(feature = "api-docs", schema(examples("123456")))]
+    #[debug(skip)]
+    pub code: String,
+}
+
+impl LoginMfaRequest {
+    pub(super) fn into_input(
+        self,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> usecase::auth::dto::MfaLoginInput {
+        usecase::auth::dto::MfaLoginInput {
+            mfa_token: self.mfa_token,
+            code: self.code,
+            ip_address,
+            user_agent,
+        }
+    }
+}
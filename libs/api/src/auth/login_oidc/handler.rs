@@ -0,0 +1,47 @@
+use actix_web::{HttpRequest, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::LoginOidcRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{auth::login::LoginResponse, error::ApiError, shared::user_agent};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("provider" = String, Path, description = "設定した ID プロバイダーの識別子")
+        ),
+        request_body = LoginOidcRequest,
+        responses(
+            (status = 200, description = "ログイン成功、または 2 段階認証の認証コードの入力が必要", body = LoginResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "状態トークン・認可コード・ID トークンのいずれかが正しくない"),
+            (status = 403, description = "ID プロバイダーで確認済みのメールアドレスがない、または利用停止中・退会済みのアカウント"),
+            (status = 404, description = "ID プロバイダーが設定されていない"),
+            (status = 409, description = "メールアドレスが未確認の同じメールアドレスのアカウントが存在する"),
+            (status = 429, description = "ログインの失敗が多すぎるため一時的にロックされている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/oidc/{provider}/callback")]
+#[tracing::instrument(skip(req, service))]
+pub async fn login_oidc_handler(
+    req: HttpRequest,
+    path: web::Path<String>,
+    service: web::Data<dyn AuthService>,
+    body: web::Json<LoginOidcRequest>,
+) -> Result<impl Responder, ApiError> {
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body
+        .into_inner()
+        .into_input(path.into_inner(), ip_address, user_agent(&req));
+
+    let output = service.complete_oidc_login(input).await?;
+
+    Ok(LoginResponse::from(output))
+}
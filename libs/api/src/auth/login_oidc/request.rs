@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use usecase::auth::dto::OidcLoginInput;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct LoginOidcRequest {
+    /// ID プロバイダーからのリダイレクトのクエリに含まれる認可コード
+    #[cfg_attr(feature = "api-docs", schema(examples("4/0AX4XfWh...")))]
+    #[debug(skip)]
+    pub code: String,
+
+    /// ID プロバイダーからのリダイレクトのクエリに含まれる `state`
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("kJ8Xq2vN5mR7tY1wZ4aB6cD9eF0gH3iL5nP8sU2xV4y"))
+    )]
+    #[debug(skip)]
+    pub state: String,
+
+    /// `/auth/oidc/{provider}/authorize` が返した状態トークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6Im9pZGMtc3RhdGUrand0In0..."))
+    )]
+    #[debug(skip)]
+    pub state_token: String,
+}
+
+impl LoginOidcRequest {
+    pub(super) fn into_input(
+        self,
+        provider: String,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> OidcLoginInput {
+        OidcLoginInput {
+            provider,
+            code: self.code,
+            state: self.state,
+            state_token: self.state_token,
+            ip_address,
+            user_agent,
+        }
+    }
+}
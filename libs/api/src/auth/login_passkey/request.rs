@@ -0,0 +1,76 @@
+use serde::Deserialize;
+use usecase::auth::{dto::PasskeyLoginInput, webauthn_service::AssertionCredential};
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct LoginPasskeyRequest {
+    /// `/auth/passkey/options` が返したセレモニートークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6IndlYmF1dGhuLWNlcmVtb255K2p3dCJ9..."))
+    )]
+    #[debug(skip)]
+    pub ceremony_token: String,
+
+    /// 2 段階目の認証の場合は、`/auth/passkey/options` に指定したものと同じチャレンジトークン
+    #[debug(skip)]
+    #[serde(default)]
+    pub mfa_token: Option<String>,
+
+    /// `navigator.credentials.get()` が返した `PublicKeyCredential` の `toJSON()` の結果
+    #[debug(skip)]
+    pub credential: PasskeyAssertionRequest,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct PasskeyAssertionRequest {
+    /// クレデンシャル ID (Base64URL)
+    #[cfg_attr(feature = "api-docs", schema(examples("c29mdHdhcmUtY3JlZGVudGlhbA")))]
+    pub id: String,
+
+    pub response: AuthenticatorAssertionResponseRequest,
+}
+
+/// 各値は Base64URL エンコード済み
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorAssertionResponseRequest {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+
+    pub authenticator_data: String,
+
+    pub signature: String,
+
+    #[serde(default)]
+    pub user_handle: Option<String>,
+}
+
+impl LoginPasskeyRequest {
+    pub(super) fn into_input(
+        self,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> PasskeyLoginInput {
+        let PasskeyAssertionRequest { id, response } = self.credential;
+
+        PasskeyLoginInput {
+            ceremony_token: self.ceremony_token,
+            mfa_token: self.mfa_token,
+            credential: AssertionCredential {
+                id,
+                client_data_json: response.client_data_json,
+                authenticator_data: response.authenticator_data,
+                signature: response.signature,
+                user_handle: response.user_handle,
+            },
+            ip_address,
+            user_agent,
+        }
+    }
+}
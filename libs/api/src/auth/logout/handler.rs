@@ -0,0 +1,52 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, post, web};
+use usecase::{
+    auth::{dto::LogoutInput, service::AuthService},
+    shared::identity::Identity as _,
+};
+
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{
+    error::ApiError,
+    middleware::{AllowPendingVerification, AuthenticatedUserContext},
+    session_cookie::remove_token_cookies,
+};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        responses(
+            (status = 204, description = "ログアウト成功 (なりすましのトークンの場合は、なりすましを終了する)"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "Cookie で認証する場合に、CSRF トークンが一致しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = [])
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/logout")]
+#[tracing::instrument(skip(req, service))]
+pub async fn logout_handler(
+    req: HttpRequest,
+    user: AllowPendingVerification<AuthenticatedUserContext>,
+    service: web::Data<dyn AuthService>,
+) -> Result<impl Responder, ApiError> {
+    let input = LogoutInput {
+        user_id: user.actor_id(),
+        token_id: user.token_id(),
+        session_id: user.session_id(),
+        expires_at: user.token_expires_at(),
+        impersonator_id: user.impersonator_id(),
+    };
+
+    service.logout(input).await?;
+
+    let mut response = HttpResponse::NoContent();
+    remove_token_cookies(&req, &mut response);
+
+    Ok(response.finish())
+}
@@ -1,5 +1,20 @@
+pub mod begin_oidc_login;
+pub mod begin_passkey_login;
+pub mod confirm_password_reset;
+pub mod jwks;
 pub mod login;
+pub mod login_magic_link;
+pub mod login_mfa;
+pub mod login_oidc;
+pub mod login_passkey;
+pub mod logout;
+pub mod oauth_token;
+pub mod refresh;
+pub mod request_magic_link;
+pub mod request_password_reset;
+pub mod resend_verification_email;
 pub mod routes;
 pub mod signup;
+pub mod verify_email;
 
 pub use routes::auth_config;
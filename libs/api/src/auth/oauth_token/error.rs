@@ -0,0 +1,80 @@
+use actix_web::{HttpResponse, ResponseError, http::StatusCode, http::header};
+use thiserror::Error;
+use usecase::usecase_error::UseCaseError;
+
+use super::OAuthTokenErrorResponse;
+
+/// トークンエンドポイントのエラー
+///
+/// 他のエンドポイントとは異なり、OAuth 2.0 のクライアントが解釈できる形式 (RFC 6749 §5.2) で返却する
+#[derive(Debug, Error)]
+pub enum OAuthTokenError {
+    #[error("{0}")]
+    InvalidRequest(&'static str),
+
+    #[error("クライアント認証に失敗しました")]
+    InvalidClient,
+
+    #[error("対応していない grant_type です")]
+    UnsupportedGrantType,
+
+    #[error(transparent)]
+    UseCaseError(UseCaseError),
+}
+
+impl From<UseCaseError> for OAuthTokenError {
+    fn from(error: UseCaseError) -> Self {
+        match error {
+            UseCaseError::Unauthorized => OAuthTokenError::InvalidClient,
+            other => OAuthTokenError::UseCaseError(other),
+        }
+    }
+}
+
+impl OAuthTokenError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            OAuthTokenError::InvalidRequest(_) => "invalid_request",
+            OAuthTokenError::InvalidClient => "invalid_client",
+            OAuthTokenError::UnsupportedGrantType => "unsupported_grant_type",
+            OAuthTokenError::UseCaseError(_) => "server_error",
+        }
+    }
+}
+
+impl ResponseError for OAuthTokenError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            OAuthTokenError::InvalidRequest(_) | OAuthTokenError::UnsupportedGrantType => {
+                StatusCode::BAD_REQUEST
+            }
+            OAuthTokenError::InvalidClient => StatusCode::UNAUTHORIZED,
+            OAuthTokenError::UseCaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let error_description = match self {
+            OAuthTokenError::UseCaseError(e) => {
+                tracing::error!(
+                    error = ?e,
+                    "Internal Server Error occurred: An unexpected error was caught at the token endpoint."
+                );
+                "Internal Server Error".to_string()
+            }
+            other => other.to_string(),
+        };
+
+        let mut response = HttpResponse::build(self.status_code());
+        response.insert_header((header::CACHE_CONTROL, "no-store"));
+        // クライアント認証の失敗時は、対応する認証方式を示す (RFC 6749 §5.2)
+        if matches!(self, OAuthTokenError::InvalidClient) {
+            response.insert_header((header::WWW_AUTHENTICATE, "Basic"));
+        }
+
+        response.json(OAuthTokenErrorResponse {
+            error: self.error_code(),
+            error_description,
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use actix_web::{HttpRequest, Responder, post, web};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use usecase::auth::{dto::ClientCredentialsInput, service::AuthService};
+
+#[cfg(feature = "api-docs")]
+use super::OAuthTokenErrorResponse;
+use super::{OAuthTokenError, OAuthTokenRequest, OAuthTokenResponse};
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+const CLIENT_CREDENTIALS_GRANT_TYPE: &str = "client_credentials";
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body(content = OAuthTokenRequest, content_type = "application/x-www-form-urlencoded"),
+        responses(
+            (status = 200, description = "アクセストークンの発行成功", body = OAuthTokenResponse),
+            (status = 400, description = "リクエストエラー (`invalid_request` / `unsupported_grant_type`)", body = OAuthTokenErrorResponse),
+            (status = 401, description = "クライアント認証エラー (`invalid_client`)", body = OAuthTokenErrorResponse),
+            (status = 500, description = "サーバーエラー", body = OAuthTokenErrorResponse),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/oauth/token")]
+#[tracing::instrument(skip(service, req))]
+pub async fn oauth_token_handler(
+    req: HttpRequest,
+    service: web::Data<dyn AuthService>,
+    body: web::Form<OAuthTokenRequest>,
+) -> Result<impl Responder, OAuthTokenError> {
+    let OAuthTokenRequest {
+        grant_type,
+        client_id,
+        client_secret,
+    } = body.into_inner();
+
+    match grant_type.as_deref() {
+        Some(CLIENT_CREDENTIALS_GRANT_TYPE) => {}
+        Some(_) => return Err(OAuthTokenError::UnsupportedGrantType),
+        None => return Err(OAuthTokenError::InvalidRequest("grant_type は必須です")),
+    }
+
+    // クライアント認証は 1 つの方式のみで行う (RFC 6749 §2.3)
+    let (client_id, client_secret) = match (basic_credentials(&req)?, client_id, client_secret) {
+        (Some(credentials), None, None) => credentials,
+        (None, Some(client_id), Some(client_secret)) => (client_id, client_secret),
+        (Some(_), _, _) => {
+            return Err(OAuthTokenError::InvalidRequest(
+                "クライアント認証の方式は 1 つのみ指定してください",
+            ));
+        }
+        (None, _, _) => return Err(OAuthTokenError::InvalidClient),
+    };
+
+    let output = service
+        .issue_client_credentials_token(ClientCredentialsInput {
+            client_id,
+            client_secret,
+        })
+        .await?;
+
+    Ok(OAuthTokenResponse::from(output))
+}
+
+/// `Authorization: Basic` ヘッダーのクライアント ID とシークレットを取得する (`client_secret_basic`)
+///
+/// クライアント ID (UUID) とシークレット (URL-safe Base64) は URL エンコードの対象となる文字を含まないため、そのまま使用する
+fn basic_credentials(req: &HttpRequest) -> Result<Option<(String, String)>, OAuthTokenError> {
+    let Some(encoded) = req
+        .headers()
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.strip_prefix("Basic "))
+    else {
+        return Ok(None);
+    };
+
+    let decoded = STANDARD
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or(OAuthTokenError::InvalidClient)?;
+
+    let (client_id, client_secret) = decoded
+        .split_once(':')
+        .ok_or(OAuthTokenError::InvalidClient)?;
+
+    Ok(Some((client_id.to_string(), client_secret.to_string())))
+}
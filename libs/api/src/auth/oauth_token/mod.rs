@@ -0,0 +1,9 @@
+pub mod error;
+pub mod handler;
+pub mod request;
+pub mod response;
+
+pub(crate) use error::OAuthTokenError;
+pub use handler::*;
+pub(crate) use request::OAuthTokenRequest;
+pub(crate) use response::{OAuthTokenErrorResponse, OAuthTokenResponse};
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+/// トークンエンドポイントへのリクエスト (`application/x-www-form-urlencoded`)
+///
+/// クライアント認証は `client_secret_basic` (Authorization ヘッダー) と `client_secret_post` (本文) のいずれかで行う
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct OAuthTokenRequest {
+    /// `client_credentials` のみ対応
+    #[cfg_attr(feature = "api-docs", schema(examples("client_credentials")))]
+    pub grant_type: Option<String>,
+
+    /// サービスアカウントのクライアント ID (`client_secret_post` の場合)
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019cbd1a-4f2e-7c3b-8a91-5d6e7f801234"))
+    )]
+    pub client_id: Option<String>,
+
+    /// サービスアカウントのクライアントシークレット (`client_secret_post` の場合)
+    #[debug(skip)]
+    pub client_secret: Option<String>,
+}
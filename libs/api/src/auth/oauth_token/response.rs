@@ -0,0 +1,64 @@
+use actix_web::{HttpResponse, Responder, http::header};
+use serde::Serialize;
+use usecase::auth::dto::ClientCredentialsOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+/// トークンエンドポイントの応答 (RFC 6749 §5.1)
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct OAuthTokenResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."))
+    )]
+    access_token: String,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("Bearer")))]
+    token_type: &'static str,
+
+    /// アクセストークンの有効期間(秒)
+    #[cfg_attr(feature = "api-docs", schema(examples(900)))]
+    expires_in: i64,
+}
+
+impl From<ClientCredentialsOutput> for OAuthTokenResponse {
+    fn from(output: ClientCredentialsOutput) -> Self {
+        let ClientCredentialsOutput {
+            access_token,
+            expires_in,
+        } = output;
+
+        OAuthTokenResponse {
+            access_token,
+            token_type: "Bearer",
+            expires_in,
+        }
+    }
+}
+
+impl Responder for OAuthTokenResponse {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _req: &actix_web::HttpRequest) -> HttpResponse<Self::Body> {
+        // トークンを含む応答はキャッシュさせない (RFC 6749 §5.1)
+        HttpResponse::Ok()
+            .insert_header((header::CACHE_CONTROL, "no-store"))
+            .insert_header((header::PRAGMA, "no-cache"))
+            .json(self)
+    }
+}
+
+/// トークンエンドポイントのエラー応答 (RFC 6749 §5.2)
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct OAuthTokenErrorResponse {
+    #[cfg_attr(feature = "api-docs", schema(examples("invalid_client")))]
+    pub error: &'static str,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("クライアント認証に失敗しました"))
+    )]
+    pub error_description: String,
+}
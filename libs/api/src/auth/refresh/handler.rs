@@ -0,0 +1,41 @@
+use actix_web::{HttpRequest, Responder, post, web};
+use usecase::auth::{dto::RefreshTokenInput, service::AuthService};
+
+use super::{RefreshTokenRequest, RefreshTokenResponse};
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, session_cookie::refresh_token_from_cookie};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = RefreshTokenRequest,
+        responses(
+            (status = 200, description = "トークン再発行成功", body = RefreshTokenResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "リフレッシュトークンが無効・期限切れ・再利用済み"),
+            (status = 403, description = "Cookie のリフレッシュトークンを使用する場合に、CSRF トークンが一致しない"),
+            (status = 403, description = "利用停止中・退会済みのアカウント (`reason` が `account_suspended` または `account_deactivated`)"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/refresh")]
+#[tracing::instrument(skip(req, service))]
+pub async fn refresh_handler(
+    req: HttpRequest,
+    service: web::Data<dyn AuthService>,
+    body: web::Json<RefreshTokenRequest>,
+) -> Result<impl Responder, ApiError> {
+    let refresh_token = match body.into_inner().refresh_token {
+        Some(refresh_token) => refresh_token,
+        None => refresh_token_from_cookie(&req)?.ok_or(ApiError::Unauthorized)?,
+    };
+    let input = RefreshTokenInput { refresh_token };
+
+    let output = service.refresh(input).await?;
+
+    Ok(RefreshTokenResponse::from(output))
+}
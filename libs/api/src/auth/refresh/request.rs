@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct RefreshTokenRequest {
+    /// Cookie モードでは省略でき、その場合は Cookie のリフレッシュトークンを使用する
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("q5b0X3Jd9m6wZk1yT2uVn8cE4aH7sL0pR3fG6jK9xQw"))
+    )]
+    #[serde(default)]
+    #[debug(skip)]
+    pub refresh_token: Option<String>,
+}
@@ -0,0 +1,68 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, body::BoxBody};
+use serde::Serialize;
+use usecase::auth::dto::RefreshTokenOutput;
+
+use crate::{auth::login::AccessScopeResponse, session_cookie::move_tokens_to_cookies};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct RefreshTokenResponse {
+    /// Cookie モードでは本文に含めず、`HttpOnly` の Cookie で返す
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9..."))
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_token: Option<String>,
+
+    /// Cookie モードでは本文に含めず、`HttpOnly` の Cookie で返す
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("Zr8pL2mQ7vX0cT5nW1yB4hK9sD3fJ6gA8eU0oI2kRtc"))
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+
+    /// アクセストークンの有効期間(秒)
+    #[cfg_attr(feature = "api-docs", schema(examples(900)))]
+    expires_in: i64,
+
+    /// アクセストークンで許可する操作の範囲
+    scope: AccessScopeResponse,
+}
+
+impl From<RefreshTokenOutput> for RefreshTokenResponse {
+    fn from(output: RefreshTokenOutput) -> Self {
+        let RefreshTokenOutput {
+            access_token,
+            refresh_token,
+            expires_in,
+            scope,
+        } = output;
+
+        RefreshTokenResponse {
+            access_token: Some(access_token),
+            refresh_token: Some(refresh_token),
+            expires_in,
+            scope: scope.into(),
+        }
+    }
+}
+
+impl Responder for RefreshTokenResponse {
+    type Body = BoxBody;
+
+    fn respond_to(mut self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        let mut response = HttpResponse::Ok();
+        move_tokens_to_cookies(
+            req,
+            &mut response,
+            &mut self.access_token,
+            &mut self.refresh_token,
+            self.expires_in,
+        );
+        response.json(self)
+    }
+}
@@ -0,0 +1,33 @@
+use actix_web::{HttpResponse, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::RequestMagicLinkRequest;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = RequestMagicLinkRequest,
+        responses(
+            (status = 202, description = "申請受付 (アカウントの存在有無にかかわらず同じレスポンスを返す)"),
+            (status = 400, description = "メールアドレスの形式が不正"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/magic-link")]
+#[tracing::instrument(skip(service))]
+pub async fn request_magic_link_handler(
+    service: web::Data<dyn AuthService>,
+    body: web::Json<RequestMagicLinkRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into();
+
+    service.request_magic_link(input).await?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+use usecase::auth::dto::RequestPasswordResetInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct RequestPasswordResetRequest {
+    #[cfg_attr(feature = "api-docs", schema(examples("user@example.com")))]
+    #[debug(skip)]
+    pub email: String,
+}
+
+impl From<RequestPasswordResetRequest> for RequestPasswordResetInput {
+    fn from(req: RequestPasswordResetRequest) -> Self {
+        Self { email: req.email }
+    }
+}
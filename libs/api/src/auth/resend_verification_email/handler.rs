@@ -0,0 +1,44 @@
+use actix_web::{HttpResponse, Responder, post, web};
+use usecase::{
+    auth::{dto::ResendVerificationEmailInput, service::AuthService},
+    shared::identity::Identity as _,
+};
+
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{
+    error::ApiError,
+    middleware::{AllowPendingVerification, AuthenticatedUserContext},
+};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        responses(
+            (status = 202, description = "確認メールの送信を受け付けた"),
+            (status = 401, description = "認証エラー"),
+            (status = 409, description = "確認済み、または確認メールを送信できない状態のユーザー"),
+            (status = 429, description = "再送回数の上限に達した"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = [])
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/verify-email/resend")]
+#[tracing::instrument(skip(service))]
+pub async fn resend_verification_email_handler(
+    user: AllowPendingVerification<AuthenticatedUserContext>,
+    service: web::Data<dyn AuthService>,
+) -> Result<impl Responder, ApiError> {
+    let input = ResendVerificationEmailInput {
+        user_id: user.actor_id(),
+    };
+
+    service.resend_verification_email(input).await?;
+
+    Ok(HttpResponse::Accepted().finish())
+}
@@ -1,10 +1,29 @@
 use actix_web::web;
 
-use super::{login, signup};
+use super::{
+    begin_oidc_login, begin_passkey_login, confirm_password_reset, jwks, login, login_magic_link,
+    login_mfa, login_oidc, login_passkey, logout, oauth_token, refresh, request_magic_link,
+    request_password_reset, resend_verification_email, signup, verify_email,
+};
 
 pub fn auth_config(cfg: &mut web::ServiceConfig) {
     cfg.service(signup::signup_handler)
-        .service(login::login_handler);
+        .service(login::login_handler)
+        .service(login_mfa::login_mfa_handler)
+        .service(begin_passkey_login::begin_passkey_login_handler)
+        .service(login_passkey::login_passkey_handler)
+        .service(begin_oidc_login::begin_oidc_login_handler)
+        .service(login_oidc::login_oidc_handler)
+        .service(request_magic_link::request_magic_link_handler)
+        .service(login_magic_link::login_magic_link_handler)
+        .service(refresh::refresh_handler)
+        .service(logout::logout_handler)
+        .service(verify_email::verify_email_handler)
+        .service(resend_verification_email::resend_verification_email_handler)
+        .service(request_password_reset::request_password_reset_handler)
+        .service(confirm_password_reset::confirm_password_reset_handler)
+        .service(jwks::jwks_handler)
+        .service(oauth_token::oauth_token_handler);
 }
 
 #[cfg(feature = "api-docs")]
@@ -22,14 +41,52 @@ pub mod openapi {
     #[openapi(
         paths(
             signup::signup_handler,
-            login::login_handler
+            login::login_handler,
+            login_mfa::login_mfa_handler,
+            begin_passkey_login::begin_passkey_login_handler,
+            login_passkey::login_passkey_handler,
+            begin_oidc_login::begin_oidc_login_handler,
+            login_oidc::login_oidc_handler,
+            request_magic_link::request_magic_link_handler,
+            login_magic_link::login_magic_link_handler,
+            refresh::refresh_handler,
+            logout::logout_handler,
+            verify_email::verify_email_handler,
+            resend_verification_email::resend_verification_email_handler,
+            request_password_reset::request_password_reset_handler,
+            confirm_password_reset::confirm_password_reset_handler,
+            jwks::jwks_handler,
+            oauth_token::oauth_token_handler
         ),
         components(
             schemas(
                 signup::SignupRequest,
                 signup::SignupResponse,
                 login::LoginRequest,
-                login::LoginResponse
+                login::LoginResponse,
+                login::LoginTokensResponse,
+                login::MfaRequiredResponse,
+                login::MfaMethodResponse,
+                login::AccessScopeResponse,
+                login_mfa::LoginMfaRequest,
+                begin_passkey_login::BeginPasskeyLoginRequest,
+                begin_passkey_login::BeginPasskeyLoginResponse,
+                login_passkey::LoginPasskeyRequest,
+                login_passkey::PasskeyAssertionRequest,
+                login_passkey::AuthenticatorAssertionResponseRequest,
+                begin_oidc_login::BeginOidcLoginResponse,
+                login_oidc::LoginOidcRequest,
+                request_magic_link::RequestMagicLinkRequest,
+                login_magic_link::LoginMagicLinkRequest,
+                refresh::RefreshTokenRequest,
+                refresh::RefreshTokenResponse,
+                verify_email::VerifyEmailRequest,
+                request_password_reset::RequestPasswordResetRequest,
+                confirm_password_reset::ConfirmPasswordResetRequest,
+                jwks::JwksResponse,
+                oauth_token::OAuthTokenRequest,
+                oauth_token::OAuthTokenResponse,
+                oauth_token::OAuthTokenErrorResponse
             )
         ),
         tags((
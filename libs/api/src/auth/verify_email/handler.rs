@@ -0,0 +1,35 @@
+use actix_web::{HttpResponse, Responder, post, web};
+use usecase::auth::service::AuthService;
+
+use super::VerifyEmailRequest;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = VerifyEmailRequest,
+        responses(
+            (status = 204, description = "メールアドレス確認成功"),
+            (status = 400, description = "トークンが不正・期限切れ、またはメールアドレスが一致しない"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "確認できない状態のユーザー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Auth.as_ref(),
+    )
+)]
+#[post("/auth/verify-email")]
+#[tracing::instrument(skip(service))]
+pub async fn verify_email_handler(
+    service: web::Data<dyn AuthService>,
+    body: web::Json<VerifyEmailRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into();
+
+    service.verify_email(input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct VerifyEmailRequest {
+    /// 確認メールのリンクに含まれるトークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJ0eXAiOiJlbWFpbC12ZXJpZmljYXRpb24rand0IiwiYWxnIjoiRWREU0EifQ..."))
+    )]
+    #[debug(skip)]
+    pub token: String,
+}
+
+impl From<VerifyEmailRequest> for usecase::auth::dto::VerifyEmailInput {
+    fn from(req: VerifyEmailRequest) -> Self {
+        Self { token: req.token }
+    }
+}
@@ -11,6 +11,9 @@ pub enum ApiError {
     #[error("権限が足りていません")]
     Forbidden,
 
+    #[error("CSRF トークンが一致しません")]
+    CsrfTokenMismatch,
+
     #[error(transparent)]
     UseCaseError(#[from] UseCaseError),
 }
@@ -20,12 +23,15 @@ impl ResponseError for ApiError {
         match self {
             ApiError::Unauthorized => StatusCode::UNAUTHORIZED,
             ApiError::Forbidden => StatusCode::FORBIDDEN,
+            ApiError::CsrfTokenMismatch => StatusCode::FORBIDDEN,
             ApiError::UseCaseError(usecase_error) => match usecase_error {
                 UseCaseError::InvalidInput(_validation_errors) => StatusCode::BAD_REQUEST,
                 UseCaseError::Unauthorized => StatusCode::UNAUTHORIZED,
                 UseCaseError::Forbidden { message: _ } => StatusCode::FORBIDDEN,
                 UseCaseError::NotFound => StatusCode::NOT_FOUND,
                 UseCaseError::Conflict { message: _ } => StatusCode::CONFLICT,
+                UseCaseError::TooManyRequests { message: _ } => StatusCode::TOO_MANY_REQUESTS,
+                UseCaseError::AccountRestricted { .. } => StatusCode::FORBIDDEN,
                 UseCaseError::Internal(_error) => StatusCode::INTERNAL_SERVER_ERROR,
             },
         }
@@ -41,6 +47,15 @@ impl ResponseError for ApiError {
                     "errors": errors,
                 }))
             }
+            // アカウントの状態による制限は、クライアントが再開・認証の案内に分岐できるよう理由のコードを含める
+            ApiError::UseCaseError(UseCaseError::AccountRestricted { reason, message }) => {
+                HttpResponse::Forbidden().json(serde_json::json!({
+                    "status": "error",
+                    "code": 403,
+                    "reason": reason.to_string(),
+                    "message": message,
+                }))
+            }
             // Internalエラー（予期せぬ技術的エラー）の場合
             ApiError::UseCaseError(UseCaseError::Internal(e)) => {
                 // 1. 構造化ログとしてエラー詳細を出力する
@@ -3,6 +3,7 @@ pub mod auth;
 pub mod error;
 pub mod middleware;
 pub mod routes;
+pub mod session_cookie;
 pub mod shared;
 pub mod user;
 
@@ -1,23 +1,192 @@
-use crate::error::ApiError;
+use crate::{error::ApiError, session_cookie::access_token_from_cookie};
 use actix_web::{FromRequest, HttpRequest, dev::Payload, web};
-use futures_util::future::{Ready, ready};
-use usecase::auth::token_service::TokenService;
-use usecase::shared::identity::{Identity, UserRoleData};
+use chrono::{DateTime, Utc};
+use futures_util::future::LocalBoxFuture;
+use usecase::auth::api_key_service::API_KEY_TOKEN_PREFIX;
+use usecase::auth::dto::{
+    ApiKeyPrincipal, AuthenticateApiKeyInput, AuthenticateServiceAccountInput,
+    ServiceAccountPrincipal, VerifyTokenEpochInput,
+};
+use usecase::auth::service::AuthService;
+use usecase::auth::token_revocation_store::TokenRevocationStore;
+use usecase::auth::token_service::{Claims, TokenService};
+use usecase::shared::identity::{
+    AccessScopeData, ActorKindData, ApiKeyScopeData, Identity, UserRoleData,
+};
+use usecase::usecase_error::{AccountRestrictionReason, UseCaseError};
 use uuid::Uuid;
 
-#[derive(derive_more::Debug, Clone, Copy)]
+/// アクセストークンを検証し、失効済みでないことを確認する
+///
+/// 利用停止・パスワードの変更などでユーザーのトークン世代番号が更新される前に発行されたトークンも拒否する
+async fn authenticate(
+    token_service: web::Data<dyn TokenService>,
+    token_revocation_store: web::Data<dyn TokenRevocationStore>,
+    auth_service: web::Data<dyn AuthService>,
+    token: Option<String>,
+) -> Result<Claims, ApiError> {
+    let token = token.ok_or(ApiError::Unauthorized)?;
+
+    let claims = token_service.verify_token(&token)?;
+
+    // ログアウト等で失効させたトークンは、有効期限内であっても拒否する
+    let is_revoked = token_revocation_store
+        .is_revoked(claims.token_id())
+        .await
+        .map_err(UseCaseError::from)?;
+    if is_revoked {
+        return Err(ApiError::Unauthorized);
+    }
+
+    // 終了させたセッションで発行されたトークンも拒否する
+    let is_session_revoked = token_revocation_store
+        .is_revoked(claims.session_id())
+        .await
+        .map_err(UseCaseError::from)?;
+    if is_session_revoked {
+        return Err(ApiError::Unauthorized);
+    }
+
+    auth_service
+        .verify_token_epoch(VerifyTokenEpochInput {
+            user_id: claims.user_id(),
+            token_epoch: claims.token_epoch(),
+        })
+        .await?;
+
+    Ok(claims)
+}
+
+/// メールアドレスの認証前に発行された、操作の範囲が制限されたトークンを拒否する
+fn ensure_full_access(scope: AccessScopeData) -> Result<(), ApiError> {
+    match scope {
+        AccessScopeData::Full => Ok(()),
+        AccessScopeData::PendingVerification => Err(UseCaseError::AccountRestricted {
+            reason: AccountRestrictionReason::EmailVerificationRequired,
+            message: "メールアドレスの認証を完了してください".to_string(),
+        }
+        .into()),
+    }
+}
+
+/// Authorization ヘッダーのサービスアカウントのアクセストークンを検証し、サービスアカウントの現在のロールを取得する
+async fn authenticate_service_account(
+    token_service: web::Data<dyn TokenService>,
+    token_revocation_store: web::Data<dyn TokenRevocationStore>,
+    auth_service: web::Data<dyn AuthService>,
+    token: Option<String>,
+) -> Result<ServiceAccountPrincipal, ApiError> {
+    let token = token.ok_or(ApiError::Unauthorized)?;
+
+    let claims = token_service.verify_service_account_token(&token)?;
+
+    let is_revoked = token_revocation_store
+        .is_revoked(claims.token_id())
+        .await
+        .map_err(UseCaseError::from)?;
+    if is_revoked {
+        return Err(ApiError::Unauthorized);
+    }
+
+    Ok(auth_service
+        .authenticate_service_account(AuthenticateServiceAccountInput {
+            service_account_id: claims.service_account_id(),
+        })
+        .await?)
+}
+
+/// Authorization ヘッダーの API キーを検証し、キーの発行者とスコープを取得する
+async fn authenticate_api_key(
+    auth_service: web::Data<dyn AuthService>,
+    token: Option<String>,
+) -> Result<ApiKeyPrincipal, ApiError> {
+    let api_key = token.ok_or(ApiError::Unauthorized)?;
+
+    Ok(auth_service
+        .authenticate_api_key(AuthenticateApiKeyInput { api_key })
+        .await?)
+}
+
+/// Bearer トークンが API キー (`pat_...`) であるか
+fn is_api_key(token: Option<&str>) -> bool {
+    token.is_some_and(|token| token.starts_with(API_KEY_TOKEN_PREFIX))
+}
+
+fn extract_auth_service(req: &HttpRequest) -> web::Data<dyn AuthService> {
+    req.app_data::<web::Data<dyn AuthService>>()
+        .expect("AuthService がアプリデータに登録されていません。 main.rs を確認してください。")
+        .clone()
+}
+
+fn extract_dependencies(
+    req: &HttpRequest,
+) -> (
+    web::Data<dyn TokenService>,
+    web::Data<dyn TokenRevocationStore>,
+) {
+    let token_service = req
+        .app_data::<web::Data<dyn TokenService>>()
+        .expect("TokenService がアプリデータに登録されていません。 main.rs を確認してください。")
+        .clone();
+
+    let token_revocation_store = req
+        .app_data::<web::Data<dyn TokenRevocationStore>>()
+        .expect(
+            "TokenRevocationStore がアプリデータに登録されていません。 main.rs を確認してください。",
+        )
+        .clone();
+
+    (token_service, token_revocation_store)
+}
+
+/// リクエストのアクセストークン
+///
+/// Authorization ヘッダーを優先し、ない場合は Cookie モードであれば Cookie のアクセストークンを使用する
+fn access_token(req: &HttpRequest) -> Result<Option<String>, ApiError> {
+    match bearer_token(req) {
+        Some(token) => Ok(Some(token.to_string())),
+        None => access_token_from_cookie(req),
+    }
+}
+
+fn bearer_token(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.strip_prefix("Bearer "))
+}
+
+/// 管理者として認証されたリクエストの主体
+///
+/// アクセストークンに加えて、管理者が発行した API キーと、管理者のロールを割り当てたサービスアカウントも受け付ける
+#[derive(derive_more::Debug, Clone)]
 pub struct AdminContext {
-    user_id: Uuid,
+    actor_id: Uuid,
+    actor_kind: ActorKindData,
+    /// API キーで認証された場合のスコープ
+    scopes: Option<Vec<ApiKeyScopeData>>,
 }
 
 impl Identity for AdminContext {
     fn actor_id(&self) -> Uuid {
-        self.user_id
+        self.actor_id
+    }
+
+    fn actor_kind(&self) -> ActorKindData {
+        self.actor_kind
     }
 
     fn actor_role(&self) -> UserRoleData {
         UserRoleData::Admin
     }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>> {
+        self.scopes.clone()
+    }
+
+    fn impersonator_id(&self) -> Option<Uuid> {
+        None
+    }
 }
 
 impl From<AdminContext> for Box<dyn Identity> {
@@ -28,40 +197,109 @@ impl From<AdminContext> for Box<dyn Identity> {
 
 impl FromRequest for AdminContext {
     type Error = ApiError;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        let token_service = req.app_data::<web::Data<dyn TokenService>>().expect(
-            "TokenService がアプリデータに登録されていません。 main.rs を確認してください。",
-        );
-
-        let auth_header = req
-            .headers()
-            .get("Authorization")
-            .and_then(|h| h.to_str().ok())
-            .and_then(|s| s.strip_prefix("Bearer "));
-
-        let token = match auth_header {
-            Some(t) => t,
-            None => return ready(Err(ApiError::Unauthorized)),
-        };
-
-        match token_service.verify_token(token) {
+        let (token_service, token_revocation_store) = extract_dependencies(req);
+        let token = access_token(req);
+        let auth_service = extract_auth_service(req);
+
+        Box::pin(async move {
+            let token = token?;
+            let (actor_id, actor_kind, role, scopes) = if is_api_key(token.as_deref()) {
+                let principal = authenticate_api_key(auth_service, token).await?;
+                (
+                    principal.user_id,
+                    ActorKindData::User,
+                    principal.user_role,
+                    Some(principal.scopes),
+                )
+            } else {
+                match authenticate(
+                    token_service.clone(),
+                    token_revocation_store.clone(),
+                    auth_service.clone(),
+                    token.clone(),
+                )
+                .await
+                {
+                    Ok(claims) => {
+                        ensure_full_access(claims.access_scope())?;
+                        // なりすましのトークンでは、管理者向けの操作を受け付けない
+                        if claims.impersonator_id().is_some() {
+                            return Err(ApiError::Forbidden);
+                        }
+                        (
+                            claims.user_id(),
+                            ActorKindData::User,
+                            claims.user_role(),
+                            None,
+                        )
+                    }
+                    // ユーザーのアクセストークンとして検証できない場合は、サービスアカウントのトークンとして検証する
+                    Err(_) => {
+                        let principal = authenticate_service_account(
+                            token_service,
+                            token_revocation_store,
+                            auth_service,
+                            token,
+                        )
+                        .await?;
+                        (
+                            principal.service_account_id,
+                            ActorKindData::ServiceAccount,
+                            principal.role,
+                            None,
+                        )
+                    }
+                }
+            };
+
             // ロールが Admin であることを確認
-            Ok(claims) if claims.user_role() == UserRoleData::Admin => ready(Ok(AdminContext {
-                user_id: claims.user_id(),
-            })),
-            // Admin でない場合は Forbidden を返す
-            Ok(_) => ready(Err(ApiError::Forbidden)),
-            Err(e) => ready(Err(ApiError::UseCaseError(e))),
-        }
+            if role != UserRoleData::Admin {
+                // Admin でない場合は Forbidden を返す
+                return Err(ApiError::Forbidden);
+            }
+
+            Ok(AdminContext {
+                actor_id,
+                actor_kind,
+                scopes,
+            })
+        })
     }
 }
 
+/// アクセストークンで認証されたリクエストの主体
+///
+/// 認証情報の管理やログアウトなど、本人による対話的なログインを前提とする操作に使用する
+///
+/// メールアドレスの認証前に発行されたトークンは拒否する。受け付ける場合は [`AllowPendingVerification`] を使用する
 #[derive(derive_more::Debug, Clone, Copy)]
 pub struct AuthenticatedUserContext {
     user_id: Uuid,
     user_role: UserRoleData,
+    access_scope: AccessScopeData,
+    token_id: Uuid,
+    session_id: Uuid,
+    token_expires_at: DateTime<Utc>,
+    impersonator_id: Option<Uuid>,
+}
+
+impl AuthenticatedUserContext {
+    /// リクエストに使用されたアクセストークンの ID (`jti`)
+    pub fn token_id(&self) -> Uuid {
+        self.token_id
+    }
+
+    /// アクセストークンの発行元となったログインセッションの ID
+    pub fn session_id(&self) -> Uuid {
+        self.session_id
+    }
+
+    pub fn token_expires_at(&self) -> DateTime<Utc> {
+        self.token_expires_at
+    }
 }
 
 impl Identity for AuthenticatedUserContext {
@@ -69,9 +307,25 @@ impl Identity for AuthenticatedUserContext {
         self.user_id
     }
 
+    fn actor_kind(&self) -> ActorKindData {
+        ActorKindData::User
+    }
+
     fn actor_role(&self) -> UserRoleData {
         self.user_role
     }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>> {
+        // メールアドレスの認証前は、ポリシーの判定でもプロフィールの閲覧のみに制限する
+        match self.access_scope {
+            AccessScopeData::Full => None,
+            AccessScopeData::PendingVerification => Some(vec![ApiKeyScopeData::ProfileRead]),
+        }
+    }
+
+    fn impersonator_id(&self) -> Option<Uuid> {
+        self.impersonator_id
+    }
 }
 
 impl From<AuthenticatedUserContext> for Box<dyn Identity> {
@@ -80,33 +334,204 @@ impl From<AuthenticatedUserContext> for Box<dyn Identity> {
     }
 }
 
+impl AuthenticatedUserContext {
+    /// 操作の範囲にかかわらずアクセストークンを検証する
+    fn authenticate(
+        req: &HttpRequest,
+    ) -> LocalBoxFuture<'static, Result<AuthenticatedUserContext, ApiError>> {
+        let (token_service, token_revocation_store) = extract_dependencies(req);
+        let token = access_token(req);
+        let auth_service = extract_auth_service(req);
+
+        Box::pin(async move {
+            // ロールにかかわらず検証を行う
+            let claims =
+                authenticate(token_service, token_revocation_store, auth_service, token?).await?;
+
+            Ok(AuthenticatedUserContext {
+                user_id: claims.user_id(),
+                user_role: claims.user_role(),
+                access_scope: claims.access_scope(),
+                token_id: claims.token_id(),
+                session_id: claims.session_id(),
+                token_expires_at: claims.expires_at(),
+                impersonator_id: claims.impersonator_id(),
+            })
+        })
+    }
+}
+
 impl FromRequest for AuthenticatedUserContext {
     type Error = ApiError;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        let token_service = req.app_data::<web::Data<dyn TokenService>>().expect(
-            "TokenService がアプリデータに登録されていません。 main.rs を確認してください。",
-        );
-
-        let auth_header = req
-            .headers()
-            .get("Authorization")
-            .and_then(|h| h.to_str().ok())
-            .and_then(|s| s.strip_prefix("Bearer "));
-
-        let token = match auth_header {
-            Some(t) => t,
-            None => return ready(Err(ApiError::Unauthorized)),
-        };
-
-        // ロールにかかわらず検証を行う
-        match token_service.verify_token(token) {
-            Ok(claims) => ready(Ok(AuthenticatedUserContext {
-                user_id: claims.user_id(),
-                user_role: claims.user_role(),
-            })),
-            Err(e) => ready(Err(ApiError::UseCaseError(e))),
+        let fut = AuthenticatedUserContext::authenticate(req);
+
+        Box::pin(async move {
+            let ctx = fut.await?;
+            ensure_full_access(ctx.access_scope)?;
+            Ok(ctx)
+        })
+    }
+}
+
+/// API キー (`Authorization: Bearer pat_...`) で認証されたリクエストの主体
+///
+/// ロールに基づくポリシーに加えて、キーに付与したスコープで操作が制限される
+#[derive(derive_more::Debug, Clone)]
+pub struct ApiKeyContext {
+    user_id: Uuid,
+    user_role: UserRoleData,
+    api_key_id: Uuid,
+    scopes: Vec<ApiKeyScopeData>,
+}
+
+impl ApiKeyContext {
+    /// リクエストに使用された API キーの ID
+    pub fn api_key_id(&self) -> Uuid {
+        self.api_key_id
+    }
+}
+
+impl Identity for ApiKeyContext {
+    fn actor_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn actor_kind(&self) -> ActorKindData {
+        ActorKindData::User
+    }
+
+    fn actor_role(&self) -> UserRoleData {
+        self.user_role
+    }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>> {
+        Some(self.scopes.clone())
+    }
+
+    fn impersonator_id(&self) -> Option<Uuid> {
+        None
+    }
+}
+
+impl From<ApiKeyContext> for Box<dyn Identity> {
+    fn from(ctx: ApiKeyContext) -> Self {
+        Box::new(ctx)
+    }
+}
+
+impl From<ApiKeyPrincipal> for ApiKeyContext {
+    fn from(principal: ApiKeyPrincipal) -> Self {
+        ApiKeyContext {
+            user_id: principal.user_id,
+            user_role: principal.user_role,
+            api_key_id: principal.api_key_id,
+            scopes: principal.scopes,
+        }
+    }
+}
+
+impl FromRequest for ApiKeyContext {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let token = bearer_token(req).map(str::to_string);
+        let auth_service = extract_auth_service(req);
+
+        Box::pin(async move {
+            if !is_api_key(token.as_deref()) {
+                return Err(ApiError::Unauthorized);
+            }
+
+            Ok(authenticate_api_key(auth_service, token).await?.into())
+        })
+    }
+}
+
+/// アクセストークン・API キーのいずれかで認証されたリクエストの主体
+#[derive(derive_more::Debug, Clone)]
+pub enum CallerContext {
+    User(AuthenticatedUserContext),
+    ApiKey(ApiKeyContext),
+}
+
+impl From<CallerContext> for Box<dyn Identity> {
+    fn from(ctx: CallerContext) -> Self {
+        match ctx {
+            CallerContext::User(ctx) => ctx.into(),
+            CallerContext::ApiKey(ctx) => ctx.into(),
+        }
+    }
+}
+
+impl FromRequest for CallerContext {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        if is_api_key(bearer_token(req)) {
+            let fut = ApiKeyContext::from_request(req, payload);
+            Box::pin(async move { Ok(CallerContext::ApiKey(fut.await?)) })
+        } else {
+            let fut = AuthenticatedUserContext::from_request(req, payload);
+            Box::pin(async move { Ok(CallerContext::User(fut.await?)) })
+        }
+    }
+}
+
+/// メールアドレスの認証前に発行された、操作の範囲が制限されたトークンも受け付ける抽出器
+///
+/// 確認メールの再送・ログアウト・自分のプロフィールの閲覧など、認証の完了に必要な操作にのみ使用する
+#[derive(derive_more::Debug, Clone)]
+pub struct AllowPendingVerification<T>(T);
+
+impl<T> AllowPendingVerification<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for AllowPendingVerification<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<AllowPendingVerification<T>> for Box<dyn Identity>
+where
+    T: Into<Box<dyn Identity>>,
+{
+    fn from(ctx: AllowPendingVerification<T>) -> Self {
+        ctx.0.into()
+    }
+}
+
+impl FromRequest for AllowPendingVerification<AuthenticatedUserContext> {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let fut = AuthenticatedUserContext::authenticate(req);
+        Box::pin(async move { Ok(AllowPendingVerification(fut.await?)) })
+    }
+}
+
+impl FromRequest for AllowPendingVerification<CallerContext> {
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        if is_api_key(bearer_token(req)) {
+            let fut = ApiKeyContext::from_request(req, payload);
+            Box::pin(async move { Ok(AllowPendingVerification(CallerContext::ApiKey(fut.await?))) })
+        } else {
+            let fut = AuthenticatedUserContext::authenticate(req);
+            Box::pin(async move { Ok(AllowPendingVerification(CallerContext::User(fut.await?))) })
         }
     }
 }
@@ -1,8 +1,9 @@
 use utoipa::OpenApi;
-use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
 
 use crate::admin::routes::{AdminApi, AdminApiTag};
 use crate::auth::routes::AuthApi;
+use crate::session_cookie::ACCESS_TOKEN_COOKIE;
 use crate::user::routes::UserApi;
 
 #[derive(OpenApi)]
@@ -31,6 +32,12 @@ impl utoipa::Modify for SecurityAddon {
                     .build(),
             ),
         );
+        // Cookie モード (`AUTH_MODE=cookie`) のデプロイメントで使用する Cookie 認証の定義
+        // 状態を変更するリクエストには、CSRF トークンの Cookie と同じ値の `X-CSRF-Token` ヘッダーが必要
+        components.add_security_scheme(
+            "cookie_auth",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(ACCESS_TOKEN_COOKIE))),
+        );
     }
 }
 
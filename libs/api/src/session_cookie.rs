@@ -0,0 +1,410 @@
+use actix_web::{
+    HttpRequest, HttpResponseBuilder,
+    cookie::{Cookie, SameSite, time::Duration},
+    http::Method,
+    web,
+};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand::RngCore as _;
+use subtle::ConstantTimeEq as _;
+
+use crate::error::ApiError;
+
+pub const ACCESS_TOKEN_COOKIE: &str = "access_token";
+pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+pub const CSRF_TOKEN_COOKIE: &str = "csrf_token";
+/// Cookie で認証した状態を変更するリクエストで、CSRF トークンの Cookie の値を送り返すヘッダー
+pub const CSRF_TOKEN_HEADER: &str = "X-CSRF-Token";
+
+// リフレッシュトークンは再発行・ログアウトのリクエストにのみ送信されるよう、パスを限定する
+const REFRESH_TOKEN_COOKIE_PATH: &str = "/auth";
+
+// CSRF トークンに含めるランダムなバイト数
+const CSRF_TOKEN_BYTES: usize = 32;
+
+/// アクセストークン・リフレッシュトークンをクライアントに受け渡す方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthMode {
+    /// レスポンスの本文で返し、`Authorization: Bearer` ヘッダーで受け取る
+    Header,
+    /// `HttpOnly` の Cookie で受け渡す (ブラウザ向け)
+    ///
+    /// Cookie で認証した状態を変更するリクエストには、ダブルサブミット方式の CSRF トークンを要求する
+    Cookie,
+}
+
+/// Cookie モードで発行する Cookie の `SameSite` 属性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum CookieSameSite {
+    Strict,
+    Lax,
+}
+
+impl From<CookieSameSite> for SameSite {
+    fn from(same_site: CookieSameSite) -> Self {
+        match same_site {
+            CookieSameSite::Strict => SameSite::Strict,
+            CookieSameSite::Lax => SameSite::Lax,
+        }
+    }
+}
+
+/// 認証情報の受け渡し方法の設定 (デプロイメントごとに選択する)
+///
+/// Cookie モードでも、API キーやサービスアカウントのトークンは `Authorization` ヘッダーで受け付ける
+#[derive(Debug, Clone)]
+pub struct AuthCookieConfig {
+    mode: AuthMode,
+    same_site: CookieSameSite,
+}
+
+impl AuthCookieConfig {
+    pub fn new(mode: AuthMode, same_site: CookieSameSite) -> Self {
+        Self { mode, same_site }
+    }
+
+    pub fn mode(&self) -> AuthMode {
+        self.mode
+    }
+
+    /// `HttpOnly; Secure` の Cookie を組み立てる
+    fn cookie(&self, name: &'static str, value: String, path: &'static str) -> Cookie<'static> {
+        Cookie::build(name, value)
+            .http_only(true)
+            .secure(true)
+            .same_site(self.same_site.into())
+            .path(path)
+            .finish()
+    }
+}
+
+pub(crate) fn extract_auth_cookie_config(req: &HttpRequest) -> web::Data<AuthCookieConfig> {
+    req.app_data::<web::Data<AuthCookieConfig>>()
+        .expect(
+            "AuthCookieConfig がアプリデータに登録されていません。 main.rs を確認してください。",
+        )
+        .clone()
+}
+
+/// Cookie モードの場合に、本文のトークンを取り出して Cookie に設定する
+///
+/// ログインのたびに CSRF トークンも発行し直す。CSRF トークンの Cookie はクライアントのスクリプトから読み取れるよう `HttpOnly` としない
+pub(crate) fn move_tokens_to_cookies(
+    req: &HttpRequest,
+    response: &mut HttpResponseBuilder,
+    access_token: &mut Option<String>,
+    refresh_token: &mut Option<String>,
+    expires_in: i64,
+) {
+    let config = extract_auth_cookie_config(req);
+    if config.mode() != AuthMode::Cookie {
+        return;
+    }
+
+    if let Some(access_token) = access_token.take() {
+        let mut cookie = config.cookie(ACCESS_TOKEN_COOKIE, access_token, "/");
+        cookie.set_max_age(Duration::seconds(expires_in));
+        response.cookie(cookie);
+    }
+
+    // リフレッシュトークンの有効期限はサーバー側で管理するため、ブラウザのセッションの間のみ保持させる
+    if let Some(refresh_token) = refresh_token.take() {
+        response.cookie(config.cookie(
+            REFRESH_TOKEN_COOKIE,
+            refresh_token,
+            REFRESH_TOKEN_COOKIE_PATH,
+        ));
+    }
+
+    let mut csrf_cookie = config.cookie(CSRF_TOKEN_COOKIE, generate_csrf_token(), "/");
+    csrf_cookie.set_http_only(false);
+    response.cookie(csrf_cookie);
+}
+
+/// Cookie モードの場合に、ログアウトしたクライアントの Cookie を削除する
+pub(crate) fn remove_token_cookies(req: &HttpRequest, response: &mut HttpResponseBuilder) {
+    let config = extract_auth_cookie_config(req);
+    if config.mode() != AuthMode::Cookie {
+        return;
+    }
+
+    for (name, path) in [
+        (ACCESS_TOKEN_COOKIE, "/"),
+        (REFRESH_TOKEN_COOKIE, REFRESH_TOKEN_COOKIE_PATH),
+        (CSRF_TOKEN_COOKIE, "/"),
+    ] {
+        let mut cookie = config.cookie(name, String::new(), path);
+        cookie.make_removal();
+        response.cookie(cookie);
+    }
+}
+
+/// Cookie モードの場合に、Cookie のアクセストークンを取得する
+///
+/// 状態を変更するリクエストでは、CSRF トークンを検証してから返す
+pub(crate) fn access_token_from_cookie(req: &HttpRequest) -> Result<Option<String>, ApiError> {
+    token_from_cookie(req, ACCESS_TOKEN_COOKIE)
+}
+
+/// Cookie モードの場合に、Cookie のリフレッシュトークンを取得する
+pub(crate) fn refresh_token_from_cookie(req: &HttpRequest) -> Result<Option<String>, ApiError> {
+    token_from_cookie(req, REFRESH_TOKEN_COOKIE)
+}
+
+fn token_from_cookie(req: &HttpRequest, name: &str) -> Result<Option<String>, ApiError> {
+    if extract_auth_cookie_config(req).mode() != AuthMode::Cookie {
+        return Ok(None);
+    }
+
+    let Some(cookie) = req.cookie(name) else {
+        return Ok(None);
+    };
+
+    if !is_safe_method(req.method()) {
+        verify_csrf_token(req)?;
+    }
+
+    Ok(Some(cookie.value().to_string()))
+}
+
+/// ダブルサブミット方式の検証: ヘッダーの値が CSRF トークンの Cookie の値と一致することを確認する
+///
+/// 他のオリジンのページは Cookie の値を読み取れないため、同じ値をヘッダーに設定できない
+fn verify_csrf_token(req: &HttpRequest) -> Result<(), ApiError> {
+    let cookie = req
+        .cookie(CSRF_TOKEN_COOKIE)
+        .ok_or(ApiError::CsrfTokenMismatch)?;
+    let header = req
+        .headers()
+        .get(CSRF_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .ok_or(ApiError::CsrfTokenMismatch)?;
+
+    if cookie.value().is_empty() || !bool::from(cookie.value().as_bytes().ct_eq(header.as_bytes()))
+    {
+        return Err(ApiError::CsrfTokenMismatch);
+    }
+
+    Ok(())
+}
+
+fn is_safe_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+fn generate_csrf_token() -> String {
+    let mut bytes = [0u8; CSRF_TOKEN_BYTES];
+    rand::rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{HttpResponse, Responder as _, cookie::Cookie, test::TestRequest};
+    use usecase::{
+        auth::dto::{LoginOutput, LoginTokens, RefreshTokenOutput},
+        shared::identity::AccessScopeData,
+    };
+
+    use super::*;
+    use crate::auth::{login::LoginResponse, refresh::RefreshTokenResponse};
+
+    const CSRF_TOKEN: &str = "csrf-token-value";
+
+    fn request(mode: AuthMode, method: Method) -> TestRequest {
+        TestRequest::default()
+            .method(method)
+            .app_data(web::Data::new(AuthCookieConfig::new(
+                mode,
+                CookieSameSite::Strict,
+            )))
+            .cookie(Cookie::new(ACCESS_TOKEN_COOKIE, "access"))
+            .cookie(Cookie::new(CSRF_TOKEN_COOKIE, CSRF_TOKEN))
+    }
+
+    fn refresh_output() -> RefreshTokenOutput {
+        RefreshTokenOutput {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_in: 900,
+            scope: AccessScopeData::Full,
+        }
+    }
+
+    async fn body_json(response: HttpResponse) -> serde_json::Value {
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    /// ログイン・再発行のレスポンスに設定された Cookie の属性を検証する
+    fn assert_token_cookies(response: &HttpResponse) {
+        let cookies: Vec<Cookie> = response.cookies().collect();
+        let find = |name: &str| cookies.iter().find(|c| c.name() == name).unwrap();
+
+        let access = find(ACCESS_TOKEN_COOKIE);
+        assert_eq!(access.value(), "access");
+        assert_eq!(access.http_only(), Some(true));
+        assert_eq!(access.secure(), Some(true));
+        assert_eq!(access.same_site(), Some(SameSite::Strict));
+        assert_eq!(access.path(), Some("/"));
+        assert_eq!(access.max_age(), Some(Duration::seconds(900)));
+
+        // リフレッシュトークンは再発行・ログアウトのリクエストにのみ送信させる
+        let refresh = find(REFRESH_TOKEN_COOKIE);
+        assert_eq!(refresh.value(), "refresh");
+        assert_eq!(refresh.http_only(), Some(true));
+        assert_eq!(refresh.secure(), Some(true));
+        assert_eq!(refresh.same_site(), Some(SameSite::Strict));
+        assert_eq!(refresh.path(), Some("/auth"));
+        assert_eq!(refresh.max_age(), None);
+
+        // CSRF トークンはスクリプトから読み取れるよう HttpOnly としない
+        let csrf = find(CSRF_TOKEN_COOKIE);
+        assert!(!csrf.value().is_empty());
+        assert_ne!(csrf.http_only(), Some(true));
+        assert_eq!(csrf.secure(), Some(true));
+        assert_eq!(csrf.same_site(), Some(SameSite::Strict));
+        assert_eq!(csrf.path(), Some("/"));
+    }
+
+    #[test]
+    fn test_post_with_matching_csrf_header_is_allowed() {
+        let req = request(AuthMode::Cookie, Method::POST)
+            .insert_header((CSRF_TOKEN_HEADER, CSRF_TOKEN))
+            .to_http_request();
+
+        assert_eq!(
+            access_token_from_cookie(&req).unwrap().as_deref(),
+            Some("access")
+        );
+    }
+
+    #[test]
+    fn test_post_without_csrf_header_is_rejected() {
+        let req = request(AuthMode::Cookie, Method::POST).to_http_request();
+
+        assert!(matches!(
+            access_token_from_cookie(&req),
+            Err(ApiError::CsrfTokenMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_post_with_mismatched_csrf_header_is_rejected() {
+        let req = request(AuthMode::Cookie, Method::POST)
+            .insert_header((CSRF_TOKEN_HEADER, "forged"))
+            .to_http_request();
+
+        assert!(matches!(
+            access_token_from_cookie(&req),
+            Err(ApiError::CsrfTokenMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_post_without_csrf_cookie_is_rejected() {
+        // CSRF トークンの Cookie がなければ、空のヘッダーとも一致させない
+        let req = TestRequest::post()
+            .app_data(web::Data::new(AuthCookieConfig::new(
+                AuthMode::Cookie,
+                CookieSameSite::Strict,
+            )))
+            .cookie(Cookie::new(ACCESS_TOKEN_COOKIE, "access"))
+            .insert_header((CSRF_TOKEN_HEADER, ""))
+            .to_http_request();
+
+        assert!(matches!(
+            access_token_from_cookie(&req),
+            Err(ApiError::CsrfTokenMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_safe_method_without_csrf_header_is_allowed() {
+        for method in [Method::GET, Method::HEAD, Method::OPTIONS] {
+            let req = request(AuthMode::Cookie, method).to_http_request();
+
+            assert_eq!(
+                access_token_from_cookie(&req).unwrap().as_deref(),
+                Some("access")
+            );
+        }
+    }
+
+    #[test]
+    fn test_header_mode_ignores_cookies() {
+        // Header モードでは Cookie を読まないため、CSRF トークンも検証しない
+        let req = request(AuthMode::Header, Method::POST).to_http_request();
+
+        assert_eq!(access_token_from_cookie(&req).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cookie_mode_without_token_cookie_returns_none() {
+        let req = request(AuthMode::Cookie, Method::POST).to_http_request();
+
+        assert_eq!(refresh_token_from_cookie(&req).unwrap(), None);
+    }
+
+    #[actix_web::test]
+    async fn test_login_sets_token_cookies() {
+        let req = request(AuthMode::Cookie, Method::POST).to_http_request();
+        let output = LoginOutput::Authenticated(LoginTokens {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_in: 900,
+            scope: AccessScopeData::Full,
+        });
+
+        let response = LoginResponse::from(output).respond_to(&req);
+
+        assert_token_cookies(&response);
+        let body = body_json(response).await;
+        assert!(body.get("access_token").is_none());
+        assert!(body.get("refresh_token").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_refresh_sets_token_cookies() {
+        let req = request(AuthMode::Cookie, Method::POST).to_http_request();
+
+        let response = RefreshTokenResponse::from(refresh_output()).respond_to(&req);
+
+        assert_token_cookies(&response);
+        let body = body_json(response).await;
+        assert!(body.get("access_token").is_none());
+        assert!(body.get("refresh_token").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_header_mode_returns_tokens_in_body() {
+        let req = request(AuthMode::Header, Method::POST).to_http_request();
+
+        let response = RefreshTokenResponse::from(refresh_output()).respond_to(&req);
+
+        assert_eq!(response.cookies().count(), 0);
+        let body = body_json(response).await;
+        assert_eq!(body["access_token"], "access");
+        assert_eq!(body["refresh_token"], "refresh");
+    }
+
+    #[test]
+    fn test_remove_token_cookies_expires_all_cookies() {
+        let req = request(AuthMode::Cookie, Method::POST).to_http_request();
+
+        let mut builder = HttpResponse::Ok();
+        remove_token_cookies(&req, &mut builder);
+        let response = builder.finish();
+
+        let cookies: Vec<Cookie> = response.cookies().collect();
+        assert_eq!(cookies.len(), 3);
+        for cookie in cookies {
+            assert_eq!(cookie.value(), "");
+            assert_eq!(cookie.max_age(), Some(Duration::ZERO));
+        }
+    }
+}
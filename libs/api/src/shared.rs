@@ -1,3 +1,14 @@
+use actix_web::{HttpRequest, http::header};
+
+/// セッションの記録に使用する、リクエストの `User-Agent` ヘッダーの値
+pub(crate) fn user_agent(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get(header::USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
 #[macro_export]
 macro_rules! impl_responder_for {
     ($target: ty, $status: expr) => {
@@ -0,0 +1,45 @@
+use actix_web::{Responder, post, web};
+use usecase::user::{dto::BeginMfaEnrollmentInput, service::UserService};
+use uuid::Uuid;
+
+use super::BeginMfaEnrollmentResponse;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "2段階認証を登録するユーザーID")
+        ),
+        responses(
+            (status = 200, description = "2段階認証の登録開始。認証コードによる確認で登録が確定する", body = BeginMfaEnrollmentResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "2段階認証が既に有効"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/{user_id}/mfa")]
+#[tracing::instrument(skip(service))]
+pub async fn begin_mfa_enrollment_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = BeginMfaEnrollmentInput {
+        target_id: *user_id,
+    };
+
+    let output = service.begin_mfa_enrollment(user.into(), input).await?;
+
+    Ok(BeginMfaEnrollmentResponse::from(output))
+}
@@ -0,0 +1,41 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::BeginMfaEnrollmentOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct BeginMfaEnrollmentResponse {
+    /// 認証アプリに手入力する場合の共有シークレット (Base32)
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP"))
+    )]
+    pub secret: String,
+
+    /// 認証アプリに QR コードで読み込ませるプロビジョニング URI
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples(
+            "otpauth://totp/MyApp%3Auser%40example%2Ecom?secret=JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP&issuer=MyApp&algorithm=SHA1&digits=6&period=30"
+        ))
+    )]
+    pub provisioning_uri: String,
+}
+
+impl From<BeginMfaEnrollmentOutput> for BeginMfaEnrollmentResponse {
+    fn from(output: BeginMfaEnrollmentOutput) -> Self {
+        let BeginMfaEnrollmentOutput {
+            secret,
+            provisioning_uri,
+        } = output;
+
+        BeginMfaEnrollmentResponse {
+            secret,
+            provisioning_uri,
+        }
+    }
+}
+
+crate::impl_responder_for!(BeginMfaEnrollmentResponse, StatusCode::OK);
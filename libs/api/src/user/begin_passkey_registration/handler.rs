@@ -0,0 +1,46 @@
+use actix_web::{Responder, post, web};
+use usecase::user::{dto::BeginPasskeyRegistrationInput, service::UserService};
+use uuid::Uuid;
+
+use super::BeginPasskeyRegistrationResponse;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "パスキーを登録するユーザーID")
+        ),
+        responses(
+            (status = 200, description = "パスキーの登録開始", body = BeginPasskeyRegistrationResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/{user_id}/passkeys/options")]
+#[tracing::instrument(skip(service))]
+pub async fn begin_passkey_registration_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = BeginPasskeyRegistrationInput {
+        target_id: *user_id,
+    };
+
+    let output = service
+        .begin_passkey_registration(user.into(), input)
+        .await?;
+
+    Ok(BeginPasskeyRegistrationResponse::from(output))
+}
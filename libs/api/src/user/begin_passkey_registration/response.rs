@@ -0,0 +1,40 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::{
+    auth::webauthn_service::PublicKeyCredentialCreationOptions,
+    user::dto::BeginPasskeyRegistrationOutput,
+};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct BeginPasskeyRegistrationResponse {
+    /// `/users/{user_id}/passkeys` に認証器の応答とともに送信するセレモニートークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6IndlYmF1dGhuLWNlcmVtb255K2p3dCJ9..."))
+    )]
+    pub ceremony_token: String,
+
+    /// `PublicKeyCredential.parseCreationOptionsFromJSON()` で読み込み、
+    /// `navigator.credentials.create()` に渡すオプション
+    #[cfg_attr(feature = "api-docs", schema(value_type = Object))]
+    pub public_key: PublicKeyCredentialCreationOptions,
+}
+
+impl From<BeginPasskeyRegistrationOutput> for BeginPasskeyRegistrationResponse {
+    fn from(output: BeginPasskeyRegistrationOutput) -> Self {
+        let BeginPasskeyRegistrationOutput {
+            ceremony_token,
+            options,
+        } = output;
+
+        BeginPasskeyRegistrationResponse {
+            ceremony_token,
+            public_key: options,
+        }
+    }
+}
+
+crate::impl_responder_for!(BeginPasskeyRegistrationResponse, StatusCode::OK);
@@ -0,0 +1,45 @@
+use actix_web::{HttpResponse, Responder, patch, web};
+use usecase::user::service::UserService;
+
+use super::ChangePasswordRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        patch,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "更新対象のユーザーID")
+        ),
+        request_body = ChangePasswordRequest,
+        responses(
+            (status = 204, description = "パスワード変更成功"),
+            (status = 400, description = "現在のパスワードが正しくない、または新しいパスワードの形式が不正"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "パスワードを変更できない状態のユーザー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[patch("/users/{user_id}/password")]
+#[tracing::instrument(skip(service))]
+pub async fn change_password_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<uuid::Uuid>,
+    service: web::Data<dyn UserService>,
+    body: web::Json<ChangePasswordRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    service.change_password(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use usecase::user::dto::ChangePasswordInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct ChangePasswordRequest {
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("password123")))]
+    pub current_password: String,
+
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("newpassword123")))]
+    pub new_password: String,
+}
+
+impl ChangePasswordRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> ChangePasswordInput {
+        ChangePasswordInput {
+            target_id,
+            current_password: self.current_password,
+            new_password: self.new_password,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use actix_web::{Responder, post, web};
+use usecase::user::service::UserService;
+use uuid::Uuid;
+
+use super::{ConfirmMfaEnrollmentRequest, ConfirmMfaEnrollmentResponse};
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "2段階認証を登録するユーザーID")
+        ),
+        request_body = ConfirmMfaEnrollmentRequest,
+        responses(
+            (status = 200, description = "2段階認証の登録完了", body = ConfirmMfaEnrollmentResponse),
+            (status = 400, description = "認証コードが正しくない"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "2段階認証の登録が開始されていない、または既に有効"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/{user_id}/mfa/confirm")]
+#[tracing::instrument(skip(service))]
+pub async fn confirm_mfa_enrollment_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+    body: web::Json<ConfirmMfaEnrollmentRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    let output = service.confirm_mfa_enrollment(user.into(), input).await?;
+
+    Ok(ConfirmMfaEnrollmentResponse::from(output))
+}
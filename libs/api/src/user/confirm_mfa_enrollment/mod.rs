@@ -0,0 +1,7 @@
+pub mod handler;
+pub mod request;
+pub mod response;
+
+pub use handler::*;
+pub(crate) use request::*;
+pub(crate) use response::*;
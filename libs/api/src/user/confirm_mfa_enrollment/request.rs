@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use usecase::user::dto::ConfirmMfaEnrollmentInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct ConfirmMfaEnrollmentRequest {
+    /// 認証アプリに表示された 6 桁の認証コード
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("123456")))]
+    pub code: String,
+}
+
+impl ConfirmMfaEnrollmentRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> ConfirmMfaEnrollmentInput {
+        ConfirmMfaEnrollmentInput {
+            target_id,
+            code: self.code,
+        }
+    }
+}
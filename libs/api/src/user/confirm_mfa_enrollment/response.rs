@@ -0,0 +1,23 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::ConfirmMfaEnrollmentOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ConfirmMfaEnrollmentResponse {
+    /// 使い捨てのリカバリーコード。この応答でのみ表示される
+    #[cfg_attr(feature = "api-docs", schema(examples(json!(["k7mqp-x2hcz", "r9tnd-4wfaj"]))))]
+    pub recovery_codes: Vec<String>,
+}
+
+impl From<ConfirmMfaEnrollmentOutput> for ConfirmMfaEnrollmentResponse {
+    fn from(output: ConfirmMfaEnrollmentOutput) -> Self {
+        let ConfirmMfaEnrollmentOutput { recovery_codes } = output;
+
+        ConfirmMfaEnrollmentResponse { recovery_codes }
+    }
+}
+
+crate::impl_responder_for!(ConfirmMfaEnrollmentResponse, StatusCode::OK);
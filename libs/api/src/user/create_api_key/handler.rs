@@ -0,0 +1,40 @@
+use actix_web::{Responder, post, web};
+use usecase::{shared::identity::Identity as _, user::service::UserService};
+
+use super::{CreateApiKeyRequest, CreateApiKeyResponse};
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = CreateApiKeyRequest,
+        responses(
+            (status = 201, description = "API キーの発行成功 (キーの平文はこの応答でのみ取得できる)", body = CreateApiKeyResponse),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/me/api-keys")]
+#[tracing::instrument(skip(service))]
+pub async fn create_api_key_handler(
+    user: AuthenticatedUserContext,
+    service: web::Data<dyn UserService>,
+    body: web::Json<CreateApiKeyRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(user.actor_id());
+
+    let output = service.create_api_key(user.into(), input).await?;
+
+    Ok(CreateApiKeyResponse::from(output))
+}
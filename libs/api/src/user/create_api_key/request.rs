@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use usecase::{shared::identity::ApiKeyScopeData, user::dto::CreateApiKeyInput};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct CreateApiKeyRequest {
+    /// 一覧で見分けるためのキーの名前
+    #[cfg_attr(feature = "api-docs", schema(examples("GitHub Actions")))]
+    pub name: String,
+
+    /// キーに許可する操作の範囲
+    pub scopes: Vec<ApiKeyScopeRequest>,
+
+    /// 発行してから失効するまでの日数
+    #[cfg_attr(feature = "api-docs", schema(examples(90)))]
+    pub expires_in_days: i64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub enum ApiKeyScopeRequest {
+    /// プロフィールの閲覧
+    #[serde(rename = "profile:read")]
+    ProfileRead,
+    /// 自分のプロフィール・メールアドレスの変更
+    #[serde(rename = "profile:write")]
+    ProfileWrite,
+    /// 管理者によるユーザー情報の閲覧
+    #[serde(rename = "admin:read")]
+    AdminRead,
+    /// 管理者によるユーザーの操作
+    #[serde(rename = "admin:write")]
+    AdminWrite,
+}
+
+impl From<ApiKeyScopeRequest> for ApiKeyScopeData {
+    fn from(scope: ApiKeyScopeRequest) -> Self {
+        match scope {
+            ApiKeyScopeRequest::ProfileRead => ApiKeyScopeData::ProfileRead,
+            ApiKeyScopeRequest::ProfileWrite => ApiKeyScopeData::ProfileWrite,
+            ApiKeyScopeRequest::AdminRead => ApiKeyScopeData::AdminRead,
+            ApiKeyScopeRequest::AdminWrite => ApiKeyScopeData::AdminWrite,
+        }
+    }
+}
+
+impl CreateApiKeyRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> CreateApiKeyInput {
+        CreateApiKeyInput {
+            target_id,
+            name: self.name,
+            scopes: self.scopes.into_iter().map(Into::into).collect(),
+            expires_in_days: self.expires_in_days,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use actix_web::http::StatusCode;
+use serde::Serialize;
+use usecase::user::dto::CreateApiKeyOutput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+use crate::user::list_api_keys::ApiKeyResponse;
+
+#[derive(derive_more::Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct CreateApiKeyResponse {
+    /// API キーの平文。`Authorization: Bearer <api_key>` として送信する
+    ///
+    /// サーバー側には保存しないため、この応答でのみ取得できる
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("pat_3f9a1c0b7d2e4a68_Zk3yQy1m0b7vVnq2k7bS0Jm5uQy1l9rTg8cXo2dHf4E"))
+    )]
+    #[debug(skip)]
+    pub api_key: String,
+
+    #[serde(flatten)]
+    pub details: ApiKeyResponse,
+}
+
+impl From<CreateApiKeyOutput> for CreateApiKeyResponse {
+    fn from(output: CreateApiKeyOutput) -> Self {
+        CreateApiKeyResponse {
+            api_key: output.api_key,
+            details: output.details.into(),
+        }
+    }
+}
+
+crate::impl_responder_for!(CreateApiKeyResponse, StatusCode::CREATED);
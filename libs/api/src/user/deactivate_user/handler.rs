@@ -0,0 +1,49 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, post, web};
+use usecase::user::service::UserService;
+
+use super::DeactivateUserRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "退会するユーザーのID")
+        ),
+        request_body = DeactivateUserRequest,
+        responses(
+            (status = 204, description = "退会成功。発行済みのトークンは無効になる"),
+            (status = 400, description = "パスワードが正しくない"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "退会できない状態のユーザー"),
+            (status = 429, description = "パスワードの誤りが多すぎるため一時的にロックされている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/{user_id}/deactivate")]
+#[tracing::instrument(skip(req, service))]
+pub async fn deactivate_user_handler(
+    req: HttpRequest,
+    user: AuthenticatedUserContext,
+    user_id: web::Path<uuid::Uuid>,
+    service: web::Data<dyn UserService>,
+    body: web::Json<DeactivateUserRequest>,
+) -> Result<impl Responder, ApiError> {
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body.into_inner().into_input(*user_id, ip_address);
+
+    service.deactivate_user(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
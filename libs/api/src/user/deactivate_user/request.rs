@@ -0,0 +1,27 @@
+use serde::Deserialize;
+use usecase::user::dto::DeactivateUserInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct DeactivateUserRequest {
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("password123")))]
+    pub password: String,
+}
+
+impl DeactivateUserRequest {
+    pub(super) fn into_input(
+        self,
+        target_id: Uuid,
+        ip_address: Option<String>,
+    ) -> DeactivateUserInput {
+        DeactivateUserInput {
+            target_id,
+            password: self.password,
+            ip_address,
+        }
+    }
+}
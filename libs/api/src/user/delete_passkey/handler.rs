@@ -0,0 +1,46 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::user::{dto::DeletePasskeyInput, service::UserService};
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "パスキーを削除するユーザーID"),
+            ("passkey_id" = uuid::Uuid, Path, description = "削除するパスキーのID")
+        ),
+        responses(
+            (status = 204, description = "パスキーの削除成功"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "パスキーが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[delete("/users/{user_id}/passkeys/{passkey_id}")]
+#[tracing::instrument(skip(service))]
+pub async fn delete_passkey_handler(
+    user: AuthenticatedUserContext,
+    path: web::Path<(Uuid, Uuid)>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let (target_id, passkey_id) = path.into_inner();
+    let input = DeletePasskeyInput {
+        target_id,
+        passkey_id,
+    };
+
+    service.delete_passkey(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
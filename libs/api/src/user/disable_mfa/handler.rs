@@ -0,0 +1,46 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::user::service::UserService;
+use uuid::Uuid;
+
+use super::DisableMfaRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "2段階認証を解除するユーザーID")
+        ),
+        request_body = DisableMfaRequest,
+        responses(
+            (status = 204, description = "2段階認証の解除成功"),
+            (status = 400, description = "認証コードが正しくない"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "2段階認証が有効になっていない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[delete("/users/{user_id}/mfa")]
+#[tracing::instrument(skip(service))]
+pub async fn disable_mfa_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+    body: web::Json<DisableMfaRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    service.disable_mfa(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use usecase::user::dto::DisableMfaInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct DisableMfaRequest {
+    /// 認証アプリに表示された 6 桁の認証コード、またはリカバリーコード
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("123456")))]
+    pub code: String,
+}
+
+impl DisableMfaRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> DisableMfaInput {
+        DisableMfaInput {
+            target_id,
+            code: self.code,
+        }
+    }
+}
@@ -4,7 +4,10 @@ use usecase::user::service::UserService;
 use super::{GetOwnProfileRequest, GetOwnProfileResponse};
 #[cfg(feature = "api-docs")]
 use crate::openapi::OpenApiTag;
-use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+use crate::{
+    error::ApiError,
+    middleware::{AllowPendingVerification, CallerContext},
+};
 
 #[cfg_attr(
     feature = "api-docs",
@@ -30,7 +33,7 @@ use crate::{error::ApiError, middleware::AuthenticatedUserContext};
 #[get("/users/me")]
 #[tracing::instrument(skip(service))]
 pub async fn get_own_profile_handler(
-    user: AuthenticatedUserContext,
+    user: AllowPendingVerification<CallerContext>,
     query: web::Query<GetOwnProfileRequest>,
     service: web::Data<dyn UserService>,
 ) -> Result<impl Responder, ApiError> {
@@ -5,7 +5,10 @@ use uuid::Uuid;
 use super::{GetProfileRequest, GetProfileResponse};
 #[cfg(feature = "api-docs")]
 use crate::openapi::OpenApiTag;
-use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+use crate::{
+    error::ApiError,
+    middleware::{AllowPendingVerification, CallerContext},
+};
 
 #[cfg_attr(
     feature = "api-docs",
@@ -32,7 +35,7 @@ use crate::{error::ApiError, middleware::AuthenticatedUserContext};
 #[get("/users/{user_id}/profile")]
 #[tracing::instrument(skip(service))]
 pub async fn get_public_profile_handler(
-    user: AuthenticatedUserContext,
+    user: AllowPendingVerification<CallerContext>,
     user_id: web::Path<Uuid>,
     query: web::Query<GetProfileRequest>,
     service: web::Data<dyn UserService>,
@@ -0,0 +1,41 @@
+use actix_web::{Responder, get, web};
+use usecase::{
+    shared::identity::Identity as _,
+    user::{dto::ListApiKeysInput, service::UserService},
+};
+
+use super::ListApiKeysResponse;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        get,
+        responses(
+            (status = 200, description = "発行済みの API キーの一覧", body = ListApiKeysResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[get("/users/me/api-keys")]
+#[tracing::instrument(skip(service))]
+pub async fn list_api_keys_handler(
+    user: AuthenticatedUserContext,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = ListApiKeysInput {
+        target_id: user.actor_id(),
+    };
+
+    let output = service.list_api_keys(user.into(), input).await?;
+
+    Ok(ListApiKeysResponse::from(output))
+}
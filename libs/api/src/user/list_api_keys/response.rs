@@ -0,0 +1,86 @@
+use actix_web::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::user::dto::{ApiKeyItem, ListApiKeysOutput};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ListApiKeysResponse {
+    pub api_keys: Vec<ApiKeyResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ApiKeyResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019ca5d2-7e41-7b8a-9c3d-2f1e0a9b8c7d"))
+    )]
+    pub api_key_id: Uuid,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("GitHub Actions")))]
+    pub name: String,
+
+    /// キーの平文の先頭に含まれる識別子
+    #[cfg_attr(feature = "api-docs", schema(examples("3f9a1c0b7d2e4a68")))]
+    pub prefix: String,
+
+    #[cfg_attr(feature = "api-docs", schema(examples(json!(["profile:read"]))))]
+    pub scopes: Vec<String>,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-05-31T10:42:15Z"))
+    )]
+    pub expires_at: DateTime<Utc>,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-03-02T10:42:15Z"))
+    )]
+    pub created_at: DateTime<Utc>,
+
+    /// 最後に使用した日時。未使用の場合は `null`
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = Option<String>, format = DateTime, examples("2026-03-03T08:00:00Z"))
+    )]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKeyItem> for ApiKeyResponse {
+    fn from(item: ApiKeyItem) -> Self {
+        let ApiKeyItem {
+            api_key_id,
+            name,
+            prefix,
+            scopes,
+            expires_at,
+            created_at,
+            last_used_at,
+        } = item;
+
+        ApiKeyResponse {
+            api_key_id,
+            name,
+            prefix,
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+            expires_at,
+            created_at,
+            last_used_at,
+        }
+    }
+}
+
+impl From<ListApiKeysOutput> for ListApiKeysResponse {
+    fn from(output: ListApiKeysOutput) -> Self {
+        ListApiKeysResponse {
+            api_keys: output.api_keys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+crate::impl_responder_for!(ListApiKeysResponse, StatusCode::OK);
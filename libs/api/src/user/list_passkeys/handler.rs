@@ -0,0 +1,43 @@
+use actix_web::{Responder, get, web};
+use usecase::user::{dto::ListPasskeysInput, service::UserService};
+use uuid::Uuid;
+
+use super::ListPasskeysResponse;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        get,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "パスキーを一覧するユーザーID")
+        ),
+        responses(
+            (status = 200, description = "登録済みのパスキーの一覧", body = ListPasskeysResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[get("/users/{user_id}/passkeys")]
+#[tracing::instrument(skip(service))]
+pub async fn list_passkeys_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = ListPasskeysInput {
+        target_id: *user_id,
+    };
+
+    let output = service.list_passkeys(user.into(), input).await?;
+
+    Ok(ListPasskeysResponse::from(output))
+}
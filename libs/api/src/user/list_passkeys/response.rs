@@ -0,0 +1,68 @@
+use actix_web::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::user::dto::{ListPasskeysOutput, PasskeyItem};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ListPasskeysResponse {
+    pub passkeys: Vec<PasskeyResponse>,
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct PasskeyResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019c8a4e-3b1f-7c2d-9e5a-4f6b7c8d9e0f"))
+    )]
+    pub passkey_id: Uuid,
+
+    #[cfg_attr(feature = "api-docs", schema(examples("MacBook の Touch ID")))]
+    pub name: String,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-02-23T14:05:27Z"))
+    )]
+    pub created_at: DateTime<Utc>,
+
+    /// 最後にログインに使用した日時。未使用の場合は `null`
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = Option<String>, format = DateTime, examples("2026-02-24T09:30:00Z"))
+    )]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<PasskeyItem> for PasskeyResponse {
+    fn from(item: PasskeyItem) -> Self {
+        let PasskeyItem {
+            passkey_id,
+            name,
+            created_at,
+            last_used_at,
+        } = item;
+
+        PasskeyResponse {
+            passkey_id,
+            name,
+            created_at,
+            last_used_at,
+        }
+    }
+}
+
+impl From<ListPasskeysOutput> for ListPasskeysResponse {
+    fn from(output: ListPasskeysOutput) -> Self {
+        ListPasskeysResponse {
+            passkeys: output.passkeys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+crate::impl_responder_for!(ListPasskeysResponse, StatusCode::OK);
+crate::impl_responder_for!(PasskeyResponse, StatusCode::OK);
@@ -0,0 +1,42 @@
+use actix_web::{Responder, get, web};
+use usecase::{
+    shared::identity::Identity as _,
+    user::{dto::ListSessionsInput, service::UserService},
+};
+
+use super::ListSessionsResponse;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        get,
+        responses(
+            (status = 200, description = "ログイン中のセッションの一覧", body = ListSessionsResponse),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[get("/users/me/sessions")]
+#[tracing::instrument(skip(service))]
+pub async fn list_sessions_handler(
+    user: AuthenticatedUserContext,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = ListSessionsInput {
+        target_id: user.actor_id(),
+        current_session_id: Some(user.session_id()),
+    };
+
+    let output = service.list_sessions(user.into(), input).await?;
+
+    Ok(ListSessionsResponse::from(output))
+}
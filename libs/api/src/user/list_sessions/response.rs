@@ -0,0 +1,90 @@
+use actix_web::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use usecase::user::dto::{ListSessionsOutput, SessionItem};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct ListSessionsResponse {
+    pub sessions: Vec<SessionResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub(crate) struct SessionResponse {
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("019ca5d2-7e41-7b8a-9c3d-2f1e0a9b8c7d"))
+    )]
+    pub session_id: Uuid,
+
+    /// User-Agent から判別したブラウザーと OS
+    #[cfg_attr(feature = "api-docs", schema(examples("Chrome (Windows)")))]
+    pub device_label: String,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36"
+        ))
+    )]
+    pub user_agent: Option<String>,
+
+    /// ログイン時の接続元 IP アドレス
+    #[cfg_attr(feature = "api-docs", schema(examples("203.0.113.10")))]
+    pub ip_address: Option<String>,
+
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-03-02T10:42:15Z"))
+    )]
+    pub created_at: DateTime<Utc>,
+
+    /// 最後にトークンを更新した日時
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(value_type = String, format = DateTime, examples("2026-03-03T08:00:00Z"))
+    )]
+    pub last_seen_at: DateTime<Utc>,
+
+    /// このリクエストに使用したセッションか
+    #[cfg_attr(feature = "api-docs", schema(examples(true)))]
+    pub current: bool,
+}
+
+impl From<SessionItem> for SessionResponse {
+    fn from(item: SessionItem) -> Self {
+        let SessionItem {
+            session_id,
+            device_label,
+            user_agent,
+            ip_address,
+            created_at,
+            last_seen_at,
+            current,
+        } = item;
+
+        SessionResponse {
+            session_id,
+            device_label,
+            user_agent,
+            ip_address,
+            created_at,
+            last_seen_at,
+            current,
+        }
+    }
+}
+
+impl From<ListSessionsOutput> for ListSessionsResponse {
+    fn from(output: ListSessionsOutput) -> Self {
+        ListSessionsResponse {
+            sessions: output.sessions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+crate::impl_responder_for!(ListSessionsResponse, StatusCode::OK);
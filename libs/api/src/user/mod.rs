@@ -1,5 +1,20 @@
+pub mod begin_mfa_enrollment;
+pub mod begin_passkey_registration;
+pub mod change_password;
+pub mod confirm_mfa_enrollment;
+pub mod create_api_key;
+pub mod deactivate_user;
+pub mod delete_passkey;
+pub mod disable_mfa;
 pub mod get_own_profile;
 pub mod get_profile;
+pub mod list_api_keys;
+pub mod list_passkeys;
+pub mod list_sessions;
+pub mod reactivate_user;
+pub mod register_passkey;
+pub mod revoke_api_key;
+pub mod revoke_session;
 pub mod routes;
 pub mod update_email;
 pub mod update_profile;
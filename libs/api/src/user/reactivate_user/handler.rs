@@ -0,0 +1,39 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, post, web};
+use usecase::user::service::UserService;
+
+use super::ReactivateUserRequest;
+use crate::error::ApiError;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        request_body = ReactivateUserRequest,
+        responses(
+            (status = 204, description = "アカウントの再開成功。ログイン後にメールアドレスの再確認が必要"),
+            (status = 400, description = "リクエストエラー"),
+            (status = 401, description = "メールアドレスまたはパスワードが正しくない"),
+            (status = 409, description = "再開できない状態のユーザー"),
+            (status = 429, description = "パスワードの誤りが多すぎるため一時的にロックされている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/reactivate")]
+#[tracing::instrument(skip(req, service))]
+pub async fn reactivate_user_handler(
+    req: HttpRequest,
+    service: web::Data<dyn UserService>,
+    body: web::Json<ReactivateUserRequest>,
+) -> Result<impl Responder, ApiError> {
+    // 転送ヘッダーは偽装できるため、ソケットの接続元アドレスを使用する
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+    let input = body.into_inner().into_input(ip_address);
+
+    service.reactivate_user(input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use usecase::user::dto::ReactivateUserInput;
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct ReactivateUserRequest {
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("user@example.com")))]
+    pub email: String,
+
+    #[debug(skip)]
+    #[cfg_attr(feature = "api-docs", schema(examples("password123")))]
+    pub password: String,
+}
+
+impl ReactivateUserRequest {
+    pub(super) fn into_input(self, ip_address: Option<String>) -> ReactivateUserInput {
+        ReactivateUserInput {
+            email: self.email,
+            password: self.password,
+            ip_address,
+        }
+    }
+}
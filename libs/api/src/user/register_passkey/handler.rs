@@ -0,0 +1,52 @@
+use actix_web::{Responder, http::StatusCode, post, web};
+use usecase::user::service::UserService;
+use uuid::Uuid;
+
+use super::RegisterPasskeyRequest;
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{
+    error::ApiError, middleware::AuthenticatedUserContext, user::list_passkeys::PasskeyResponse,
+};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        post,
+        params(
+            ("user_id" = uuid::Uuid, Path, description = "パスキーを登録するユーザーID")
+        ),
+        request_body = RegisterPasskeyRequest,
+        responses(
+            (status = 201, description = "パスキーの登録成功", body = PasskeyResponse),
+            (status = 400, description = "リクエストエラー、またはパスキーの検証に失敗した"),
+            (status = 401, description = "認証エラー、またはセレモニートークンが正しくない"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "ユーザーが存在しない"),
+            (status = 409, description = "パスキーが既に登録されている"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[post("/users/{user_id}/passkeys")]
+#[tracing::instrument(skip(service))]
+pub async fn register_passkey_handler(
+    user: AuthenticatedUserContext,
+    user_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+    body: web::Json<RegisterPasskeyRequest>,
+) -> Result<impl Responder, ApiError> {
+    let input = body.into_inner().into_input(*user_id);
+
+    let output = service
+        .finish_passkey_registration(user.into(), input)
+        .await?;
+
+    Ok(PasskeyResponse::from(output)
+        .customize()
+        .with_status(StatusCode::CREATED))
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use usecase::{
+    auth::webauthn_service::RegistrationCredential, user::dto::FinishPasskeyRegistrationInput,
+};
+#[cfg(feature = "api-docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(derive_more::Debug, Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct RegisterPasskeyRequest {
+    /// `/users/{user_id}/passkeys/options` が返したセレモニートークン
+    #[cfg_attr(
+        feature = "api-docs",
+        schema(examples("eyJhbGciOiJFUzI1NiIsInR5cCI6IndlYmF1dGhuLWNlcmVtb255K2p3dCJ9..."))
+    )]
+    #[debug(skip)]
+    pub ceremony_token: String,
+
+    /// 一覧で見分けるためのパスキーの名前
+    #[cfg_attr(feature = "api-docs", schema(examples("MacBook の Touch ID")))]
+    pub name: String,
+
+    /// `navigator.credentials.create()` が返した `PublicKeyCredential` の `toJSON()` の結果
+    #[debug(skip)]
+    pub credential: PasskeyRegistrationRequest,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+pub struct PasskeyRegistrationRequest {
+    /// クレデンシャル ID (Base64URL)
+    #[cfg_attr(feature = "api-docs", schema(examples("c29mdHdhcmUtY3JlZGVudGlhbA")))]
+    pub id: String,
+
+    pub response: AuthenticatorAttestationResponseRequest,
+}
+
+/// 各値は Base64URL エンコード済み
+#[derive(Deserialize)]
+#[cfg_attr(feature = "api-docs", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorAttestationResponseRequest {
+    #[serde(rename = "clientDataJSON")]
+    pub client_data_json: String,
+
+    pub attestation_object: String,
+}
+
+impl RegisterPasskeyRequest {
+    pub(super) fn into_input(self, target_id: Uuid) -> FinishPasskeyRegistrationInput {
+        let PasskeyRegistrationRequest { id, response } = self.credential;
+
+        FinishPasskeyRegistrationInput {
+            target_id,
+            ceremony_token: self.ceremony_token,
+            name: self.name,
+            credential: RegistrationCredential {
+                id,
+                client_data_json: response.client_data_json,
+                attestation_object: response.attestation_object,
+            },
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::{
+    shared::identity::Identity as _,
+    user::{dto::RevokeApiKeyInput, service::UserService},
+};
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("api_key_id" = uuid::Uuid, Path, description = "失効させる API キーのID")
+        ),
+        responses(
+            (status = 204, description = "API キーの失効成功"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "API キーが存在しない"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[delete("/users/me/api-keys/{api_key_id}")]
+#[tracing::instrument(skip(service))]
+pub async fn revoke_api_key_handler(
+    user: AuthenticatedUserContext,
+    api_key_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = RevokeApiKeyInput {
+        target_id: user.actor_id(),
+        api_key_id: *api_key_id,
+    };
+
+    service.revoke_api_key(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
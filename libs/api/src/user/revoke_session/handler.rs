@@ -0,0 +1,47 @@
+use actix_web::{HttpResponse, Responder, delete, web};
+use usecase::{
+    shared::identity::Identity as _,
+    user::{dto::RevokeSessionInput, service::UserService},
+};
+use uuid::Uuid;
+
+#[cfg(feature = "api-docs")]
+use crate::openapi::OpenApiTag;
+use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+
+#[cfg_attr(
+    feature = "api-docs",
+    utoipa::path(
+        delete,
+        params(
+            ("session_id" = uuid::Uuid, Path, description = "終了させるセッションのID")
+        ),
+        responses(
+            (status = 204, description = "セッションの終了成功"),
+            (status = 401, description = "認証エラー"),
+            (status = 403, description = "権限エラー"),
+            (status = 404, description = "セッションが存在しない、または終了済み"),
+            (status = 500, description = "サーバーエラー"),
+        ),
+        security(
+            ("bearer_auth" = []) // Swagger UIで鍵マークを表示
+        ),
+        tag = OpenApiTag::Users.as_ref(),
+    )
+)]
+#[delete("/users/me/sessions/{session_id}")]
+#[tracing::instrument(skip(service))]
+pub async fn revoke_session_handler(
+    user: AuthenticatedUserContext,
+    session_id: web::Path<Uuid>,
+    service: web::Data<dyn UserService>,
+) -> Result<impl Responder, ApiError> {
+    let input = RevokeSessionInput {
+        target_id: user.actor_id(),
+        session_id: *session_id,
+    };
+
+    service.revoke_session(user.into(), input).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
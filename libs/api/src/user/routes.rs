@@ -1,12 +1,32 @@
 use actix_web::web;
 
-use crate::user::{get_own_profile, get_profile, update_email, update_profile};
+use crate::user::{
+    begin_mfa_enrollment, begin_passkey_registration, change_password, confirm_mfa_enrollment,
+    create_api_key, deactivate_user, delete_passkey, disable_mfa, get_own_profile, get_profile,
+    list_api_keys, list_passkeys, list_sessions, reactivate_user, register_passkey, revoke_api_key,
+    revoke_session, update_email, update_profile,
+};
 
 pub fn user_config(cfg: &mut web::ServiceConfig) {
     cfg.service(get_own_profile::get_own_profile_handler)
         .service(get_profile::get_public_profile_handler)
         .service(update_email::update_email_handler)
-        .service(update_profile::update_profile_handler);
+        .service(update_profile::update_profile_handler)
+        .service(change_password::change_password_handler)
+        .service(deactivate_user::deactivate_user_handler)
+        .service(reactivate_user::reactivate_user_handler)
+        .service(begin_mfa_enrollment::begin_mfa_enrollment_handler)
+        .service(confirm_mfa_enrollment::confirm_mfa_enrollment_handler)
+        .service(disable_mfa::disable_mfa_handler)
+        .service(begin_passkey_registration::begin_passkey_registration_handler)
+        .service(register_passkey::register_passkey_handler)
+        .service(list_passkeys::list_passkeys_handler)
+        .service(delete_passkey::delete_passkey_handler)
+        .service(create_api_key::create_api_key_handler)
+        .service(list_api_keys::list_api_keys_handler)
+        .service(revoke_api_key::revoke_api_key_handler)
+        .service(list_sessions::list_sessions_handler)
+        .service(revoke_session::revoke_session_handler);
 }
 
 #[cfg(feature = "api-docs")]
@@ -27,6 +47,21 @@ pub mod openapi {
             get_profile::get_public_profile_handler,
             update_email::update_email_handler,
             update_profile::update_profile_handler,
+            change_password::change_password_handler,
+            deactivate_user::deactivate_user_handler,
+            reactivate_user::reactivate_user_handler,
+            begin_mfa_enrollment::begin_mfa_enrollment_handler,
+            confirm_mfa_enrollment::confirm_mfa_enrollment_handler,
+            disable_mfa::disable_mfa_handler,
+            begin_passkey_registration::begin_passkey_registration_handler,
+            register_passkey::register_passkey_handler,
+            list_passkeys::list_passkeys_handler,
+            delete_passkey::delete_passkey_handler,
+            create_api_key::create_api_key_handler,
+            list_api_keys::list_api_keys_handler,
+            revoke_api_key::revoke_api_key_handler,
+            list_sessions::list_sessions_handler,
+            revoke_session::revoke_session_handler,
         ),
         components(
             schemas(
@@ -37,7 +72,27 @@ pub mod openapi {
                 update_email::UpdateEmailRequest,
                 update_email::UpdateEmailResponse,
                 update_profile::UpdateProfileRequest,
-                update_profile::UpdateProfileResponse
+                update_profile::UpdateProfileResponse,
+                change_password::ChangePasswordRequest,
+                deactivate_user::DeactivateUserRequest,
+                reactivate_user::ReactivateUserRequest,
+                begin_mfa_enrollment::BeginMfaEnrollmentResponse,
+                confirm_mfa_enrollment::ConfirmMfaEnrollmentRequest,
+                confirm_mfa_enrollment::ConfirmMfaEnrollmentResponse,
+                disable_mfa::DisableMfaRequest,
+                begin_passkey_registration::BeginPasskeyRegistrationResponse,
+                register_passkey::RegisterPasskeyRequest,
+                register_passkey::PasskeyRegistrationRequest,
+                register_passkey::AuthenticatorAttestationResponseRequest,
+                list_passkeys::ListPasskeysResponse,
+                list_passkeys::PasskeyResponse,
+                create_api_key::CreateApiKeyRequest,
+                create_api_key::ApiKeyScopeRequest,
+                create_api_key::CreateApiKeyResponse,
+                list_api_keys::ListApiKeysResponse,
+                list_api_keys::ApiKeyResponse,
+                list_sessions::ListSessionsResponse,
+                list_sessions::SessionResponse
             )
         ),
         tags((
@@ -4,7 +4,7 @@ use usecase::user::service::UserService;
 use super::{UpdateEmailRequest, UpdateEmailResponse};
 #[cfg(feature = "api-docs")]
 use crate::openapi::OpenApiTag;
-use crate::{error::ApiError, middleware::AuthenticatedUserContext};
+use crate::{error::ApiError, middleware::CallerContext};
 
 #[cfg_attr(
     feature = "api-docs",
@@ -30,7 +30,7 @@ use crate::{error::ApiError, middleware::AuthenticatedUserContext};
 #[patch("/users/{user_id}/email")]
 #[tracing::instrument(skip(service))]
 pub async fn update_email_handler(
-    user: AuthenticatedUserContext,
+    user: CallerContext,
     user_id: web::Path<uuid::Uuid>,
     service: web::Data<dyn UserService>,
     body: web::Json<UpdateEmailRequest>,
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{auth::service_account::ServiceAccountId, user::UserId};
+
+/// 操作の主体の ID
+///
+/// ユーザーだけでなく、サービスアカウントのような人間以外の主体も含む
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorId {
+    User(UserId),
+    ServiceAccount(ServiceAccountId),
+}
+
+// ポリシーで「操作の対象が自分自身か」を判定するための比較
+// サービスアカウントはどのユーザーとも一致しない
+impl PartialEq<UserId> for ActorId {
+    fn eq(&self, other: &UserId) -> bool {
+        match self {
+            ActorId::User(user_id) => user_id == other,
+            ActorId::ServiceAccount(_) => false,
+        }
+    }
+}
+
+/// 監査記録に残す操作の主体
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuditActor {
+    User {
+        user_id: UserId,
+    },
+    ServiceAccount {
+        service_account_id: ServiceAccountId,
+        name: String,
+    },
+}
@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{ApiKeyId, ApiKeyIssueError, ApiKeyPrefix, ApiKeyScope, ApiKeySecretHash};
+
+/// スクリプトや CI などからパスワードを使わずに API を呼び出すための、ユーザーが発行したキー
+#[derive(Entity)]
+pub struct ApiKey {
+    #[entity_id]
+    id: ApiKeyId,
+    user_id: UserId,
+    /// 一覧で見分けるためにユーザーが付ける名前
+    name: String,
+    prefix: ApiKeyPrefix,
+    secret_hash: ApiKeySecretHash,
+    /// 許可する操作の範囲 (重複なし・昇順)
+    scopes: Vec<ApiKeyScope>,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    // 新しい API キーを発行するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue(
+        id: ApiKeyId,
+        user_id: UserId,
+        name: String,
+        prefix: ApiKeyPrefix,
+        secret_hash: ApiKeySecretHash,
+        mut scopes: Vec<ApiKeyScope>,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Result<Self, ApiKeyIssueError> {
+        if scopes.is_empty() {
+            return Err(ApiKeyIssueError::EmptyScopes);
+        }
+        scopes.sort();
+        scopes.dedup();
+
+        let now = clock.now();
+        if expires_at <= now {
+            return Err(ApiKeyIssueError::InvalidExpiry { expires_at });
+        }
+
+        Ok(Self {
+            id,
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            expires_at,
+            created_at: now,
+            last_used_at: None,
+        })
+    }
+
+    // 永続化処理された API キーを再構築するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: ApiKeyId,
+        user_id: UserId,
+        name: String,
+        prefix: ApiKeyPrefix,
+        secret_hash: ApiKeySecretHash,
+        scopes: Vec<ApiKeyScope>,
+        expires_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            expires_at,
+            created_at,
+            last_used_at,
+        }
+    }
+
+    pub fn id(&self) -> ApiKeyId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn prefix(&self) -> &ApiKeyPrefix {
+        &self.prefix
+    }
+
+    pub fn secret_hash(&self) -> &ApiKeySecretHash {
+        &self.secret_hash
+    }
+
+    pub fn scopes(&self) -> &[ApiKeyScope] {
+        &self.scopes
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+}
+
+// 認証に関するメソッド群
+impl ApiKey {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    /// 提示されたキーのハッシュ値が一致し、有効期限内であれば使用を記録する
+    ///
+    /// 一致しない場合・期限切れの場合は `false` を返し、使用日時は更新しない
+    pub fn authenticate(&mut self, secret_hash: &ApiKeySecretHash, clock: &dyn Clock) -> bool {
+        let now = clock.now();
+        if &self.secret_hash != secret_hash || self.is_expired(now) {
+            return false;
+        }
+
+        self.last_used_at = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    fn issue(
+        scopes: Vec<ApiKeyScope>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<ApiKey, ApiKeyIssueError> {
+        ApiKey::issue(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            "CI".to_string(),
+            ApiKeyPrefix::from_raw_str("0123456789abcdef"),
+            ApiKeySecretHash::from_raw_str("secret-hash"),
+            scopes,
+            expires_at,
+            &clock_at(base_time()),
+        )
+    }
+
+    #[test]
+    fn test_issue_normalizes_scopes() {
+        let api_key = issue(
+            vec![
+                ApiKeyScope::ProfileWrite,
+                ApiKeyScope::ProfileRead,
+                ApiKeyScope::ProfileWrite,
+            ],
+            base_time() + Duration::days(30),
+        )
+        .unwrap();
+
+        assert_eq!(
+            api_key.scopes(),
+            &[ApiKeyScope::ProfileRead, ApiKeyScope::ProfileWrite]
+        );
+        assert_eq!(api_key.created_at(), base_time());
+        assert_eq!(api_key.last_used_at(), None);
+    }
+
+    #[rstest]
+    #[case::empty_scopes(vec![], base_time() + Duration::days(30), ApiKeyIssueError::EmptyScopes)]
+    #[case::expired(
+        vec![ApiKeyScope::ProfileRead],
+        base_time(),
+        ApiKeyIssueError::InvalidExpiry { expires_at: base_time() }
+    )]
+    fn test_issue_failure(
+        #[case] scopes: Vec<ApiKeyScope>,
+        #[case] expires_at: DateTime<Utc>,
+        #[case] expected: ApiKeyIssueError,
+    ) {
+        assert_eq!(issue(scopes, expires_at).err(), Some(expected));
+    }
+
+    #[rstest]
+    #[case::valid("secret-hash", Duration::days(29), true)]
+    #[case::wrong_secret("other-hash", Duration::days(29), false)]
+    #[case::expired("secret-hash", Duration::days(30), false)]
+    fn test_authenticate(
+        #[case] secret_hash: &str,
+        #[case] elapsed: Duration,
+        #[case] expected: bool,
+    ) {
+        let mut api_key = issue(
+            vec![ApiKeyScope::ProfileRead],
+            base_time() + Duration::days(30),
+        )
+        .unwrap();
+        let used_at = base_time() + elapsed;
+
+        let result = api_key.authenticate(
+            &ApiKeySecretHash::from_raw_str(secret_hash),
+            &clock_at(used_at),
+        );
+
+        assert_eq!(result, expected);
+        assert_eq!(api_key.last_used_at(), expected.then_some(used_at));
+    }
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ApiKeyIssueError {
+    #[error("API キーには 1 つ以上のスコープが必要です")]
+    EmptyScopes,
+
+    #[error("API キーの有効期限は発行日時より後である必要があります: {expires_at}")]
+    InvalidExpiry { expires_at: DateTime<Utc> },
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ApiKeyReconstructionError {
+    #[error("不正なスコープが保存されています: {invalid_scope}")]
+    InvalidScope { invalid_scope: String },
+}
@@ -0,0 +1,14 @@
+mod entity;
+mod error;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::ApiKey;
+pub use error::{ApiKeyIssueError, ApiKeyReconstructionError};
+pub use repository::{ApiKeyRepository, ApiKeyRepositoryError};
+pub use service::{ApiKeyIdGenerationError, ApiKeyIdGenerator, ApiKeyIdGeneratorFactory};
+pub use value_objects::{
+    api_key_id::ApiKeyId, api_key_prefix::ApiKeyPrefix, api_key_scope::ApiKeyScope,
+    api_key_secret_hash::ApiKeySecretHash,
+};
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{ApiKey, ApiKeyId, ApiKeyIdGenerationError, ApiKeyPrefix, ApiKeyReconstructionError};
+
+#[derive(Debug, Error)]
+pub enum ApiKeyRepositoryError {
+    #[error(transparent)]
+    ReconstructionError(#[from] ApiKeyReconstructionError),
+
+    #[error(transparent)]
+    IdGenerationError(#[from] ApiKeyIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn find_by_id(&self, id: ApiKeyId) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+    async fn find_by_prefix(
+        &self,
+        prefix: &ApiKeyPrefix,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Vec<ApiKey>, ApiKeyRepositoryError>;
+    async fn save(&self, api_key: ApiKey) -> Result<ApiKey, ApiKeyRepositoryError>;
+    async fn delete(&self, id: ApiKeyId) -> Result<(), ApiKeyRepositoryError>;
+}
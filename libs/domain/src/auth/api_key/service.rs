@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::ApiKeyId;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyIdGenerationError {
+    #[error("API キーIDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait ApiKeyIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<ApiKeyId, ApiKeyIdGenerationError>;
+}
+
+pub trait ApiKeyIdGeneratorFactory: Send + Sync {
+    fn create_api_key_id_generator(&self) -> Arc<dyn ApiKeyIdGenerator>;
+}
@@ -0,0 +1,14 @@
+// API キーの検索に使用する識別子
+// キーの平文に含まれ、一覧でキーを見分けるためにも表示する (秘密の部分は含まない)
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct ApiKeyPrefix(String);
+
+impl ApiKeyPrefix {
+    pub fn from_raw_str(prefix: &str) -> Self {
+        Self(prefix.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use strum::{Display, EnumString, IntoStaticStr};
+
+use crate::auth::api_key::ApiKeyReconstructionError;
+
+/// API キーで許可する操作の範囲
+///
+/// パスワードや 2 段階認証などの認証情報の管理は、いずれのスコープでも許可しない
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, IntoStaticStr)]
+pub enum ApiKeyScope {
+    /// 自分や他のユーザーのプロフィールの閲覧
+    #[strum(serialize = "profile:read")]
+    ProfileRead,
+    /// 自分のプロフィール・メールアドレスの変更
+    #[strum(serialize = "profile:write")]
+    ProfileWrite,
+    /// 管理者によるユーザー情報の閲覧
+    #[strum(serialize = "admin:read")]
+    AdminRead,
+    /// 管理者によるユーザーの利用停止・ロック解除などの操作
+    #[strum(serialize = "admin:write")]
+    AdminWrite,
+}
+
+#[derive(Debug, PartialEq, Eq, EnumString)]
+enum ApiKeyScopeKind {
+    #[strum(serialize = "profile:read")]
+    ProfileRead,
+    #[strum(serialize = "profile:write")]
+    ProfileWrite,
+    #[strum(serialize = "admin:read")]
+    AdminRead,
+    #[strum(serialize = "admin:write")]
+    AdminWrite,
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = ApiKeyReconstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind =
+            s.parse::<ApiKeyScopeKind>()
+                .map_err(|_| ApiKeyReconstructionError::InvalidScope {
+                    invalid_scope: s.to_string(),
+                })?;
+
+        match kind {
+            ApiKeyScopeKind::ProfileRead => Ok(ApiKeyScope::ProfileRead),
+            ApiKeyScopeKind::ProfileWrite => Ok(ApiKeyScope::ProfileWrite),
+            ApiKeyScopeKind::AdminRead => Ok(ApiKeyScope::AdminRead),
+            ApiKeyScopeKind::AdminWrite => Ok(ApiKeyScope::AdminWrite),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(ApiKeyScope::ProfileRead, "profile:read")]
+    #[case(ApiKeyScope::ProfileWrite, "profile:write")]
+    #[case(ApiKeyScope::AdminRead, "admin:read")]
+    #[case(ApiKeyScope::AdminWrite, "admin:write")]
+    fn test_api_key_scope_round_trip(#[case] scope: ApiKeyScope, #[case] expected: &str) {
+        assert_eq!(scope.to_string(), expected);
+        assert_eq!(ApiKeyScope::from_str(expected), Ok(scope));
+    }
+
+    #[rstest]
+    #[case("ProfileRead")]
+    #[case("profile")]
+    #[case("")]
+    fn test_api_key_scope_from_str_failure(#[case] input: &str) {
+        assert_eq!(
+            ApiKeyScope::from_str(input),
+            Err(ApiKeyReconstructionError::InvalidScope {
+                invalid_scope: input.to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,10 @@
+// API キーの秘密の部分のハッシュ値
+// キーの平文は発行時に一度だけ利用者に表示し、サーバー側ではハッシュ値のみを保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct ApiKeySecretHash(String);
+
+impl ApiKeySecretHash {
+    pub fn from_raw_str(hash: &str) -> Self {
+        Self(hash.to_string())
+    }
+}
@@ -0,0 +1,4 @@
+pub mod api_key_id;
+pub mod api_key_prefix;
+pub mod api_key_scope;
+pub mod api_key_secret_hash;
@@ -0,0 +1,169 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{ExternalSubject, FederatedIdentityId, IdentityProviderId};
+
+/// 外部 ID プロバイダーのアカウントとユーザーの紐づけ
+#[derive(Entity)]
+pub struct FederatedIdentity {
+    #[entity_id]
+    id: FederatedIdentityId,
+    user_id: UserId,
+    provider: IdentityProviderId,
+    subject: ExternalSubject,
+    /// 紐づけた時点で ID プロバイダーが返したメールアドレス (監査用)
+    email: String,
+    created_at: DateTime<Utc>,
+    last_login_at: Option<DateTime<Utc>>,
+}
+
+impl FederatedIdentity {
+    // 外部アカウントをユーザーに紐づけるためのコンストラクタ
+    // 紐づけはログインの中で行うため、紐づけた時刻を最終ログイン時刻とする
+    pub fn link(
+        id: FederatedIdentityId,
+        user_id: UserId,
+        provider: IdentityProviderId,
+        subject: ExternalSubject,
+        email: String,
+        clock: &dyn Clock,
+    ) -> Self {
+        let now = clock.now();
+
+        Self {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at: now,
+            last_login_at: Some(now),
+        }
+    }
+
+    // 永続化処理された紐づけを再構築するためのコンストラクタ
+    pub fn reconstruct(
+        id: FederatedIdentityId,
+        user_id: UserId,
+        provider: IdentityProviderId,
+        subject: ExternalSubject,
+        email: String,
+        created_at: DateTime<Utc>,
+        last_login_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at,
+            last_login_at,
+        }
+    }
+
+    pub fn id(&self) -> FederatedIdentityId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn provider(&self) -> &IdentityProviderId {
+        &self.provider
+    }
+
+    pub fn subject(&self) -> &ExternalSubject {
+        &self.subject
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_login_at(&self) -> Option<DateTime<Utc>> {
+        self.last_login_at
+    }
+
+    /// 外部アカウントによるログインを記録する
+    pub fn record_login(&mut self, clock: &dyn Clock) {
+        self.last_login_at = Some(clock.now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::federated_identity::IdentityProviderEmailVerification,
+        user::{EmailTrait as _, EmailVerificationError, EmailVerifier as _, UnverifiedEmail},
+    };
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    #[test]
+    fn test_link_and_record_login() {
+        let mut identity = FederatedIdentity::link(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            IdentityProviderId::from_raw_str("google"),
+            ExternalSubject::from_raw_str("110169484474386276334"),
+            "user@example.com".to_string(),
+            &clock_at(base_time()),
+        );
+        assert_eq!(identity.created_at(), base_time());
+        assert_eq!(identity.last_login_at(), Some(base_time()));
+
+        let logged_in_at = base_time() + chrono::Duration::days(1);
+        identity.record_login(&clock_at(logged_in_at));
+
+        assert_eq!(identity.created_at(), base_time());
+        assert_eq!(identity.last_login_at(), Some(logged_in_at));
+    }
+
+    #[rstest]
+    #[case::same("user@example.com", Ok(()))]
+    #[case::case_insensitive("User@Example.com", Ok(()))]
+    #[case::other("other@example.com", Err(EmailVerificationError::EmailMismatch))]
+    fn test_identity_provider_email_verification(
+        #[case] verified_by_provider: &str,
+        #[case] expected: Result<(), EmailVerificationError>,
+    ) {
+        let email = UnverifiedEmail::new("user@example.com").unwrap();
+        let verification = IdentityProviderEmailVerification {
+            email: verified_by_provider.to_string(),
+        };
+
+        let result = verification.verify(&email);
+
+        assert_eq!(result.map(|_| ()), expected);
+    }
+}
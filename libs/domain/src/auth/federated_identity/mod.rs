@@ -0,0 +1,15 @@
+mod entity;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::FederatedIdentity;
+pub use repository::{FederatedIdentityRepository, FederatedIdentityRepositoryError};
+pub use service::{
+    FederatedIdentityIdGenerationError, FederatedIdentityIdGenerator,
+    FederatedIdentityIdGeneratorFactory, IdentityProviderEmailVerification,
+};
+pub use value_objects::{
+    external_subject::ExternalSubject, federated_identity_id::FederatedIdentityId,
+    identity_provider_id::IdentityProviderId,
+};
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{
+    ExternalSubject, FederatedIdentity, FederatedIdentityIdGenerationError, IdentityProviderId,
+};
+
+#[derive(Debug, Error)]
+pub enum FederatedIdentityRepositoryError {
+    #[error(transparent)]
+    IdGenerationError(#[from] FederatedIdentityIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait FederatedIdentityRepository: Send + Sync {
+    async fn find_by_provider_subject(
+        &self,
+        provider: &IdentityProviderId,
+        subject: &ExternalSubject,
+    ) -> Result<Option<FederatedIdentity>, FederatedIdentityRepositoryError>;
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<FederatedIdentity>, FederatedIdentityRepositoryError>;
+    async fn save(
+        &self,
+        identity: FederatedIdentity,
+    ) -> Result<FederatedIdentity, FederatedIdentityRepositoryError>;
+}
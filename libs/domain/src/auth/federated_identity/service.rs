@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::user::{
+    EmailTrait as _, EmailVerificationError, EmailVerifier, UnverifiedEmail, VerifiedEmail,
+};
+
+use super::FederatedIdentityId;
+
+#[derive(Debug, Error)]
+pub enum FederatedIdentityIdGenerationError {
+    #[error("外部アカウント連携IDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait FederatedIdentityIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<FederatedIdentityId, FederatedIdentityIdGenerationError>;
+}
+
+pub trait FederatedIdentityIdGeneratorFactory: Send + Sync {
+    fn create_federated_identity_id_generator(&self) -> Arc<dyn FederatedIdentityIdGenerator>;
+}
+
+/// ID プロバイダーが確認済み (`email_verified`) とした、ID トークンのメールアドレス
+///
+/// 初回ログイン時に作成したユーザーのメールアドレスを、確認メールを経ずに確認済みにするために使用する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityProviderEmailVerification {
+    pub email: String,
+}
+
+impl EmailVerifier for IdentityProviderEmailVerification {
+    fn verify(&self, email: &UnverifiedEmail) -> Result<VerifiedEmail, EmailVerificationError> {
+        if !self.email.eq_ignore_ascii_case(email.as_str()) {
+            return Err(EmailVerificationError::EmailMismatch);
+        }
+
+        Ok(email.verify())
+    }
+}
@@ -0,0 +1,14 @@
+// ID プロバイダーが発行した ID トークンの `sub` クレーム
+// プロバイダー内で一意かつ不変であるため、メールアドレスの代わりに外部アカウントの特定に使用する
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::AsRef)]
+pub struct ExternalSubject(String);
+
+impl ExternalSubject {
+    pub fn from_raw_str(subject: &str) -> Self {
+        Self(subject.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
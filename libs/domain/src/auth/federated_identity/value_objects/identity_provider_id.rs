@@ -0,0 +1,13 @@
+// 設定で定義した外部 ID プロバイダーの識別子 (例: "google")
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::AsRef)]
+pub struct IdentityProviderId(String);
+
+impl IdentityProviderId {
+    pub fn from_raw_str(provider_id: &str) -> Self {
+        Self(provider_id.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
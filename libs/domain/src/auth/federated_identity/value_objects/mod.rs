@@ -0,0 +1,3 @@
+pub mod external_subject;
+pub mod federated_identity_id;
+pub mod identity_provider_id;
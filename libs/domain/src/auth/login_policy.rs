@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::user::UserState;
+
+/// ログイン後に発行するアクセストークンで許可する操作の範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessScope {
+    /// 通常の操作すべて
+    #[default]
+    Full,
+    /// メールアドレスの認証と自分のプロフィールの閲覧のみ
+    PendingVerification,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum LoginRejection {
+    #[error("このアカウントは管理者によって利用停止されています")]
+    Suspended,
+    #[error("このアカウントは退会済みです")]
+    Deactivated,
+}
+
+/// ユーザーの状態に応じて、ログインの可否と発行するトークンの範囲を決める
+pub struct LoginPolicy;
+
+impl LoginPolicy {
+    pub fn evaluate(state: &UserState) -> Result<AccessScope, LoginRejection> {
+        match state {
+            UserState::Active { .. } | UserState::ActiveWithUnverifiedEmail { .. } => {
+                Ok(AccessScope::Full)
+            }
+            // メールアドレスの認証を完了するまでは、認証に必要な操作のみ許可する
+            UserState::PendingVerification { .. } => Ok(AccessScope::PendingVerification),
+            UserState::SuspendedByAdmin { .. } => Err(LoginRejection::Suspended),
+            UserState::DeactivatedByUser { .. } => Err(LoginRejection::Deactivated),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::user::{EmailTrait, UnverifiedEmail, VerifiedEmail};
+
+    use super::*;
+
+    #[rstest]
+    #[case(UserState::Active { email: VerifiedEmail::new("user@example.com").unwrap() }, Ok(AccessScope::Full))]
+    #[case(UserState::ActiveWithUnverifiedEmail { email: UnverifiedEmail::new("user@example.com").unwrap() }, Ok(AccessScope::Full))]
+    #[case(UserState::PendingVerification { email: UnverifiedEmail::new("user@example.com").unwrap() }, Ok(AccessScope::PendingVerification))]
+    #[case(UserState::SuspendedByAdmin { email: UnverifiedEmail::new("user@example.com").unwrap() }, Err(LoginRejection::Suspended))]
+    #[case(UserState::DeactivatedByUser { email: UnverifiedEmail::new("user@example.com").unwrap() }, Err(LoginRejection::Deactivated))]
+    fn test_evaluate_login_by_user_state(
+        #[case] state: UserState,
+        #[case] expected: Result<AccessScope, LoginRejection>,
+    ) {
+        assert_eq!(LoginPolicy::evaluate(&state), expected);
+    }
+}
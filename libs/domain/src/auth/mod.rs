@@ -1,2 +1,11 @@
+pub mod actor;
+pub mod api_key;
+pub mod federated_identity;
+pub mod login_policy;
+pub mod password_reset_token;
 pub mod policies;
 pub mod policy;
+pub mod refresh_token;
+pub mod service_account;
+pub mod session;
+pub mod webauthn_credential;
@@ -0,0 +1,343 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+use strum::EnumString;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{
+    PasswordResetTokenConsumptionError, PasswordResetTokenHash, PasswordResetTokenId,
+    PasswordResetTokenReconstructionError,
+};
+
+#[derive(Entity)]
+pub struct PasswordResetToken {
+    #[entity_id]
+    id: PasswordResetTokenId,
+    user_id: UserId,
+    token_hash: PasswordResetTokenHash,
+    state: PasswordResetTokenState,
+    issued_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    // パスワードリセットの要求時に新しいトークンを発行するためのコンストラクタ
+    pub fn issue(
+        id: PasswordResetTokenId,
+        user_id: UserId,
+        token_hash: PasswordResetTokenHash,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            state: PasswordResetTokenState::Active,
+            issued_at: clock.now(),
+            expires_at,
+        }
+    }
+
+    // 永続化処理されたトークンを再構築するためのコンストラクタ
+    pub fn reconstruct(
+        id: PasswordResetTokenId,
+        user_id: UserId,
+        token_hash: PasswordResetTokenHash,
+        state_source: PasswordResetTokenStateRaw,
+        issued_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, PasswordResetTokenReconstructionError> {
+        let state = state_source.try_into()?;
+
+        Ok(Self {
+            id,
+            user_id,
+            token_hash,
+            state,
+            issued_at,
+            expires_at,
+        })
+    }
+
+    pub fn id(&self) -> PasswordResetTokenId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_hash(&self) -> &PasswordResetTokenHash {
+        &self.token_hash
+    }
+
+    pub fn state(&self) -> &PasswordResetTokenState {
+        &self.state
+    }
+
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.issued_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordResetTokenState {
+    Active,                                // 未使用
+    Used { used_at: DateTime<Utc> },       // パスワードのリセットに使用済み
+    Revoked { revoked_at: DateTime<Utc> }, // 失効済み
+}
+
+// トークンの状態遷移に関するメソッド群
+impl PasswordResetToken {
+    /// トークンを使用済みにする
+    ///
+    /// トークンは一度しか使用できない
+    pub fn consume(&mut self, clock: &dyn Clock) -> Result<(), PasswordResetTokenConsumptionError> {
+        let now = clock.now();
+
+        match &self.state {
+            PasswordResetTokenState::Active => {
+                if self.expires_at <= now {
+                    Err(PasswordResetTokenConsumptionError::Expired)?
+                }
+            }
+            PasswordResetTokenState::Used { .. } => {
+                Err(PasswordResetTokenConsumptionError::AlreadyUsed)?
+            }
+            PasswordResetTokenState::Revoked { .. } => {
+                Err(PasswordResetTokenConsumptionError::Revoked)?
+            }
+        }
+
+        self.state = PasswordResetTokenState::Used { used_at: now };
+
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, clock: &dyn Clock) {
+        match &self.state {
+            PasswordResetTokenState::Active => {
+                self.state = PasswordResetTokenState::Revoked {
+                    revoked_at: clock.now(),
+                };
+            }
+            // 使用済み・失効済みのトークンはそれ以上使用できないため何もしない
+            PasswordResetTokenState::Used { .. } | PasswordResetTokenState::Revoked { .. } => {}
+        }
+    }
+}
+
+pub struct PasswordResetTokenStateRaw {
+    pub status: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PasswordResetTokenStateRaw> for PasswordResetTokenState {
+    type Error = PasswordResetTokenReconstructionError;
+
+    fn try_from(raw: PasswordResetTokenStateRaw) -> Result<Self, Self::Error> {
+        let PasswordResetTokenStateRaw {
+            status,
+            used_at,
+            revoked_at,
+        } = raw;
+
+        let kind = status.parse::<PasswordResetTokenStateKind>().map_err(|_| {
+            PasswordResetTokenReconstructionError::InvalidStatus {
+                invalid_status: status,
+            }
+        })?;
+
+        match kind {
+            PasswordResetTokenStateKind::Active => Ok(PasswordResetTokenState::Active),
+            PasswordResetTokenStateKind::Used => Ok(PasswordResetTokenState::Used {
+                used_at: used_at.ok_or(PasswordResetTokenReconstructionError::UsedButNoUsedAt)?,
+            }),
+            PasswordResetTokenStateKind::Revoked => Ok(PasswordResetTokenState::Revoked {
+                revoked_at: revoked_at
+                    .ok_or(PasswordResetTokenReconstructionError::RevokedButNoRevokedAt)?,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, strum::Display, EnumString, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum PasswordResetTokenStateKind {
+    Active,
+    Used,
+    Revoked,
+}
+
+impl PasswordResetTokenState {
+    pub fn kind(&self) -> &'static str {
+        self.kind_raw().into()
+    }
+
+    fn kind_raw(&self) -> PasswordResetTokenStateKind {
+        match self {
+            PasswordResetTokenState::Active => PasswordResetTokenStateKind::Active,
+            PasswordResetTokenState::Used { .. } => PasswordResetTokenStateKind::Used,
+            PasswordResetTokenState::Revoked { .. } => PasswordResetTokenStateKind::Revoked,
+        }
+    }
+
+    pub fn used_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            PasswordResetTokenState::Used { used_at } => Some(*used_at),
+            _ => None,
+        }
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            PasswordResetTokenState::Revoked { revoked_at } => Some(*revoked_at),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    #[fixture]
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    #[fixture]
+    fn active_token(base_time: DateTime<Utc>) -> PasswordResetToken {
+        PasswordResetToken::issue(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            PasswordResetTokenHash::from_raw_str("hash-1"),
+            base_time + Duration::minutes(30),
+            &clock_at(base_time),
+        )
+    }
+
+    #[rstest]
+    fn test_consume_success(mut active_token: PasswordResetToken, base_time: DateTime<Utc>) {
+        let now = base_time + Duration::minutes(10);
+
+        active_token.consume(&clock_at(now)).unwrap();
+
+        assert_eq!(
+            active_token.state(),
+            &PasswordResetTokenState::Used { used_at: now }
+        );
+    }
+
+    #[rstest]
+    #[case::expired(
+        PasswordResetTokenState::Active,
+        Duration::minutes(31),
+        PasswordResetTokenConsumptionError::Expired
+    )]
+    #[case::already_used(
+        PasswordResetTokenState::Used { used_at: Utc::now() },
+        Duration::minutes(10),
+        PasswordResetTokenConsumptionError::AlreadyUsed
+    )]
+    #[case::revoked(
+        PasswordResetTokenState::Revoked { revoked_at: Utc::now() },
+        Duration::minutes(10),
+        PasswordResetTokenConsumptionError::Revoked
+    )]
+    fn test_consume_failure(
+        mut active_token: PasswordResetToken,
+        base_time: DateTime<Utc>,
+        #[case] state: PasswordResetTokenState,
+        #[case] elapsed: Duration,
+        #[case] expected: PasswordResetTokenConsumptionError,
+    ) {
+        active_token.state = state;
+
+        let result = active_token.consume(&clock_at(base_time + elapsed));
+
+        assert_eq!(result, Err(expected));
+        assert_eq!(active_token.state(), &state);
+    }
+
+    #[rstest]
+    fn test_revoke_does_not_overwrite_used_token(
+        mut active_token: PasswordResetToken,
+        base_time: DateTime<Utc>,
+    ) {
+        let used_at = base_time + Duration::minutes(10);
+        active_token.consume(&clock_at(used_at)).unwrap();
+
+        active_token.revoke(&clock_at(used_at + Duration::minutes(1)));
+
+        assert_eq!(
+            active_token.state(),
+            &PasswordResetTokenState::Used { used_at }
+        );
+    }
+
+    #[rstest]
+    #[case("active", None, None, PasswordResetTokenState::Active)]
+    #[case("used", Some(base_time()), None, PasswordResetTokenState::Used { used_at: base_time() })]
+    #[case("revoked", None, Some(base_time()), PasswordResetTokenState::Revoked { revoked_at: base_time() })]
+    fn test_try_from_state_raw(
+        #[case] status: &str,
+        #[case] used_at: Option<DateTime<Utc>>,
+        #[case] revoked_at: Option<DateTime<Utc>>,
+        #[case] expected: PasswordResetTokenState,
+    ) {
+        let raw = PasswordResetTokenStateRaw {
+            status: status.to_string(),
+            used_at,
+            revoked_at,
+        };
+
+        let state: PasswordResetTokenState = raw.try_into().unwrap();
+        assert_eq!(state, expected);
+    }
+
+    #[rstest]
+    #[case("unknown", PasswordResetTokenReconstructionError::InvalidStatus { invalid_status: "unknown".to_string() })]
+    #[case("used", PasswordResetTokenReconstructionError::UsedButNoUsedAt)]
+    #[case(
+        "revoked",
+        PasswordResetTokenReconstructionError::RevokedButNoRevokedAt
+    )]
+    fn test_try_from_invalid_state_raw(
+        #[case] status: &str,
+        #[case] expected: PasswordResetTokenReconstructionError,
+    ) {
+        let raw = PasswordResetTokenStateRaw {
+            status: status.to_string(),
+            used_at: None,
+            revoked_at: None,
+        };
+
+        let result: Result<PasswordResetTokenState, _> = raw.try_into();
+        assert_eq!(result.unwrap_err(), expected);
+    }
+}
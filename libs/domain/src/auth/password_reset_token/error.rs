@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PasswordResetTokenConsumptionError {
+    #[error("パスワードリセットトークンの有効期限が切れています")]
+    Expired,
+
+    #[error("パスワードリセットトークンは使用済みです")]
+    AlreadyUsed,
+
+    #[error("パスワードリセットトークンは失効しています")]
+    Revoked,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PasswordResetTokenReconstructionError {
+    #[error("不正な形式のステータスが保存されています: {invalid_status}")]
+    InvalidStatus { invalid_status: String },
+
+    #[error("Used にもかかわらず used_at が None です")]
+    UsedButNoUsedAt,
+
+    #[error("Revoked にもかかわらず revoked_at が None です")]
+    RevokedButNoRevokedAt,
+}
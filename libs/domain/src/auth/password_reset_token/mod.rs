@@ -0,0 +1,20 @@
+mod entity;
+mod error;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::{
+    PasswordResetToken, PasswordResetTokenState, PasswordResetTokenStateKind,
+    PasswordResetTokenStateRaw,
+};
+pub use error::{PasswordResetTokenConsumptionError, PasswordResetTokenReconstructionError};
+pub use repository::{PasswordResetTokenRepository, PasswordResetTokenRepositoryError};
+pub use service::{
+    PasswordResetTokenIdGenerationError, PasswordResetTokenIdGenerator,
+    PasswordResetTokenIdGeneratorFactory,
+};
+pub use value_objects::{
+    password_reset_token_hash::PasswordResetTokenHash,
+    password_reset_token_id::PasswordResetTokenId,
+};
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{
+    PasswordResetToken, PasswordResetTokenHash, PasswordResetTokenIdGenerationError,
+    PasswordResetTokenReconstructionError,
+};
+
+#[derive(Debug, Error)]
+pub enum PasswordResetTokenRepositoryError {
+    #[error(transparent)]
+    ReconstructionError(#[from] PasswordResetTokenReconstructionError),
+
+    #[error(transparent)]
+    IdGenerationError(#[from] PasswordResetTokenIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait PasswordResetTokenRepository: Send + Sync {
+    /// トークンのハッシュ値からリセットトークンを取得する
+    ///
+    /// 同じトークンによる同時のリセットを待たせるため、取得した行をトランザクションの終了までロックする
+    async fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &PasswordResetTokenHash,
+    ) -> Result<Option<PasswordResetToken>, PasswordResetTokenRepositoryError>;
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<PasswordResetToken>, PasswordResetTokenRepositoryError>;
+    async fn save(
+        &self,
+        token: PasswordResetToken,
+    ) -> Result<PasswordResetToken, PasswordResetTokenRepositoryError>;
+}
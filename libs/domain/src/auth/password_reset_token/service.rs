@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::PasswordResetTokenId;
+
+#[derive(Debug, Error)]
+pub enum PasswordResetTokenIdGenerationError {
+    #[error("パスワードリセットトークンIDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait PasswordResetTokenIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<PasswordResetTokenId, PasswordResetTokenIdGenerationError>;
+}
+
+pub trait PasswordResetTokenIdGeneratorFactory: Send + Sync {
+    fn create_password_reset_token_id_generator(&self) -> Arc<dyn PasswordResetTokenIdGenerator>;
+}
@@ -0,0 +1,2 @@
+pub mod password_reset_token_hash;
+pub mod password_reset_token_id;
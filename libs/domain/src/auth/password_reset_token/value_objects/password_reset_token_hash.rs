@@ -0,0 +1,10 @@
+// パスワードリセットトークンのハッシュ値
+// トークンの平文はメールでのみ本人に渡し、サーバー側ではハッシュ値のみを保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct PasswordResetTokenHash(String);
+
+impl PasswordResetTokenHash {
+    pub fn from_raw_str(hash: &str) -> Self {
+        Self(hash.to_string())
+    }
+}
@@ -20,6 +20,11 @@ impl Policy for ChangeEmailPolicy {
     // 管理者は任意のユーザーのメールアドレスを変更できる
     // ユーザーは自分自身のメールアドレスを変更できる
     fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人の認証情報・連絡先を変更させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
         let target_id = self.0.target_id;
 
         match ctx.actor_role {
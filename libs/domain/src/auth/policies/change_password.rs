@@ -0,0 +1,34 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::UserId,
+};
+
+#[derive(Clone, Copy)]
+pub struct ChangePasswordPayload {
+    pub target_id: UserId,
+}
+
+pub struct ChangePasswordPolicy(ChangePasswordPayload);
+
+impl ChangePasswordPolicy {
+    pub fn new(payload: ChangePasswordPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ChangePasswordPolicy {
+    // パスワードの変更には現在のパスワードが必要なため、ロールにかかわらず自分自身のみ変更できる
+    // (管理者が他のユーザーのパスワードを再設定させたい場合はパスワードリセットを案内する)
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人の認証情報・連絡先を変更させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
+        if ctx.actor_id == self.0.target_id {
+            Ok(())
+        } else {
+            Err(AuthorizationError::Forbidden)
+        }
+    }
+}
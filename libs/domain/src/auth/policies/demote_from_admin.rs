@@ -0,0 +1,30 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::{UserId, UserRole},
+};
+
+#[derive(Clone, Copy)]
+pub struct DemoteFromAdminPayload {
+    pub target_id: UserId,
+}
+
+pub struct DemoteFromAdminPolicy(DemoteFromAdminPayload);
+
+impl DemoteFromAdminPolicy {
+    pub fn new(payload: DemoteFromAdminPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for DemoteFromAdminPolicy {
+    // 管理者は任意の管理者 (自分自身を含む) を降格できる
+    // 最後の管理者を降格できないことは、ドメインモデルで確認する
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        let _target_id = self.0.target_id;
+
+        match ctx.actor_role {
+            UserRole::Admin => Ok(()),               // 管理者は降格可能
+            _ => Err(AuthorizationError::Forbidden), // その他の役割は拒否
+        }
+    }
+}
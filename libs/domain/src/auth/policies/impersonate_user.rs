@@ -0,0 +1,43 @@
+use crate::{
+    auth::{
+        actor::ActorId,
+        policy::{AuthorizationContext, AuthorizationError, Policy},
+    },
+    user::{UserId, UserRole},
+};
+
+#[derive(Clone, Copy)]
+pub struct ImpersonateUserPayload {
+    pub target_id: UserId,
+    pub target_role: UserRole,
+}
+
+pub struct ImpersonateUserPolicy(ImpersonateUserPayload);
+
+impl ImpersonateUserPolicy {
+    pub fn new(payload: ImpersonateUserPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ImpersonateUserPolicy {
+    // 管理者は自分以外の非管理者ユーザーになりすますことができる
+    // なりすましのトークンには実際に操作する管理者を記録するため、サービスアカウントは対象外とする
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中に、さらに別のユーザーになりすますことはできない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+        if !matches!(ctx.actor_id, ActorId::User(_)) {
+            return Err(AuthorizationError::Forbidden);
+        }
+        // 自分自身や他の管理者になりすますことはできない
+        if ctx.actor_id == self.0.target_id || self.0.target_role == UserRole::Admin {
+            return Err(AuthorizationError::CannotImpersonateAdmin);
+        }
+        match ctx.actor_role {
+            UserRole::Admin => Ok(()),
+            _ => Err(AuthorizationError::Forbidden),
+        }
+    }
+}
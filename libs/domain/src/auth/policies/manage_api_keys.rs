@@ -0,0 +1,33 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::UserId,
+};
+
+#[derive(Clone, Copy)]
+pub struct ManageApiKeysPayload {
+    pub target_id: UserId,
+}
+
+pub struct ManageApiKeysPolicy(ManageApiKeysPayload);
+
+impl ManageApiKeysPolicy {
+    pub fn new(payload: ManageApiKeysPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ManageApiKeysPolicy {
+    // API キーは発行したユーザーの権限で動作するため、ロールにかかわらず自分自身のみ操作できる
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人の認証情報・連絡先を変更させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
+        if ctx.actor_id == self.0.target_id {
+            Ok(())
+        } else {
+            Err(AuthorizationError::Forbidden)
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::UserId,
+};
+
+#[derive(Clone, Copy)]
+pub struct ManageMfaPayload {
+    pub target_id: UserId,
+}
+
+pub struct ManageMfaPolicy(ManageMfaPayload);
+
+impl ManageMfaPolicy {
+    pub fn new(payload: ManageMfaPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ManageMfaPolicy {
+    // 2 段階認証の登録・解除には本人の認証アプリが必要なため、ロールにかかわらず自分自身のみ操作できる
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人の認証情報・連絡先を変更させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
+        if ctx.actor_id == self.0.target_id {
+            Ok(())
+        } else {
+            Err(AuthorizationError::Forbidden)
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::UserId,
+};
+
+#[derive(Clone, Copy)]
+pub struct ManagePasskeysPayload {
+    pub target_id: UserId,
+}
+
+pub struct ManagePasskeysPolicy(ManagePasskeysPayload);
+
+impl ManagePasskeysPolicy {
+    pub fn new(payload: ManagePasskeysPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ManagePasskeysPolicy {
+    // パスキーの登録には本人の認証器が必要なため、ロールにかかわらず自分自身のみ操作できる
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人の認証情報・連絡先を変更させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
+        if ctx.actor_id == self.0.target_id {
+            Ok(())
+        } else {
+            Err(AuthorizationError::Forbidden)
+        }
+    }
+}
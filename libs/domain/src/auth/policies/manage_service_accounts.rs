@@ -0,0 +1,29 @@
+use crate::{
+    auth::{
+        actor::ActorId,
+        policy::{AuthorizationContext, AuthorizationError, Policy},
+    },
+    user::UserRole,
+};
+
+#[derive(Clone, Copy)]
+pub struct ManageServiceAccountsPayload;
+
+pub struct ManageServiceAccountsPolicy(ManageServiceAccountsPayload);
+
+impl ManageServiceAccountsPolicy {
+    pub fn new(payload: ManageServiceAccountsPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ManageServiceAccountsPolicy {
+    // 管理者のユーザーのみがサービスアカウントを作成・削除できる
+    // サービスアカウント自身が別のサービスアカウントを作成して権限を広げることは許可しない
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        match (ctx.actor_id, ctx.actor_role) {
+            (ActorId::User(_), UserRole::Admin) => Ok(()),
+            _ => Err(AuthorizationError::Forbidden),
+        }
+    }
+}
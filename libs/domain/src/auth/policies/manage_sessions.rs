@@ -0,0 +1,33 @@
+use crate::{
+    auth::policy::{AuthorizationContext, AuthorizationError, Policy},
+    user::UserId,
+};
+
+#[derive(Clone, Copy)]
+pub struct ManageSessionsPayload {
+    pub target_id: UserId,
+}
+
+pub struct ManageSessionsPolicy(ManageSessionsPayload);
+
+impl ManageSessionsPolicy {
+    pub fn new(payload: ManageSessionsPayload) -> Self {
+        Self(payload)
+    }
+}
+
+impl Policy for ManageSessionsPolicy {
+    // ログイン中のセッションは本人のみが確認・終了できる (管理者であっても他のユーザーのセッションは操作できない)
+    fn check(&self, ctx: &AuthorizationContext) -> Result<(), AuthorizationError> {
+        // なりすまし中の管理者には、本人のセッションを確認・終了させない
+        if ctx.is_impersonating() {
+            return Err(AuthorizationError::ForbiddenWhileImpersonating);
+        }
+
+        if ctx.actor_id == self.0.target_id {
+            Ok(())
+        } else {
+            Err(AuthorizationError::Forbidden)
+        }
+    }
+}
@@ -1,8 +1,16 @@
 pub mod activate_user;
 pub mod change_email;
+pub mod change_password;
 pub mod deactivate_user;
+pub mod demote_from_admin;
 pub mod find_user_by_id_for_suspend;
+pub mod impersonate_user;
 pub mod list_users;
+pub mod manage_api_keys;
+pub mod manage_mfa;
+pub mod manage_passkeys;
+pub mod manage_service_accounts;
+pub mod manage_sessions;
 pub mod promote_to_admin;
 pub mod suspend_user;
 pub mod unlock_user;
@@ -1,12 +1,22 @@
 use crate::{
+    auth::actor::ActorId,
+    auth::api_key::ApiKeyScope,
     auth::policies::{
         activate_user::{ActivateUserPayload, ActivateUserPolicy},
         change_email::{ChangeEmailPayload, ChangeEmailPolicy},
+        change_password::{ChangePasswordPayload, ChangePasswordPolicy},
         deactivate_user::{DeactivateUserPayload, DeactivateUserPolicy},
+        demote_from_admin::{DemoteFromAdminPayload, DemoteFromAdminPolicy},
         find_user_by_id_for_suspend::{
             FindUserByIdForSuspendPayload, FindUserByIdForSuspendPolicy,
         },
+        impersonate_user::{ImpersonateUserPayload, ImpersonateUserPolicy},
         list_users::{ListUsersPayload, ListUsersPolicy},
+        manage_api_keys::{ManageApiKeysPayload, ManageApiKeysPolicy},
+        manage_mfa::{ManageMfaPayload, ManageMfaPolicy},
+        manage_passkeys::{ManagePasskeysPayload, ManagePasskeysPolicy},
+        manage_service_accounts::{ManageServiceAccountsPayload, ManageServiceAccountsPolicy},
+        manage_sessions::{ManageSessionsPayload, ManageSessionsPolicy},
         promote_to_admin::{PromoteToAdminPayload, PromoteToAdminPolicy},
         suspend_user::{SuspendUserPayload, SuspendUserPolicy},
         unlock_user::{UnlockUserPayload, UnlockUserPolicy},
@@ -25,20 +35,69 @@ pub enum UserAction {
     DeactivateUser(DeactivateUserPayload),                 // 退会
     ActivateUser(ActivateUserPayload),                     // 利用再開
     PromoteToAdmin(PromoteToAdminPayload),                 // 管理者への昇格
+    DemoteFromAdmin(DemoteFromAdminPayload),               // 管理者からの降格
     ListUsers(ListUsersPayload),                           // ユーザー一覧の取得
     ViewPublicProfile(ViewPublicProfilePayload),           // プロフィール閲覧
     ViewDetailedProfile(ViewDetailedProfilePayload),       // 詳細プロフィール閲覧
     FindUserByIdForSuspend(FindUserByIdForSuspendPayload), // ユーザーIDによるユーザー検索
     UpdateProfile(UpdateProfilePayload),                   // プロフィール更新
     ChangeEmail(ChangeEmailPayload),                       // メールアドレス変更
+    ChangePassword(ChangePasswordPayload),                 // パスワード変更
+    ManageMfa(ManageMfaPayload),                           // 2段階認証の登録・解除
+    ManagePasskeys(ManagePasskeysPayload),                 // パスキーの登録・一覧・削除
+    ManageApiKeys(ManageApiKeysPayload),                   // API キーの発行・一覧・失効
+    ManageServiceAccounts(ManageServiceAccountsPayload),   // サービスアカウントの作成・一覧・削除
+    ManageSessions(ManageSessionsPayload),                 // ログイン中のセッションの一覧・終了
+    ImpersonateUser(ImpersonateUserPayload),               // 管理者によるなりすまし
+}
+
+impl UserAction {
+    /// API キーでこの操作を行うために必要なスコープ
+    ///
+    /// 認証情報の管理や退会・利用再開は本人による対話的なログインでのみ許可するため、`None` を返す
+    pub fn required_scope(&self) -> Option<ApiKeyScope> {
+        match self {
+            UserAction::ViewPublicProfile(_) | UserAction::ViewDetailedProfile(_) => {
+                Some(ApiKeyScope::ProfileRead)
+            }
+            UserAction::UpdateProfile(_) | UserAction::ChangeEmail(_) => {
+                Some(ApiKeyScope::ProfileWrite)
+            }
+            UserAction::ListUsers(_) | UserAction::FindUserByIdForSuspend(_) => {
+                Some(ApiKeyScope::AdminRead)
+            }
+            UserAction::SuspendUser(_)
+            | UserAction::UnlockUser(_)
+            | UserAction::PromoteToAdmin(_)
+            | UserAction::DemoteFromAdmin(_) => Some(ApiKeyScope::AdminWrite),
+            UserAction::ChangePassword(_)
+            | UserAction::DeactivateUser(_)
+            | UserAction::ActivateUser(_)
+            | UserAction::ManageMfa(_)
+            | UserAction::ManagePasskeys(_)
+            | UserAction::ManageApiKeys(_)
+            | UserAction::ManageServiceAccounts(_)
+            | UserAction::ManageSessions(_)
+            | UserAction::ImpersonateUser(_) => None,
+        }
+    }
 }
 
 pub struct AuthorizationContext {
-    pub actor_id: UserId,
+    pub actor_id: ActorId,
     pub actor_role: UserRole,
+    /// なりすましの場合に、実際に操作している管理者の ID
+    pub impersonator_id: Option<UserId>,
     pub action: UserAction,
 }
 
+impl AuthorizationContext {
+    /// 管理者が他のユーザーになりすまして操作しているか
+    pub fn is_impersonating(&self) -> bool {
+        self.impersonator_id.is_some()
+    }
+}
+
 // 認可エラーの定義
 // TODO: #38 で整理
 #[derive(Debug, thiserror::Error)]
@@ -51,6 +110,12 @@ pub enum AuthorizationError {
     CannotUnlockSelf,
     #[error("管理者を管理者が停止することはできません")]
     CannotSuspendAdmin,
+    #[error("API キーに必要なスコープが付与されていません")]
+    InsufficientScope,
+    #[error("なりすまし中はこの操作を行えません")]
+    ForbiddenWhileImpersonating,
+    #[error("自分自身や管理者になりすますことはできません")]
+    CannotImpersonateAdmin,
 }
 
 impl AuthorizationError {
@@ -60,6 +125,11 @@ impl AuthorizationError {
             AuthorizationError::CannotSuspendSelf => "自分自身を利用停止にすることはできません",
             AuthorizationError::CannotUnlockSelf => "自分自身のロック解除はできません",
             AuthorizationError::CannotSuspendAdmin => "管理者を管理者が停止することはできません",
+            AuthorizationError::InsufficientScope => "API キーに必要なスコープが付与されていません",
+            AuthorizationError::ForbiddenWhileImpersonating => "なりすまし中はこの操作を行えません",
+            AuthorizationError::CannotImpersonateAdmin => {
+                "自分自身や管理者になりすますことはできません"
+            }
         }
     }
 }
@@ -69,8 +139,12 @@ pub trait Policy {
 }
 
 pub trait Actor {
-    fn actor_id(&self) -> UserId;
+    fn actor_id(&self) -> ActorId;
     fn actor_role(&self) -> UserRole;
+    /// API キーで認証された場合に付与されているスコープ。対話的なログインの場合は `None`
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScope>>;
+    /// 管理者が他のユーザーになりすましている場合に、実際に操作している管理者の ID
+    fn impersonator_id(&self) -> Option<UserId>;
 }
 
 // 認可サービス（ポリシーの管理）
@@ -78,12 +152,22 @@ pub struct AuthorizationService;
 
 impl AuthorizationService {
     pub fn can(actor: &impl Actor, action: UserAction) -> Result<(), AuthorizationError> {
+        // API キーによる操作は、ロールに基づくポリシーに加えてスコープでも制限する
+        if let Some(scopes) = actor.actor_scopes() {
+            match action.required_scope() {
+                Some(scope) if scopes.contains(&scope) => {}
+                _ => return Err(AuthorizationError::InsufficientScope),
+            }
+        }
+
         let actor_id = actor.actor_id();
         let actor_role = actor.actor_role();
+        let impersonator_id = actor.impersonator_id();
 
         let ctx = AuthorizationContext {
             actor_id,
             actor_role,
+            impersonator_id,
             action,
         };
 
@@ -93,6 +177,7 @@ impl AuthorizationService {
             UserAction::DeactivateUser(payload) => Box::new(DeactivateUserPolicy::new(payload)),
             UserAction::ActivateUser(payload) => Box::new(ActivateUserPolicy::new(payload)),
             UserAction::PromoteToAdmin(payload) => Box::new(PromoteToAdminPolicy::new(payload)),
+            UserAction::DemoteFromAdmin(payload) => Box::new(DemoteFromAdminPolicy::new(payload)),
             UserAction::ListUsers(payload) => Box::new(ListUsersPolicy::new(payload)),
             UserAction::ViewDetailedProfile(payload) => {
                 Box::new(ViewDetailedProfilePolicy::new(payload))
@@ -105,8 +190,138 @@ impl AuthorizationService {
             ),
             UserAction::UpdateProfile(payload) => Box::new(UpdateProfilePolicy::new(payload)),
             UserAction::ChangeEmail(payload) => Box::new(ChangeEmailPolicy::new(payload)),
+            UserAction::ChangePassword(payload) => Box::new(ChangePasswordPolicy::new(payload)),
+            UserAction::ManageMfa(payload) => Box::new(ManageMfaPolicy::new(payload)),
+            UserAction::ManagePasskeys(payload) => Box::new(ManagePasskeysPolicy::new(payload)),
+            UserAction::ManageApiKeys(payload) => Box::new(ManageApiKeysPolicy::new(payload)),
+            UserAction::ManageServiceAccounts(payload) => {
+                Box::new(ManageServiceAccountsPolicy::new(payload))
+            }
+            UserAction::ManageSessions(payload) => Box::new(ManageSessionsPolicy::new(payload)),
+            UserAction::ImpersonateUser(payload) => Box::new(ImpersonateUserPolicy::new(payload)),
         };
 
         policy.check(&ctx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct TestActor {
+        user_id: UserId,
+        role: UserRole,
+        scopes: Option<Vec<ApiKeyScope>>,
+        impersonator_id: Option<UserId>,
+    }
+
+    impl Actor for TestActor {
+        fn actor_id(&self) -> ActorId {
+            ActorId::User(self.user_id)
+        }
+
+        fn actor_role(&self) -> UserRole {
+            self.role
+        }
+
+        fn actor_scopes(&self) -> Option<Vec<ApiKeyScope>> {
+            self.scopes.clone()
+        }
+
+        fn impersonator_id(&self) -> Option<UserId> {
+            self.impersonator_id
+        }
+    }
+
+    fn deactivate(target_id: UserId) -> UserAction {
+        UserAction::DeactivateUser(DeactivateUserPayload { target_id })
+    }
+
+    fn activate(target_id: UserId) -> UserAction {
+        UserAction::ActivateUser(ActivateUserPayload { target_id })
+    }
+
+    #[rstest]
+    #[case::deactivate(deactivate as fn(UserId) -> UserAction)]
+    #[case::activate(activate as fn(UserId) -> UserAction)]
+    fn test_account_lifecycle_requires_interactive_login(#[case] action: fn(UserId) -> UserAction) {
+        let user_id = UserId::from(Uuid::now_v7());
+        assert_eq!(action(user_id).required_scope(), None);
+
+        // 管理者の API キーであっても、退会・利用再開は行えない
+        let api_key_actor = TestActor {
+            user_id,
+            role: UserRole::Admin,
+            scopes: Some(vec![ApiKeyScope::AdminRead, ApiKeyScope::AdminWrite]),
+            impersonator_id: None,
+        };
+        assert!(matches!(
+            AuthorizationService::can(&api_key_actor, action(user_id)),
+            Err(AuthorizationError::InsufficientScope)
+        ));
+
+        let interactive_actor = TestActor {
+            scopes: None,
+            ..api_key_actor
+        };
+        assert!(AuthorizationService::can(&interactive_actor, action(user_id)).is_ok());
+    }
+
+    fn change_email(target_id: UserId) -> UserAction {
+        UserAction::ChangeEmail(ChangeEmailPayload { target_id })
+    }
+
+    fn change_password(target_id: UserId) -> UserAction {
+        UserAction::ChangePassword(ChangePasswordPayload { target_id })
+    }
+
+    fn manage_mfa(target_id: UserId) -> UserAction {
+        UserAction::ManageMfa(ManageMfaPayload { target_id })
+    }
+
+    fn manage_passkeys(target_id: UserId) -> UserAction {
+        UserAction::ManagePasskeys(ManagePasskeysPayload { target_id })
+    }
+
+    fn manage_api_keys(target_id: UserId) -> UserAction {
+        UserAction::ManageApiKeys(ManageApiKeysPayload { target_id })
+    }
+
+    fn manage_sessions(target_id: UserId) -> UserAction {
+        UserAction::ManageSessions(ManageSessionsPayload { target_id })
+    }
+
+    #[rstest]
+    #[case::change_email(change_email as fn(UserId) -> UserAction)]
+    #[case::change_password(change_password as fn(UserId) -> UserAction)]
+    #[case::manage_mfa(manage_mfa as fn(UserId) -> UserAction)]
+    #[case::manage_passkeys(manage_passkeys as fn(UserId) -> UserAction)]
+    #[case::manage_api_keys(manage_api_keys as fn(UserId) -> UserAction)]
+    #[case::manage_sessions(manage_sessions as fn(UserId) -> UserAction)]
+    fn test_sensitive_self_service_is_forbidden_while_impersonating(
+        #[case] action: fn(UserId) -> UserAction,
+    ) {
+        let user_id = UserId::from(Uuid::now_v7());
+
+        let impersonated_actor = TestActor {
+            user_id,
+            role: UserRole::User,
+            scopes: None,
+            impersonator_id: Some(UserId::from(Uuid::now_v7())),
+        };
+        assert!(matches!(
+            AuthorizationService::can(&impersonated_actor, action(user_id)),
+            Err(AuthorizationError::ForbiddenWhileImpersonating)
+        ));
+
+        let actor = TestActor {
+            impersonator_id: None,
+            ..impersonated_actor
+        };
+        assert!(AuthorizationService::can(&actor, action(user_id)).is_ok());
+    }
+}
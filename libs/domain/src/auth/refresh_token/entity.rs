@@ -0,0 +1,417 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+use strum::EnumString;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{
+    RefreshTokenFamilyId, RefreshTokenHash, RefreshTokenId, RefreshTokenReconstructionError,
+    RefreshTokenRotationError,
+};
+
+#[derive(Entity)]
+pub struct RefreshToken {
+    #[entity_id]
+    id: RefreshTokenId,
+    family_id: RefreshTokenFamilyId,
+    user_id: UserId,
+    token_epoch: u32, // 発行時点のユーザーのトークン世代番号
+    token_hash: RefreshTokenHash,
+    state: RefreshTokenState,
+    issued_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    // ログイン時に新しいファミリーの最初のトークンを発行するためのコンストラクタ
+    pub fn issue(
+        id: RefreshTokenId,
+        user_id: UserId,
+        token_epoch: u32,
+        token_hash: RefreshTokenHash,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Self {
+        Self {
+            id,
+            family_id: id.into(),
+            user_id,
+            token_epoch,
+            token_hash,
+            state: RefreshTokenState::Active,
+            issued_at: clock.now(),
+            expires_at,
+        }
+    }
+
+    // 永続化処理されたトークンを再構築するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: RefreshTokenId,
+        family_id: RefreshTokenFamilyId,
+        user_id: UserId,
+        token_epoch: u32,
+        token_hash: RefreshTokenHash,
+        state_source: RefreshTokenStateRaw,
+        issued_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Self, RefreshTokenReconstructionError> {
+        let state = state_source.try_into()?;
+
+        Ok(Self {
+            id,
+            family_id,
+            user_id,
+            token_epoch,
+            token_hash,
+            state,
+            issued_at,
+            expires_at,
+        })
+    }
+
+    pub fn id(&self) -> RefreshTokenId {
+        self.id
+    }
+
+    pub fn family_id(&self) -> RefreshTokenFamilyId {
+        self.family_id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn token_epoch(&self) -> u32 {
+        self.token_epoch
+    }
+
+    pub fn token_hash(&self) -> &RefreshTokenHash {
+        &self.token_hash
+    }
+
+    pub fn state(&self) -> &RefreshTokenState {
+        &self.state
+    }
+
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.issued_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshTokenState {
+    Active,                                // 未使用
+    Rotated { rotated_at: DateTime<Utc> }, // 後継トークンと交換済み
+    Revoked { revoked_at: DateTime<Utc> }, // 失効済み
+}
+
+// トークンの状態遷移に関するメソッド群
+impl RefreshToken {
+    /// トークンを使用済みにし、同じファミリーに属する後継トークンを返す
+    ///
+    /// 使用済みのトークンが再度提示された場合は `Reused` を返す。
+    /// 呼び出し側はこれを漏洩の兆候とみなし、ファミリー全体を失効させること。
+    ///
+    /// 発行後にユーザーのトークン世代番号 (`current_token_epoch`) が進んでいる場合は `EpochMismatch` を返す。
+    /// 呼び出し側はセッションを終了させること。
+    pub fn rotate(
+        &mut self,
+        next_id: RefreshTokenId,
+        next_token_hash: RefreshTokenHash,
+        next_expires_at: DateTime<Utc>,
+        current_token_epoch: u32,
+        clock: &dyn Clock,
+    ) -> Result<RefreshToken, RefreshTokenRotationError> {
+        let now = clock.now();
+
+        match &self.state {
+            RefreshTokenState::Active => {
+                if self.expires_at <= now {
+                    Err(RefreshTokenRotationError::Expired)?
+                }
+                if self.token_epoch != current_token_epoch {
+                    Err(RefreshTokenRotationError::EpochMismatch)?
+                }
+            }
+            RefreshTokenState::Rotated { .. } => Err(RefreshTokenRotationError::Reused)?,
+            RefreshTokenState::Revoked { .. } => Err(RefreshTokenRotationError::Revoked)?,
+        }
+
+        self.state = RefreshTokenState::Rotated { rotated_at: now };
+
+        Ok(RefreshToken {
+            id: next_id,
+            family_id: self.family_id,
+            user_id: self.user_id,
+            token_epoch: self.token_epoch,
+            token_hash: next_token_hash,
+            state: RefreshTokenState::Active,
+            issued_at: now,
+            expires_at: next_expires_at,
+        })
+    }
+
+    pub fn revoke(&mut self, clock: &dyn Clock) {
+        match &self.state {
+            RefreshTokenState::Active | RefreshTokenState::Rotated { .. } => {
+                self.state = RefreshTokenState::Revoked {
+                    revoked_at: clock.now(),
+                };
+            }
+            RefreshTokenState::Revoked { .. } => {} // すでに失効済みなので何もしない
+        }
+    }
+}
+
+pub struct RefreshTokenStateRaw {
+    pub status: String,
+    pub rotated_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<RefreshTokenStateRaw> for RefreshTokenState {
+    type Error = RefreshTokenReconstructionError;
+
+    fn try_from(raw: RefreshTokenStateRaw) -> Result<Self, Self::Error> {
+        let RefreshTokenStateRaw {
+            status,
+            rotated_at,
+            revoked_at,
+        } = raw;
+
+        let kind = status.parse::<RefreshTokenStateKind>().map_err(|_| {
+            RefreshTokenReconstructionError::InvalidStatus {
+                invalid_status: status,
+            }
+        })?;
+
+        match kind {
+            RefreshTokenStateKind::Active => Ok(RefreshTokenState::Active),
+            RefreshTokenStateKind::Rotated => Ok(RefreshTokenState::Rotated {
+                rotated_at: rotated_at
+                    .ok_or(RefreshTokenReconstructionError::RotatedButNoRotatedAt)?,
+            }),
+            RefreshTokenStateKind::Revoked => Ok(RefreshTokenState::Revoked {
+                revoked_at: revoked_at
+                    .ok_or(RefreshTokenReconstructionError::RevokedButNoRevokedAt)?,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, strum::Display, EnumString, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum RefreshTokenStateKind {
+    Active,
+    Rotated,
+    Revoked,
+}
+
+impl RefreshTokenState {
+    pub fn kind(&self) -> &'static str {
+        self.kind_raw().into()
+    }
+
+    fn kind_raw(&self) -> RefreshTokenStateKind {
+        match self {
+            RefreshTokenState::Active => RefreshTokenStateKind::Active,
+            RefreshTokenState::Rotated { .. } => RefreshTokenStateKind::Rotated,
+            RefreshTokenState::Revoked { .. } => RefreshTokenStateKind::Revoked,
+        }
+    }
+
+    pub fn rotated_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            RefreshTokenState::Rotated { rotated_at } => Some(*rotated_at),
+            _ => None,
+        }
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            RefreshTokenState::Revoked { revoked_at } => Some(*revoked_at),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    #[fixture]
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    #[fixture]
+    fn active_token(base_time: DateTime<Utc>) -> RefreshToken {
+        RefreshToken::issue(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            0,
+            RefreshTokenHash::from_raw_str("hash-1"),
+            base_time + Duration::days(14),
+            &clock_at(base_time),
+        )
+    }
+
+    #[rstest]
+    fn test_issue_starts_new_family(active_token: RefreshToken) {
+        assert_eq!(active_token.family_id(), active_token.id().into());
+        assert_eq!(active_token.state(), &RefreshTokenState::Active);
+    }
+
+    #[rstest]
+    fn test_rotate_success(mut active_token: RefreshToken, base_time: DateTime<Utc>) {
+        let now = base_time + Duration::hours(1);
+        let next_id: RefreshTokenId = Uuid::now_v7().into();
+
+        let next = active_token
+            .rotate(
+                next_id,
+                RefreshTokenHash::from_raw_str("hash-2"),
+                now + Duration::days(14),
+                0,
+                &clock_at(now),
+            )
+            .unwrap();
+
+        assert_eq!(
+            active_token.state(),
+            &RefreshTokenState::Rotated { rotated_at: now }
+        );
+        assert_eq!(next.id(), next_id);
+        assert_eq!(next.family_id(), active_token.family_id());
+        assert_eq!(next.user_id(), active_token.user_id());
+        assert_eq!(next.token_epoch(), active_token.token_epoch());
+        assert_eq!(next.state(), &RefreshTokenState::Active);
+        assert_eq!(next.issued_at(), now);
+    }
+
+    #[rstest]
+    #[case::expired(
+        RefreshTokenState::Active,
+        Duration::days(15),
+        0,
+        RefreshTokenRotationError::Expired
+    )]
+    #[case::epoch_mismatch(
+        RefreshTokenState::Active,
+        Duration::hours(1),
+        1,
+        RefreshTokenRotationError::EpochMismatch
+    )]
+    #[case::reused(
+        RefreshTokenState::Rotated { rotated_at: Utc::now() },
+        Duration::hours(1),
+        0,
+        RefreshTokenRotationError::Reused
+    )]
+    #[case::revoked(
+        RefreshTokenState::Revoked { revoked_at: Utc::now() },
+        Duration::hours(1),
+        0,
+        RefreshTokenRotationError::Revoked
+    )]
+    fn test_rotate_failure(
+        mut active_token: RefreshToken,
+        base_time: DateTime<Utc>,
+        #[case] state: RefreshTokenState,
+        #[case] elapsed: Duration,
+        #[case] current_token_epoch: u32,
+        #[case] expected: RefreshTokenRotationError,
+    ) {
+        active_token.state = state;
+        let now = base_time + elapsed;
+
+        let result = active_token.rotate(
+            Uuid::now_v7().into(),
+            RefreshTokenHash::from_raw_str("hash-2"),
+            now + Duration::days(14),
+            current_token_epoch,
+            &clock_at(now),
+        );
+
+        assert_eq!(result.err(), Some(expected));
+        assert_eq!(active_token.state(), &state);
+    }
+
+    #[rstest]
+    fn test_revoke(mut active_token: RefreshToken, base_time: DateTime<Utc>) {
+        let now = base_time + Duration::hours(1);
+        active_token.revoke(&clock_at(now));
+        assert_eq!(
+            active_token.state(),
+            &RefreshTokenState::Revoked { revoked_at: now }
+        );
+
+        // すでに失効済みの場合は失効日時を更新しない
+        active_token.revoke(&clock_at(now + Duration::hours(1)));
+        assert_eq!(
+            active_token.state(),
+            &RefreshTokenState::Revoked { revoked_at: now }
+        );
+    }
+
+    #[rstest]
+    #[case("active", None, None, RefreshTokenState::Active)]
+    #[case("rotated", Some(base_time()), None, RefreshTokenState::Rotated { rotated_at: base_time() })]
+    #[case("revoked", None, Some(base_time()), RefreshTokenState::Revoked { revoked_at: base_time() })]
+    fn test_try_from_state_raw(
+        #[case] status: &str,
+        #[case] rotated_at: Option<DateTime<Utc>>,
+        #[case] revoked_at: Option<DateTime<Utc>>,
+        #[case] expected: RefreshTokenState,
+    ) {
+        let raw = RefreshTokenStateRaw {
+            status: status.to_string(),
+            rotated_at,
+            revoked_at,
+        };
+
+        let state: RefreshTokenState = raw.try_into().unwrap();
+        assert_eq!(state, expected);
+    }
+
+    #[rstest]
+    #[case("unknown", RefreshTokenReconstructionError::InvalidStatus { invalid_status: "unknown".to_string() })]
+    #[case("rotated", RefreshTokenReconstructionError::RotatedButNoRotatedAt)]
+    #[case("revoked", RefreshTokenReconstructionError::RevokedButNoRevokedAt)]
+    fn test_try_from_invalid_state_raw(
+        #[case] status: &str,
+        #[case] expected: RefreshTokenReconstructionError,
+    ) {
+        let raw = RefreshTokenStateRaw {
+            status: status.to_string(),
+            rotated_at: None,
+            revoked_at: None,
+        };
+
+        let result: Result<RefreshTokenState, _> = raw.try_into();
+        assert_eq!(result.unwrap_err(), expected);
+    }
+}
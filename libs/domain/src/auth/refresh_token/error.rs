@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RefreshTokenRotationError {
+    #[error("リフレッシュトークンの有効期限が切れています")]
+    Expired,
+
+    #[error("使用済みのリフレッシュトークンが再利用されました")]
+    Reused,
+
+    #[error("リフレッシュトークンは失効しています")]
+    Revoked,
+
+    #[error("アカウントの変更により、リフレッシュトークンは無効になっています")]
+    EpochMismatch,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RefreshTokenReconstructionError {
+    #[error("不正な形式のステータスが保存されています: {invalid_status}")]
+    InvalidStatus { invalid_status: String },
+
+    #[error("Rotated にもかかわらず rotated_at が None です")]
+    RotatedButNoRotatedAt,
+
+    #[error("Revoked にもかかわらず revoked_at が None です")]
+    RevokedButNoRevokedAt,
+}
@@ -0,0 +1,16 @@
+mod entity;
+mod error;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::{RefreshToken, RefreshTokenState, RefreshTokenStateKind, RefreshTokenStateRaw};
+pub use error::{RefreshTokenReconstructionError, RefreshTokenRotationError};
+pub use repository::{RefreshTokenRepository, RefreshTokenRepositoryError};
+pub use service::{
+    RefreshTokenIdGenerationError, RefreshTokenIdGenerator, RefreshTokenIdGeneratorFactory,
+};
+pub use value_objects::{
+    refresh_token_hash::RefreshTokenHash,
+    refresh_token_id::{RefreshTokenFamilyId, RefreshTokenId},
+};
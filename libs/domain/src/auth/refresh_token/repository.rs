@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{
+    RefreshToken, RefreshTokenFamilyId, RefreshTokenHash, RefreshTokenIdGenerationError,
+    RefreshTokenReconstructionError,
+};
+
+#[derive(Debug, Error)]
+pub enum RefreshTokenRepositoryError {
+    #[error(transparent)]
+    ReconstructionError(#[from] RefreshTokenReconstructionError),
+
+    #[error(transparent)]
+    IdGenerationError(#[from] RefreshTokenIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    /// トークンのハッシュ値からリフレッシュトークンを取得する
+    ///
+    /// 同じトークンによる同時のローテーションを待たせるため、取得した行をトランザクションの終了までロックする
+    async fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &RefreshTokenHash,
+    ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError>;
+    /// ユーザーの未使用のリフレッシュトークンを取得する
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<RefreshToken>, RefreshTokenRepositoryError>;
+    async fn find_by_family_id(
+        &self,
+        family_id: RefreshTokenFamilyId,
+    ) -> Result<Vec<RefreshToken>, RefreshTokenRepositoryError>;
+    async fn save(&self, token: RefreshToken) -> Result<RefreshToken, RefreshTokenRepositoryError>;
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::RefreshTokenId;
+
+#[derive(Debug, Error)]
+pub enum RefreshTokenIdGenerationError {
+    #[error("リフレッシュトークンIDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait RefreshTokenIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<RefreshTokenId, RefreshTokenIdGenerationError>;
+}
+
+pub trait RefreshTokenIdGeneratorFactory: Send + Sync {
+    fn create_refresh_token_id_generator(&self) -> Arc<dyn RefreshTokenIdGenerator>;
+}
@@ -0,0 +1,2 @@
+pub mod refresh_token_hash;
+pub mod refresh_token_id;
@@ -0,0 +1,10 @@
+// リフレッシュトークンのハッシュ値
+// トークンの平文はクライアントにのみ渡し、サーバー側ではハッシュ値のみを保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct RefreshTokenHash(String);
+
+impl RefreshTokenHash {
+    pub fn from_raw_str(hash: &str) -> Self {
+        Self(hash.to_string())
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::From,
+    derive_more::Into,
+)]
+pub struct RefreshTokenId(Uuid);
+
+// ローテーションで連鎖するリフレッシュトークン群（ファミリー）の識別子
+// ファミリーの最初のトークンの ID をそのままファミリー ID として用いる
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::From,
+    derive_more::Into,
+)]
+pub struct RefreshTokenFamilyId(Uuid);
+
+impl From<RefreshTokenId> for RefreshTokenFamilyId {
+    fn from(id: RefreshTokenId) -> Self {
+        Self(id.0)
+    }
+}
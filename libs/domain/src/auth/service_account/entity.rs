@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+
+use crate::{shared::service::clock::Clock, user::UserRole};
+
+use super::{ServiceAccountId, ServiceAccountSecretHash};
+
+/// 人間のユーザーを介さずに API を呼び出す、内部サービス向けの主体
+///
+/// OAuth 2.0 のクライアントクレデンシャルグラントで、ID (`client_id`) とシークレットを提示してアクセストークンを取得する
+#[derive(Entity)]
+pub struct ServiceAccount {
+    #[entity_id]
+    id: ServiceAccountId,
+    /// 監査記録や一覧で見分けるための名前
+    name: String,
+    /// 割り当てたロール。認可ポリシーはユーザーと同じロールで判定する
+    role: UserRole,
+    secret_hash: ServiceAccountSecretHash,
+    created_at: DateTime<Utc>,
+    last_authenticated_at: Option<DateTime<Utc>>,
+}
+
+impl ServiceAccount {
+    // 新しいサービスアカウントを作成するためのコンストラクタ
+    pub fn create(
+        id: ServiceAccountId,
+        name: String,
+        role: UserRole,
+        secret_hash: ServiceAccountSecretHash,
+        clock: &dyn Clock,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            role,
+            secret_hash,
+            created_at: clock.now(),
+            last_authenticated_at: None,
+        }
+    }
+
+    // 永続化処理されたサービスアカウントを再構築するためのコンストラクタ
+    pub fn reconstruct(
+        id: ServiceAccountId,
+        name: String,
+        role: UserRole,
+        secret_hash: ServiceAccountSecretHash,
+        created_at: DateTime<Utc>,
+        last_authenticated_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            role,
+            secret_hash,
+            created_at,
+            last_authenticated_at,
+        }
+    }
+
+    pub fn id(&self) -> ServiceAccountId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn role(&self) -> UserRole {
+        self.role
+    }
+
+    pub fn secret_hash(&self) -> &ServiceAccountSecretHash {
+        &self.secret_hash
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_authenticated_at(&self) -> Option<DateTime<Utc>> {
+        self.last_authenticated_at
+    }
+}
+
+// 認証に関するメソッド群
+impl ServiceAccount {
+    /// 提示されたシークレットのハッシュ値が一致すれば認証日時を記録する
+    ///
+    /// 一致しない場合は `false` を返し、認証日時は更新しない
+    pub fn authenticate(
+        &mut self,
+        secret_hash: &ServiceAccountSecretHash,
+        clock: &dyn Clock,
+    ) -> bool {
+        if &self.secret_hash != secret_hash {
+            return false;
+        }
+
+        self.last_authenticated_at = Some(clock.now());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    fn service_account() -> ServiceAccount {
+        ServiceAccount::create(
+            Uuid::now_v7().into(),
+            "billing-worker".to_string(),
+            UserRole::Admin,
+            ServiceAccountSecretHash::from_raw_str("hash"),
+            &clock_at(base_time()),
+        )
+    }
+
+    #[rstest]
+    #[case::matching_secret("hash", true)]
+    #[case::wrong_secret("other", false)]
+    fn test_authenticate(#[case] secret_hash: &str, #[case] expected: bool) {
+        let mut service_account = service_account();
+        let now = base_time() + Duration::minutes(5);
+
+        let result = service_account.authenticate(
+            &ServiceAccountSecretHash::from_raw_str(secret_hash),
+            &clock_at(now),
+        );
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            service_account.last_authenticated_at(),
+            expected.then_some(now)
+        );
+    }
+}
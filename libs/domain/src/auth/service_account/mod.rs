@@ -0,0 +1,13 @@
+mod entity;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::ServiceAccount;
+pub use repository::{ServiceAccountRepository, ServiceAccountRepositoryError};
+pub use service::{
+    ServiceAccountIdGenerationError, ServiceAccountIdGenerator, ServiceAccountIdGeneratorFactory,
+};
+pub use value_objects::{
+    service_account_id::ServiceAccountId, service_account_secret_hash::ServiceAccountSecretHash,
+};
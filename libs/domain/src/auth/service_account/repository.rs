@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use super::{ServiceAccount, ServiceAccountId, ServiceAccountIdGenerationError};
+
+#[derive(Debug, Error)]
+pub enum ServiceAccountRepositoryError {
+    #[error(transparent)]
+    IdGenerationError(#[from] ServiceAccountIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait ServiceAccountRepository: Send + Sync {
+    async fn find_by_id(
+        &self,
+        id: ServiceAccountId,
+    ) -> Result<Option<ServiceAccount>, ServiceAccountRepositoryError>;
+    async fn find_all(&self) -> Result<Vec<ServiceAccount>, ServiceAccountRepositoryError>;
+    async fn save(
+        &self,
+        service_account: ServiceAccount,
+    ) -> Result<ServiceAccount, ServiceAccountRepositoryError>;
+    async fn delete(&self, id: ServiceAccountId) -> Result<(), ServiceAccountRepositoryError>;
+}
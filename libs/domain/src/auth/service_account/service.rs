@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::ServiceAccountId;
+
+#[derive(Debug, Error)]
+pub enum ServiceAccountIdGenerationError {
+    #[error("サービスアカウントIDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait ServiceAccountIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<ServiceAccountId, ServiceAccountIdGenerationError>;
+}
+
+pub trait ServiceAccountIdGeneratorFactory: Send + Sync {
+    fn create_service_account_id_generator(&self) -> Arc<dyn ServiceAccountIdGenerator>;
+}
@@ -0,0 +1,2 @@
+pub mod service_account_id;
+pub mod service_account_secret_hash;
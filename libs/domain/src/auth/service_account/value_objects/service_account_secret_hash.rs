@@ -0,0 +1,10 @@
+// サービスアカウントのクライアントシークレットのハッシュ値
+// シークレットの平文は作成時に一度だけ管理者に表示し、サーバー側ではハッシュ値のみを保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct ServiceAccountSecretHash(String);
+
+impl ServiceAccountSecretHash {
+    pub fn from_raw_str(hash: &str) -> Self {
+        Self(hash.to_string())
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{DeviceLabel, SessionId};
+
+// 保存する User-Agent の最大文字数 (クライアントが任意の長さの値を送れるため)
+const MAX_USER_AGENT_CHARS: usize = 512;
+
+/// ログインしたクライアントの情報
+#[derive(derive_more::Debug, Clone, Default)]
+pub struct SessionClient {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+/// ログインごとに開始するセッション
+///
+/// リフレッシュトークンのファミリーと 1 対 1 に対応し、ユーザーがログイン中の端末を確認・終了するために記録する
+#[derive(Entity)]
+pub struct Session {
+    #[entity_id]
+    id: SessionId,
+    user_id: UserId,
+    device_label: DeviceLabel,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    created_at: DateTime<Utc>,
+    /// 最後にトークンを発行 (ログイン・再発行) した日時
+    last_seen_at: DateTime<Utc>,
+    /// リフレッシュトークンの有効期限。再発行のたびに延長される
+    expires_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    // ログイン時に新しいセッションを開始するためのコンストラクタ
+    pub fn start(
+        id: SessionId,
+        user_id: UserId,
+        client: SessionClient,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Self {
+        let SessionClient {
+            user_agent,
+            ip_address,
+        } = client;
+        let user_agent =
+            user_agent.map(|user_agent| user_agent.chars().take(MAX_USER_AGENT_CHARS).collect());
+        let now = clock.now();
+
+        Self {
+            id,
+            user_id,
+            device_label: DeviceLabel::from_user_agent(user_agent.as_deref()),
+            user_agent,
+            ip_address,
+            created_at: now,
+            last_seen_at: now,
+            expires_at,
+            revoked_at: None,
+        }
+    }
+
+    // 永続化処理されたセッションを再構築するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: SessionId,
+        user_id: UserId,
+        device_label: DeviceLabel,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+        created_at: DateTime<Utc>,
+        last_seen_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        revoked_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            device_label,
+            user_agent,
+            ip_address,
+            created_at,
+            last_seen_at,
+            expires_at,
+            revoked_at,
+        }
+    }
+
+    pub fn id(&self) -> SessionId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn device_label(&self) -> &DeviceLabel {
+        &self.device_label
+    }
+
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    pub fn ip_address(&self) -> Option<&str> {
+        self.ip_address.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_seen_at(&self) -> DateTime<Utc> {
+        self.last_seen_at
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+
+    /// 終了しておらず、有効期限内であるか
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+}
+
+// セッションの状態遷移に関するメソッド群
+impl Session {
+    /// トークンの再発行を記録し、有効期限を延長する
+    pub fn record_activity(&mut self, expires_at: DateTime<Utc>, clock: &dyn Clock) {
+        self.last_seen_at = clock.now();
+        self.expires_at = expires_at;
+    }
+
+    pub fn revoke(&mut self, clock: &dyn Clock) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(clock.now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    #[fixture]
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    fn start_session(base_time: DateTime<Utc>, user_agent: Option<String>) -> Session {
+        Session::start(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            SessionClient {
+                user_agent,
+                ip_address: Some("192.0.2.1".to_string()),
+            },
+            base_time + Duration::days(30),
+            &clock_at(base_time),
+        )
+    }
+
+    #[rstest]
+    fn test_start_session_derives_device_label(base_time: DateTime<Utc>) {
+        let session = start_session(
+            base_time,
+            Some("Mozilla/5.0 (X11; Linux x86_64; rv:127.0) Gecko/20100101 Firefox/127.0".into()),
+        );
+
+        assert_eq!(session.device_label().as_str(), "Firefox (Linux)");
+        assert_eq!(session.created_at(), base_time);
+        assert_eq!(session.last_seen_at(), base_time);
+        assert!(session.is_active(base_time));
+    }
+
+    #[rstest]
+    fn test_start_session_truncates_long_user_agent(base_time: DateTime<Utc>) {
+        let session = start_session(base_time, Some("a".repeat(MAX_USER_AGENT_CHARS + 10)));
+
+        assert_eq!(
+            session.user_agent().unwrap().chars().count(),
+            MAX_USER_AGENT_CHARS
+        );
+    }
+
+    #[rstest]
+    fn test_record_activity_extends_expiry(base_time: DateTime<Utc>) {
+        let mut session = start_session(base_time, None);
+        let later = base_time + Duration::days(10);
+
+        session.record_activity(later + Duration::days(30), &clock_at(later));
+
+        assert_eq!(session.last_seen_at(), later);
+        assert!(session.is_active(base_time + Duration::days(31)));
+    }
+
+    #[rstest]
+    fn test_session_is_inactive_after_expiry(base_time: DateTime<Utc>) {
+        let session = start_session(base_time, None);
+
+        assert!(!session.is_active(base_time + Duration::days(30)));
+    }
+
+    #[rstest]
+    fn test_revoke_keeps_first_revoked_at(base_time: DateTime<Utc>) {
+        let mut session = start_session(base_time, None);
+
+        session.revoke(&clock_at(base_time + Duration::minutes(1)));
+        session.revoke(&clock_at(base_time + Duration::minutes(2)));
+
+        assert_eq!(session.revoked_at(), Some(base_time + Duration::minutes(1)));
+        assert!(!session.is_active(base_time + Duration::minutes(3)));
+    }
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SessionLimitError {
+    #[error("Invalid configuration for SessionLimit: {0}")]
+    InvalidConfig(String),
+}
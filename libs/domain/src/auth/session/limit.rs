@@ -0,0 +1,104 @@
+use super::{Session, SessionId, SessionLimitError};
+
+/// ユーザーごとに同時に保持できるセッション数の上限
+pub struct SessionLimit {
+    /// `None` の場合は上限を設けない
+    max_sessions_per_user: Option<u32>,
+}
+
+impl SessionLimit {
+    pub fn new(max_sessions_per_user: Option<u32>) -> Result<Self, SessionLimitError> {
+        if max_sessions_per_user == Some(0) {
+            return Err(SessionLimitError::InvalidConfig(
+                "max_sessions_per_user must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            max_sessions_per_user,
+        })
+    }
+
+    pub fn max_sessions_per_user(&self) -> Option<u32> {
+        self.max_sessions_per_user
+    }
+
+    /// 新しいセッションを開始する前に終了させるセッション
+    ///
+    /// 新しいセッションを加えても上限を超えないよう、開始日時の古いものから選ぶ
+    pub fn sessions_to_evict(&self, active_sessions: &[Session]) -> Vec<SessionId> {
+        let Some(max) = self.max_sessions_per_user else {
+            return Vec::new();
+        };
+
+        let mut sessions: Vec<&Session> = active_sessions.iter().collect();
+        sessions.sort_by_key(|session| session.created_at());
+
+        let excess = (sessions.len() + 1).saturating_sub(max as usize);
+        sessions.into_iter().take(excess).map(Session::id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone as _, Utc};
+    use rstest::*;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::session::{DeviceLabel, SessionId},
+        user::UserId,
+    };
+
+    use super::*;
+
+    #[fixture]
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn session_started_at(user_id: UserId, created_at: DateTime<Utc>) -> Session {
+        Session::reconstruct(
+            Uuid::now_v7().into(),
+            user_id,
+            DeviceLabel::from_raw_str("Chrome (Windows)"),
+            None,
+            None,
+            created_at,
+            created_at,
+            created_at + Duration::days(30),
+            None,
+        )
+    }
+
+    #[rstest]
+    fn test_new_rejects_zero_limit() {
+        assert!(SessionLimit::new(Some(0)).is_err());
+    }
+
+    #[rstest]
+    #[case(None, 0)]
+    #[case(Some(5), 0)]
+    #[case(Some(3), 1)]
+    #[case(Some(1), 3)]
+    fn test_sessions_to_evict_oldest_first(
+        base_time: DateTime<Utc>,
+        #[case] max_sessions_per_user: Option<u32>,
+        #[case] expected_count: usize,
+    ) {
+        let user_id: UserId = Uuid::now_v7().into();
+        // 開始日時の順序とは異なる順序で並べる
+        let sessions = vec![
+            session_started_at(user_id, base_time + Duration::hours(2)),
+            session_started_at(user_id, base_time),
+            session_started_at(user_id, base_time + Duration::hours(1)),
+        ];
+        let mut oldest_first: Vec<SessionId> =
+            vec![sessions[1].id(), sessions[2].id(), sessions[0].id()];
+        oldest_first.truncate(expected_count);
+
+        let limit = SessionLimit::new(max_sessions_per_user).unwrap();
+
+        assert_eq!(limit.sessions_to_evict(&sessions), oldest_first);
+    }
+}
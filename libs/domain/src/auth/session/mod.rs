@@ -0,0 +1,11 @@
+mod entity;
+mod error;
+mod limit;
+mod repository;
+mod value_objects;
+
+pub use entity::{Session, SessionClient};
+pub use error::SessionLimitError;
+pub use limit::SessionLimit;
+pub use repository::{SessionRepository, SessionRepositoryError};
+pub use value_objects::{device_label::DeviceLabel, session_id::SessionId};
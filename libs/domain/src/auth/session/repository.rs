@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{Session, SessionId};
+
+#[derive(Debug, Error)]
+pub enum SessionRepositoryError {
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn find_by_id(&self, id: SessionId) -> Result<Option<Session>, SessionRepositoryError>;
+    /// 終了しておらず、`now` の時点で有効期限内のセッションを開始日時の昇順で取得する
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, SessionRepositoryError>;
+    async fn save(&self, session: Session) -> Result<Session, SessionRepositoryError>;
+}
@@ -0,0 +1,105 @@
+/// セッションの一覧で端末を見分けるための表示名 (例: `Chrome (Windows)`)
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct DeviceLabel(String);
+
+const UNKNOWN_DEVICE: &str = "不明なデバイス";
+
+// 判定の順序に意味がある (Edge・Opera の User-Agent は Chrome・Safari の表記も含むため)
+const BROWSERS: [(&str, &str); 7] = [
+    ("Edg/", "Edge"),
+    ("OPR/", "Opera"),
+    ("Firefox/", "Firefox"),
+    ("FxiOS/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Safari/", "Safari"),
+];
+
+// iPhone・iPad・Android の User-Agent は macOS・Linux の表記も含むため、先に判定する
+const PLATFORMS: [(&str, &str); 6] = [
+    ("iPhone", "iPhone"),
+    ("iPad", "iPad"),
+    ("Android", "Android"),
+    ("Windows", "Windows"),
+    ("Macintosh", "macOS"),
+    ("Linux", "Linux"),
+];
+
+impl DeviceLabel {
+    /// User-Agent からブラウザと OS を推定して表示名を組み立てる
+    ///
+    /// 推定できない場合は「不明なデバイス」とする
+    pub fn from_user_agent(user_agent: Option<&str>) -> Self {
+        let user_agent = user_agent.unwrap_or_default();
+        let find = |candidates: &[(&str, &'static str)]| {
+            candidates
+                .iter()
+                .find(|(marker, _)| user_agent.contains(marker))
+                .map(|(_, name)| *name)
+        };
+
+        let label = match (find(&BROWSERS), find(&PLATFORMS)) {
+            (Some(browser), Some(platform)) => format!("{browser} ({platform})"),
+            (Some(name), None) | (None, Some(name)) => name.to_string(),
+            (None, None) => UNKNOWN_DEVICE.to_string(),
+        };
+
+        Self(label)
+    }
+
+    pub fn from_raw_str(label: &str) -> Self {
+        Self(label.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        Some(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36"
+        ),
+        "Chrome (Windows)"
+    )]
+    #[case(
+        Some(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 Edg/126.0.0.0"
+        ),
+        "Edge (Windows)"
+    )]
+    #[case(
+        Some(
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_5) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15"
+        ),
+        "Safari (macOS)"
+    )]
+    #[case(
+        Some(
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1"
+        ),
+        "Safari (iPhone)"
+    )]
+    #[case(
+        Some("Mozilla/5.0 (X11; Linux x86_64; rv:127.0) Gecko/20100101 Firefox/127.0"),
+        "Firefox (Linux)"
+    )]
+    #[case(
+        Some(
+            "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Mobile Safari/537.36"
+        ),
+        "Chrome (Android)"
+    )]
+    #[case(Some("curl/8.7.1"), "不明なデバイス")]
+    #[case(None, "不明なデバイス")]
+    fn test_device_label_from_user_agent(#[case] user_agent: Option<&str>, #[case] expected: &str) {
+        assert_eq!(DeviceLabel::from_user_agent(user_agent).as_str(), expected);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod device_label;
+pub mod session_id;
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::refresh_token::RefreshTokenFamilyId;
+
+// ログインセッションの識別子
+// セッションはリフレッシュトークンのファミリーと 1 対 1 に対応するため、ファミリー ID をそのまま用いる
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::From,
+    derive_more::Into,
+)]
+pub struct SessionId(Uuid);
+
+impl From<RefreshTokenFamilyId> for SessionId {
+    fn from(family_id: RefreshTokenFamilyId) -> Self {
+        Self(family_id.into())
+    }
+}
+
+impl From<SessionId> for RefreshTokenFamilyId {
+    fn from(id: SessionId) -> Self {
+        id.0.into()
+    }
+}
@@ -0,0 +1,231 @@
+use chrono::{DateTime, Utc};
+use derive_entity::Entity;
+
+use crate::{shared::service::clock::Clock, user::UserId};
+
+use super::{
+    CredentialId, CredentialPublicKey, WebAuthnCredentialId, WebAuthnCredentialReconstructionError,
+    WebAuthnSignCountError,
+};
+
+/// ユーザーが登録したパスキー (WebAuthn のクレデンシャル)
+#[derive(Entity)]
+pub struct WebAuthnCredential {
+    #[entity_id]
+    id: WebAuthnCredentialId,
+    user_id: UserId,
+    credential_id: CredentialId,
+    public_key: CredentialPublicKey,
+    /// 認証器の署名カウンター。カウンターを持たない認証器では常に 0 となる
+    sign_count: u32,
+    /// 一覧で見分けるためにユーザーが付ける名前
+    name: String,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl WebAuthnCredential {
+    // 登録セレモニーの検証後に新しいクレデンシャルを作成するためのコンストラクタ
+    pub fn register(
+        id: WebAuthnCredentialId,
+        user_id: UserId,
+        credential_id: CredentialId,
+        public_key: CredentialPublicKey,
+        sign_count: u32,
+        name: String,
+        clock: &dyn Clock,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            credential_id,
+            public_key,
+            sign_count,
+            name,
+            created_at: clock.now(),
+            last_used_at: None,
+        }
+    }
+
+    // 永続化処理されたクレデンシャルを再構築するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
+    pub fn reconstruct(
+        id: WebAuthnCredentialId,
+        user_id: UserId,
+        credential_id: CredentialId,
+        public_key: CredentialPublicKey,
+        sign_count: i64,
+        name: String,
+        created_at: DateTime<Utc>,
+        last_used_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, WebAuthnCredentialReconstructionError> {
+        let sign_count = u32::try_from(sign_count).map_err(|_| {
+            WebAuthnCredentialReconstructionError::InvalidSignCount {
+                invalid_sign_count: sign_count,
+            }
+        })?;
+
+        Ok(Self {
+            id,
+            user_id,
+            credential_id,
+            public_key,
+            sign_count,
+            name,
+            created_at,
+            last_used_at,
+        })
+    }
+
+    pub fn id(&self) -> WebAuthnCredentialId {
+        self.id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn credential_id(&self) -> &CredentialId {
+        &self.credential_id
+    }
+
+    pub fn public_key(&self) -> &CredentialPublicKey {
+        &self.public_key
+    }
+
+    pub fn sign_count(&self) -> u32 {
+        self.sign_count
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn last_used_at(&self) -> Option<DateTime<Utc>> {
+        self.last_used_at
+    }
+}
+
+// 認証に関するメソッド群
+impl WebAuthnCredential {
+    /// 署名の検証に成功した認証を記録する
+    ///
+    /// 保存値か受信値のいずれかが 0 でない場合、署名カウンターは前回より増加していなければならない
+    /// (WebAuthn Level 2 §7.2 step 21)。増加していない場合はクレデンシャルの複製を疑い、認証を拒否する
+    pub fn record_authentication(
+        &mut self,
+        sign_count: u32,
+        clock: &dyn Clock,
+    ) -> Result<(), WebAuthnSignCountError> {
+        if (sign_count != 0 || self.sign_count != 0) && sign_count <= self.sign_count {
+            return Err(WebAuthnSignCountError::NotIncreased {
+                stored: self.sign_count,
+                received: sign_count,
+            });
+        }
+
+        self.sign_count = sign_count;
+        self.last_used_at = Some(clock.now());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use mockall::mock;
+    use rstest::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn clock_at(now: DateTime<Utc>) -> MockClock {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(now);
+        clock
+    }
+
+    fn credential(sign_count: u32) -> WebAuthnCredential {
+        WebAuthnCredential::register(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            CredentialId::from_raw_str("Y3JlZGVudGlhbC1pZA"),
+            CredentialPublicKey::from_bytes(vec![0xa5]),
+            sign_count,
+            "MacBook".to_string(),
+            &clock_at(base_time()),
+        )
+    }
+
+    #[rstest]
+    #[case::increased(5, 6)]
+    #[case::counter_not_supported(0, 0)]
+    fn test_record_authentication_success(#[case] stored: u32, #[case] received: u32) {
+        let mut credential = credential(stored);
+        let used_at = base_time() + chrono::Duration::minutes(5);
+
+        credential
+            .record_authentication(received, &clock_at(used_at))
+            .unwrap();
+
+        assert_eq!(credential.sign_count(), received);
+        assert_eq!(credential.last_used_at(), Some(used_at));
+    }
+
+    #[rstest]
+    #[case::same(5, 5)]
+    #[case::decreased(5, 4)]
+    #[case::reset_to_zero(5, 0)]
+    fn test_record_authentication_rejects_counter_regression(
+        #[case] stored: u32,
+        #[case] received: u32,
+    ) {
+        let mut credential = credential(stored);
+
+        let result = credential.record_authentication(received, &clock_at(base_time()));
+
+        assert_eq!(
+            result,
+            Err(WebAuthnSignCountError::NotIncreased { stored, received })
+        );
+        assert_eq!(credential.sign_count(), stored);
+        assert_eq!(credential.last_used_at(), None);
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_negative_sign_count() {
+        let result = WebAuthnCredential::reconstruct(
+            Uuid::now_v7().into(),
+            Uuid::now_v7().into(),
+            CredentialId::from_raw_str("Y3JlZGVudGlhbC1pZA"),
+            CredentialPublicKey::from_bytes(vec![0xa5]),
+            -1,
+            "MacBook".to_string(),
+            base_time(),
+            None,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(WebAuthnCredentialReconstructionError::InvalidSignCount {
+                invalid_sign_count: -1
+            })
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum WebAuthnSignCountError {
+    // 認証器の署名カウンターが巻き戻っている場合、クレデンシャルが複製された可能性がある
+    #[error("署名カウンターが前回の認証時から増加していません: 保存値 {stored}, 受信値 {received}")]
+    NotIncreased { stored: u32, received: u32 },
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum WebAuthnCredentialReconstructionError {
+    #[error("不正な署名カウンターが保存されています: {invalid_sign_count}")]
+    InvalidSignCount { invalid_sign_count: i64 },
+}
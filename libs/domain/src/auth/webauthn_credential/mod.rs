@@ -0,0 +1,17 @@
+mod entity;
+mod error;
+mod repository;
+mod service;
+mod value_objects;
+
+pub use entity::WebAuthnCredential;
+pub use error::{WebAuthnCredentialReconstructionError, WebAuthnSignCountError};
+pub use repository::{WebAuthnCredentialRepository, WebAuthnCredentialRepositoryError};
+pub use service::{
+    WebAuthnCredentialIdGenerationError, WebAuthnCredentialIdGenerator,
+    WebAuthnCredentialIdGeneratorFactory,
+};
+pub use value_objects::{
+    credential_id::CredentialId, credential_public_key::CredentialPublicKey,
+    webauthn_credential_id::WebAuthnCredentialId,
+};
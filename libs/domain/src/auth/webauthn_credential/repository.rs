@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::user::UserId;
+
+use super::{
+    CredentialId, WebAuthnCredential, WebAuthnCredentialId, WebAuthnCredentialIdGenerationError,
+    WebAuthnCredentialReconstructionError,
+};
+
+#[derive(Debug, Error)]
+pub enum WebAuthnCredentialRepositoryError {
+    #[error(transparent)]
+    ReconstructionError(#[from] WebAuthnCredentialReconstructionError),
+
+    #[error(transparent)]
+    IdGenerationError(#[from] WebAuthnCredentialIdGenerationError),
+
+    #[error("データの保存または取得に失敗しました: {0}")]
+    Persistence(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait WebAuthnCredentialRepository: Send + Sync {
+    async fn find_by_id(
+        &self,
+        id: WebAuthnCredentialId,
+    ) -> Result<Option<WebAuthnCredential>, WebAuthnCredentialRepositoryError>;
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &CredentialId,
+    ) -> Result<Option<WebAuthnCredential>, WebAuthnCredentialRepositoryError>;
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<WebAuthnCredential>, WebAuthnCredentialRepositoryError>;
+    async fn save(
+        &self,
+        credential: WebAuthnCredential,
+    ) -> Result<WebAuthnCredential, WebAuthnCredentialRepositoryError>;
+    async fn delete(
+        &self,
+        id: WebAuthnCredentialId,
+    ) -> Result<(), WebAuthnCredentialRepositoryError>;
+}
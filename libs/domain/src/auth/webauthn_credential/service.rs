@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::WebAuthnCredentialId;
+
+#[derive(Debug, Error)]
+pub enum WebAuthnCredentialIdGenerationError {
+    #[error("パスキーIDの生成に失敗しました: {0}")]
+    GenerationFailed(#[source] anyhow::Error),
+}
+
+pub trait WebAuthnCredentialIdGenerator: Send + Sync {
+    fn generate(&self) -> Result<WebAuthnCredentialId, WebAuthnCredentialIdGenerationError>;
+}
+
+pub trait WebAuthnCredentialIdGeneratorFactory: Send + Sync {
+    fn create_webauthn_credential_id_generator(&self) -> Arc<dyn WebAuthnCredentialIdGenerator>;
+}
@@ -0,0 +1,14 @@
+// 認証器が発行したクレデンシャル ID (Base64URL エンコード済み)
+// 認証時に提示されたクレデンシャルを特定するために使用する
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::AsRef)]
+pub struct CredentialId(String);
+
+impl CredentialId {
+    pub fn from_raw_str(credential_id: &str) -> Self {
+        Self(credential_id.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
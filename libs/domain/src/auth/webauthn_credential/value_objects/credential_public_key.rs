@@ -0,0 +1,14 @@
+// クレデンシャルの公開鍵 (COSE_Key 形式の CBOR バイト列)
+// 署名の検証時に都度デコードするため、登録時に認証器から受け取った形式のまま保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::AsRef)]
+pub struct CredentialPublicKey(Vec<u8>);
+
+impl CredentialPublicKey {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
@@ -0,0 +1,3 @@
+pub mod credential_id;
+pub mod credential_public_key;
+pub mod webauthn_credential_id;
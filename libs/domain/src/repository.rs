@@ -1,5 +1,12 @@
 use std::sync::Arc;
 
+use crate::auth::api_key::ApiKeyRepository;
+use crate::auth::federated_identity::FederatedIdentityRepository;
+use crate::auth::password_reset_token::PasswordResetTokenRepository;
+use crate::auth::refresh_token::RefreshTokenRepository;
+use crate::auth::service_account::ServiceAccountRepository;
+use crate::auth::session::SessionRepository;
+use crate::auth::webauthn_credential::WebAuthnCredentialRepository;
 use crate::shared::outbox_event::OutboxRepository;
 
 use super::user::UserRepository;
@@ -11,6 +18,20 @@ pub trait RepositoryFactory<'a>: Send + Sync {
 
     fn outbox_repository(&self) -> Arc<dyn OutboxRepository + 'a>;
 
+    fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepository + 'a>;
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository + 'a>;
+
+    fn webauthn_credential_repository(&self) -> Arc<dyn WebAuthnCredentialRepository + 'a>;
+
+    fn federated_identity_repository(&self) -> Arc<dyn FederatedIdentityRepository + 'a>;
+
+    fn api_key_repository(&self) -> Arc<dyn ApiKeyRepository + 'a>;
+
+    fn service_account_repository(&self) -> Arc<dyn ServiceAccountRepository + 'a>;
+
+    fn session_repository(&self) -> Arc<dyn SessionRepository + 'a>;
+
     // 将来的な拡張:
     // fn post_repository(&self) -> Box<dyn PostRepository + '_>;
 }
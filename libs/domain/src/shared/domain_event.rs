@@ -20,6 +20,7 @@ mod tests {
     use rstest::rstest;
 
     use chrono::{DateTime, TimeZone as _, Utc};
+    use uuid::Uuid;
 
     use crate::user::{self, EmailTrait, UnverifiedEmail, UserCreatedEvent};
 
@@ -32,6 +33,7 @@ mod tests {
     #[rstest]
     #[case(
         UserEvent::Created(UserCreatedEvent {
+            user_id: Uuid::now_v7().into(),
             email: UnverifiedEmail::new("user@example.com").unwrap(),
             username: "user123".to_string(),
             registered_at: fixed_time(),
@@ -43,6 +45,7 @@ mod tests {
             email: UnverifiedEmail::new("user@example.com").unwrap(),
             username: "user123".to_string(),
             reason: "Violation of terms".to_string(),
+            suspended_by: None,
             suspended_at: fixed_time(),
         }),
         "UserEvent::Suspended"
@@ -51,6 +54,8 @@ mod tests {
         UserEvent::Unlocked(user::UserUnlockedEvent {
             username: "user123".to_string(),
             email: UnverifiedEmail::new("user@example.com").unwrap(),
+            reason: None,
+            unlocked_by: None,
             unlocked_at: fixed_time(),
         }),
         "UserEvent::Unlocked"
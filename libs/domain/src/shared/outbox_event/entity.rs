@@ -407,6 +407,7 @@ mod tests {
     #[fixture]
     fn domain_event(base_time: DateTime<Utc>) -> DomainEvent {
         DomainEvent::UserEvent(UserEvent::Created(UserCreatedEvent {
+            user_id: Uuid::now_v7().into(),
             email: UnverifiedEmail::new("test@example.com").unwrap(),
             username: "testuser".to_string(),
             registered_at: base_time,
@@ -3,6 +3,12 @@ use derive_entity::Entity;
 use strum::EnumString;
 
 use crate::{
+    auth::{
+        actor::AuditActor,
+        login_policy::{LoginPolicy, LoginRejection},
+        password_reset_token::PasswordResetTokenId,
+        session::SessionId,
+    },
     shared::{
         outbox_event::{
             EntityWithEvents, OutboxEvent, OutboxEventIdGenerationError, OutboxEventIdGenerator,
@@ -11,17 +17,28 @@ use crate::{
     },
     user::{
         Email, EmailTrait, UserEvent, UserId, UserReconstructionError, UserStateTransitionError,
-        error::ModificationWithInvalidStateError,
+        error::{
+            LoginLockoutError, MfaError, ModificationWithInvalidStateError, PasswordChangeError,
+            RoleChangeError,
+        },
         events::{
-            UserCreatedEvent, UserDeactivatedEvent, UserEmailChangedEvent, UserEmailVerifiedEvent,
-            UserReactivatedEvent, UserSuspendedEvent, UserUnlockedEvent, UsernameChangedEvent,
+            UserCreatedEvent, UserDeactivatedEvent, UserDemotedFromAdminEvent,
+            UserEmailChangedEvent, UserEmailVerificationRequestedEvent, UserEmailVerifiedEvent,
+            UserImpersonationEndedEvent, UserImpersonationStartedEvent, UserLockedOutEvent,
+            UserMagicLinkRequestedEvent, UserMfaDisabledEvent, UserMfaEnabledEvent,
+            UserPasswordChangedEvent, UserPasswordResetEvent, UserPasswordResetRequestedEvent,
+            UserPromotedToAdminEvent, UserReactivatedEvent, UserSuspendedEvent, UserUnlockedEvent,
+            UsernameChangedEvent,
         },
+        login_lockout::{LoginFailureOutcome, LoginFailures, LoginLockoutConfig},
+        mfa::{MfaState, MfaStateRaw, RecoveryCodeHash, TotpSecret},
         service::{UniqueEmail, UniqueUserInfo, UniqueUsername},
     },
 };
 
 use super::{
-    EmailVerifier, HashedPassword, UnverifiedEmail, UserDomainError, UserRole, VerifiedEmail,
+    EmailVerificationError, EmailVerifier, HashedPassword, PasswordHasher, PasswordOwner,
+    PasswordPolicy, RawPassword, UnverifiedEmail, UserDomainError, UserRole, VerifiedEmail,
 };
 
 #[derive(Entity)]
@@ -32,6 +49,12 @@ pub struct User {
     password: HashedPassword,
     role: UserRole,
     state: UserState,
+    login_failures: LoginFailures,
+    mfa: MfaState,
+    /// 発行済みのアクセストークンを一括で無効にするための世代番号
+    ///
+    /// 利用停止・退会・ロールの変更・パスワードの変更のたびに増やし、アクセストークンに埋め込んだ値と一致しないものは拒否する
+    token_epoch: u32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     events: Vec<UserEvent>,
@@ -53,9 +76,13 @@ impl User {
             state: UserState::PendingVerification {
                 email: email.clone(),
             },
+            login_failures: LoginFailures::default(),
+            mfa: MfaState::Disabled,
+            token_epoch: 0,
             created_at: now,
             updated_at: now,
             events: vec![UserEvent::Created(UserCreatedEvent {
+                user_id: id,
                 email,
                 username,
                 registered_at: now,
@@ -64,17 +91,22 @@ impl User {
     }
 
     // 永続化処理されたユーザーを再構築するためのコンストラクタ
+    #[allow(clippy::too_many_arguments)]
     pub fn reconstruct(
         id: UserId,
         username: String,
         password: HashedPassword,
         role: &str,
         state_source: UserStateRaw,
+        login_failures: LoginFailures,
+        mfa_source: MfaStateRaw,
+        token_epoch: u32,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Result<Self, UserReconstructionError> {
         let state = state_source.try_into()?;
         let role = role.try_into()?;
+        let mfa = mfa_source.try_into()?;
 
         Ok(Self {
             id,
@@ -82,6 +114,9 @@ impl User {
             password,
             role,
             state,
+            login_failures,
+            mfa,
+            token_epoch,
             created_at,
             updated_at,
             events: vec![],
@@ -92,6 +127,11 @@ impl User {
         self.events.push(event);
     }
 
+    /// それまでに発行したアクセストークンを無効にする
+    fn bump_token_epoch(&mut self) {
+        self.token_epoch = self.token_epoch.wrapping_add(1);
+    }
+
     pub fn id(&self) -> UserId {
         self.id
     }
@@ -122,6 +162,18 @@ impl User {
         }
     }
 
+    pub fn login_failures(&self) -> &LoginFailures {
+        &self.login_failures
+    }
+
+    pub fn mfa(&self) -> &MfaState {
+        &self.mfa
+    }
+
+    pub fn token_epoch(&self) -> u32 {
+        self.token_epoch
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -167,6 +219,304 @@ impl User {
     }
 }
 
+// パスワードの変更・リセットに関するメソッド群
+impl User {
+    fn ensure_password_modifiable(&self) -> Result<(), ModificationWithInvalidStateError> {
+        match &self.state {
+            UserState::Active { .. }
+            | UserState::PendingVerification { .. }
+            | UserState::ActiveWithUnverifiedEmail { .. } => Ok(()),
+            UserState::SuspendedByAdmin { .. } | UserState::DeactivatedByUser { .. } => {
+                Err(ModificationWithInvalidStateError::PasswordModification {
+                    state: self.state.kind_raw(),
+                })
+            }
+        }
+    }
+
+    /// 現在のパスワードを確認したうえで、新しいパスワードに変更する
+    ///
+    /// 新しいパスワードはパスワードポリシーで検証する
+    pub fn change_password(
+        &mut self,
+        current_password: &RawPassword,
+        new_password: &RawPassword,
+        password_policy: &PasswordPolicy,
+        password_hasher: &dyn PasswordHasher,
+        clock: &dyn Clock,
+    ) -> Result<(), PasswordChangeError> {
+        self.ensure_password_modifiable()?;
+
+        if !password_hasher.verify(current_password, &self.password) {
+            return Err(PasswordChangeError::IncorrectCurrentPassword);
+        }
+
+        password_policy.validate(new_password, self.password_owner())?;
+
+        self.password = password_hasher.hash(new_password)?;
+        self.bump_token_epoch();
+
+        let now = clock.now();
+        self.updated_at = now;
+
+        self.record_event(UserEvent::PasswordChanged(UserPasswordChangedEvent {
+            username: self.username.clone(),
+            email: self.email(),
+            changed_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// パスワードポリシーの検証に使用する、ユーザー名とメールアドレス
+    pub fn password_owner(&self) -> PasswordOwner<'_> {
+        let email = match &self.state {
+            UserState::Active { email } => email.as_str(),
+            UserState::SuspendedByAdmin { email }
+            | UserState::DeactivatedByUser { email }
+            | UserState::PendingVerification { email }
+            | UserState::ActiveWithUnverifiedEmail { email } => email.as_str(),
+        };
+
+        PasswordOwner {
+            username: &self.username,
+            email,
+        }
+    }
+
+    /// パスワードのリセットを要求する
+    ///
+    /// リセットリンクを記載したメールを送信するためのイベントを記録する
+    pub fn request_password_reset(
+        &mut self,
+        reset_token_id: PasswordResetTokenId,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) -> Result<(), ModificationWithInvalidStateError> {
+        self.ensure_password_modifiable()?;
+
+        self.record_event(UserEvent::PasswordResetRequested(
+            UserPasswordResetRequestedEvent {
+                user_id: self.id,
+                email: self.email(),
+                username: self.username.clone(),
+                reset_token_id,
+                expires_at,
+                requested_at: clock.now(),
+            },
+        ));
+
+        Ok(())
+    }
+
+    /// リセットトークンの検証を終えたユーザーのパスワードを再設定する
+    pub fn reset_password(
+        &mut self,
+        new_password: HashedPassword,
+        clock: &dyn Clock,
+    ) -> Result<(), ModificationWithInvalidStateError> {
+        self.ensure_password_modifiable()?;
+
+        self.password = new_password;
+        self.bump_token_epoch();
+
+        let now = clock.now();
+        self.updated_at = now;
+
+        self.record_event(UserEvent::PasswordReset(UserPasswordResetEvent {
+            username: self.username.clone(),
+            email: self.email(),
+            reset_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// メールで送信するログインリンクを要求する
+    ///
+    /// ログインできない状態のアカウントには送信しない
+    pub fn request_magic_link(&mut self, clock: &dyn Clock) -> Result<(), LoginRejection> {
+        LoginPolicy::evaluate(&self.state)?;
+
+        self.record_event(UserEvent::MagicLinkRequested(UserMagicLinkRequestedEvent {
+            user_id: self.id,
+            email: self.email(),
+            username: self.username.clone(),
+            requested_at: clock.now(),
+        }));
+
+        Ok(())
+    }
+
+    /// ログイン成功時に、保存済みのハッシュを現在のアルゴリズム・パラメータで再計算したものに置き換える
+    ///
+    /// パスワード自体は変わらないため、イベントの発行や更新日時の変更は行わない。
+    /// 検証に使用したハッシュ (`verified_password`) から変わっている場合は、検証後にパスワードが変更されたとみなし、
+    /// 変更後のパスワードを元に戻さないよう何もせずに `false` を返す。
+    pub fn upgrade_password_hash(
+        &mut self,
+        verified_password: &HashedPassword,
+        rehashed_password: HashedPassword,
+    ) -> bool {
+        if &self.password != verified_password {
+            return false;
+        }
+
+        self.password = rehashed_password;
+        true
+    }
+}
+
+// ログイン失敗によるロックアウトに関するメソッド群
+impl User {
+    pub fn is_locked_out(&self, now: DateTime<Utc>) -> bool {
+        self.login_failures.is_locked(now)
+    }
+
+    /// パスワードの誤りによるログインの失敗を記録する
+    ///
+    /// 期間内の失敗回数が上限に達した場合はロックアウトし、所有者に通知するためのイベントを記録する。
+    /// ロックアウト中の失敗は数えない。
+    pub fn record_failed_login(&mut self, config: &LoginLockoutConfig, clock: &dyn Clock) {
+        let now = clock.now();
+        if self.is_locked_out(now) {
+            return;
+        }
+
+        if let LoginFailureOutcome::LockedOut { locked_until } =
+            self.login_failures.record_failure(config, now)
+        {
+            self.record_event(UserEvent::LockedOut(UserLockedOutEvent {
+                username: self.username.clone(),
+                email: self.email(),
+                locked_until,
+                locked_at: now,
+            }));
+        }
+    }
+
+    /// ログインの成功時に、それまでの失敗の記録を消去する
+    ///
+    /// 消去すべき記録があった場合は `true` を返す
+    pub fn record_successful_login(&mut self) -> bool {
+        if self.login_failures.is_clear() {
+            return false;
+        }
+        self.login_failures.clear();
+        true
+    }
+
+    /// 管理者がロックアウトを解除する
+    pub fn clear_login_lockout(&mut self, clock: &dyn Clock) -> Result<(), LoginLockoutError> {
+        if !self.is_locked_out(clock.now()) {
+            return Err(LoginLockoutError::NotLockedOut);
+        }
+
+        self.login_failures.clear();
+
+        Ok(())
+    }
+}
+
+// 2 段階認証 (TOTP) に関するメソッド群
+impl User {
+    /// 2 段階認証の登録を開始する
+    ///
+    /// 確認が済んでいない登録がある場合は、新しいシークレットで置き換える
+    pub fn begin_mfa_enrollment(&mut self, secret: TotpSecret) -> Result<(), MfaError> {
+        if self.mfa.is_enabled() {
+            return Err(MfaError::AlreadyEnabled);
+        }
+
+        self.mfa = MfaState::Pending { secret };
+
+        Ok(())
+    }
+
+    /// 認証コードの確認が済んだ登録を有効にする
+    ///
+    /// `step` は確認に使用した認証コードのタイムステップで、同じコードでのログインを防ぐために記録する
+    pub fn confirm_mfa_enrollment(
+        &mut self,
+        step: u64,
+        recovery_codes: Vec<RecoveryCodeHash>,
+        clock: &dyn Clock,
+    ) -> Result<(), MfaError> {
+        let secret = match &self.mfa {
+            MfaState::Pending { secret } => secret.clone(),
+            MfaState::Enabled { .. } => return Err(MfaError::AlreadyEnabled),
+            MfaState::Disabled => return Err(MfaError::EnrollmentNotStarted),
+        };
+
+        let now = clock.now();
+        self.mfa = MfaState::Enabled {
+            secret,
+            recovery_codes,
+            last_used_step: Some(step),
+            enabled_at: now,
+        };
+        self.updated_at = now;
+
+        self.record_event(UserEvent::MfaEnabled(UserMfaEnabledEvent {
+            username: self.username.clone(),
+            email: self.email(),
+            enabled_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// 2 段階認証を解除する
+    pub fn disable_mfa(&mut self, clock: &dyn Clock) -> Result<(), MfaError> {
+        if !self.mfa.is_enabled() {
+            return Err(MfaError::NotEnabled);
+        }
+
+        let now = clock.now();
+        self.mfa = MfaState::Disabled;
+        self.updated_at = now;
+
+        self.record_event(UserEvent::MfaDisabled(UserMfaDisabledEvent {
+            username: self.username.clone(),
+            email: self.email(),
+            disabled_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// 認証コードの使用を記録する
+    ///
+    /// 最後に使用されたコード以前のタイムステップのコードは、再利用とみなして拒否する
+    pub fn record_totp_use(&mut self, step: u64) -> Result<(), MfaError> {
+        let MfaState::Enabled { last_used_step, .. } = &mut self.mfa else {
+            return Err(MfaError::NotEnabled);
+        };
+
+        if last_used_step.is_some_and(|last_used_step| step <= last_used_step) {
+            return Err(MfaError::CodeAlreadyUsed);
+        }
+        *last_used_step = Some(step);
+
+        Ok(())
+    }
+
+    /// リカバリーコードを使用済みにする (各コードは一度しか使用できない)
+    pub fn consume_recovery_code(&mut self, code_hash: &RecoveryCodeHash) -> Result<(), MfaError> {
+        let MfaState::Enabled { recovery_codes, .. } = &mut self.mfa else {
+            return Err(MfaError::NotEnabled);
+        };
+
+        let position = recovery_codes
+            .iter()
+            .position(|hash| hash == code_hash)
+            .ok_or(MfaError::InvalidRecoveryCode)?;
+        recovery_codes.remove(position);
+
+        Ok(())
+    }
+}
+
 // ユーザーの状態遷移に関するメソッド群
 impl User {
     pub fn verify_email<V: EmailVerifier>(
@@ -214,6 +564,33 @@ impl User {
         Ok(())
     }
 
+    /// 確認メールの再送を要求する
+    ///
+    /// 状態は変えず、確認メールを送信するためのイベントのみを記録する
+    pub fn request_email_verification(&mut self, clock: &dyn Clock) -> Result<(), UserDomainError> {
+        let email = match &self.state {
+            UserState::Active { .. } => Err(EmailVerificationError::AlreadyVerified)?,
+            UserState::SuspendedByAdmin { .. } | UserState::DeactivatedByUser { .. } => Err(
+                ModificationWithInvalidStateError::EmailVerificationRequest {
+                    state: self.state.kind_raw(),
+                },
+            )?,
+            UserState::PendingVerification { email }
+            | UserState::ActiveWithUnverifiedEmail { email } => email.clone(),
+        };
+
+        self.record_event(UserEvent::EmailVerificationRequested(
+            UserEmailVerificationRequestedEvent {
+                user_id: self.id,
+                email,
+                username: self.username.clone(),
+                requested_at: clock.now(),
+            },
+        ));
+
+        Ok(())
+    }
+
     pub fn change_email(
         &mut self,
         UniqueEmail(new_email): UniqueEmail,
@@ -256,6 +633,7 @@ impl User {
         self.updated_at = now;
 
         self.record_event(UserEvent::EmailChanged(UserEmailChangedEvent {
+            user_id: self.id,
             new_email,
             username: self.username.clone(),
             changed_at: now,
@@ -263,7 +641,12 @@ impl User {
         Ok(())
     }
 
-    pub fn suspend(&mut self, reason: String, clock: &dyn Clock) -> Result<(), UserDomainError> {
+    pub fn suspend(
+        &mut self,
+        reason: String,
+        suspended_by: AuditActor,
+        clock: &dyn Clock,
+    ) -> Result<(), UserDomainError> {
         let email = match &self.state {
             UserState::Active { email } => {
                 let email = email.unverify();
@@ -296,6 +679,8 @@ impl User {
             }
         };
 
+        self.bump_token_epoch();
+
         let now = clock.now();
         self.updated_at = now;
 
@@ -303,6 +688,7 @@ impl User {
             username: self.username.clone(),
             email,
             reason,
+            suspended_by: Some(suspended_by),
             suspended_at: now,
         }));
 
@@ -334,6 +720,8 @@ impl User {
             }
         };
 
+        self.bump_token_epoch();
+
         let now = clock.now();
         self.updated_at = now;
 
@@ -383,7 +771,12 @@ impl User {
         Ok(())
     }
 
-    pub fn unlock_suspension(&mut self, clock: &dyn Clock) -> Result<(), UserDomainError> {
+    pub fn unlock_suspension(
+        &mut self,
+        reason: String,
+        unlocked_by: AuditActor,
+        clock: &dyn Clock,
+    ) -> Result<(), UserDomainError> {
         let email = match &self.state {
             UserState::Active { .. }
             | UserState::DeactivatedByUser { .. }
@@ -408,11 +801,140 @@ impl User {
         self.record_event(UserEvent::Unlocked(UserUnlockedEvent {
             username: self.username.clone(),
             email,
+            reason: Some(reason),
+            unlocked_by: Some(unlocked_by),
             unlocked_at: now,
         }));
 
         Ok(())
     }
+
+    /// 管理者に昇格する
+    ///
+    /// `admins` には現在の管理者を渡す。自分以外の利用中の管理者に昇格を通知する
+    pub fn promote_to_admin(
+        &mut self,
+        admins: &[User],
+        promoted_by: AuditActor,
+        clock: &dyn Clock,
+    ) -> Result<(), RoleChangeError> {
+        if self.role == UserRole::Admin {
+            return Ok(()); // すでに管理者なので何もしない
+        }
+
+        // 昇格の通知にメールアドレスを使用するため、確認済みのメールアドレスを要求する
+        let UserState::Active { email } = &self.state else {
+            return Err(RoleChangeError::NotVerified {
+                state: self.state.kind_raw(),
+            });
+        };
+        let email = email.clone();
+
+        self.role = UserRole::Admin;
+        // 昇格前のロールを含むアクセストークンを無効にする
+        self.bump_token_epoch();
+
+        let now = clock.now();
+        self.updated_at = now;
+
+        self.record_event(UserEvent::PromotedToAdmin(UserPromotedToAdminEvent {
+            username: self.username.clone(),
+            email,
+            promoted_by: Some(promoted_by),
+            other_admin_emails: self.other_admin_emails(admins),
+            promoted_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// 管理者から一般ユーザーに降格する
+    ///
+    /// `admins` には現在の管理者を渡す。自分以外に利用中の管理者が残らない場合は降格できない
+    pub fn demote_from_admin(
+        &mut self,
+        admins: &[User],
+        demoted_by: AuditActor,
+        clock: &dyn Clock,
+    ) -> Result<(), RoleChangeError> {
+        if self.role != UserRole::Admin {
+            return Ok(()); // すでに一般ユーザーなので何もしない
+        }
+
+        let other_admin_emails = self.other_admin_emails(admins);
+        if other_admin_emails.is_empty() {
+            return Err(RoleChangeError::LastAdmin);
+        }
+
+        self.role = UserRole::User;
+        // 降格前のロールを含むアクセストークンを無効にする
+        self.bump_token_epoch();
+
+        let now = clock.now();
+        self.updated_at = now;
+
+        self.record_event(UserEvent::DemotedFromAdmin(UserDemotedFromAdminEvent {
+            username: self.username.clone(),
+            email: self.email(),
+            demoted_by,
+            other_admin_emails,
+            demoted_at: now,
+        }));
+
+        Ok(())
+    }
+
+    /// 自分以外の利用中 (メールアドレスを確認済み) の管理者のメールアドレス
+    fn other_admin_emails(&self, admins: &[User]) -> Vec<VerifiedEmail> {
+        admins
+            .iter()
+            .filter(|admin| admin.id != self.id && admin.role == UserRole::Admin)
+            .filter_map(|admin| match &admin.state {
+                UserState::Active { email } => Some(email.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 管理者によるなりすましの開始を監査記録として残す
+    ///
+    /// ユーザーの状態は変更しない
+    pub fn record_impersonation_started(
+        &mut self,
+        impersonator_id: UserId,
+        reason: String,
+        session_id: SessionId,
+        expires_at: DateTime<Utc>,
+        clock: &dyn Clock,
+    ) {
+        self.record_event(UserEvent::ImpersonationStarted(
+            UserImpersonationStartedEvent {
+                user_id: self.id,
+                username: self.username.clone(),
+                impersonator_id,
+                reason,
+                session_id,
+                expires_at,
+                started_at: clock.now(),
+            },
+        ));
+    }
+
+    /// 管理者によるなりすましの終了を監査記録として残す
+    pub fn record_impersonation_ended(
+        &mut self,
+        impersonator_id: UserId,
+        session_id: SessionId,
+        clock: &dyn Clock,
+    ) {
+        self.record_event(UserEvent::ImpersonationEnded(UserImpersonationEndedEvent {
+            user_id: self.id,
+            username: self.username.clone(),
+            impersonator_id,
+            session_id,
+            ended_at: clock.now(),
+        }));
+    }
 }
 
 impl EntityWithEvents for User {
@@ -468,7 +990,7 @@ impl TryFrom<UserStateRaw> for UserState {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, strum::Display, EnumString, strum::IntoStaticStr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, EnumString, strum::IntoStaticStr)]
 #[strum(serialize_all = "snake_case")]
 pub enum UserStateKind {
     Active,
@@ -496,12 +1018,673 @@ impl UserState {
 
 #[cfg(test)]
 mod tests {
-    use rstest::rstest;
+    use chrono::TimeZone as _;
+    use mockall::mock;
+    use rstest::{fixture, rstest};
+    use uuid::Uuid;
 
-    use crate::user::EmailFormatError;
+    use crate::user::{EmailFormatError, EmailVerificationClaim, PasswordPolicyViolation};
 
     use super::*;
 
+    mock! {
+        pub Clock {}
+        impl Clock for Clock {
+            fn now(&self) -> DateTime<Utc>;
+        }
+    }
+
+    mock! {
+        pub PasswordHasher {}
+        impl PasswordHasher for PasswordHasher {
+            fn hash(&self, raw: &RawPassword) -> Result<HashedPassword, crate::user::PasswordHashingError>;
+            fn verify(&self, raw: &RawPassword, hashed: &HashedPassword) -> bool;
+            fn needs_rehash(&self, hashed: &HashedPassword) -> bool;
+        }
+    }
+
+    fn hashed_password(hash: &str) -> HashedPassword {
+        HashedPassword::new(&format!("$argon2id$v=19$m=19456,t=2,p=1$c2FsdA${hash}")).unwrap()
+    }
+
+    struct NoBreachedPasswords;
+
+    impl crate::user::BreachedPasswordChecker for NoBreachedPasswords {
+        fn is_breached(&self, _: &RawPassword) -> bool {
+            false
+        }
+    }
+
+    fn password_policy() -> PasswordPolicy {
+        let config = crate::user::PasswordPolicyConfig::new(8, 64, vec![], true).unwrap();
+        PasswordPolicy::new(config, std::sync::Arc::new(NoBreachedPasswords))
+    }
+
+    fn clock() -> MockClock {
+        let mut clock = MockClock::new();
+        clock
+            .expect_now()
+            .return_const(Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap());
+        clock
+    }
+
+    #[fixture]
+    fn pending_user() -> User {
+        let mut user = User::new(
+            Uuid::now_v7().into(),
+            UniqueUserInfo {
+                username: "user123".to_string(),
+                email: UnverifiedEmail::new("user@example.com").unwrap(),
+            },
+            hashed_password("aGFzaGVk"),
+            clock().now(),
+        )
+        .unwrap();
+        user.events.clear();
+        user
+    }
+
+    #[rstest]
+    fn test_verify_email_with_matching_claim(mut pending_user: User) {
+        let claim = EmailVerificationClaim {
+            user_id: pending_user.id(),
+            email: "user@example.com".to_string(),
+        };
+
+        pending_user.verify_email(&claim, &clock()).unwrap();
+
+        assert_eq!(
+            pending_user.state(),
+            &UserState::Active {
+                email: VerifiedEmail::new("user@example.com").unwrap()
+            }
+        );
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::EmailVerified(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_verify_email_rejects_claim_for_previous_email(mut pending_user: User) {
+        // トークン発行後にメールアドレスが変更された場合
+        let claim = EmailVerificationClaim {
+            user_id: pending_user.id(),
+            email: "old@example.com".to_string(),
+        };
+
+        let result = pending_user.verify_email(&claim, &clock());
+
+        assert!(matches!(
+            result,
+            Err(UserDomainError::EmailVerificationError(
+                EmailVerificationError::EmailMismatch
+            ))
+        ));
+        assert_eq!(pending_user.state().kind(), "pending_verification");
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_change_password(mut pending_user: User) {
+        let mut hasher = MockPasswordHasher::new();
+        hasher
+            .expect_verify()
+            .withf(|raw, hashed| {
+                raw.as_bytes() == b"current-password" && hashed == &hashed_password("aGFzaGVk")
+            })
+            .return_const(true);
+        hasher
+            .expect_hash()
+            .returning(|_| Ok(hashed_password("bmV3LWhhc2g")));
+
+        pending_user
+            .change_password(
+                &RawPassword::new("current-password"),
+                &RawPassword::new("new-password"),
+                &password_policy(),
+                &hasher,
+                &clock(),
+            )
+            .unwrap();
+
+        assert_eq!(pending_user.password(), &hashed_password("bmV3LWhhc2g"));
+        assert_eq!(pending_user.token_epoch(), 1);
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::PasswordChanged(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_change_password_with_incorrect_current_password(mut pending_user: User) {
+        let mut hasher = MockPasswordHasher::new();
+        hasher.expect_verify().return_const(false);
+        hasher.expect_hash().never();
+
+        let result = pending_user.change_password(
+            &RawPassword::new("wrong-password"),
+            &RawPassword::new("new-password"),
+            &password_policy(),
+            &hasher,
+            &clock(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(PasswordChangeError::IncorrectCurrentPassword)
+        ));
+        assert_eq!(pending_user.password(), &hashed_password("aGFzaGVk"));
+        assert_eq!(pending_user.token_epoch(), 0);
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_change_password_violating_policy(mut pending_user: User) {
+        let mut hasher = MockPasswordHasher::new();
+        hasher.expect_verify().return_const(true);
+        hasher.expect_hash().never();
+
+        // ユーザー名を含むパスワード
+        let result = pending_user.change_password(
+            &RawPassword::new("current-password"),
+            &RawPassword::new("my-user123-password"),
+            &password_policy(),
+            &hasher,
+            &clock(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(PasswordChangeError::PasswordPolicyViolations(violations))
+                if violations.violations().contains(&PasswordPolicyViolation::ContainsUsername)
+        ));
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_reset_password(mut pending_user: User) {
+        pending_user
+            .reset_password(hashed_password("bmV3LWhhc2g"), &clock())
+            .unwrap();
+
+        assert_eq!(pending_user.password(), &hashed_password("bmV3LWhhc2g"));
+        assert_eq!(pending_user.token_epoch(), 1);
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::PasswordReset(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_request_magic_link(mut pending_user: User) {
+        pending_user.request_magic_link(&clock()).unwrap();
+
+        match pending_user.events.as_slice() {
+            [UserEvent::MagicLinkRequested(requested)] => {
+                assert_eq!(requested.user_id, pending_user.id());
+                assert_eq!(requested.email.as_str(), "user@example.com");
+            }
+            events => panic!("unexpected events: {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_request_magic_link_when_suspended(mut pending_user: User) {
+        pending_user
+            .suspend(
+                "Violation of terms".to_string(),
+                AuditActor::User {
+                    user_id: Uuid::now_v7().into(),
+                },
+                &clock(),
+            )
+            .unwrap();
+        pending_user.events.clear();
+
+        assert_eq!(
+            pending_user.request_magic_link(&clock()),
+            Err(LoginRejection::Suspended)
+        );
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_suspend_bumps_token_epoch_once(mut pending_user: User) {
+        let suspended_by = AuditActor::User {
+            user_id: Uuid::now_v7().into(),
+        };
+
+        pending_user
+            .suspend(
+                "Violation of terms".to_string(),
+                suspended_by.clone(),
+                &clock(),
+            )
+            .unwrap();
+        // すでに停止中の場合は何もしない
+        pending_user
+            .suspend("Violation of terms".to_string(), suspended_by, &clock())
+            .unwrap();
+
+        assert_eq!(pending_user.token_epoch(), 1);
+    }
+
+    #[rstest]
+    fn test_unlock_suspension(mut pending_user: User) {
+        let unlocked_by = AuditActor::User {
+            user_id: Uuid::now_v7().into(),
+        };
+
+        // 停止中でなければ利用再開できない
+        assert!(matches!(
+            pending_user.unlock_suspension(
+                "Appeal accepted".to_string(),
+                unlocked_by.clone(),
+                &clock()
+            ),
+            Err(UserDomainError::StateTransitionError(
+                UserStateTransitionError::NotSuspended { .. }
+            ))
+        ));
+
+        pending_user
+            .suspend(
+                "Violation of terms".to_string(),
+                unlocked_by.clone(),
+                &clock(),
+            )
+            .unwrap();
+        pending_user.events.clear();
+
+        pending_user
+            .unlock_suspension("Appeal accepted".to_string(), unlocked_by.clone(), &clock())
+            .unwrap();
+
+        assert_eq!(pending_user.state().kind(), "active_with_unverified_email");
+        match pending_user.events.as_slice() {
+            [UserEvent::Unlocked(unlocked)] => {
+                assert_eq!(unlocked.reason.as_deref(), Some("Appeal accepted"));
+                assert_eq!(unlocked.unlocked_by, Some(unlocked_by));
+            }
+            events => panic!("unexpected events: {events:?}"),
+        }
+    }
+
+    fn active_user(username: &str, email: &str, role: UserRole) -> User {
+        let mut user = User::new(
+            Uuid::now_v7().into(),
+            UniqueUserInfo {
+                username: username.to_string(),
+                email: UnverifiedEmail::new(email).unwrap(),
+            },
+            hashed_password("aGFzaGVk"),
+            clock().now(),
+        )
+        .unwrap();
+        user.state = UserState::Active {
+            email: VerifiedEmail::new(email).unwrap(),
+        };
+        user.role = role;
+        user.events.clear();
+        user
+    }
+
+    #[rstest]
+    fn test_promote_to_admin(mut pending_user: User) {
+        let admin = active_user("admin1", "admin1@example.com", UserRole::Admin);
+        let suspended_admin = {
+            let mut user = active_user("admin2", "admin2@example.com", UserRole::Admin);
+            user.state = UserState::SuspendedByAdmin {
+                email: UnverifiedEmail::new("admin2@example.com").unwrap(),
+            };
+            user
+        };
+        let admins = [admin, suspended_admin];
+        let promoted_by = AuditActor::User {
+            user_id: admins[0].id(),
+        };
+
+        // メールアドレスの確認前は昇格できない
+        assert_eq!(
+            pending_user.promote_to_admin(&admins, promoted_by.clone(), &clock()),
+            Err(RoleChangeError::NotVerified {
+                state: UserStateKind::PendingVerification
+            })
+        );
+        assert_eq!(pending_user.role(), UserRole::User);
+
+        let mut user = active_user("user123", "user@example.com", UserRole::User);
+        user.promote_to_admin(&admins, promoted_by.clone(), &clock())
+            .unwrap();
+
+        assert_eq!(user.role(), UserRole::Admin);
+        assert_eq!(user.token_epoch(), 1);
+        match user.events.as_slice() {
+            [UserEvent::PromotedToAdmin(promoted)] => {
+                assert_eq!(promoted.promoted_by, Some(promoted_by.clone()));
+                // 停止中の管理者には通知しない
+                assert_eq!(
+                    promoted.other_admin_emails,
+                    vec![VerifiedEmail::new("admin1@example.com").unwrap()]
+                );
+            }
+            events => panic!("unexpected events: {events:?}"),
+        }
+
+        // すでに管理者の場合は何もしない
+        user.events.clear();
+        user.promote_to_admin(&admins, promoted_by, &clock())
+            .unwrap();
+        assert!(user.events.is_empty());
+        assert_eq!(user.token_epoch(), 1);
+    }
+
+    #[rstest]
+    fn test_demote_from_admin() {
+        let mut admin = active_user("admin1", "admin1@example.com", UserRole::Admin);
+        let other_admin = active_user("admin2", "admin2@example.com", UserRole::Admin);
+        let demoted_by = AuditActor::User {
+            user_id: other_admin.id(),
+        };
+
+        // 自分以外に利用中の管理者がいなければ降格できない
+        let deactivated_admin = {
+            let mut user = active_user("admin3", "admin3@example.com", UserRole::Admin);
+            user.state = UserState::DeactivatedByUser {
+                email: UnverifiedEmail::new("admin3@example.com").unwrap(),
+            };
+            user
+        };
+        assert_eq!(
+            admin.demote_from_admin(&[deactivated_admin], demoted_by.clone(), &clock()),
+            Err(RoleChangeError::LastAdmin)
+        );
+        assert_eq!(admin.role(), UserRole::Admin);
+
+        let admins = [other_admin];
+        admin
+            .demote_from_admin(&admins, demoted_by.clone(), &clock())
+            .unwrap();
+
+        assert_eq!(admin.role(), UserRole::User);
+        assert_eq!(admin.token_epoch(), 1);
+        match admin.events.as_slice() {
+            [UserEvent::DemotedFromAdmin(demoted)] => {
+                assert_eq!(demoted.demoted_by, demoted_by);
+                assert_eq!(
+                    demoted.other_admin_emails,
+                    vec![VerifiedEmail::new("admin2@example.com").unwrap()]
+                );
+            }
+            events => panic!("unexpected events: {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_reset_password_when_suspended(mut pending_user: User) {
+        pending_user
+            .suspend(
+                "Violation of terms".to_string(),
+                AuditActor::User {
+                    user_id: Uuid::now_v7().into(),
+                },
+                &clock(),
+            )
+            .unwrap();
+        pending_user.events.clear();
+
+        let result = pending_user.reset_password(hashed_password("bmV3LWhhc2g"), &clock());
+
+        assert_eq!(
+            result,
+            Err(ModificationWithInvalidStateError::PasswordModification {
+                state: UserStateKind::SuspendedByAdmin
+            })
+        );
+        assert_eq!(pending_user.password(), &hashed_password("aGFzaGVk"));
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_upgrade_password_hash(mut pending_user: User) {
+        let updated_at = pending_user.updated_at();
+
+        let upgraded = pending_user
+            .upgrade_password_hash(&hashed_password("aGFzaGVk"), hashed_password("cmVoYXNoZWQ"));
+
+        assert!(upgraded);
+        assert_eq!(pending_user.password(), &hashed_password("cmVoYXNoZWQ"));
+        assert_eq!(pending_user.updated_at(), updated_at);
+        assert_eq!(pending_user.token_epoch(), 0);
+        assert!(pending_user.events.is_empty());
+    }
+
+    #[rstest]
+    fn test_upgrade_password_hash_skips_changed_password(mut pending_user: User) {
+        // 検証に使用したハッシュが、検証後に変更されたパスワードのハッシュに置き換わっている場合
+        let upgraded = pending_user.upgrade_password_hash(
+            &hashed_password("b2xkLWhhc2g"),
+            hashed_password("cmVoYXNoZWQ"),
+        );
+
+        assert!(!upgraded);
+        assert_eq!(pending_user.password(), &hashed_password("aGFzaGVk"));
+    }
+
+    #[rstest]
+    fn test_record_failed_login_locks_out(mut pending_user: User) {
+        let config = LoginLockoutConfig::new(2, 600, 900).unwrap();
+
+        pending_user.record_failed_login(&config, &clock());
+        assert!(!pending_user.is_locked_out(clock().now()));
+        assert!(pending_user.events.is_empty());
+
+        pending_user.record_failed_login(&config, &clock());
+        assert!(pending_user.is_locked_out(clock().now()));
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::LockedOut(UserLockedOutEvent { locked_until, .. })]
+                if *locked_until == clock().now() + chrono::Duration::seconds(900)
+        ));
+
+        // ロックアウト中の失敗は数えず、イベントも記録しない
+        pending_user.record_failed_login(&config, &clock());
+        assert_eq!(pending_user.events.len(), 1);
+    }
+
+    #[rstest]
+    fn test_record_successful_login_clears_failures(mut pending_user: User) {
+        let config = LoginLockoutConfig::new(3, 600, 900).unwrap();
+        assert!(!pending_user.record_successful_login());
+
+        pending_user.record_failed_login(&config, &clock());
+
+        assert!(pending_user.record_successful_login());
+        assert_eq!(pending_user.login_failures(), &LoginFailures::default());
+    }
+
+    #[rstest]
+    fn test_clear_login_lockout(mut pending_user: User) {
+        let config = LoginLockoutConfig::new(1, 600, 900).unwrap();
+
+        assert_eq!(
+            pending_user.clear_login_lockout(&clock()),
+            Err(LoginLockoutError::NotLockedOut)
+        );
+
+        pending_user.record_failed_login(&config, &clock());
+        pending_user.clear_login_lockout(&clock()).unwrap();
+
+        assert!(!pending_user.is_locked_out(clock().now()));
+    }
+
+    fn totp_secret() -> TotpSecret {
+        TotpSecret::from_raw_str("JBSWY3DPEHPK3PXP")
+    }
+
+    #[rstest]
+    fn test_mfa_enrollment(mut pending_user: User) {
+        assert_eq!(
+            pending_user.confirm_mfa_enrollment(1, vec![], &clock()),
+            Err(MfaError::EnrollmentNotStarted)
+        );
+
+        pending_user.begin_mfa_enrollment(totp_secret()).unwrap();
+        assert!(!pending_user.mfa().is_enabled());
+        assert!(pending_user.events.is_empty());
+
+        pending_user
+            .confirm_mfa_enrollment(
+                100,
+                vec![RecoveryCodeHash::from_raw_str("hash-1")],
+                &clock(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            pending_user.mfa(),
+            &MfaState::Enabled {
+                secret: totp_secret(),
+                recovery_codes: vec![RecoveryCodeHash::from_raw_str("hash-1")],
+                last_used_step: Some(100),
+                enabled_at: clock().now(),
+            }
+        );
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::MfaEnabled(_)]
+        ));
+
+        // 有効な状態では登録をやり直せない
+        assert_eq!(
+            pending_user.begin_mfa_enrollment(totp_secret()),
+            Err(MfaError::AlreadyEnabled)
+        );
+    }
+
+    #[rstest]
+    fn test_record_impersonation(mut pending_user: User) {
+        let impersonator_id: UserId = Uuid::now_v7().into();
+        let session_id: SessionId = Uuid::now_v7().into();
+        let expires_at = clock().now() + chrono::Duration::minutes(30);
+
+        pending_user.record_impersonation_started(
+            impersonator_id,
+            "問い合わせの調査".to_string(),
+            session_id,
+            expires_at,
+            &clock(),
+        );
+        pending_user.record_impersonation_ended(impersonator_id, session_id, &clock());
+
+        // 監査記録のみを残し、ユーザーの状態は変更しない
+        assert!(matches!(
+            pending_user.state(),
+            UserState::PendingVerification { .. }
+        ));
+        match pending_user.events.as_slice() {
+            [
+                UserEvent::ImpersonationStarted(started),
+                UserEvent::ImpersonationEnded(ended),
+            ] => {
+                assert_eq!(started.impersonator_id, impersonator_id);
+                assert_eq!(started.session_id, session_id);
+                assert_eq!(started.expires_at, expires_at);
+                assert_eq!(ended.impersonator_id, impersonator_id);
+                assert_eq!(ended.session_id, session_id);
+            }
+            events => panic!("unexpected events: {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_disable_mfa(mut pending_user: User) {
+        assert_eq!(
+            pending_user.disable_mfa(&clock()),
+            Err(MfaError::NotEnabled)
+        );
+
+        pending_user.begin_mfa_enrollment(totp_secret()).unwrap();
+        pending_user
+            .confirm_mfa_enrollment(100, vec![], &clock())
+            .unwrap();
+        pending_user.events.clear();
+
+        pending_user.disable_mfa(&clock()).unwrap();
+
+        assert_eq!(pending_user.mfa(), &MfaState::Disabled);
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::MfaDisabled(_)]
+        ));
+    }
+
+    #[rstest]
+    fn test_record_totp_use_rejects_reused_step(mut pending_user: User) {
+        pending_user.begin_mfa_enrollment(totp_secret()).unwrap();
+        pending_user
+            .confirm_mfa_enrollment(100, vec![], &clock())
+            .unwrap();
+
+        assert_eq!(
+            pending_user.record_totp_use(100),
+            Err(MfaError::CodeAlreadyUsed)
+        );
+        assert_eq!(
+            pending_user.record_totp_use(99),
+            Err(MfaError::CodeAlreadyUsed)
+        );
+
+        pending_user.record_totp_use(101).unwrap();
+        assert_eq!(pending_user.mfa().last_used_step(), Some(101));
+    }
+
+    #[rstest]
+    fn test_consume_recovery_code(mut pending_user: User) {
+        let code_hash = RecoveryCodeHash::from_raw_str("hash-1");
+        pending_user.begin_mfa_enrollment(totp_secret()).unwrap();
+        pending_user
+            .confirm_mfa_enrollment(100, vec![code_hash.clone()], &clock())
+            .unwrap();
+
+        pending_user.consume_recovery_code(&code_hash).unwrap();
+
+        // 使用済みのリカバリーコードは再利用できない
+        assert_eq!(
+            pending_user.consume_recovery_code(&code_hash),
+            Err(MfaError::InvalidRecoveryCode)
+        );
+        assert!(pending_user.mfa().recovery_codes().is_empty());
+    }
+
+    #[rstest]
+    fn test_request_email_verification(mut pending_user: User) {
+        pending_user.request_email_verification(&clock()).unwrap();
+
+        assert!(matches!(
+            pending_user.events.as_slice(),
+            [UserEvent::EmailVerificationRequested(UserEmailVerificationRequestedEvent { email, .. })]
+                if email.as_str() == "user@example.com"
+        ));
+    }
+
+    #[rstest]
+    fn test_request_email_verification_when_already_verified(mut pending_user: User) {
+        pending_user.state = UserState::Active {
+            email: VerifiedEmail::new("user@example.com").unwrap(),
+        };
+
+        let result = pending_user.request_email_verification(&clock());
+
+        assert!(matches!(
+            result,
+            Err(UserDomainError::EmailVerificationError(
+                EmailVerificationError::AlreadyVerified
+            ))
+        ));
+    }
+
     #[rstest]
     #[case("active", "valid@email.com", UserState::Active { email: VerifiedEmail::new("valid@email.com").unwrap() })]
     #[case("suspended_by_admin", "valid@email.com", UserState::SuspendedByAdmin { email: UnverifiedEmail::new("valid@email.com").unwrap() })]
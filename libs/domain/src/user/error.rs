@@ -4,11 +4,12 @@ use crate::{
     shared::outbox_event::OutboxEventIdGenerationError,
     user::{
         UserIdGenerationError, UserState, UserStateKind,
-        value_objects::{email::EmailFormatError, password::PasswordPolicyViolation},
+        password_policy::PasswordPolicyViolations,
+        value_objects::{email::EmailFormatError, password::HashedPasswordFormatError},
     },
 };
 
-use super::EmailVerificationError;
+use super::{EmailVerificationError, PasswordHashingError};
 
 /// Domain-level errors related to `User`.
 ///
@@ -24,7 +25,7 @@ pub enum UserDomainError {
     InvalidEmail(#[from] EmailFormatError),
 
     #[error(transparent)]
-    PasswordPolicyViolation(#[from] PasswordPolicyViolation),
+    PasswordPolicyViolations(#[from] PasswordPolicyViolations),
 
     #[error(transparent)]
     AlreadyExists(#[from] UserUniqueConstraintViolation),
@@ -59,6 +60,10 @@ pub enum ModificationWithInvalidStateError {
     EmailModification { state: UserStateKind },
     #[error("以下のユーザー状態でのユーザー名の変更はできません: {state}")]
     UsernameModification { state: UserStateKind },
+    #[error("以下のユーザー状態ではメールアドレスの確認を要求できません: {state}")]
+    EmailVerificationRequest { state: UserStateKind },
+    #[error("以下のユーザー状態でのパスワードの変更はできません: {state}")]
+    PasswordModification { state: UserStateKind },
 }
 
 impl ModificationWithInvalidStateError {
@@ -70,10 +75,64 @@ impl ModificationWithInvalidStateError {
             ModificationWithInvalidStateError::UsernameModification { state: _ } => {
                 "該当ユーザーはユーザー名の変更ができない状態です"
             }
+            ModificationWithInvalidStateError::EmailVerificationRequest { state: _ } => {
+                "該当ユーザーはメールアドレスの確認を要求できない状態です"
+            }
+            ModificationWithInvalidStateError::PasswordModification { state: _ } => {
+                "該当ユーザーはパスワードの変更ができない状態です"
+            }
         }
     }
 }
 
+#[derive(Debug, Error)]
+pub enum PasswordChangeError {
+    #[error("現在のパスワードが一致しません")]
+    IncorrectCurrentPassword,
+
+    #[error(transparent)]
+    PasswordPolicyViolations(#[from] PasswordPolicyViolations),
+
+    #[error(transparent)]
+    ModificationWithInvalidStateError(#[from] ModificationWithInvalidStateError),
+
+    #[error(transparent)]
+    HashingError(#[from] PasswordHashingError),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LoginLockoutError {
+    #[error("指定のユーザーはロックアウトされていません")]
+    NotLockedOut,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RoleChangeError {
+    #[error("メールアドレスを確認済みの利用中のユーザーのみ管理者に昇格できます: {state}")]
+    NotVerified { state: UserStateKind },
+
+    #[error("最後の管理者を降格することはできません")]
+    LastAdmin,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MfaError {
+    #[error("2段階認証は既に有効です")]
+    AlreadyEnabled,
+
+    #[error("2段階認証は有効になっていません")]
+    NotEnabled,
+
+    #[error("2段階認証の登録が開始されていません")]
+    EnrollmentNotStarted,
+
+    #[error("この認証コードは既に使用されています")]
+    CodeAlreadyUsed,
+
+    #[error("リカバリーコードが一致しません")]
+    InvalidRecoveryCode,
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum UserStateTransitionError {
     #[error("ユーザーは既に退会しています: {to:?}への遷移は許可されていません")]
@@ -97,4 +156,14 @@ pub enum UserReconstructionError {
     InvalidStatus { invalid_status: String },
     #[error("不正な形式のロールが保存されています: {invalid_role}")]
     InvalidRole { invalid_role: String },
+    #[error("不正な形式のパスワードハッシュが保存されています: {0}")]
+    InvalidPasswordHash(#[from] HashedPasswordFormatError),
+    #[error("不正な形式の2段階認証のステータスが保存されています: {invalid_status}")]
+    InvalidMfaStatus { invalid_status: String },
+    #[error("2段階認証の登録中または有効にもかかわらず TOTP シークレットが None です")]
+    MfaWithoutTotpSecret,
+    #[error("2段階認証が有効にもかかわらず enabled_at が None です")]
+    MfaEnabledButNoEnabledAt,
+    #[error("不正な TOTP のタイムステップが保存されています")]
+    InvalidTotpStep,
 }
@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::user::{Email, UnverifiedEmail, VerifiedEmail};
+use crate::auth::actor::AuditActor;
+use crate::auth::password_reset_token::PasswordResetTokenId;
+use crate::auth::session::SessionId;
+use crate::user::{Email, UnverifiedEmail, UserId, VerifiedEmail};
 
 #[derive(Deserialize, Serialize, Debug, Clone, strum::Display)]
 pub enum UserEvent {
@@ -11,9 +14,20 @@ pub enum UserEvent {
     Deactivated(UserDeactivatedEvent),
     Reactivated(UserReactivatedEvent),
     PromotedToAdmin(UserPromotedToAdminEvent),
+    DemotedFromAdmin(UserDemotedFromAdminEvent),
     UsernameChanged(UsernameChangedEvent),
     EmailChanged(UserEmailChangedEvent),
     EmailVerified(UserEmailVerifiedEvent),
+    EmailVerificationRequested(UserEmailVerificationRequestedEvent),
+    PasswordResetRequested(UserPasswordResetRequestedEvent),
+    MagicLinkRequested(UserMagicLinkRequestedEvent),
+    PasswordReset(UserPasswordResetEvent),
+    PasswordChanged(UserPasswordChangedEvent),
+    LockedOut(UserLockedOutEvent),
+    MfaEnabled(UserMfaEnabledEvent),
+    MfaDisabled(UserMfaDisabledEvent),
+    ImpersonationStarted(UserImpersonationStartedEvent),
+    ImpersonationEnded(UserImpersonationEndedEvent),
 }
 
 impl UserEvent {
@@ -26,15 +40,27 @@ impl UserEvent {
             UserEvent::Deactivated(e) => e.deactivated_at,
             UserEvent::Reactivated(e) => e.reactivated_at,
             UserEvent::PromotedToAdmin(e) => e.promoted_at,
+            UserEvent::DemotedFromAdmin(e) => e.demoted_at,
             UserEvent::UsernameChanged(e) => e.changed_at,
             UserEvent::EmailChanged(e) => e.changed_at,
             UserEvent::EmailVerified(e) => e.verified_at,
+            UserEvent::EmailVerificationRequested(e) => e.requested_at,
+            UserEvent::PasswordResetRequested(e) => e.requested_at,
+            UserEvent::MagicLinkRequested(e) => e.requested_at,
+            UserEvent::PasswordReset(e) => e.reset_at,
+            UserEvent::PasswordChanged(e) => e.changed_at,
+            UserEvent::LockedOut(e) => e.locked_at,
+            UserEvent::MfaEnabled(e) => e.enabled_at,
+            UserEvent::MfaDisabled(e) => e.disabled_at,
+            UserEvent::ImpersonationStarted(e) => e.started_at,
+            UserEvent::ImpersonationEnded(e) => e.ended_at,
         }
     }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct UserCreatedEvent {
+    pub user_id: UserId,
     pub email: UnverifiedEmail,
     pub username: String,
     pub registered_at: DateTime<Utc>,
@@ -45,6 +71,11 @@ pub struct UserSuspendedEvent {
     pub username: String,
     pub email: UnverifiedEmail,
     pub reason: String,
+    /// 停止を行った管理者またはサービスアカウント
+    ///
+    /// この項目を追加する前に記録されたイベントには含まれない
+    #[serde(default)]
+    pub suspended_by: Option<AuditActor>,
     pub suspended_at: DateTime<Utc>,
 }
 
@@ -52,6 +83,16 @@ pub struct UserSuspendedEvent {
 pub struct UserUnlockedEvent {
     pub username: String,
     pub email: UnverifiedEmail,
+    /// 利用再開の理由
+    ///
+    /// この項目を追加する前に記録されたイベントには含まれない
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// 利用再開を行った管理者またはサービスアカウント
+    ///
+    /// この項目を追加する前に記録されたイベントには含まれない
+    #[serde(default)]
+    pub unlocked_by: Option<AuditActor>,
     pub unlocked_at: DateTime<Utc>,
 }
 
@@ -73,9 +114,28 @@ pub struct UserReactivatedEvent {
 pub struct UserPromotedToAdminEvent {
     pub username: String,
     pub email: VerifiedEmail,
+    /// 昇格を行った管理者またはサービスアカウント
+    ///
+    /// この項目を追加する前に記録されたイベントには含まれない
+    #[serde(default)]
+    pub promoted_by: Option<AuditActor>,
+    /// 昇格を通知する他の管理者のメールアドレス (昇格の時点で利用中の管理者)
+    #[serde(default)]
+    pub other_admin_emails: Vec<VerifiedEmail>,
     pub promoted_at: DateTime<Utc>,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserDemotedFromAdminEvent {
+    pub username: String,
+    pub email: Email,
+    /// 降格を行った管理者またはサービスアカウント
+    pub demoted_by: AuditActor,
+    /// 降格を通知する他の管理者のメールアドレス (降格の時点で利用中の管理者)
+    pub other_admin_emails: Vec<VerifiedEmail>,
+    pub demoted_at: DateTime<Utc>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct UsernameChangedEvent {
     pub old_username: String,
@@ -86,6 +146,7 @@ pub struct UsernameChangedEvent {
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct UserEmailChangedEvent {
+    pub user_id: UserId,
     pub new_email: UnverifiedEmail,
     pub username: String,
     pub changed_at: DateTime<Utc>,
@@ -97,3 +158,93 @@ pub struct UserEmailVerifiedEvent {
     pub username: String,
     pub verified_at: DateTime<Utc>,
 }
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserEmailVerificationRequestedEvent {
+    pub user_id: UserId,
+    pub email: UnverifiedEmail,
+    pub username: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserPasswordResetRequestedEvent {
+    pub user_id: UserId,
+    pub email: Email,
+    pub username: String,
+    /// リセットトークンの ID
+    ///
+    /// リンクのトークンは送信時にこの ID から導出するため、イベントにはトークンを含めない
+    pub reset_token_id: PasswordResetTokenId,
+    pub expires_at: DateTime<Utc>,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// ログインリンクの送信はリレーワーカーがイベントを処理する際に行う
+///
+/// リンクのトークンは送信時に発行するため、イベントにはトークンを含めない
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserMagicLinkRequestedEvent {
+    pub user_id: UserId,
+    pub email: Email,
+    pub username: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserPasswordResetEvent {
+    pub username: String,
+    pub email: Email,
+    pub reset_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserPasswordChangedEvent {
+    pub username: String,
+    pub email: Email,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserLockedOutEvent {
+    pub username: String,
+    pub email: Email,
+    pub locked_until: DateTime<Utc>,
+    pub locked_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserMfaEnabledEvent {
+    pub username: String,
+    pub email: Email,
+    pub enabled_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserMfaDisabledEvent {
+    pub username: String,
+    pub email: Email,
+    pub disabled_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserImpersonationStartedEvent {
+    pub user_id: UserId,
+    pub username: String,
+    /// なりすましを開始した管理者
+    pub impersonator_id: UserId,
+    pub reason: String,
+    /// なりすましのアクセストークンに埋め込んだセッション ID
+    pub session_id: SessionId,
+    pub expires_at: DateTime<Utc>,
+    pub started_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserImpersonationEndedEvent {
+    pub user_id: UserId,
+    pub username: String,
+    pub impersonator_id: UserId,
+    pub session_id: SessionId,
+    pub ended_at: DateTime<Utc>,
+}
@@ -0,0 +1,217 @@
+use chrono::{DateTime, Duration, Utc};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LoginLockoutConfigError {
+    #[error("Invalid configuration for LoginLockoutConfig: {0}")]
+    InvalidConfig(String),
+}
+
+/// アカウント単位のログイン失敗によるロックアウトの設定
+#[derive(Debug, Clone)]
+pub struct LoginLockoutConfig {
+    /// ロックアウトするまでに許容する失敗回数
+    max_failed_attempts: u32,
+
+    /// 失敗回数を数える期間。最初の失敗からこの期間を過ぎると数え直す
+    failure_window: Duration,
+
+    /// ロックアウトの継続期間
+    lockout_duration: Duration,
+}
+
+impl LoginLockoutConfig {
+    pub fn new(
+        max_failed_attempts: u32,
+        failure_window_secs: i64,
+        lockout_duration_secs: i64,
+    ) -> Result<Self, LoginLockoutConfigError> {
+        if max_failed_attempts == 0 {
+            return Err(LoginLockoutConfigError::InvalidConfig(
+                "max_failed_attempts must be positive".to_string(),
+            ));
+        }
+
+        if failure_window_secs <= 0 {
+            return Err(LoginLockoutConfigError::InvalidConfig(
+                "failure_window_secs must be positive".to_string(),
+            ));
+        }
+
+        if lockout_duration_secs <= 0 {
+            return Err(LoginLockoutConfigError::InvalidConfig(
+                "lockout_duration_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            max_failed_attempts,
+            failure_window: Duration::seconds(failure_window_secs),
+            lockout_duration: Duration::seconds(lockout_duration_secs),
+        })
+    }
+}
+
+/// ログイン失敗の記録と、それによるロックアウトの状態
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoginFailures {
+    failed_attempts: u32,
+    first_failed_at: Option<DateTime<Utc>>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+/// 失敗を記録した結果
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum LoginFailureOutcome {
+    Counted,
+    LockedOut { locked_until: DateTime<Utc> },
+}
+
+impl LoginFailures {
+    /// 永続化された値から再構築する
+    pub fn reconstruct(
+        failed_attempts: u32,
+        first_failed_at: Option<DateTime<Utc>>,
+        locked_until: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            failed_attempts,
+            first_failed_at,
+            locked_until,
+        }
+    }
+
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    pub fn first_failed_at(&self) -> Option<DateTime<Utc>> {
+        self.first_failed_at
+    }
+
+    pub fn locked_until(&self) -> Option<DateTime<Utc>> {
+        self.locked_until
+    }
+
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| now < locked_until)
+    }
+
+    /// 失敗の記録もロックアウトも残っていないか
+    pub fn is_clear(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub(crate) fn record_failure(
+        &mut self,
+        config: &LoginLockoutConfig,
+        now: DateTime<Utc>,
+    ) -> LoginFailureOutcome {
+        let window_expired = self
+            .first_failed_at
+            .is_none_or(|first_failed_at| now - first_failed_at >= config.failure_window);
+
+        if window_expired {
+            self.failed_attempts = 1;
+            self.first_failed_at = Some(now);
+        } else {
+            self.failed_attempts += 1;
+        }
+
+        if self.failed_attempts < config.max_failed_attempts {
+            return LoginFailureOutcome::Counted;
+        }
+
+        // ロックアウト後は失敗回数を数え直す
+        let locked_until = now + config.lockout_duration;
+        *self = Self {
+            failed_attempts: 0,
+            first_failed_at: None,
+            locked_until: Some(locked_until),
+        };
+
+        LoginFailureOutcome::LockedOut { locked_until }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use rstest::*;
+
+    use super::*;
+
+    #[fixture]
+    fn config() -> LoginLockoutConfig {
+        LoginLockoutConfig::new(3, 600, 900).unwrap()
+    }
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap() + Duration::minutes(minutes)
+    }
+
+    #[rstest]
+    fn test_locks_out_after_max_failures_within_window(config: LoginLockoutConfig) {
+        let mut failures = LoginFailures::default();
+
+        assert_eq!(
+            failures.record_failure(&config, at(0)),
+            LoginFailureOutcome::Counted
+        );
+        assert_eq!(
+            failures.record_failure(&config, at(1)),
+            LoginFailureOutcome::Counted
+        );
+        assert_eq!(
+            failures.record_failure(&config, at(2)),
+            LoginFailureOutcome::LockedOut {
+                locked_until: at(17)
+            }
+        );
+
+        assert!(failures.is_locked(at(16)));
+        assert!(!failures.is_locked(at(17)));
+        assert_eq!(failures.failed_attempts(), 0);
+    }
+
+    #[rstest]
+    fn test_failures_outside_window_are_counted_again(config: LoginLockoutConfig) {
+        let mut failures = LoginFailures::default();
+
+        failures.record_failure(&config, at(0));
+        failures.record_failure(&config, at(1));
+
+        assert_eq!(
+            failures.record_failure(&config, at(10)),
+            LoginFailureOutcome::Counted
+        );
+        assert_eq!(failures.failed_attempts(), 1);
+        assert_eq!(failures.first_failed_at(), Some(at(10)));
+    }
+
+    #[rstest]
+    fn test_clear(config: LoginLockoutConfig) {
+        let mut failures = LoginFailures::default();
+        for minutes in 0..3 {
+            failures.record_failure(&config, at(minutes));
+        }
+
+        failures.clear();
+
+        assert!(failures.is_clear());
+        assert!(!failures.is_locked(at(3)));
+    }
+
+    #[rstest]
+    #[case(0, 600, 900)]
+    #[case(3, 0, 900)]
+    #[case(3, 600, 0)]
+    fn test_invalid_config(#[case] max: u32, #[case] window: i64, #[case] lockout: i64) {
+        assert!(LoginLockoutConfig::new(max, window, lockout).is_err());
+    }
+}
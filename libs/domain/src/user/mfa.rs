@@ -0,0 +1,221 @@
+use chrono::{DateTime, Utc};
+use strum::EnumString;
+
+use crate::user::UserReconstructionError;
+
+/// TOTP (RFC 6238) の共有シークレット (Base32 エンコード済み)
+#[derive(Clone, PartialEq, Eq, derive_more::Debug)]
+#[debug("TotpSecret(..)")]
+pub struct TotpSecret(String);
+
+impl TotpSecret {
+    pub fn from_raw_str(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// リカバリーコードのハッシュ値
+// リカバリーコードの平文は登録の確定時に一度だけ本人に提示し、サーバー側ではハッシュ値のみを保持する
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, derive_more::AsRef)]
+pub struct RecoveryCodeHash(String);
+
+impl RecoveryCodeHash {
+    pub fn from_raw_str(hash: &str) -> Self {
+        Self(hash.to_string())
+    }
+}
+
+/// 2 段階認証 (TOTP) の登録状態
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum MfaState {
+    /// 未登録
+    #[default]
+    Disabled,
+    /// シークレットを発行済みで、認証コードによる確認待ち
+    Pending { secret: TotpSecret },
+    /// 有効
+    Enabled {
+        secret: TotpSecret,
+        recovery_codes: Vec<RecoveryCodeHash>,
+        /// 最後に使用された認証コードのタイムステップ。同じコードの再利用を防ぐために使用する
+        last_used_step: Option<u64>,
+        enabled_at: DateTime<Utc>,
+    },
+}
+
+impl MfaState {
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, MfaState::Enabled { .. })
+    }
+
+    pub fn kind(&self) -> &'static str {
+        self.kind_raw().into()
+    }
+
+    fn kind_raw(&self) -> MfaStateKind {
+        match self {
+            MfaState::Disabled => MfaStateKind::Disabled,
+            MfaState::Pending { .. } => MfaStateKind::Pending,
+            MfaState::Enabled { .. } => MfaStateKind::Enabled,
+        }
+    }
+
+    pub fn totp_secret(&self) -> Option<&TotpSecret> {
+        match self {
+            MfaState::Disabled => None,
+            MfaState::Pending { secret } | MfaState::Enabled { secret, .. } => Some(secret),
+        }
+    }
+
+    pub fn recovery_codes(&self) -> &[RecoveryCodeHash] {
+        match self {
+            MfaState::Enabled { recovery_codes, .. } => recovery_codes,
+            _ => &[],
+        }
+    }
+
+    pub fn last_used_step(&self) -> Option<u64> {
+        match self {
+            MfaState::Enabled { last_used_step, .. } => *last_used_step,
+            _ => None,
+        }
+    }
+
+    pub fn enabled_at(&self) -> Option<DateTime<Utc>> {
+        match self {
+            MfaState::Enabled { enabled_at, .. } => Some(*enabled_at),
+            _ => None,
+        }
+    }
+}
+
+pub struct MfaStateRaw {
+    pub status: String,
+    pub totp_secret: Option<String>,
+    pub recovery_codes: Vec<String>,
+    pub last_used_step: Option<i64>,
+    pub enabled_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<MfaStateRaw> for MfaState {
+    type Error = UserReconstructionError;
+
+    fn try_from(raw: MfaStateRaw) -> Result<Self, Self::Error> {
+        let MfaStateRaw {
+            status,
+            totp_secret,
+            recovery_codes,
+            last_used_step,
+            enabled_at,
+        } = raw;
+
+        let kind = status.parse::<MfaStateKind>().map_err(|_| {
+            UserReconstructionError::InvalidMfaStatus {
+                invalid_status: status,
+            }
+        })?;
+
+        let secret = || {
+            totp_secret
+                .as_deref()
+                .map(TotpSecret::from_raw_str)
+                .ok_or(UserReconstructionError::MfaWithoutTotpSecret)
+        };
+
+        match kind {
+            MfaStateKind::Disabled => Ok(MfaState::Disabled),
+            MfaStateKind::Pending => Ok(MfaState::Pending { secret: secret()? }),
+            MfaStateKind::Enabled => Ok(MfaState::Enabled {
+                secret: secret()?,
+                recovery_codes: recovery_codes
+                    .iter()
+                    .map(|hash| RecoveryCodeHash::from_raw_str(hash))
+                    .collect(),
+                last_used_step: last_used_step
+                    .map(u64::try_from)
+                    .transpose()
+                    .map_err(|_| UserReconstructionError::InvalidTotpStep)?,
+                enabled_at: enabled_at.ok_or(UserReconstructionError::MfaEnabledButNoEnabledAt)?,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, strum::Display, EnumString, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum MfaStateKind {
+    Disabled,
+    Pending,
+    Enabled,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use rstest::*;
+
+    use super::*;
+
+    fn enabled_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn raw(status: &str) -> MfaStateRaw {
+        MfaStateRaw {
+            status: status.to_string(),
+            totp_secret: Some("JBSWY3DPEHPK3PXP".to_string()),
+            recovery_codes: vec!["hash-1".to_string()],
+            last_used_step: Some(100),
+            enabled_at: Some(enabled_at()),
+        }
+    }
+
+    #[rstest]
+    #[case("disabled", MfaState::Disabled)]
+    #[case("pending", MfaState::Pending { secret: TotpSecret::from_raw_str("JBSWY3DPEHPK3PXP") })]
+    #[case("enabled", MfaState::Enabled {
+        secret: TotpSecret::from_raw_str("JBSWY3DPEHPK3PXP"),
+        recovery_codes: vec![RecoveryCodeHash::from_raw_str("hash-1")],
+        last_used_step: Some(100),
+        enabled_at: enabled_at(),
+    })]
+    fn test_try_from_mfa_state_raw(#[case] status: &str, #[case] expected: MfaState) {
+        let state: MfaState = raw(status).try_into().unwrap();
+        assert_eq!(state, expected);
+    }
+
+    #[rstest]
+    #[case(
+        MfaStateRaw { status: "unknown".to_string(), ..raw("enabled") },
+        UserReconstructionError::InvalidMfaStatus { invalid_status: "unknown".to_string() }
+    )]
+    #[case(
+        MfaStateRaw { totp_secret: None, ..raw("pending") },
+        UserReconstructionError::MfaWithoutTotpSecret
+    )]
+    #[case(
+        MfaStateRaw { enabled_at: None, ..raw("enabled") },
+        UserReconstructionError::MfaEnabledButNoEnabledAt
+    )]
+    #[case(
+        MfaStateRaw { last_used_step: Some(-1), ..raw("enabled") },
+        UserReconstructionError::InvalidTotpStep
+    )]
+    fn test_try_from_invalid_mfa_state_raw(
+        #[case] raw: MfaStateRaw,
+        #[case] expected: UserReconstructionError,
+    ) {
+        let result: Result<MfaState, _> = raw.try_into();
+        assert_eq!(result.unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_totp_secret_is_not_printed() {
+        let secret = TotpSecret::from_raw_str("JBSWY3DPEHPK3PXP");
+        assert_eq!(format!("{secret:?}"), "TotpSecret(..)");
+    }
+}
@@ -2,25 +2,40 @@ mod entity;
 mod error;
 mod events;
 mod factory;
+mod login_lockout;
+mod mfa;
+mod password_policy;
+mod query;
 mod repository;
 mod service;
 mod value_objects;
 
 pub use entity::{User, UserState, UserStateKind, UserStateRaw};
 pub use error::{
-    ModificationWithInvalidStateError, UserDomainError, UserReconstructionError,
-    UserStateTransitionError, UserUniqueConstraintViolation,
+    LoginLockoutError, MfaError, ModificationWithInvalidStateError, PasswordChangeError,
+    RoleChangeError, UserDomainError, UserReconstructionError, UserStateTransitionError,
+    UserUniqueConstraintViolation,
 };
 pub use events::*;
 pub use factory::UserFactory;
+pub use login_lockout::{LoginFailures, LoginLockoutConfig, LoginLockoutConfigError};
+pub use mfa::{MfaState, MfaStateKind, MfaStateRaw, RecoveryCodeHash, TotpSecret};
+pub use password_policy::{
+    BreachedPasswordChecker, CharacterClass, PasswordOwner, PasswordPolicy, PasswordPolicyConfig,
+    PasswordPolicyConfigError, PasswordPolicyViolation, PasswordPolicyViolations,
+};
+pub use query::{
+    SortDirection, UserListFilter, UserListPage, UserListQuery, UserListSort, UserListSortKey,
+};
 pub use repository::{UserRepository, UserRepositoryError};
 pub use service::{
-    EmailVerificationError, EmailVerifier, PasswordHasher, PasswordHashingError,
-    UserIdGenerationError, UserIdGenerator, UserIdGeneratorFactory, UserUniquenessService,
+    EmailVerificationClaim, EmailVerificationError, EmailVerifier, PasswordHasher,
+    PasswordHashingError, UserIdGenerationError, UserIdGenerator, UserIdGeneratorFactory,
+    UserUniquenessService,
 };
 pub use value_objects::{
     email::{Email, EmailFormatError, EmailTrait, UnverifiedEmail, VerifiedEmail},
-    password::{HashedPassword, PasswordPolicyViolation, RawPassword},
+    password::{HashedPassword, HashedPasswordFormatError, RawPassword},
     role::UserRole,
     user_id::UserId,
 };
@@ -0,0 +1,332 @@
+use std::{fmt, sync::Arc};
+
+use thiserror::Error;
+
+use super::RawPassword;
+
+// ユーザー名・メールアドレスの包含チェックの対象とする最小の文字数
+// 短すぎる文字列は偶然含まれることが多いため対象外とする
+const MIN_USER_INFO_CHARS: usize = 3;
+
+/// パスワードに含めることを要求できる文字種
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum CharacterClass {
+    Uppercase,
+    Lowercase,
+    Digit,
+    Symbol,
+}
+
+impl CharacterClass {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharacterClass::Uppercase => c.is_uppercase(),
+            CharacterClass::Lowercase => c.is_lowercase(),
+            CharacterClass::Digit => c.is_numeric(),
+            CharacterClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            CharacterClass::Uppercase => "英大文字",
+            CharacterClass::Lowercase => "英小文字",
+            CharacterClass::Digit => "数字",
+            CharacterClass::Symbol => "記号",
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PasswordPolicyViolation {
+    #[error("パスワードは{min}文字以上である必要があります")]
+    TooShort { min: usize },
+
+    #[error("パスワードは{max}文字以下である必要があります")]
+    TooLong { max: usize },
+
+    #[error("パスワードには{}を1文字以上含める必要があります", class.label())]
+    MissingCharacterClass { class: CharacterClass },
+
+    #[error("ユーザー名を含むパスワードは使用できません")]
+    ContainsUsername,
+
+    #[error("メールアドレスを含むパスワードは使用できません")]
+    ContainsEmail,
+
+    #[error("このパスワードは過去に漏洩したことが確認されているため使用できません")]
+    Breached,
+}
+
+/// パスワードポリシーに違反したすべてのルール
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub struct PasswordPolicyViolations(Vec<PasswordPolicyViolation>);
+
+impl PasswordPolicyViolations {
+    pub fn violations(&self) -> &[PasswordPolicyViolation] {
+        &self.0
+    }
+}
+
+impl fmt::Display for PasswordPolicyViolations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", messages.join(", "))
+    }
+}
+
+impl IntoIterator for PasswordPolicyViolations {
+    type Item = PasswordPolicyViolation;
+    type IntoIter = std::vec::IntoIter<PasswordPolicyViolation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// 過去に漏洩したパスワードかどうかを判定する
+pub trait BreachedPasswordChecker: Send + Sync {
+    fn is_breached(&self, password: &RawPassword) -> bool;
+}
+
+#[derive(Debug, Error)]
+pub enum PasswordPolicyConfigError {
+    #[error("Invalid configuration for PasswordPolicyConfig: {0}")]
+    InvalidConfig(String),
+}
+
+/// パスワードポリシーの各ルールの設定
+#[derive(Debug, Clone)]
+pub struct PasswordPolicyConfig {
+    /// 最小文字数 (バイト数ではなく文字数で数える)
+    min_length: usize,
+
+    /// 最大文字数。ハッシュ化の計算コストを悪用されないよう上限を設ける
+    max_length: usize,
+
+    /// 含めることを要求する文字種
+    required_character_classes: Vec<CharacterClass>,
+
+    /// ユーザー名・メールアドレスを含むパスワードを禁止するか
+    forbid_user_info: bool,
+}
+
+impl PasswordPolicyConfig {
+    pub fn new(
+        min_length: usize,
+        max_length: usize,
+        required_character_classes: Vec<CharacterClass>,
+        forbid_user_info: bool,
+    ) -> Result<Self, PasswordPolicyConfigError> {
+        if min_length == 0 {
+            return Err(PasswordPolicyConfigError::InvalidConfig(
+                "min_length must be positive".to_string(),
+            ));
+        }
+
+        if max_length < min_length {
+            return Err(PasswordPolicyConfigError::InvalidConfig(
+                "max_length must be greater than or equal to min_length".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            min_length,
+            max_length,
+            required_character_classes,
+            forbid_user_info,
+        })
+    }
+
+    pub fn min_length(&self) -> usize {
+        self.min_length
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    pub fn required_character_classes(&self) -> &[CharacterClass] {
+        &self.required_character_classes
+    }
+
+    pub fn forbid_user_info(&self) -> bool {
+        self.forbid_user_info
+    }
+}
+
+/// パスワードの所有者の情報 (ユーザー名・メールアドレスの包含チェックに使用する)
+#[derive(Debug, Clone, Copy)]
+pub struct PasswordOwner<'a> {
+    pub username: &'a str,
+    pub email: &'a str,
+}
+
+/// 新しく設定するパスワードがポリシーを満たしているかを検証するドメインサービス
+pub struct PasswordPolicy {
+    config: PasswordPolicyConfig,
+    breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+}
+
+impl PasswordPolicy {
+    pub fn new(
+        config: PasswordPolicyConfig,
+        breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+    ) -> Self {
+        Self {
+            config,
+            breached_password_checker,
+        }
+    }
+
+    /// すべてのルールを検証し、違反したルールをまとめて返す
+    pub fn validate(
+        &self,
+        password: &RawPassword,
+        owner: PasswordOwner<'_>,
+    ) -> Result<(), PasswordPolicyViolations> {
+        let value = password.as_str();
+        let mut violations = Vec::new();
+
+        let length = value.chars().count();
+        if length < self.config.min_length {
+            violations.push(PasswordPolicyViolation::TooShort {
+                min: self.config.min_length,
+            });
+        }
+        if length > self.config.max_length {
+            violations.push(PasswordPolicyViolation::TooLong {
+                max: self.config.max_length,
+            });
+        }
+
+        for class in &self.config.required_character_classes {
+            if !value.chars().any(|c| class.matches(c)) {
+                violations.push(PasswordPolicyViolation::MissingCharacterClass { class: *class });
+            }
+        }
+
+        if self.config.forbid_user_info {
+            let lowercase_value = value.to_lowercase();
+
+            if contains_ignoring_case(&lowercase_value, owner.username) {
+                violations.push(PasswordPolicyViolation::ContainsUsername);
+            }
+
+            // メールアドレス全体に加えて、ローカル部のみを含む場合も禁止する
+            let local_part = owner.email.split('@').next().unwrap_or_default();
+            if contains_ignoring_case(&lowercase_value, owner.email)
+                || contains_ignoring_case(&lowercase_value, local_part)
+            {
+                violations.push(PasswordPolicyViolation::ContainsEmail);
+            }
+        }
+
+        // 長さの上限を超える入力は漏洩リストとの照合を行わない
+        if length <= self.config.max_length && self.breached_password_checker.is_breached(password)
+        {
+            violations.push(PasswordPolicyViolation::Breached);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(PasswordPolicyViolations(violations))
+        }
+    }
+}
+
+fn contains_ignoring_case(lowercase_value: &str, needle: &str) -> bool {
+    needle.chars().count() >= MIN_USER_INFO_CHARS
+        && lowercase_value.contains(&needle.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+
+    struct BreachedList(Vec<&'static str>);
+
+    impl BreachedPasswordChecker for BreachedList {
+        fn is_breached(&self, password: &RawPassword) -> bool {
+            self.0.contains(&password.as_str())
+        }
+    }
+
+    const OWNER: PasswordOwner<'static> = PasswordOwner {
+        username: "alice",
+        email: "alice.smith@example.com",
+    };
+
+    #[fixture]
+    fn policy() -> PasswordPolicy {
+        let config = PasswordPolicyConfig::new(
+            8,
+            16,
+            vec![
+                CharacterClass::Uppercase,
+                CharacterClass::Lowercase,
+                CharacterClass::Digit,
+                CharacterClass::Symbol,
+            ],
+            true,
+        )
+        .unwrap();
+
+        PasswordPolicy::new(config, Arc::new(BreachedList(vec!["P@ssw0rd123"])))
+    }
+
+    fn validate(policy: &PasswordPolicy, password: &str) -> Vec<PasswordPolicyViolation> {
+        policy
+            .validate(&RawPassword::new(password), OWNER)
+            .err()
+            .map(|v| v.violations().to_vec())
+            .unwrap_or_default()
+    }
+
+    #[rstest]
+    #[case::valid("Tr0ub4dor&3", vec![])]
+    // バイト数ではなく文字数で数える (9 文字だが 19 バイト)
+    #[case::multibyte_chars("パスワードAa1!", vec![])]
+    #[case::too_short("Ab1!", vec![PasswordPolicyViolation::TooShort { min: 8 }])]
+    #[case::too_long("Abcdefgh1!abcdefg", vec![PasswordPolicyViolation::TooLong { max: 16 }])]
+    #[case::missing_classes(
+        "abcdefghij",
+        vec![
+            PasswordPolicyViolation::MissingCharacterClass { class: CharacterClass::Uppercase },
+            PasswordPolicyViolation::MissingCharacterClass { class: CharacterClass::Digit },
+            PasswordPolicyViolation::MissingCharacterClass { class: CharacterClass::Symbol },
+        ]
+    )]
+    #[case::contains_username("xALICEx1!Z", vec![PasswordPolicyViolation::ContainsUsername])]
+    #[case::contains_email_local_part(
+        "Alice.Smith9!",
+        vec![PasswordPolicyViolation::ContainsUsername, PasswordPolicyViolation::ContainsEmail]
+    )]
+    #[case::breached("P@ssw0rd123", vec![PasswordPolicyViolation::Breached])]
+    fn test_validate(
+        policy: PasswordPolicy,
+        #[case] password: &str,
+        #[case] expected: Vec<PasswordPolicyViolation>,
+    ) {
+        assert_eq!(validate(&policy, password), expected);
+    }
+
+    #[rstest]
+    fn test_validate_without_user_info_rule() {
+        let config = PasswordPolicyConfig::new(8, 64, vec![], false).unwrap();
+        let policy = PasswordPolicy::new(config, Arc::new(BreachedList(vec![])));
+
+        assert!(validate(&policy, "alice-password").is_empty());
+    }
+
+    #[rstest]
+    #[case(0, 64)]
+    #[case(12, 8)]
+    fn test_invalid_config(#[case] min_length: usize, #[case] max_length: usize) {
+        assert!(PasswordPolicyConfig::new(min_length, max_length, vec![], true).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+
+use super::{User, UserId, UserRole, UserStateKind};
+
+/// 管理者向けのユーザー一覧の取得条件
+#[derive(Debug)]
+pub struct UserListQuery {
+    pub filter: UserListFilter,
+    pub sort: UserListSort,
+    /// 前のページの最後のユーザーの ID。`None` の場合は先頭のページを取得する
+    pub cursor: Option<UserId>,
+    /// 1ページあたりの件数
+    pub limit: u64,
+}
+
+/// ユーザー一覧の絞り込み条件。`None` の条件では絞り込まない
+#[derive(Debug, Default)]
+pub struct UserListFilter {
+    pub state: Option<UserStateKind>,
+    pub role: Option<UserRole>,
+    /// 登録日時の下限 (この日時を含む)
+    pub created_from: Option<DateTime<Utc>>,
+    /// 登録日時の上限 (この日時を含まない)
+    pub created_until: Option<DateTime<Utc>>,
+    pub email_prefix: Option<String>,
+    pub username_prefix: Option<String>,
+}
+
+/// ユーザー一覧の並び順
+///
+/// 並び替えの値が同じユーザーは ID の順に並べ、ページの境界を一意に定める
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UserListSort {
+    pub key: UserListSortKey,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserListSortKey {
+    #[default]
+    CreatedAt,
+    Username,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// ユーザー一覧の1ページ分の取得結果
+pub struct UserListPage {
+    pub users: Vec<User>,
+    /// 次のページを取得するためのカーソル。最後のページの場合は `None`
+    pub next_cursor: Option<UserId>,
+    /// 絞り込み条件に一致するユーザーの総数
+    pub total: u64,
+}
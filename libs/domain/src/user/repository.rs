@@ -1,8 +1,9 @@
 use crate::{
     shared::outbox_event::OutboxEventIdGenerationError,
     user::{
-        UserDomainError, UserId, UserIdGenerationError, UserReconstructionError,
-        UserUniqueConstraintViolation, value_objects::email::EmailFormatError,
+        UserDomainError, UserId, UserIdGenerationError, UserListPage, UserListQuery,
+        UserReconstructionError, UserRole, UserUniqueConstraintViolation,
+        value_objects::email::EmailFormatError,
     },
 };
 use async_trait::async_trait;
@@ -18,6 +19,9 @@ pub enum UserRepositoryError {
     #[error(transparent)]
     ReconstructionError(#[from] UserReconstructionError),
 
+    #[error("一覧のカーソルに指定したユーザーが存在しません: {cursor}")]
+    CursorNotFound { cursor: UserId },
+
     #[error("データの保存または取得に失敗しました: {0}")]
     Persistence(#[source] anyhow::Error),
 }
@@ -25,10 +29,24 @@ pub enum UserRepositoryError {
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: UserId) -> Result<Option<User>, UserRepositoryError>;
+    /// ID でユーザーを取得する
+    ///
+    /// 取得した値をもとに更新する間に他の更新が割り込まないよう、取得した行をトランザクションの終了までロックする
+    async fn find_by_id_for_update(&self, id: UserId) -> Result<Option<User>, UserRepositoryError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError>;
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, UserRepositoryError>;
     async fn save(&self, user: User) -> Result<User, UserRepositoryError>;
-    async fn find_all(&self) -> Result<Vec<User>, UserRepositoryError>;
+    /// 条件に一致するユーザーを1ページ分取得する
+    ///
+    /// カーソルのユーザーの並び替えの値より後ろのユーザーを、並び替えの値と ID の順に返す
+    async fn find_page(&self, query: &UserListQuery) -> Result<UserListPage, UserRepositoryError>;
+    /// 指定したロールのユーザーをすべて取得する
+    ///
+    /// ロールの変更と同時に実行される他のロールの変更を待たせるため、取得した行をトランザクションの終了までロックする
+    async fn find_by_role_for_update(
+        &self,
+        role: UserRole,
+    ) -> Result<Vec<User>, UserRepositoryError>;
 }
 
 impl From<UserUniqueConstraintViolation> for UserRepositoryError {
@@ -18,17 +18,48 @@ pub enum PasswordHashingError {
 pub trait PasswordHasher: Send + Sync {
     fn hash(&self, raw: &RawPassword) -> Result<HashedPassword, PasswordHashingError>;
     fn verify(&self, raw: &RawPassword, hashed: &HashedPassword) -> bool;
+    /// 保存済みのハッシュが現在のアルゴリズム・パラメータと異なり、再ハッシュが必要かどうか
+    fn needs_rehash(&self, hashed: &HashedPassword) -> bool;
 }
 
 #[derive(Debug, Error, PartialEq)]
 pub enum EmailVerificationError {
-    // TODO: #35 でエラーの詳細を追加する
+    #[error("確認トークンの有効期限が切れています")]
+    Expired,
+
+    #[error("確認トークンが不正です")]
+    Tampered,
+
+    #[error("確認トークンのメールアドレスが現在のメールアドレスと一致しません")]
+    EmailMismatch,
+
+    #[error("メールアドレスはすでに確認済みです")]
+    AlreadyVerified,
 }
 
 pub trait EmailVerifier {
     fn verify(&self, email: &UnverifiedEmail) -> Result<VerifiedEmail, EmailVerificationError>;
 }
 
+/// 署名と有効期限の検証を終えた確認トークンの内容
+///
+/// トークンの発行後にメールアドレスが変更されていた場合は、古いアドレス宛てのトークンとして拒否する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailVerificationClaim {
+    pub user_id: UserId,
+    pub email: String,
+}
+
+impl EmailVerifier for EmailVerificationClaim {
+    fn verify(&self, email: &UnverifiedEmail) -> Result<VerifiedEmail, EmailVerificationError> {
+        if self.email != email.as_str() {
+            return Err(EmailVerificationError::EmailMismatch);
+        }
+
+        Ok(email.verify())
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum UserIdGenerationError {
     #[error("ユーザーIDの生成に失敗しました: {0}")]
@@ -32,6 +32,14 @@ impl VerifiedEmail {
     }
 }
 
+// 未検証メールアドレスを検証済みメールアドレスに変換する
+// 検証を経ずに変換できないよう、ドメイン層の外には公開しない
+impl UnverifiedEmail {
+    pub(crate) fn verify(&self) -> VerifiedEmail {
+        VerifiedEmail(self.0.clone())
+    }
+}
+
 #[derive(Debug, Error, PartialEq)]
 pub enum EmailFormatError {
     #[error("以下のメールアドレスは形式が正しくありません: {invalid_email}")]
@@ -1,28 +1,25 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// 平文のパスワード
+///
+/// ログイン時の入力など、ポリシーを適用しない場面でも使用するため、生成時には検証を行わない。
+/// 新しく設定するパスワードは `PasswordPolicy` で検証すること。
 #[derive(Debug, Clone)]
 pub struct RawPassword(String);
 
-#[derive(Debug, Error, PartialEq)]
-pub enum PasswordPolicyViolation {
-    #[error("パスワードは8文字以上である必要があります")]
-    TooShort,
-}
-
 impl RawPassword {
-    pub fn new(value: &str) -> Result<Self, PasswordPolicyViolation> {
-        // TODO: #54 でパスワードポリシーを強化する
-        if value.len() >= 8 {
-            Ok(Self(value.to_string()))
-        } else {
-            Err(PasswordPolicyViolation::TooShort)
-        }
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
     }
 
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 #[derive(
@@ -38,9 +35,123 @@ impl RawPassword {
 )]
 pub struct HashedPassword(String);
 
-// TODO: #60 で正しくハッシュ化されているかの検証機構を追加する必要あり
+#[derive(Debug, Error, PartialEq)]
+pub enum HashedPasswordFormatError {
+    #[error("パスワードハッシュの形式が正しくありません")]
+    InvalidFormat,
+}
+
 impl HashedPassword {
-    pub fn from_raw_str(hash: &str) -> Self {
-        Self(hash.to_string())
+    /// PHC 文字列形式（`$<id>[$<params>]$<salt>$<hash>`）または bcrypt の MCF 形式であることを検証して生成する
+    pub fn new(hash: &str) -> Result<Self, HashedPasswordFormatError> {
+        // bcrypt の識別子（`$2a$` など）は PHC 文字列としても解釈できてしまうため、先に判定を分ける
+        let is_well_formed = if hash.starts_with("$2") {
+            is_bcrypt_string(hash)
+        } else {
+            is_phc_string(hash)
+        };
+        if !is_well_formed {
+            return Err(HashedPasswordFormatError::InvalidFormat);
+        }
+        Ok(Self(hash.to_string()))
+    }
+}
+
+const MAX_PHC_IDENTIFIER_LENGTH: usize = 32;
+
+fn is_phc_identifier(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_PHC_IDENTIFIER_LENGTH
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+fn is_phc_b64(value: &str) -> bool {
+    // 標準の B64 に加え、passlib 由来のハッシュで用いられる '.' も許容する
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '.'))
+}
+
+fn is_phc_params(value: &str) -> bool {
+    value.split(',').all(|param| {
+        let Some((name, param_value)) = param.split_once('=') else {
+            return false;
+        };
+        is_phc_identifier(name)
+            && !param_value.is_empty()
+            && param_value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '+' | '.' | '-'))
+    })
+}
+
+fn is_phc_string(value: &str) -> bool {
+    let Some(rest) = value.strip_prefix('$') else {
+        return false;
+    };
+    let fields: Vec<&str> = rest.split('$').collect();
+
+    // id, [バージョン], [パラメータ], ソルト, ハッシュ
+    let [id, params @ .., salt, hash] = fields.as_slice() else {
+        return false;
+    };
+    params.len() <= 2
+        && is_phc_identifier(id)
+        && params.iter().all(|p| is_phc_params(p))
+        && is_phc_b64(salt)
+        && is_phc_b64(hash)
+}
+
+const BCRYPT_SALT_AND_HASH_LENGTH: usize = 53;
+
+fn is_bcrypt_string(value: &str) -> bool {
+    let fields: Vec<&str> = value.split('$').collect();
+    let ["", variant, cost, salt_and_hash] = fields.as_slice() else {
+        return false;
+    };
+    matches!(*variant, "2a" | "2b" | "2x" | "2y")
+        && cost.len() == 2
+        && cost.chars().all(|c| c.is_ascii_digit())
+        && salt_and_hash.len() == BCRYPT_SALT_AND_HASH_LENGTH
+        && salt_and_hash
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$argon2i$m=4096,t=3,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$pbkdf2-sha256$i=600000,l=32$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$scrypt$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$2b$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW")]
+    fn test_hashed_password_accepts_well_formed_hash(#[case] hash: &str) {
+        let hashed = HashedPassword::new(hash).unwrap();
+        assert_eq!(hashed.as_ref(), hash);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("hashed")]
+    #[case("$argon2id$")]
+    #[case("$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$")]
+    #[case("$Argon2id$v=19$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$argon2id$v=19$m=19456,t=2,p=1$c2FsdH NhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$argon2id$v=19$m=,t=2$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo")]
+    #[case("$2b$12$tooshort")]
+    #[case("$2z$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW")]
+    fn test_hashed_password_rejects_malformed_hash(#[case] hash: &str) {
+        assert_eq!(
+            HashedPassword::new(hash),
+            Err(HashedPasswordFormatError::InvalidFormat)
+        );
     }
 }
@@ -0,0 +1,48 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::api_key::{
+        ApiKeyId, ApiKeyIdGenerationError, ApiKeyIdGenerator, ApiKeyIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidApiKeyIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidApiKeyIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl ApiKeyIdGenerator for UuidApiKeyIdGenerator {
+    fn generate(&self) -> Result<ApiKeyId, ApiKeyIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| ApiKeyIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidApiKeyIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidApiKeyIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl ApiKeyIdGeneratorFactory for UuidApiKeyIdGeneratorFactory {
+    fn create_api_key_id_generator(&self) -> Arc<dyn ApiKeyIdGenerator> {
+        Arc::new(UuidApiKeyIdGenerator::new(self.clock.clone()))
+    }
+}
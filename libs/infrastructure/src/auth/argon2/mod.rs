@@ -1 +1,2 @@
 pub mod password_service;
+pub mod pepper;
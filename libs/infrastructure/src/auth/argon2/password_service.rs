@@ -1,5 +1,5 @@
 use argon2::{
-    Argon2,
+    Algorithm, Argon2, KeyId, Params, ParamsBuilder, Version,
     password_hash::{
         PasswordHash, PasswordHasher as _, PasswordVerifier, SaltString, rand_core::OsRng,
     },
@@ -7,26 +7,273 @@ use argon2::{
 use domain::user::PasswordHasher;
 use domain::user::PasswordHashingError;
 use domain::user::{HashedPassword, RawPassword};
+use pbkdf2::Pbkdf2;
+use thiserror::Error;
 
-pub struct Argon2PasswordHasher;
+use super::pepper::PepperSet;
+
+pub struct Argon2Config {
+    params: Params,
+}
+
+#[derive(Debug, Error)]
+pub enum Argon2ConfigError {
+    #[error("Invalid configuration for Argon2PasswordHasher: {0}")]
+    InvalidConfig(String),
+}
+
+impl Argon2Config {
+    pub fn new(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Self, Argon2ConfigError> {
+        let params = Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|e| Argon2ConfigError::InvalidConfig(e.to_string()))?;
+
+        Ok(Self { params })
+    }
+}
+
+/// Argon2id でハッシュ化するパスワードハッシャー
+///
+/// 移行元システムから取り込んだ bcrypt・PBKDF2 のハッシュも検証でき、
+/// 現在の設定と異なるハッシュは `needs_rehash` で再ハッシュ対象として報告する。
+/// ペッパーが設定されている場合は Argon2 の secret として混ぜ込み、その ID を `keyid` に記録する。
+pub struct Argon2PasswordHasher {
+    config: Argon2Config,
+    peppers: PepperSet,
+}
+
+const ARGON2_ALGORITHM: Algorithm = Algorithm::Argon2id;
+const ARGON2_VERSION: Version = Version::V0x13;
+
+impl Argon2PasswordHasher {
+    pub fn new(config: Argon2Config, peppers: PepperSet) -> Self {
+        Self { config, peppers }
+    }
+
+    /// 現在のペッパーを使用する Argon2 インスタンスを生成する
+    fn current_argon2(&self) -> Result<Argon2<'_>, argon2::Error> {
+        let params = &self.config.params;
+
+        let Some((pepper_id, secret)) = self.peppers.current() else {
+            return Ok(Argon2::new(
+                ARGON2_ALGORITHM,
+                ARGON2_VERSION,
+                params.clone(),
+            ));
+        };
+
+        let params = ParamsBuilder::new()
+            .m_cost(params.m_cost())
+            .t_cost(params.t_cost())
+            .p_cost(params.p_cost())
+            .keyid(KeyId::new(pepper_id.as_bytes())?)
+            .build()?;
+
+        Argon2::new_with_secret(secret, ARGON2_ALGORITHM, ARGON2_VERSION, params)
+    }
+
+    fn verify_argon2(&self, raw: &RawPassword, parsed_hash: &PasswordHash<'_>) -> bool {
+        let Ok(params) = Params::try_from(parsed_hash) else {
+            return false;
+        };
+
+        // ハッシュに記録された keyid のペッパーで検証する
+        let argon2 = if params.keyid().is_empty() {
+            Argon2::default()
+        } else {
+            let Some(secret) = std::str::from_utf8(params.keyid())
+                .ok()
+                .and_then(|pepper_id| self.peppers.secret(pepper_id))
+            else {
+                return false;
+            };
+            let Ok(argon2) = Argon2::new_with_secret(
+                secret,
+                Algorithm::default(),
+                Version::default(),
+                Params::default(),
+            ) else {
+                return false;
+            };
+            argon2
+        };
+
+        argon2.verify_password(raw.as_bytes(), parsed_hash).is_ok()
+    }
+}
 
 impl PasswordHasher for Argon2PasswordHasher {
     fn hash(&self, raw: &RawPassword) -> Result<HashedPassword, PasswordHashingError> {
         let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-        let hash = argon2
+        let hash = self
+            .current_argon2()
+            .map_err(|_| PasswordHashingError::HashingFailed)?
             .hash_password(raw.as_bytes(), &salt)
             .map_err(|_| PasswordHashingError::HashingFailed)?
             .to_string();
-        Ok(HashedPassword::from_raw_str(&hash))
+        HashedPassword::new(&hash).map_err(|_| PasswordHashingError::HashingFailed)
     }
 
     fn verify(&self, raw: &RawPassword, hashed: &HashedPassword) -> bool {
-        let Ok(parsed_hash) = PasswordHash::new(hashed.as_ref()) else {
+        let hashed = hashed.as_ref();
+
+        // bcrypt は PHC 文字列形式ではないため個別に検証する
+        if hashed.starts_with("$2") {
+            return bcrypt::verify(raw.as_bytes(), hashed).unwrap_or(false);
+        }
+
+        let Ok(parsed_hash) = PasswordHash::new(hashed) else {
             return false;
         };
-        Argon2::default()
-            .verify_password(raw.as_bytes(), &parsed_hash)
-            .is_ok()
+
+        // ハッシュに記録されたアルゴリズム・パラメータで検証する
+        if Algorithm::try_from(parsed_hash.algorithm).is_ok() {
+            self.verify_argon2(raw, &parsed_hash)
+        } else {
+            Pbkdf2.verify_password(raw.as_bytes(), &parsed_hash).is_ok()
+        }
+    }
+
+    fn needs_rehash(&self, hashed: &HashedPassword) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(hashed.as_ref()) else {
+            // bcrypt など PHC 文字列として解釈できないハッシュは移行対象
+            return true;
+        };
+
+        if Algorithm::try_from(parsed_hash.algorithm) != Ok(ARGON2_ALGORITHM) {
+            return true;
+        }
+
+        if parsed_hash.version != Some(ARGON2_VERSION.into()) {
+            return true;
+        }
+
+        let Ok(params) = Params::try_from(&parsed_hash) else {
+            return true;
+        };
+        let current = &self.config.params;
+
+        let current_pepper_id = self.peppers.current_id().unwrap_or_default();
+
+        params.m_cost() != current.m_cost()
+            || params.t_cost() != current.t_cost()
+            || params.p_cost() != current.p_cost()
+            || params.keyid() != current_pepper_id.as_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use argon2::password_hash::PasswordHasher as _;
+
+    use super::*;
+
+    const SECRET_OLD: &str = "old-pepper-secret-0001";
+    const SECRET_NEW: &str = "new-pepper-secret-0002";
+
+    /// テストを高速にするため、最小限のコストでハッシュ化する
+    fn hasher(current_id: &str, peppers: &str) -> Argon2PasswordHasher {
+        Argon2PasswordHasher::new(
+            Argon2Config::new(8, 1, 1).unwrap(),
+            PepperSet::new(current_id, peppers).unwrap(),
+        )
+    }
+
+    fn keyid_of(hashed: &HashedPassword) -> Vec<u8> {
+        let parsed = PasswordHash::new(hashed.as_ref()).unwrap();
+        Params::try_from(&parsed).unwrap().keyid().to_vec()
+    }
+
+    #[test]
+    fn test_hash_and_verify_records_current_pepper_id() {
+        let hasher = hasher("new", &format!("old={SECRET_OLD},new={SECRET_NEW}"));
+        let raw = RawPassword::new("correct horse battery staple");
+
+        let hashed = hasher.hash(&raw).unwrap();
+
+        assert_eq!(keyid_of(&hashed), b"new");
+        assert!(hasher.verify(&raw, &hashed));
+        assert!(!hasher.verify(&RawPassword::new("wrong password"), &hashed));
+        assert!(!hasher.needs_rehash(&hashed));
+    }
+
+    #[test]
+    fn test_hash_without_pepper_has_no_keyid() {
+        let hasher = hasher("", "");
+        let raw = RawPassword::new("correct horse battery staple");
+
+        let hashed = hasher.hash(&raw).unwrap();
+
+        assert!(keyid_of(&hashed).is_empty());
+        assert!(hasher.verify(&raw, &hashed));
+    }
+
+    #[test]
+    fn test_verify_hash_made_with_retired_pepper() {
+        let raw = RawPassword::new("correct horse battery staple");
+        let hashed = hasher("old", &format!("old={SECRET_OLD}"))
+            .hash(&raw)
+            .unwrap();
+
+        // ローテーション後も、古いペッパーが残っていれば検証できる
+        let rotated = hasher("new", &format!("old={SECRET_OLD},new={SECRET_NEW}"));
+        assert!(rotated.verify(&raw, &hashed));
+
+        // 古いペッパーを削除した後は検証できない
+        let removed = hasher("new", &format!("new={SECRET_NEW}"));
+        assert!(!removed.verify(&raw, &hashed));
+    }
+
+    #[test]
+    fn test_needs_rehash_after_active_pepper_changes() {
+        let raw = RawPassword::new("correct horse battery staple");
+        let peppers = format!("old={SECRET_OLD},new={SECRET_NEW}");
+        let hashed = hasher("old", &peppers).hash(&raw).unwrap();
+
+        assert!(!hasher("old", &peppers).needs_rehash(&hashed));
+        assert!(hasher("new", &peppers).needs_rehash(&hashed));
+        assert!(hasher("", &peppers).needs_rehash(&hashed));
+    }
+
+    #[test]
+    fn test_needs_rehash_when_cost_parameters_change() {
+        let raw = RawPassword::new("correct horse battery staple");
+        let hashed = hasher("", "").hash(&raw).unwrap();
+
+        let stronger =
+            Argon2PasswordHasher::new(Argon2Config::new(16, 2, 1).unwrap(), PepperSet::empty());
+
+        assert!(stronger.verify(&raw, &hashed));
+        assert!(stronger.needs_rehash(&hashed));
+    }
+    #[test]
+    fn test_verify_legacy_bcrypt_hash() {
+        let hasher = hasher("", "");
+        let raw = RawPassword::new("correct horse battery staple");
+        let hashed =
+            HashedPassword::new(&bcrypt::hash("correct horse battery staple", 4).unwrap()).unwrap();
+
+        assert!(hasher.verify(&raw, &hashed));
+        assert!(!hasher.verify(&RawPassword::new("wrong password"), &hashed));
+        assert!(hasher.needs_rehash(&hashed));
+    }
+
+    #[test]
+    fn test_verify_legacy_pbkdf2_hash() {
+        let hasher = hasher("", "");
+        let raw = RawPassword::new("correct horse battery staple");
+        let salt = SaltString::generate(&mut OsRng);
+        let pbkdf2_hash = Pbkdf2
+            .hash_password(b"correct horse battery staple", &salt)
+            .unwrap()
+            .to_string();
+        let hashed = HashedPassword::new(&pbkdf2_hash).unwrap();
+
+        assert!(hasher.verify(&raw, &hashed));
+        assert!(!hasher.verify(&RawPassword::new("wrong password"), &hashed));
+        assert!(hasher.needs_rehash(&hashed));
     }
 }
@@ -0,0 +1,215 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use argon2::Params;
+use thiserror::Error;
+
+/// ペッパーの最小バイト数
+const MIN_PEPPER_SECRET_LEN: usize = 16;
+
+#[derive(Debug, Error)]
+pub enum PepperSetError {
+    #[error("Invalid configuration for PepperSet: {0}")]
+    InvalidConfig(String),
+
+    #[error("Failed to read pepper file '{path}': {source}")]
+    ReadFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// パスワードのハッシュ化に混ぜ込む、DB の外で管理するペッパーの一覧
+///
+/// ペッパーの ID は Argon2 の `keyid` パラメータとしてハッシュに記録されるため、
+/// ローテーション中は複数のペッパーを併用して検証できる。
+pub struct PepperSet {
+    current_id: Option<String>,
+    secrets: HashMap<String, Vec<u8>>,
+}
+
+impl PepperSet {
+    /// ペッパーを設定しない場合に使用する、空の一覧
+    pub fn empty() -> Self {
+        Self {
+            current_id: None,
+            secrets: HashMap::new(),
+        }
+    }
+
+    /// `peppers` は `id=secret` をカンマまたは改行区切りで並べた文字列
+    /// (例: `2026-02=...,2026-01=...`)
+    ///
+    /// `current_id` が空の場合、新しいハッシュにはペッパーを使用しない。
+    pub fn new(current_id: &str, peppers: &str) -> Result<Self, PepperSetError> {
+        let mut secrets = HashMap::new();
+
+        for entry in peppers
+            .split([',', '\n'])
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let Some((id, secret)) = entry.split_once('=') else {
+                return Err(PepperSetError::InvalidConfig(
+                    "pepper entry must be in the form 'id=secret'".to_string(),
+                ));
+            };
+            let (id, secret) = (id.trim(), secret.trim());
+
+            validate_pepper_id(id)?;
+            if secret.len() < MIN_PEPPER_SECRET_LEN {
+                return Err(PepperSetError::InvalidConfig(format!(
+                    "pepper '{id}' must be at least {MIN_PEPPER_SECRET_LEN} bytes long"
+                )));
+            }
+            if secrets
+                .insert(id.to_string(), secret.as_bytes().to_vec())
+                .is_some()
+            {
+                return Err(PepperSetError::InvalidConfig(format!(
+                    "pepper '{id}' is configured more than once"
+                )));
+            }
+        }
+
+        let current_id = Some(current_id.trim())
+            .filter(|id| !id.is_empty())
+            .map(|id| {
+                if secrets.contains_key(id) {
+                    Ok(id.to_string())
+                } else {
+                    Err(PepperSetError::InvalidConfig(format!(
+                        "current pepper '{id}' is not configured"
+                    )))
+                }
+            })
+            .transpose()?;
+
+        Ok(Self {
+            current_id,
+            secrets,
+        })
+    }
+
+    /// 1 行に 1 つ `id=secret` を記載したファイルから読み込む
+    pub fn load(current_id: &str, path: impl Into<PathBuf>) -> Result<Self, PepperSetError> {
+        let path = path.into();
+        let content = fs::read_to_string(&path)
+            .map_err(|source| PepperSetError::ReadFailed { path, source })?;
+
+        Self::new(current_id, &content)
+    }
+
+    /// 新しいハッシュに使用するペッパーの ID
+    pub(crate) fn current_id(&self) -> Option<&str> {
+        self.current_id.as_deref()
+    }
+
+    pub(crate) fn current(&self) -> Option<(&str, &[u8])> {
+        let id = self.current_id()?;
+        Some((id, self.secret(id)?))
+    }
+
+    pub(crate) fn secret(&self, id: &str) -> Option<&[u8]> {
+        self.secrets.get(id).map(Vec::as_slice)
+    }
+}
+
+fn validate_pepper_id(id: &str) -> Result<(), PepperSetError> {
+    // ID は Argon2 の keyid としてハッシュに埋め込むため、その上限に収める
+    if id.is_empty()
+        || id.len() > Params::MAX_KEYID_LEN
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    {
+        return Err(PepperSetError::InvalidConfig(format!(
+            "pepper id '{id}' must be 1 to {} characters of [A-Za-z0-9_-]",
+            Params::MAX_KEYID_LEN
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const SECRET_A: &str = "aaaaaaaaaaaaaaaaaaaaaaaa";
+    const SECRET_B: &str = "bbbbbbbbbbbbbbbbbbbbbbbb";
+
+    #[test]
+    fn test_new_parses_comma_and_newline_separated_entries() {
+        let peppers = PepperSet::new(
+            "2026-02",
+            &format!("2026-02={SECRET_B},\n 2026-01 = {SECRET_A}\n"),
+        )
+        .unwrap();
+
+        assert_eq!(peppers.current_id(), Some("2026-02"));
+        assert_eq!(peppers.current(), Some(("2026-02", SECRET_B.as_bytes())));
+        assert_eq!(peppers.secret("2026-01"), Some(SECRET_A.as_bytes()));
+        assert_eq!(peppers.secret("2025-12"), None);
+    }
+
+    #[test]
+    fn test_new_without_current_id_disables_peppering() {
+        let peppers = PepperSet::new("", &format!("old={SECRET_A}")).unwrap();
+
+        // 新しいハッシュにはペッパーを使わないが、既存のハッシュは検証できる
+        assert_eq!(peppers.current(), None);
+        assert_eq!(peppers.secret("old"), Some(SECRET_A.as_bytes()));
+    }
+
+    #[test]
+    fn test_new_rejects_unknown_current_id() {
+        let result = PepperSet::new("2026-03", &format!("2026-02={SECRET_A}"));
+
+        assert!(matches!(
+            result,
+            Err(PepperSetError::InvalidConfig(message)) if message.contains("'2026-03' is not configured")
+        ));
+    }
+
+    #[test]
+    fn test_new_rejects_duplicate_id() {
+        let result = PepperSet::new("a", &format!("a={SECRET_A},a={SECRET_B}"));
+
+        assert!(matches!(
+            result,
+            Err(PepperSetError::InvalidConfig(message)) if message.contains("more than once")
+        ));
+    }
+
+    #[rstest]
+    #[case::missing_separator(format!("a{SECRET_A}"))]
+    #[case::empty_id(format!("={SECRET_A}"))]
+    #[case::invalid_id_character(format!("a.b={SECRET_A}"))]
+    #[case::too_long_id(format!("abcdefghi={SECRET_A}"))]
+    #[case::short_secret("a=short".to_string())]
+    fn test_new_rejects_malformed_entry(#[case] peppers: String) {
+        let result = PepperSet::new("", &peppers);
+
+        assert!(matches!(result, Err(PepperSetError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_load_reads_one_entry_per_line() {
+        let path = std::env::temp_dir().join(format!("peppers-{}.txt", std::process::id()));
+        fs::write(&path, format!("a={SECRET_A}\nb={SECRET_B}\n")).unwrap();
+
+        let peppers = PepperSet::load("b", &path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(peppers.unwrap().current(), Some(("b", SECRET_B.as_bytes())));
+    }
+
+    #[test]
+    fn test_load_reports_missing_file() {
+        let result = PepperSet::load("", "/nonexistent/peppers.txt");
+
+        assert!(matches!(result, Err(PepperSetError::ReadFailed { .. })));
+    }
+}
@@ -0,0 +1,94 @@
+use std::{fs, path::PathBuf};
+
+use domain::user::{BreachedPasswordChecker, RawPassword};
+use sha1::{Digest as _, Sha1};
+use thiserror::Error;
+
+type Sha1Digest = [u8; 20];
+
+#[derive(Debug, Error)]
+pub enum HibpFileError {
+    #[error("Failed to read breached password file '{path}': {source}")]
+    ReadFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid line {line} in breached password file '{path}'")]
+    InvalidLine { path: PathBuf, line: usize },
+}
+
+/// Have I Been Pwned の Pwned Passwords 形式のファイルから読み込んだ、漏洩済みパスワードの一覧
+///
+/// 各行は `SHA-1 ハッシュ(16進数40文字):出現回数` の形式 (出現回数は省略可)。
+/// 全件をメモリに展開するため、出現回数の多いものに絞り込んだファイルを使用することを推奨する。
+pub struct HibpBreachedPasswordList {
+    // 二分探索のためにソート済みで保持する
+    digests: Vec<Sha1Digest>,
+}
+
+impl HibpBreachedPasswordList {
+    /// ファイルを設定しない場合に使用する、空の一覧
+    pub fn empty() -> Self {
+        Self {
+            digests: Vec::new(),
+        }
+    }
+
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, HibpFileError> {
+        let path = path.into();
+        let content = fs::read_to_string(&path).map_err(|source| HibpFileError::ReadFailed {
+            path: path.clone(),
+            source,
+        })?;
+
+        let mut digests = content
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_number, line)| {
+                let hash = line.split_once(':').map_or(line, |(hash, _count)| hash);
+                parse_hex_digest(hash).ok_or_else(|| HibpFileError::InvalidLine {
+                    path: path.clone(),
+                    line: line_number,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        digests.sort_unstable();
+        digests.dedup();
+
+        tracing::info!(
+            path = %path.display(),
+            count = digests.len(),
+            "漏洩済みパスワードの一覧を読み込みました"
+        );
+
+        Ok(Self { digests })
+    }
+}
+
+impl BreachedPasswordChecker for HibpBreachedPasswordList {
+    fn is_breached(&self, password: &RawPassword) -> bool {
+        if self.digests.is_empty() {
+            return false;
+        }
+
+        let digest: Sha1Digest = Sha1::digest(password.as_bytes()).into();
+        self.digests.binary_search(&digest).is_ok()
+    }
+}
+
+fn parse_hex_digest(hex: &str) -> Option<Sha1Digest> {
+    if hex.len() != 40 || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut digest = [0u8; 20];
+    for (i, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(digest)
+}
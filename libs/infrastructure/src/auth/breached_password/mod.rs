@@ -0,0 +1 @@
+pub mod hibp_file;
@@ -0,0 +1 @@
+pub mod uuid_generator;
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::federated_identity::{
+        FederatedIdentityId, FederatedIdentityIdGenerationError, FederatedIdentityIdGenerator,
+        FederatedIdentityIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidFederatedIdentityIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidFederatedIdentityIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl FederatedIdentityIdGenerator for UuidFederatedIdentityIdGenerator {
+    fn generate(&self) -> Result<FederatedIdentityId, FederatedIdentityIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| FederatedIdentityIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidFederatedIdentityIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidFederatedIdentityIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl FederatedIdentityIdGeneratorFactory for UuidFederatedIdentityIdGeneratorFactory {
+    fn create_federated_identity_id_generator(&self) -> Arc<dyn FederatedIdentityIdGenerator> {
+        Arc::new(UuidFederatedIdentityIdGenerator::new(self.clock.clone()))
+    }
+}
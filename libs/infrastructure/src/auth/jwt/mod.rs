@@ -0,0 +1 @@
+pub mod pem_key_files;
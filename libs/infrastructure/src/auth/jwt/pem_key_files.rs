@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+use usecase::auth::jwt_key::{
+    JwtAlgorithm, JwtKeyError, JwtKeySet, JwtSigningKey, JwtVerificationKey,
+};
+
+/// ディスク上の PEM ファイルから JWT の鍵を読み込むための設定
+pub struct PemKeyFiles {
+    algorithm: JwtAlgorithm,
+    signing_key_id: String,
+    signing_key_path: PathBuf,
+    verification_keys: Vec<(String, PathBuf)>,
+}
+
+#[derive(Debug, Error)]
+pub enum PemKeyFilesError {
+    #[error("Invalid configuration for PemKeyFiles: {0}")]
+    InvalidConfig(String),
+
+    #[error("Failed to read key file '{path}': {source}")]
+    ReadFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    InvalidKey(#[from] JwtKeyError),
+}
+
+impl PemKeyFiles {
+    /// `verification_keys` は `kid=path` をカンマ区切りで並べた文字列
+    /// (例: `2026-02=keys/2026-02.pub.pem,2026-01=keys/2026-01.pub.pem`)
+    pub fn new(
+        algorithm: &str,
+        signing_key_id: String,
+        signing_key_path: String,
+        verification_keys: &str,
+    ) -> Result<Self, PemKeyFilesError> {
+        let algorithm = algorithm.parse::<JwtAlgorithm>().map_err(|_| {
+            PemKeyFilesError::InvalidConfig(format!(
+                "unsupported algorithm '{algorithm}' (expected 'RS256' or 'EdDSA')"
+            ))
+        })?;
+
+        if signing_key_id.is_empty() {
+            return Err(PemKeyFilesError::InvalidConfig(
+                "signing_key_id must not be empty".to_string(),
+            ));
+        }
+
+        let verification_keys = verification_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((kid, path)) if !kid.trim().is_empty() && !path.trim().is_empty() => {
+                    Ok((kid.trim().to_string(), PathBuf::from(path.trim())))
+                }
+                _ => Err(PemKeyFilesError::InvalidConfig(format!(
+                    "verification key entry must be in the form 'kid=path': '{entry}'"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if verification_keys.is_empty() {
+            return Err(PemKeyFilesError::InvalidConfig(
+                "at least one verification key must be configured".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            algorithm,
+            signing_key_id,
+            signing_key_path: PathBuf::from(signing_key_path),
+            verification_keys,
+        })
+    }
+
+    pub fn load(&self) -> Result<JwtKeySet, PemKeyFilesError> {
+        let signing_key = JwtSigningKey::from_pem(
+            self.signing_key_id.clone(),
+            self.algorithm,
+            &read_file(&self.signing_key_path)?,
+        )?;
+
+        let verification_keys = self
+            .verification_keys
+            .iter()
+            .map(|(kid, path)| {
+                Ok(JwtVerificationKey::from_pem(
+                    kid.clone(),
+                    self.algorithm,
+                    &read_file(path)?,
+                )?)
+            })
+            .collect::<Result<Vec<_>, PemKeyFilesError>>()?;
+
+        Ok(JwtKeySet::new(signing_key, verification_keys)?)
+    }
+}
+
+fn read_file(path: &PathBuf) -> Result<Vec<u8>, PemKeyFilesError> {
+    std::fs::read(path).map_err(|source| PemKeyFilesError::ReadFailed {
+        path: path.clone(),
+        source,
+    })
+}
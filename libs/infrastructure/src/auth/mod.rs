@@ -1 +1,12 @@
+pub mod api_key;
 pub mod argon2;
+pub mod breached_password;
+pub mod federated_identity;
+pub mod jwt;
+pub mod oidc;
+pub mod password_reset_token;
+pub mod refresh_token;
+pub mod service_account;
+pub mod token_epoch;
+pub mod token_revocation;
+pub mod webauthn_credential;
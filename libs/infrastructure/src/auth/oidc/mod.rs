@@ -0,0 +1 @@
+pub mod reqwest_client;
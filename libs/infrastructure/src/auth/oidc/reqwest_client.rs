@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use serde::de::DeserializeOwned;
+use usecase::auth::oidc_client::{
+    OidcClient, OidcClientError, OidcProviderMetadata, OidcTokenRequest, OidcTokenResponse,
+};
+
+// ID プロバイダーが応答しない場合にログインの処理を長時間待たせないためのタイムアウト
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// reqwest による ID プロバイダーとの HTTP 通信
+pub struct ReqwestOidcClient {
+    client: reqwest::Client,
+}
+
+impl ReqwestOidcClient {
+    pub fn new() -> Result<Self, OidcClientError> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            // 認可コードの交換先を別のホストに差し替えられないよう、リダイレクトには従わない
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| OidcClientError::Request(e.into()))?;
+
+        Ok(Self { client })
+    }
+
+    async fn read_json<T: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, OidcClientError> {
+        let status = response.status();
+        if !status.is_success() {
+            return Err(OidcClientError::Rejected {
+                status: status.as_u16(),
+            });
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| OidcClientError::InvalidResponse(e.into()))
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, OidcClientError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| OidcClientError::Request(e.into()))?;
+
+        Self::read_json(response).await
+    }
+}
+
+#[async_trait]
+impl OidcClient for ReqwestOidcClient {
+    async fn fetch_provider_metadata(
+        &self,
+        discovery_url: &str,
+    ) -> Result<OidcProviderMetadata, OidcClientError> {
+        self.get_json(discovery_url).await
+    }
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, OidcClientError> {
+        self.get_json(jwks_uri).await
+    }
+
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        request: OidcTokenRequest<'_>,
+    ) -> Result<OidcTokenResponse, OidcClientError> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", request.code),
+            ("redirect_uri", request.redirect_uri),
+            ("code_verifier", request.code_verifier),
+        ];
+
+        let response = self
+            .client
+            .post(token_endpoint)
+            .basic_auth(request.client_id, Some(request.client_secret))
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| OidcClientError::Request(e.into()))?;
+
+        Self::read_json(response).await
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::password_reset_token::{
+        PasswordResetTokenId, PasswordResetTokenIdGenerationError, PasswordResetTokenIdGenerator,
+        PasswordResetTokenIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidPasswordResetTokenIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidPasswordResetTokenIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl PasswordResetTokenIdGenerator for UuidPasswordResetTokenIdGenerator {
+    fn generate(&self) -> Result<PasswordResetTokenId, PasswordResetTokenIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| PasswordResetTokenIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidPasswordResetTokenIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidPasswordResetTokenIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl PasswordResetTokenIdGeneratorFactory for UuidPasswordResetTokenIdGeneratorFactory {
+    fn create_password_reset_token_id_generator(&self) -> Arc<dyn PasswordResetTokenIdGenerator> {
+        Arc::new(UuidPasswordResetTokenIdGenerator::new(self.clock.clone()))
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::refresh_token::{
+        RefreshTokenId, RefreshTokenIdGenerationError, RefreshTokenIdGenerator,
+        RefreshTokenIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidRefreshTokenIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidRefreshTokenIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl RefreshTokenIdGenerator for UuidRefreshTokenIdGenerator {
+    fn generate(&self) -> Result<RefreshTokenId, RefreshTokenIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| RefreshTokenIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidRefreshTokenIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidRefreshTokenIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl RefreshTokenIdGeneratorFactory for UuidRefreshTokenIdGeneratorFactory {
+    fn create_refresh_token_id_generator(&self) -> Arc<dyn RefreshTokenIdGenerator> {
+        Arc::new(UuidRefreshTokenIdGenerator::new(self.clock.clone()))
+    }
+}
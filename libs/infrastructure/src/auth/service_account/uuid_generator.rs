@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::service_account::{
+        ServiceAccountId, ServiceAccountIdGenerationError, ServiceAccountIdGenerator,
+        ServiceAccountIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidServiceAccountIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidServiceAccountIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl ServiceAccountIdGenerator for UuidServiceAccountIdGenerator {
+    fn generate(&self) -> Result<ServiceAccountId, ServiceAccountIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| ServiceAccountIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidServiceAccountIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidServiceAccountIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl ServiceAccountIdGeneratorFactory for UuidServiceAccountIdGeneratorFactory {
+    fn create_service_account_id_generator(&self) -> Arc<dyn ServiceAccountIdGenerator> {
+        Arc::new(UuidServiceAccountIdGenerator::new(self.clock.clone()))
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::{shared::service::clock::Clock, user::UserId};
+use usecase::auth::token_epoch_cache::{TokenEpochCache, TokenEpochCacheError};
+
+/// プロセス内のメモリにトークン世代番号を `ttl` の間だけ保持する実装
+///
+/// `ttl` が 0 の場合は何も保持せず、常にデータベースから取得させる
+pub struct InMemoryTokenEpochCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<UserId, (u32, DateTime<Utc>)>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemoryTokenEpochCache {
+    pub fn new(ttl: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl TokenEpochCache for InMemoryTokenEpochCache {
+    async fn get(&self, user_id: UserId) -> Result<Option<u32>, TokenEpochCacheError> {
+        let now = self.clock.now();
+        let entries = self.entries.lock().unwrap();
+
+        Ok(entries
+            .get(&user_id)
+            .filter(|(_, expires_at)| *expires_at > now)
+            .map(|(token_epoch, _)| *token_epoch))
+    }
+
+    async fn put(&self, user_id: UserId, token_epoch: u32) -> Result<(), TokenEpochCacheError> {
+        if self.ttl <= Duration::zero() {
+            return Ok(());
+        }
+
+        let now = self.clock.now();
+        let mut entries = self.entries.lock().unwrap();
+
+        // 書き込みのついでに期限切れのエントリを削除する
+        entries.retain(|_, (_, expires_at)| *expires_at > now);
+        entries.insert(user_id, (token_epoch, now + self.ttl));
+
+        Ok(())
+    }
+
+    async fn invalidate(&self, user_id: UserId) -> Result<(), TokenEpochCacheError> {
+        self.entries.lock().unwrap().remove(&user_id);
+
+        Ok(())
+    }
+}
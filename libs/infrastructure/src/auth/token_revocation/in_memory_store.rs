@@ -0,0 +1,175 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::shared::service::clock::Clock;
+use usecase::auth::token_revocation_store::{TokenRevocationStore, TokenRevocationStoreError};
+use uuid::Uuid;
+
+/// プロセス内のメモリに失効情報を保持する実装
+///
+/// 再起動で失効情報が失われ、複数インスタンス間でも共有されないため、開発・単一インスタンス向け
+pub struct InMemoryTokenRevocationStore {
+    entries: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemoryTokenRevocationStore {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for InMemoryTokenRevocationStore {
+    async fn revoke(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), TokenRevocationStoreError> {
+        self.revoke_if_absent(token_id, expires_at).await?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, token_id: Uuid) -> Result<bool, TokenRevocationStoreError> {
+        let now = self.clock.now();
+        let entries = self.entries.lock().unwrap();
+
+        Ok(entries
+            .get(&token_id)
+            .is_some_and(|expires_at| *expires_at > now))
+    }
+
+    async fn revoke_if_absent(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRevocationStoreError> {
+        let now = self.clock.now();
+        let mut entries = self.entries.lock().unwrap();
+
+        // 書き込みのついでに期限切れのエントリを削除する
+        entries.retain(|_, entry_expires_at| *entry_expires_at > now);
+
+        // ロックを保持したまま確認と追加を行う
+        if entries.contains_key(&token_id) {
+            return Ok(false);
+        }
+        entries.insert(token_id, expires_at);
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use futures_util::future::join_all;
+
+    use super::*;
+
+    /// テストの中で時刻を進められる時計
+    struct TestClock(Mutex<DateTime<Utc>>);
+
+    impl TestClock {
+        fn advance(&self, duration: Duration) {
+            *self.0.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for TestClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    fn store() -> (InMemoryTokenRevocationStore, Arc<TestClock>) {
+        let clock = Arc::new(TestClock(Mutex::new(
+            Utc.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap(),
+        )));
+        (InMemoryTokenRevocationStore::new(clock.clone()), clock)
+    }
+
+    #[actix_web::test]
+    async fn test_revoked_token_is_reported_as_revoked() {
+        let (store, clock) = store();
+        let token_id = Uuid::now_v7();
+
+        store
+            .revoke(token_id, clock.now() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        assert!(store.is_revoked(token_id).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_token_is_not_revoked() {
+        let (store, clock) = store();
+        store
+            .revoke(Uuid::now_v7(), clock.now() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        assert!(!store.is_revoked(Uuid::now_v7()).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_revoke_if_absent_succeeds_only_once() {
+        let (store, clock) = store();
+        let token_id = Uuid::now_v7();
+        let expires_at = clock.now() + Duration::minutes(15);
+
+        // 並行して使用されても、使用済みにできるのは 1 件のみ
+        let results = join_all((0..8).map(|_| store.revoke_if_absent(token_id, expires_at))).await;
+        let succeeded = results
+            .into_iter()
+            .filter(|result| *result.as_ref().unwrap());
+
+        assert_eq!(succeeded.count(), 1);
+        assert!(store.is_revoked(token_id).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_revoke_if_absent_rejects_revoked_token() {
+        let (store, clock) = store();
+        let token_id = Uuid::now_v7();
+        let expires_at = clock.now() + Duration::minutes(15);
+
+        store.revoke(token_id, expires_at).await.unwrap();
+
+        assert!(!store.revoke_if_absent(token_id, expires_at).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_expired_entries_are_pruned() {
+        let (store, clock) = store();
+        let expired = Uuid::now_v7();
+        let alive = Uuid::now_v7();
+
+        store
+            .revoke(expired, clock.now() + Duration::minutes(1))
+            .await
+            .unwrap();
+        clock.advance(Duration::minutes(2));
+
+        // 有効期限を過ぎたトークンは、削除される前でも失効扱いにしない
+        assert!(!store.is_revoked(expired).await.unwrap());
+
+        store
+            .revoke(alive, clock.now() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        let entries = store.entries.lock().unwrap();
+        assert!(!entries.contains_key(&expired));
+        assert!(entries.contains_key(&alive));
+    }
+}
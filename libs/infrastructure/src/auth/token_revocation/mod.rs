@@ -0,0 +1,12 @@
+pub mod in_memory_store;
+pub mod seaorm_store;
+
+/// アクセストークンの失効情報の保存先
+#[derive(Debug, Clone, Copy, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum TokenRevocationBackend {
+    /// プロセス内のメモリ (単一インスタンス・開発向け)
+    InMemory,
+    /// データベース (複数インスタンス構成向け)
+    Database,
+}
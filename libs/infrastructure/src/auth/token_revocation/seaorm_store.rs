@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::shared::service::clock::Clock;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, sea_query::OnConflict,
+};
+use usecase::auth::token_revocation_store::{TokenRevocationStore, TokenRevocationStoreError};
+use uuid::Uuid;
+
+use crate::persistence::seaorm::entities::revoked_access_token as revoked_access_token_entity;
+
+/// データベースに失効情報を保持する実装 (複数インスタンス構成向け)
+pub struct SeaOrmTokenRevocationStore {
+    db: DatabaseConnection,
+    clock: Arc<dyn Clock>,
+}
+
+impl SeaOrmTokenRevocationStore {
+    pub fn new(db: DatabaseConnection, clock: Arc<dyn Clock>) -> Self {
+        Self { db, clock }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for SeaOrmTokenRevocationStore {
+    async fn revoke(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), TokenRevocationStoreError> {
+        self.revoke_if_absent(token_id, expires_at).await?;
+
+        Ok(())
+    }
+
+    async fn is_revoked(&self, token_id: Uuid) -> Result<bool, TokenRevocationStoreError> {
+        let now = self.clock.now();
+
+        let model = revoked_access_token_entity::Entity::find_by_id(token_id)
+            .filter(revoked_access_token_entity::Column::ExpiresAt.gt(now))
+            .one(&self.db)
+            .await
+            .map_err(|e| TokenRevocationStoreError::Storage(e.into()))?;
+
+        Ok(model.is_some())
+    }
+
+    async fn revoke_if_absent(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRevocationStoreError> {
+        let now = self.clock.now();
+
+        // 書き込みのついでに期限切れのエントリを削除する
+        revoked_access_token_entity::Entity::delete_many()
+            .filter(revoked_access_token_entity::Column::ExpiresAt.lte(now))
+            .exec(&self.db)
+            .await
+            .map_err(|e| TokenRevocationStoreError::Storage(e.into()))?;
+
+        let active_model = revoked_access_token_entity::ActiveModel {
+            token_id: Set(token_id),
+            expires_at: Set(expires_at.into()),
+            revoked_at: Set(now.into()),
+        };
+
+        // 失効済みの場合は何もせず、挿入した行数で今回失効させたかどうかを判定する
+        let rows_affected = revoked_access_token_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(revoked_access_token_entity::Column::TokenId)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| TokenRevocationStoreError::Storage(e.into()))?;
+
+        Ok(rows_affected == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+    use futures_util::future::join_all;
+
+    use super::*;
+    use crate::persistence::seaorm::test_db;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn store_at(db: &DatabaseConnection, now: DateTime<Utc>) -> SeaOrmTokenRevocationStore {
+        SeaOrmTokenRevocationStore::new(db.clone(), Arc::new(FixedClock(now)))
+    }
+
+    async fn database() -> DatabaseConnection {
+        let db = test_db::connect().await;
+        test_db::create_table(&db, revoked_access_token_entity::Entity).await;
+        db
+    }
+
+    #[actix_web::test]
+    async fn test_revoked_token_is_reported_as_revoked() {
+        let db = database().await;
+        let store = store_at(&db, base_time());
+        let token_id = Uuid::now_v7();
+
+        store
+            .revoke(token_id, base_time() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        assert!(store.is_revoked(token_id).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_token_is_not_revoked() {
+        let db = database().await;
+        let store = store_at(&db, base_time());
+        store
+            .revoke(Uuid::now_v7(), base_time() + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        assert!(!store.is_revoked(Uuid::now_v7()).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_revoking_twice_is_not_an_error() {
+        let db = database().await;
+        let store = store_at(&db, base_time());
+        let token_id = Uuid::now_v7();
+        let expires_at = base_time() + Duration::minutes(15);
+
+        store.revoke(token_id, expires_at).await.unwrap();
+        store.revoke(token_id, expires_at).await.unwrap();
+
+        assert!(store.is_revoked(token_id).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_revoke_if_absent_succeeds_only_once() {
+        let db = database().await;
+        let store = store_at(&db, base_time());
+        let token_id = Uuid::now_v7();
+        let expires_at = base_time() + Duration::minutes(15);
+
+        // 並行して使用されても、使用済みにできるのは 1 件のみ
+        let results = join_all((0..8).map(|_| store.revoke_if_absent(token_id, expires_at))).await;
+        let succeeded = results
+            .into_iter()
+            .filter(|result| *result.as_ref().unwrap());
+
+        assert_eq!(succeeded.count(), 1);
+        assert!(store.is_revoked(token_id).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_revoke_if_absent_rejects_revoked_token() {
+        let db = database().await;
+        let store = store_at(&db, base_time());
+        let token_id = Uuid::now_v7();
+        let expires_at = base_time() + Duration::minutes(15);
+
+        store.revoke(token_id, expires_at).await.unwrap();
+
+        assert!(!store.revoke_if_absent(token_id, expires_at).await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_expired_entries_are_pruned() {
+        let db = database().await;
+        let expired = Uuid::now_v7();
+        let alive = Uuid::now_v7();
+
+        store_at(&db, base_time())
+            .revoke(expired, base_time() + Duration::minutes(1))
+            .await
+            .unwrap();
+
+        let later = base_time() + Duration::minutes(2);
+        let store = store_at(&db, later);
+
+        // 有効期限を過ぎたトークンは、削除される前でも失効扱いにしない
+        assert!(!store.is_revoked(expired).await.unwrap());
+
+        store
+            .revoke(alive, later + Duration::minutes(15))
+            .await
+            .unwrap();
+
+        let remaining: Vec<Uuid> = revoked_access_token_entity::Entity::find()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| model.token_id)
+            .collect();
+        assert_eq!(remaining, vec![alive]);
+    }
+}
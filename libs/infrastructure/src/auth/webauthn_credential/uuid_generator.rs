@@ -0,0 +1,49 @@
+use std::sync::{Arc, Mutex};
+
+use crate::shared::uuid::{calculate_v7_timestamp_parts, generate_uuid_v7_with_parts};
+use domain::{
+    auth::webauthn_credential::{
+        WebAuthnCredentialId, WebAuthnCredentialIdGenerationError, WebAuthnCredentialIdGenerator,
+        WebAuthnCredentialIdGeneratorFactory,
+    },
+    shared::service::clock::Clock,
+};
+use uuid::ContextV7;
+
+pub struct UuidWebAuthnCredentialIdGenerator {
+    clock: Arc<dyn Clock>,
+    context: Mutex<ContextV7>,
+}
+
+impl UuidWebAuthnCredentialIdGenerator {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            context: Mutex::new(ContextV7::new()),
+        }
+    }
+}
+
+impl WebAuthnCredentialIdGenerator for UuidWebAuthnCredentialIdGenerator {
+    fn generate(&self) -> Result<WebAuthnCredentialId, WebAuthnCredentialIdGenerationError> {
+        let (seconds, nanos) = calculate_v7_timestamp_parts(self.clock.now())
+            .map_err(|e| WebAuthnCredentialIdGenerationError::GenerationFailed(e.into()))?;
+        Ok(generate_uuid_v7_with_parts(&self.context, seconds, nanos).into())
+    }
+}
+
+pub struct UuidWebAuthnCredentialIdGeneratorFactory {
+    clock: Arc<dyn Clock>,
+}
+
+impl UuidWebAuthnCredentialIdGeneratorFactory {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl WebAuthnCredentialIdGeneratorFactory for UuidWebAuthnCredentialIdGeneratorFactory {
+    fn create_webauthn_credential_id_generator(&self) -> Arc<dyn WebAuthnCredentialIdGenerator> {
+        Arc::new(UuidWebAuthnCredentialIdGenerator::new(self.clock.clone()))
+    }
+}
@@ -8,25 +8,73 @@ pub mod user;
 
 use std::sync::Arc;
 
-use crate::auth::argon2::password_service::Argon2PasswordHasher;
+use crate::auth::api_key::uuid_generator::UuidApiKeyIdGeneratorFactory;
+use crate::auth::argon2::{
+    password_service::{Argon2Config, Argon2PasswordHasher},
+    pepper::PepperSet,
+};
+use crate::auth::federated_identity::uuid_generator::UuidFederatedIdentityIdGeneratorFactory;
+use crate::auth::password_reset_token::uuid_generator::UuidPasswordResetTokenIdGeneratorFactory;
+use crate::auth::refresh_token::uuid_generator::UuidRefreshTokenIdGeneratorFactory;
+use crate::auth::service_account::uuid_generator::UuidServiceAccountIdGeneratorFactory;
+use crate::auth::token_epoch::in_memory_cache::InMemoryTokenEpochCache;
+use crate::auth::token_revocation::TokenRevocationBackend;
+use crate::auth::token_revocation::in_memory_store::InMemoryTokenRevocationStore;
+use crate::auth::token_revocation::seaorm_store::SeaOrmTokenRevocationStore;
+use crate::auth::webauthn_credential::uuid_generator::UuidWebAuthnCredentialIdGeneratorFactory;
 use crate::outbox_event::outbox_event_id_generator::UuidOutboxEventIdGeneratorFactory;
 use crate::persistence::seaorm::transaction::SeaOrmTransactionManager;
 use crate::relay::next_attempt_calculator::backoff_next_attempt_calculator::{
     BackoffCalculatorConfig, BackoffNextAttemptCalculator,
 };
 use crate::shared::clock::SystemClock;
+use crate::shared::in_memory_rate_limiter::InMemoryRateLimiter;
 use crate::user::uuid_generator::UuidUserIdGeneratorFactory;
+use domain::auth::session::SessionLimit;
 use domain::transaction::TransactionManager;
-use domain::user::UserFactory;
+use domain::user::{
+    BreachedPasswordChecker, LoginLockoutConfig, PasswordPolicy, PasswordPolicyConfig, UserFactory,
+};
+use usecase::auth::api_key_config::ApiKeyConfig;
+use usecase::auth::api_key_interactor::ApiKeyInteractor;
+use usecase::auth::email_verification_config::EmailVerificationConfig;
+use usecase::auth::email_verification_token_interactor::EmailVerificationTokenInteractor;
 use usecase::auth::interactor::AuthInteractor;
+use usecase::auth::jwt_key::JwtKeySet;
+use usecase::auth::login_throttle_config::LoginThrottleConfig;
+use usecase::auth::magic_link_config::MagicLinkConfig;
+use usecase::auth::magic_link_token_interactor::MagicLinkTokenInteractor;
+use usecase::auth::mfa_challenge_token_interactor::MfaChallengeTokenInteractor;
+use usecase::auth::mfa_config::MfaConfig;
+use usecase::auth::oidc_client::OidcClient;
+use usecase::auth::oidc_config::OidcConfig;
+use usecase::auth::oidc_interactor::OidcInteractor;
+use usecase::auth::password_reset_config::PasswordResetConfig;
+use usecase::auth::password_reset_token_interactor::PasswordResetTokenInteractor;
 use usecase::auth::service::AuthService;
+use usecase::auth::token_config::TokenConfig;
 use usecase::auth::token_interactor::TokenInteractor;
+use usecase::auth::token_revocation_store::TokenRevocationStore;
 use usecase::auth::token_service::TokenService;
+use usecase::auth::totp_interactor::TotpInteractor;
+use usecase::auth::webauthn_config::WebAuthnConfig;
+use usecase::auth::webauthn_interactor::WebAuthnInteractor;
 use usecase::relay::event_mapper::{EventFactories, EventMapper};
 use usecase::relay::handler_factory_impl::user_created_factory::UserCreatedFactory;
 use usecase::relay::handler_factory_impl::user_deactivated_factory::UserDeactivatedFactory;
+use usecase::relay::handler_factory_impl::user_demoted_from_admin_factory::UserDemotedFromAdminFactory;
 use usecase::relay::handler_factory_impl::user_email_changed_factory::UserEmailChangedFactory;
+use usecase::relay::handler_factory_impl::user_email_verification_requested_factory::UserEmailVerificationRequestedFactory;
 use usecase::relay::handler_factory_impl::user_email_verified_factory::UserEmailVerifiedFactory;
+use usecase::relay::handler_factory_impl::user_impersonation_ended_factory::UserImpersonationEndedFactory;
+use usecase::relay::handler_factory_impl::user_impersonation_started_factory::UserImpersonationStartedFactory;
+use usecase::relay::handler_factory_impl::user_locked_out_factory::UserLockedOutFactory;
+use usecase::relay::handler_factory_impl::user_magic_link_requested_factory::UserMagicLinkRequestedFactory;
+use usecase::relay::handler_factory_impl::user_mfa_disabled_factory::UserMfaDisabledFactory;
+use usecase::relay::handler_factory_impl::user_mfa_enabled_factory::UserMfaEnabledFactory;
+use usecase::relay::handler_factory_impl::user_password_changed_factory::UserPasswordChangedFactory;
+use usecase::relay::handler_factory_impl::user_password_reset_factory::UserPasswordResetFactory;
+use usecase::relay::handler_factory_impl::user_password_reset_requested_factory::UserPasswordResetRequestedFactory;
 use usecase::relay::handler_factory_impl::user_promoted_to_admin_factory::UserPromotedToAdminFactory;
 use usecase::relay::handler_factory_impl::user_reactivated_factory::UserReactivatedFactory;
 use usecase::relay::handler_factory_impl::user_suspended_factory::UserSuspendedFactory;
@@ -34,17 +82,23 @@ use usecase::relay::handler_factory_impl::user_unlocked_factory::UserUnlockedFac
 use usecase::relay::handler_factory_impl::username_changed_factory::UsernameChangedFactory;
 use usecase::relay::interactor::RelayInteractor;
 use usecase::relay::service::OutboxRelayService;
+use usecase::service_account::interactor::ServiceAccountInteractor;
+use usecase::service_account::service::ServiceAccountService;
 use usecase::shared::email_service::EmailService;
 use usecase::user::interactor::UserInteractor;
 use usecase::user::service::UserService;
 
 pub struct RepoRegistry<TM: TransactionManager> {
     transaction_manager: Arc<TM>,
+    token_revocation_store: Arc<dyn TokenRevocationStore>,
 }
 
 impl RepoRegistry<SeaOrmTransactionManager> {
     /// SeaORM 用の具体的な実装で構築
-    pub fn new_seaorm(db: sea_orm::DatabaseConnection) -> Self {
+    pub fn new_seaorm(
+        db: sea_orm::DatabaseConnection,
+        token_revocation_backend: TokenRevocationBackend,
+    ) -> Self {
         let clock = Arc::new(SystemClock);
         let outbox_event_id_generator_factory =
             Arc::new(UuidOutboxEventIdGeneratorFactory::new(clock.clone()));
@@ -52,8 +106,17 @@ impl RepoRegistry<SeaOrmTransactionManager> {
             db.clone(),
             outbox_event_id_generator_factory,
         ));
+        let token_revocation_store: Arc<dyn TokenRevocationStore> = match token_revocation_backend {
+            TokenRevocationBackend::InMemory => {
+                Arc::new(InMemoryTokenRevocationStore::new(clock.clone()))
+            }
+            TokenRevocationBackend::Database => {
+                Arc::new(SeaOrmTokenRevocationStore::new(db.clone(), clock.clone()))
+            }
+        };
         Self {
             transaction_manager,
+            token_revocation_store,
         }
     }
 }
@@ -62,51 +125,226 @@ impl RepoRegistry<SeaOrmTransactionManager> {
 pub struct AppRegistry {
     pub auth_service: Arc<dyn AuthService>,
     pub user_service: Arc<dyn UserService>,
+    pub service_account_service: Arc<dyn ServiceAccountService>,
     pub token_service: Arc<dyn TokenService>,
+    pub token_revocation_store: Arc<dyn TokenRevocationStore>,
     pub outbox_relay_service: Arc<dyn OutboxRelayService>,
 }
 
 impl AppRegistry {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<TM: TransactionManager + 'static>(
         repos: RepoRegistry<TM>,
         email_service: Arc<dyn EmailService>,
-        jwt_secret: String,
+        jwt_key_set: JwtKeySet,
+        token_config: TokenConfig,
+        email_verification_config: EmailVerificationConfig,
+        password_reset_config: PasswordResetConfig,
+        magic_link_config: MagicLinkConfig,
+        argon2_config: Argon2Config,
+        password_peppers: PepperSet,
+        password_policy_config: PasswordPolicyConfig,
+        breached_password_checker: Arc<dyn BreachedPasswordChecker>,
+        login_lockout_config: LoginLockoutConfig,
+        login_throttle_config: LoginThrottleConfig,
+        session_limit: SessionLimit,
+        mfa_config: MfaConfig,
+        webauthn_config: WebAuthnConfig,
+        oidc_config: OidcConfig,
+        oidc_client: Arc<dyn OidcClient>,
+        api_key_config: ApiKeyConfig,
         backoff_calculator_config: BackoffCalculatorConfig,
     ) -> Self {
         let clock = Arc::new(SystemClock);
         let next_attempt_calculator =
             Arc::new(BackoffNextAttemptCalculator::new(backoff_calculator_config));
 
-        let password_hasher = Arc::new(Argon2PasswordHasher);
+        let password_hasher = Arc::new(Argon2PasswordHasher::new(argon2_config, password_peppers));
+
+        let password_policy = Arc::new(PasswordPolicy::new(
+            password_policy_config,
+            breached_password_checker,
+        ));
+
+        let jwt_key_set = Arc::new(jwt_key_set);
+
+        // アクセストークンの検証時に参照する、ユーザーの現在のトークン世代番号のキャッシュ
+        let token_epoch_cache = Arc::new(InMemoryTokenEpochCache::new(
+            token_config.token_epoch_cache_ttl(),
+            clock.clone(),
+        ));
 
-        let token_service = Arc::new(TokenInteractor::new(jwt_secret, clock.clone()));
+        let token_service = Arc::new(TokenInteractor::new(
+            jwt_key_set.clone(),
+            token_config,
+            clock.clone(),
+        ));
+
+        // 確認メールの再送回数の制限
+        let email_verification_resend_limiter = Arc::new(InMemoryRateLimiter::new(
+            email_verification_config.resend_max_attempts(),
+            email_verification_config.resend_window(),
+            clock.clone(),
+        ));
+
+        // 接続元 IP アドレス単位のログイン失敗回数の制限
+        let login_ip_rate_limiter = Arc::new(InMemoryRateLimiter::new(
+            login_throttle_config.ip_max_failed_attempts(),
+            login_throttle_config.ip_failure_window(),
+            clock.clone(),
+        ));
+
+        let login_lockout_config = Arc::new(login_lockout_config);
+
+        let mfa_config = Arc::new(mfa_config);
+
+        let totp_service = Arc::new(TotpInteractor::new(mfa_config.clone(), clock.clone()));
+
+        let mfa_challenge_token_service = Arc::new(MfaChallengeTokenInteractor::new(
+            jwt_key_set.clone(),
+            mfa_config,
+            clock.clone(),
+        ));
+
+        let webauthn_service = Arc::new(WebAuthnInteractor::new(
+            jwt_key_set.clone(),
+            Arc::new(webauthn_config),
+            clock.clone(),
+        ));
+
+        let oidc_service = Arc::new(OidcInteractor::new(
+            jwt_key_set.clone(),
+            Arc::new(oidc_config),
+            oidc_client,
+            clock.clone(),
+        ));
+
+        let api_key_service = Arc::new(ApiKeyInteractor::new());
+
+        let magic_link_token_service = Arc::new(MagicLinkTokenInteractor::new(
+            jwt_key_set.clone(),
+            Arc::new(magic_link_config),
+            clock.clone(),
+        ));
+
+        let email_verification_token_service = Arc::new(EmailVerificationTokenInteractor::new(
+            jwt_key_set,
+            Arc::new(email_verification_config),
+            clock.clone(),
+        ));
+
+        let password_reset_token_service = Arc::new(PasswordResetTokenInteractor::new(
+            Arc::new(password_reset_config),
+            clock.clone(),
+        ));
 
         let user_id_generator_factory = Arc::new(UuidUserIdGeneratorFactory::new(clock.clone()));
 
+        let refresh_token_id_generator_factory =
+            Arc::new(UuidRefreshTokenIdGeneratorFactory::new(clock.clone()));
+
+        let password_reset_token_id_generator_factory =
+            Arc::new(UuidPasswordResetTokenIdGeneratorFactory::new(clock.clone()));
+
+        let webauthn_credential_id_generator_factory =
+            Arc::new(UuidWebAuthnCredentialIdGeneratorFactory::new(clock.clone()));
+
+        let federated_identity_id_generator_factory =
+            Arc::new(UuidFederatedIdentityIdGeneratorFactory::new(clock.clone()));
+
+        let api_key_id_generator_factory =
+            Arc::new(UuidApiKeyIdGeneratorFactory::new(clock.clone()));
+
+        let service_account_id_generator_factory =
+            Arc::new(UuidServiceAccountIdGeneratorFactory::new(clock.clone()));
+
         let user_factory = Arc::new(UserFactory::new(clock.clone()));
 
         let auth_service = Arc::new(AuthInteractor::new(
             repos.transaction_manager.clone(),
-            password_hasher,
+            password_hasher.clone(),
+            password_policy.clone(),
+            login_lockout_config.clone(),
+            Arc::new(session_limit),
             token_service.clone(),
+            repos.token_revocation_store.clone(),
+            token_epoch_cache.clone(),
+            email_verification_token_service.clone(),
+            email_verification_resend_limiter,
+            login_ip_rate_limiter.clone(),
+            password_reset_token_service.clone(),
+            magic_link_token_service.clone(),
+            mfa_challenge_token_service,
+            totp_service.clone(),
+            webauthn_service.clone(),
+            oidc_service,
+            api_key_service.clone(),
             user_factory.clone(),
             user_id_generator_factory.clone(),
+            refresh_token_id_generator_factory,
+            password_reset_token_id_generator_factory,
+            federated_identity_id_generator_factory,
+            clock.clone(),
         ));
 
         let user_service = Arc::new(UserInteractor::new(
             repos.transaction_manager.clone(),
+            password_hasher,
+            password_policy,
+            login_lockout_config,
+            login_ip_rate_limiter,
+            totp_service,
+            webauthn_service,
+            webauthn_credential_id_generator_factory,
+            api_key_service,
+            Arc::new(api_key_config),
+            api_key_id_generator_factory,
+            token_service.clone(),
+            repos.token_revocation_store.clone(),
+            token_epoch_cache,
+            clock.clone(),
+        ));
+
+        let service_account_service = Arc::new(ServiceAccountInteractor::new(
+            repos.transaction_manager.clone(),
+            service_account_id_generator_factory,
             clock.clone(),
         ));
 
-        let user_created_factory = UserCreatedFactory::new(email_service.clone());
+        let user_created_factory = UserCreatedFactory::new(
+            email_service.clone(),
+            email_verification_token_service.clone(),
+        );
         let user_suspended_factory = UserSuspendedFactory::new(email_service.clone());
         let user_unlocked_factory = UserUnlockedFactory::new(email_service.clone());
         let user_deactivated_factory = UserDeactivatedFactory::new(email_service.clone());
         let user_reactivated_factory = UserReactivatedFactory::new(email_service.clone());
-        let user_promoted_to_admin_factory = UserPromotedToAdminFactory::new();
+        let user_promoted_to_admin_factory = UserPromotedToAdminFactory::new(email_service.clone());
+        let user_demoted_from_admin_factory =
+            UserDemotedFromAdminFactory::new(email_service.clone());
         let user_username_changed_factory = UsernameChangedFactory::new(email_service.clone());
-        let user_email_changed_factory = UserEmailChangedFactory::new(email_service.clone());
+        let user_email_changed_factory = UserEmailChangedFactory::new(
+            email_service.clone(),
+            email_verification_token_service.clone(),
+        );
         let user_email_verified_factory = UserEmailVerifiedFactory::new();
+        let user_email_verification_requested_factory = UserEmailVerificationRequestedFactory::new(
+            email_service.clone(),
+            email_verification_token_service,
+        );
+        let user_password_reset_requested_factory = UserPasswordResetRequestedFactory::new(
+            email_service.clone(),
+            password_reset_token_service,
+        );
+        let user_magic_link_requested_factory =
+            UserMagicLinkRequestedFactory::new(email_service.clone(), magic_link_token_service);
+        let user_password_reset_factory = UserPasswordResetFactory::new(email_service.clone());
+        let user_password_changed_factory = UserPasswordChangedFactory::new(email_service.clone());
+        let user_locked_out_factory = UserLockedOutFactory::new(email_service.clone());
+        let user_mfa_enabled_factory = UserMfaEnabledFactory::new(email_service.clone());
+        let user_mfa_disabled_factory = UserMfaDisabledFactory::new(email_service.clone());
+        let user_impersonation_started_factory = UserImpersonationStartedFactory::new();
+        let user_impersonation_ended_factory = UserImpersonationEndedFactory::new();
 
         let event_mapper = EventMapper::new(EventFactories {
             user_created: Box::new(user_created_factory),
@@ -115,9 +353,20 @@ impl AppRegistry {
             user_deactivated: Box::new(user_deactivated_factory),
             user_reactivated: Box::new(user_reactivated_factory),
             user_promoted_to_admin: Box::new(user_promoted_to_admin_factory),
+            user_demoted_from_admin: Box::new(user_demoted_from_admin_factory),
             user_username_changed: Box::new(user_username_changed_factory),
             user_email_changed: Box::new(user_email_changed_factory),
             user_email_verified: Box::new(user_email_verified_factory),
+            user_email_verification_requested: Box::new(user_email_verification_requested_factory),
+            user_password_reset_requested: Box::new(user_password_reset_requested_factory),
+            user_magic_link_requested: Box::new(user_magic_link_requested_factory),
+            user_password_reset: Box::new(user_password_reset_factory),
+            user_password_changed: Box::new(user_password_changed_factory),
+            user_locked_out: Box::new(user_locked_out_factory),
+            user_mfa_enabled: Box::new(user_mfa_enabled_factory),
+            user_mfa_disabled: Box::new(user_mfa_disabled_factory),
+            user_impersonation_started: Box::new(user_impersonation_started_factory),
+            user_impersonation_ended: Box::new(user_impersonation_ended_factory),
         });
 
         let outbox_relay_service = Arc::new(RelayInteractor::new(
@@ -130,7 +379,9 @@ impl AppRegistry {
         Self {
             auth_service,
             user_service,
+            service_account_service,
             token_service,
+            token_revocation_store: repos.token_revocation_store.clone(),
             outbox_relay_service,
         }
     }
@@ -2,5 +2,13 @@
 
 pub mod prelude;
 
+pub mod api_key;
+pub mod federated_identity;
 pub mod outbox;
+pub mod password_reset_token;
+pub mod refresh_token;
+pub mod revoked_access_token;
+pub mod service_account;
+pub mod session;
 pub mod user;
+pub mod webauthn_credential;
@@ -1,4 +1,12 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
+pub use super::api_key::Entity as ApiKey;
+pub use super::federated_identity::Entity as FederatedIdentity;
 pub use super::outbox::Entity as Outbox;
+pub use super::password_reset_token::Entity as PasswordResetToken;
+pub use super::refresh_token::Entity as RefreshToken;
+pub use super::revoked_access_token::Entity as RevokedAccessToken;
+pub use super::service_account::Entity as ServiceAccount;
+pub use super::session::Entity as Session;
 pub use super::user::Entity as User;
+pub use super::webauthn_credential::Entity as WebauthnCredential;
@@ -17,9 +17,68 @@ pub struct Model {
     pub updated_at: DateTimeWithTimeZone,
     pub role: String,
     pub status: String,
+    pub failed_login_attempts: i32,
+    pub first_failed_login_at: Option<DateTimeWithTimeZone>,
+    pub locked_until: Option<DateTimeWithTimeZone>,
+    pub mfa_status: String,
+    pub totp_secret: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub mfa_recovery_codes: Json,
+    pub totp_last_used_step: Option<i64>,
+    pub mfa_enabled_at: Option<DateTimeWithTimeZone>,
+    pub token_epoch: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::api_key::Entity")]
+    ApiKey,
+    #[sea_orm(has_many = "super::federated_identity::Entity")]
+    FederatedIdentity,
+    #[sea_orm(has_many = "super::password_reset_token::Entity")]
+    PasswordResetToken,
+    #[sea_orm(has_many = "super::refresh_token::Entity")]
+    RefreshToken,
+    #[sea_orm(has_many = "super::session::Entity")]
+    Session,
+    #[sea_orm(has_many = "super::webauthn_credential::Entity")]
+    WebauthnCredential,
+}
+
+impl Related<super::api_key::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ApiKey.def()
+    }
+}
+
+impl Related<super::federated_identity::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FederatedIdentity.def()
+    }
+}
+
+impl Related<super::password_reset_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PasswordResetToken.def()
+    }
+}
+
+impl Related<super::refresh_token::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RefreshToken.def()
+    }
+}
+
+impl Related<super::session::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Session.def()
+    }
+}
+
+impl Related<super::webauthn_credential::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebauthnCredential.def()
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
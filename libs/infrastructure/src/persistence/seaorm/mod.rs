@@ -2,4 +2,6 @@ pub mod connect;
 pub mod db_error_mapper_impl;
 pub mod entities;
 pub mod repository;
+#[cfg(test)]
+pub(crate) mod test_db;
 pub mod transaction;
@@ -0,0 +1,159 @@
+use std::str::FromStr as _;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, sea_query::OnConflict};
+
+use super::super::entities::api_key as api_key_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::api_key::{
+        ApiKey, ApiKeyId, ApiKeyPrefix, ApiKeyRepository, ApiKeyRepositoryError, ApiKeyScope,
+        ApiKeySecretHash,
+    },
+    user::UserId,
+};
+
+pub struct SeaOrmApiKeyRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmApiKeyRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(&self, model: api_key_entity::Model) -> Result<ApiKey, ApiKeyRepositoryError> {
+        let api_key_entity::Model {
+            id,
+            user_id,
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            expires_at,
+            created_at,
+            last_used_at,
+        } = model;
+
+        let scopes: Vec<String> = serde_json::from_value(scopes)
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+        let scopes = scopes
+            .iter()
+            .map(|scope| ApiKeyScope::from_str(scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ApiKey::reconstruct(
+            id.into(),
+            user_id.into(),
+            name,
+            ApiKeyPrefix::from_raw_str(&prefix),
+            ApiKeySecretHash::from_raw_str(&secret_hash),
+            scopes,
+            expires_at.into(),
+            created_at.into(),
+            last_used_at.map(Into::into),
+        ))
+    }
+}
+
+#[async_trait]
+impl<C, T> ApiKeyRepository for SeaOrmApiKeyRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_id(&self, id: ApiKeyId) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        let model = api_key_entity::Entity::find_by_id(id)
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_prefix(
+        &self,
+        prefix: &ApiKeyPrefix,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let model = api_key_entity::Entity::find()
+            .filter(api_key_entity::Column::Prefix.eq(prefix.as_str()))
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = api_key_entity::Entity::find()
+            .filter(api_key_entity::Column::UserId.eq(user_id))
+            .order_by_asc(api_key_entity::Column::CreatedAt)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    /// 保存（新規作成 or 使用時の更新）を行うメソッド
+    async fn save(&self, api_key: ApiKey) -> Result<ApiKey, ApiKeyRepositoryError> {
+        let scopes: Vec<&'static str> =
+            api_key.scopes().iter().map(|&scope| scope.into()).collect();
+
+        let active_model = api_key_entity::ActiveModel {
+            id: Set(api_key.id().into()),
+            user_id: Set(api_key.user_id().into()),
+            name: Set(api_key.name().to_string()),
+            prefix: Set(api_key.prefix().to_string()),
+            secret_hash: Set(api_key.secret_hash().to_string()),
+            scopes: Set(serde_json::json!(scopes)),
+            expires_at: Set(api_key.expires_at().into()),
+            created_at: Set(api_key.created_at().into()),
+            last_used_at: Set(api_key.last_used_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 発行後に変化し得るのは最終使用日時のみ
+        let saved_model = api_key_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(api_key_entity::Column::Id)
+                    .update_column(api_key_entity::Column::LastUsedAt)
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+
+        self.map_to_domain(saved_model)
+    }
+
+    async fn delete(&self, id: ApiKeyId) -> Result<(), ApiKeyRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        api_key_entity::Entity::delete_by_id(id)
+            .exec(self.conn.connect())
+            .await
+            .map_err(|e| ApiKeyRepositoryError::Persistence(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, sea_query::OnConflict};
+
+use super::super::entities::federated_identity as federated_identity_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::federated_identity::{
+        ExternalSubject, FederatedIdentity, FederatedIdentityRepository,
+        FederatedIdentityRepositoryError, IdentityProviderId,
+    },
+    user::UserId,
+};
+
+pub struct SeaOrmFederatedIdentityRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmFederatedIdentityRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(&self, model: federated_identity_entity::Model) -> FederatedIdentity {
+        let federated_identity_entity::Model {
+            id,
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at,
+            last_login_at,
+        } = model;
+
+        FederatedIdentity::reconstruct(
+            id.into(),
+            user_id.into(),
+            IdentityProviderId::from_raw_str(&provider),
+            ExternalSubject::from_raw_str(&subject),
+            email,
+            created_at.into(),
+            last_login_at.map(Into::into),
+        )
+    }
+}
+
+#[async_trait]
+impl<C, T> FederatedIdentityRepository for SeaOrmFederatedIdentityRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_provider_subject(
+        &self,
+        provider: &IdentityProviderId,
+        subject: &ExternalSubject,
+    ) -> Result<Option<FederatedIdentity>, FederatedIdentityRepositoryError> {
+        let model = federated_identity_entity::Entity::find()
+            .filter(federated_identity_entity::Column::Provider.eq(provider.as_str()))
+            .filter(federated_identity_entity::Column::Subject.eq(subject.as_str()))
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| FederatedIdentityRepositoryError::Persistence(e.into()))?;
+
+        Ok(model.map(|m| self.map_to_domain(m)))
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<FederatedIdentity>, FederatedIdentityRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = federated_identity_entity::Entity::find()
+            .filter(federated_identity_entity::Column::UserId.eq(user_id))
+            .order_by_asc(federated_identity_entity::Column::CreatedAt)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| FederatedIdentityRepositoryError::Persistence(e.into()))?;
+
+        Ok(models.into_iter().map(|m| self.map_to_domain(m)).collect())
+    }
+
+    /// 保存（新規作成 or ログイン時の更新）を行うメソッド
+    async fn save(
+        &self,
+        identity: FederatedIdentity,
+    ) -> Result<FederatedIdentity, FederatedIdentityRepositoryError> {
+        let active_model = federated_identity_entity::ActiveModel {
+            id: Set(identity.id().into()),
+            user_id: Set(identity.user_id().into()),
+            provider: Set(identity.provider().to_string()),
+            subject: Set(identity.subject().to_string()),
+            email: Set(identity.email().to_string()),
+            created_at: Set(identity.created_at().into()),
+            last_login_at: Set(identity.last_login_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 紐づけ後に変化し得るのはログイン時に更新するカラムのみ
+        let saved_model = federated_identity_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(federated_identity_entity::Column::Id)
+                    .update_column(federated_identity_entity::Column::LastLoginAt)
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| FederatedIdentityRepositoryError::Persistence(e.into()))?;
+
+        Ok(self.map_to_domain(saved_model))
+    }
+}
@@ -1,2 +1,9 @@
+pub mod api_key_repository;
+pub mod federated_identity_repository;
 pub mod outbox_repository;
+pub mod password_reset_token_repository;
+pub mod refresh_token_repository;
+pub mod service_account_repository;
+pub mod session_repository;
 pub mod user_repository;
+pub mod webauthn_credential_repository;
@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, Set, sea_query::OnConflict};
+
+use super::super::entities::password_reset_token as password_reset_token_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::password_reset_token::{
+        PasswordResetToken, PasswordResetTokenHash, PasswordResetTokenRepository,
+        PasswordResetTokenRepositoryError, PasswordResetTokenState, PasswordResetTokenStateRaw,
+    },
+    user::UserId,
+};
+
+pub struct SeaOrmPasswordResetTokenRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmPasswordResetTokenRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(
+        &self,
+        model: password_reset_token_entity::Model,
+    ) -> Result<PasswordResetToken, PasswordResetTokenRepositoryError> {
+        let password_reset_token_entity::Model {
+            id,
+            user_id,
+            token_hash,
+            status,
+            issued_at,
+            expires_at,
+            used_at,
+            revoked_at,
+        } = model;
+
+        let token = PasswordResetToken::reconstruct(
+            id.into(),
+            user_id.into(),
+            PasswordResetTokenHash::from_raw_str(&token_hash),
+            PasswordResetTokenStateRaw {
+                status,
+                used_at: used_at.map(Into::into),
+                revoked_at: revoked_at.map(Into::into),
+            },
+            issued_at.into(),
+            expires_at.into(),
+        )?;
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl<C, T> PasswordResetTokenRepository for SeaOrmPasswordResetTokenRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &PasswordResetTokenHash,
+    ) -> Result<Option<PasswordResetToken>, PasswordResetTokenRepositoryError> {
+        let model = password_reset_token_entity::Entity::find()
+            .filter(password_reset_token_entity::Column::TokenHash.eq(token_hash.as_ref()))
+            .lock_exclusive()
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| PasswordResetTokenRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<PasswordResetToken>, PasswordResetTokenRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = password_reset_token_entity::Entity::find()
+            .filter(password_reset_token_entity::Column::UserId.eq(user_id))
+            .filter(
+                password_reset_token_entity::Column::Status
+                    .eq(PasswordResetTokenState::Active.kind()),
+            )
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| PasswordResetTokenRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    /// 保存（新規作成 or 状態の更新）を行うメソッド
+    async fn save(
+        &self,
+        token: PasswordResetToken,
+    ) -> Result<PasswordResetToken, PasswordResetTokenRepositoryError> {
+        let state = token.state();
+
+        let active_model = password_reset_token_entity::ActiveModel {
+            id: Set(token.id().into()),
+            user_id: Set(token.user_id().into()),
+            token_hash: Set(token.token_hash().to_string()),
+            status: Set(state.kind().to_string()),
+            issued_at: Set(token.issued_at().into()),
+            expires_at: Set(token.expires_at().into()),
+            used_at: Set(state.used_at().map(Into::into)),
+            revoked_at: Set(state.revoked_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 発行後に変化し得るのは状態に関するカラムのみ
+        let saved_model = password_reset_token_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(password_reset_token_entity::Column::Id)
+                    .update_columns([
+                        password_reset_token_entity::Column::Status,
+                        password_reset_token_entity::Column::UsedAt,
+                        password_reset_token_entity::Column::RevokedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| PasswordResetTokenRepositoryError::Persistence(e.into()))?;
+
+        self.map_to_domain(saved_model)
+    }
+}
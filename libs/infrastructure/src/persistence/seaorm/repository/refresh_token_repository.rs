@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QuerySelect, Set, sea_query::OnConflict};
+
+use super::super::entities::refresh_token as refresh_token_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::refresh_token::{
+        RefreshToken, RefreshTokenFamilyId, RefreshTokenHash, RefreshTokenRepository,
+        RefreshTokenRepositoryError, RefreshTokenStateKind, RefreshTokenStateRaw,
+    },
+    user::UserId,
+};
+
+pub struct SeaOrmRefreshTokenRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmRefreshTokenRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(
+        &self,
+        model: refresh_token_entity::Model,
+    ) -> Result<RefreshToken, RefreshTokenRepositoryError> {
+        let refresh_token_entity::Model {
+            id,
+            family_id,
+            user_id,
+            token_epoch,
+            token_hash,
+            status,
+            issued_at,
+            expires_at,
+            rotated_at,
+            revoked_at,
+        } = model;
+
+        let token = RefreshToken::reconstruct(
+            id.into(),
+            family_id.into(),
+            user_id.into(),
+            token_epoch.try_into().unwrap_or_default(),
+            RefreshTokenHash::from_raw_str(&token_hash),
+            RefreshTokenStateRaw {
+                status,
+                rotated_at: rotated_at.map(Into::into),
+                revoked_at: revoked_at.map(Into::into),
+            },
+            issued_at.into(),
+            expires_at.into(),
+        )?;
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl<C, T> RefreshTokenRepository for SeaOrmRefreshTokenRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_token_hash_for_update(
+        &self,
+        token_hash: &RefreshTokenHash,
+    ) -> Result<Option<RefreshToken>, RefreshTokenRepositoryError> {
+        let model = refresh_token_entity::Entity::find()
+            .filter(refresh_token_entity::Column::TokenHash.eq(token_hash.as_ref()))
+            .lock_exclusive()
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| RefreshTokenRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<RefreshToken>, RefreshTokenRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = refresh_token_entity::Entity::find()
+            .filter(refresh_token_entity::Column::UserId.eq(user_id))
+            .filter(
+                refresh_token_entity::Column::Status.eq(RefreshTokenStateKind::Active.to_string()),
+            )
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| RefreshTokenRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    async fn find_by_family_id(
+        &self,
+        family_id: RefreshTokenFamilyId,
+    ) -> Result<Vec<RefreshToken>, RefreshTokenRepositoryError> {
+        let family_id: uuid::Uuid = family_id.into();
+
+        let models = refresh_token_entity::Entity::find()
+            .filter(refresh_token_entity::Column::FamilyId.eq(family_id))
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| RefreshTokenRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    /// 保存（新規作成 or 状態の更新）を行うメソッド
+    async fn save(&self, token: RefreshToken) -> Result<RefreshToken, RefreshTokenRepositoryError> {
+        let state = token.state();
+
+        let active_model = refresh_token_entity::ActiveModel {
+            id: Set(token.id().into()),
+            family_id: Set(token.family_id().into()),
+            user_id: Set(token.user_id().into()),
+            token_epoch: Set(token.token_epoch().try_into().unwrap_or(i32::MAX)),
+            token_hash: Set(token.token_hash().to_string()),
+            status: Set(state.kind().to_string()),
+            issued_at: Set(token.issued_at().into()),
+            expires_at: Set(token.expires_at().into()),
+            rotated_at: Set(state.rotated_at().map(Into::into)),
+            revoked_at: Set(state.revoked_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 発行後に変化し得るのは状態に関するカラムのみ
+        let saved_model = refresh_token_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(refresh_token_entity::Column::Id)
+                    .update_columns([
+                        refresh_token_entity::Column::Status,
+                        refresh_token_entity::Column::RotatedAt,
+                        refresh_token_entity::Column::RevokedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| RefreshTokenRepositoryError::Persistence(e.into()))?;
+
+        self.map_to_domain(saved_model)
+    }
+}
@@ -0,0 +1,133 @@
+use std::str::FromStr as _;
+
+use async_trait::async_trait;
+use sea_orm::{EntityTrait, QueryOrder, Set, sea_query::OnConflict};
+
+use super::super::entities::service_account as service_account_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::service_account::{
+        ServiceAccount, ServiceAccountId, ServiceAccountRepository, ServiceAccountRepositoryError,
+        ServiceAccountSecretHash,
+    },
+    user::UserRole,
+};
+
+pub struct SeaOrmServiceAccountRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmServiceAccountRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(
+        &self,
+        model: service_account_entity::Model,
+    ) -> Result<ServiceAccount, ServiceAccountRepositoryError> {
+        let service_account_entity::Model {
+            id,
+            name,
+            role,
+            secret_hash,
+            created_at,
+            last_authenticated_at,
+        } = model;
+
+        let role = UserRole::from_str(&role)
+            .map_err(|e| ServiceAccountRepositoryError::Persistence(e.into()))?;
+
+        Ok(ServiceAccount::reconstruct(
+            id.into(),
+            name,
+            role,
+            ServiceAccountSecretHash::from_raw_str(&secret_hash),
+            created_at.into(),
+            last_authenticated_at.map(Into::into),
+        ))
+    }
+}
+
+#[async_trait]
+impl<C, T> ServiceAccountRepository for SeaOrmServiceAccountRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_id(
+        &self,
+        id: ServiceAccountId,
+    ) -> Result<Option<ServiceAccount>, ServiceAccountRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        let model = service_account_entity::Entity::find_by_id(id)
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| ServiceAccountRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self) -> Result<Vec<ServiceAccount>, ServiceAccountRepositoryError> {
+        let models = service_account_entity::Entity::find()
+            .order_by_asc(service_account_entity::Column::CreatedAt)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| ServiceAccountRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    /// 保存（新規作成 or 認証時の更新）を行うメソッド
+    async fn save(
+        &self,
+        service_account: ServiceAccount,
+    ) -> Result<ServiceAccount, ServiceAccountRepositoryError> {
+        let active_model = service_account_entity::ActiveModel {
+            id: Set(service_account.id().into()),
+            name: Set(service_account.name().to_string()),
+            role: Set(service_account.role().to_string()),
+            secret_hash: Set(service_account.secret_hash().to_string()),
+            created_at: Set(service_account.created_at().into()),
+            last_authenticated_at: Set(service_account.last_authenticated_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 作成後に変化し得るのは最終認証日時のみ
+        let saved_model = service_account_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(service_account_entity::Column::Id)
+                    .update_column(service_account_entity::Column::LastAuthenticatedAt)
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| ServiceAccountRepositoryError::Persistence(e.into()))?;
+
+        self.map_to_domain(saved_model)
+    }
+
+    async fn delete(&self, id: ServiceAccountId) -> Result<(), ServiceAccountRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        service_account_entity::Entity::delete_by_id(id)
+            .exec(self.conn.connect())
+            .await
+            .map_err(|e| ServiceAccountRepositoryError::Persistence(e.into()))?;
+
+        Ok(())
+    }
+}
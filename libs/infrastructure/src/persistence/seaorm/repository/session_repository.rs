@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, sea_query::OnConflict};
+
+use super::super::entities::session as session_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::session::{DeviceLabel, Session, SessionId, SessionRepository, SessionRepositoryError},
+    user::UserId,
+};
+
+pub struct SeaOrmSessionRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmSessionRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(&self, model: session_entity::Model) -> Session {
+        let session_entity::Model {
+            id,
+            user_id,
+            device_label,
+            user_agent,
+            ip_address,
+            created_at,
+            last_seen_at,
+            expires_at,
+            revoked_at,
+        } = model;
+
+        Session::reconstruct(
+            id.into(),
+            user_id.into(),
+            DeviceLabel::from_raw_str(&device_label),
+            user_agent,
+            ip_address,
+            created_at.into(),
+            last_seen_at.into(),
+            expires_at.into(),
+            revoked_at.map(Into::into),
+        )
+    }
+}
+
+#[async_trait]
+impl<C, T> SessionRepository for SeaOrmSessionRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_id(&self, id: SessionId) -> Result<Option<Session>, SessionRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        let model = session_entity::Entity::find_by_id(id)
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| SessionRepositoryError::Persistence(e.into()))?;
+
+        Ok(model.map(|m| self.map_to_domain(m)))
+    }
+
+    async fn find_active_by_user_id(
+        &self,
+        user_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, SessionRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = session_entity::Entity::find()
+            .filter(session_entity::Column::UserId.eq(user_id))
+            .filter(session_entity::Column::RevokedAt.is_null())
+            .filter(session_entity::Column::ExpiresAt.gt(now))
+            .order_by_asc(session_entity::Column::CreatedAt)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| SessionRepositoryError::Persistence(e.into()))?;
+
+        Ok(models.into_iter().map(|m| self.map_to_domain(m)).collect())
+    }
+
+    /// 保存（新規作成 or 再発行・終了時の更新）を行うメソッド
+    async fn save(&self, session: Session) -> Result<Session, SessionRepositoryError> {
+        let active_model = session_entity::ActiveModel {
+            id: Set(session.id().into()),
+            user_id: Set(session.user_id().into()),
+            device_label: Set(session.device_label().to_string()),
+            user_agent: Set(session.user_agent().map(str::to_string)),
+            ip_address: Set(session.ip_address().map(str::to_string)),
+            created_at: Set(session.created_at().into()),
+            last_seen_at: Set(session.last_seen_at().into()),
+            expires_at: Set(session.expires_at().into()),
+            revoked_at: Set(session.revoked_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 開始後に変化し得るのは再発行・終了時に更新するカラムのみ
+        let saved_model = session_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(session_entity::Column::Id)
+                    .update_columns([
+                        session_entity::Column::LastSeenAt,
+                        session_entity::Column::ExpiresAt,
+                        session_entity::Column::RevokedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| SessionRepositoryError::Persistence(e.into()))?;
+
+        Ok(self.map_to_domain(saved_model))
+    }
+}
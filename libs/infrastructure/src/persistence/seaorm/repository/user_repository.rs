@@ -2,7 +2,12 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use migration::constants::UniqueConstraints;
-use sea_orm::{ColumnTrait, DbErr, EntityTrait, QueryFilter, Set, sea_query::OnConflict};
+use sea_orm::{
+    ColumnTrait, Condition, DbErr, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, Value,
+    sea_query::{LikeExpr, OnConflict},
+};
+use uuid::Uuid;
 
 use super::super::entities::user as user_entity;
 use crate::persistence::{
@@ -10,8 +15,9 @@ use crate::persistence::{
     seaorm::{connect::Connectable, transaction::EntityTracker},
 };
 use domain::user::{
-    HashedPassword, User, UserId, UserRepository, UserRepositoryError, UserStateRaw,
-    UserUniqueConstraintViolation,
+    HashedPassword, LoginFailures, MfaStateRaw, SortDirection, User, UserId, UserListFilter,
+    UserListPage, UserListQuery, UserListSortKey, UserReconstructionError, UserRepository,
+    UserRepositoryError, UserRole, UserStateRaw, UserUniqueConstraintViolation,
 };
 
 pub struct SeaOrmUserRepository<C, T>
@@ -44,14 +50,42 @@ impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmUserRepository<C, T>
             updated_at,
             role,
             status,
+            failed_login_attempts,
+            first_failed_login_at,
+            locked_until,
+            mfa_status,
+            totp_secret,
+            mfa_recovery_codes,
+            totp_last_used_step,
+            mfa_enabled_at,
+            token_epoch,
         } = model;
 
+        let login_failures = LoginFailures::reconstruct(
+            failed_login_attempts.try_into().unwrap_or_default(),
+            first_failed_login_at.map(Into::into),
+            locked_until.map(Into::into),
+        );
+
+        let recovery_codes: Vec<String> = serde_json::from_value(mfa_recovery_codes)
+            .map_err(|e| UserRepositoryError::Persistence(e.into()))?;
+        let mfa = MfaStateRaw {
+            status: mfa_status,
+            totp_secret,
+            recovery_codes,
+            last_used_step: totp_last_used_step,
+            enabled_at: mfa_enabled_at.map(Into::into),
+        };
+
         let user = User::reconstruct(
             id.into(),
             username,
-            HashedPassword::from_raw_str(&password_hash),
+            HashedPassword::new(&password_hash).map_err(UserReconstructionError::from)?,
             &role,
             UserStateRaw { status, email },
+            login_failures,
+            mfa,
+            token_epoch.try_into().unwrap_or_default(),
             created_at.into(),
             updated_at.into(),
         )?;
@@ -90,6 +124,69 @@ impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmUserRepository<C, T>
     }
 }
 
+/// 一覧の絞り込み条件を SQL の条件に変換する
+fn filter_condition(filter: &UserListFilter) -> Condition {
+    let UserListFilter {
+        state,
+        role,
+        created_from,
+        created_until,
+        email_prefix,
+        username_prefix,
+    } = filter;
+
+    Condition::all()
+        .add_option(state.map(|state| user_entity::Column::Status.eq(state.to_string())))
+        .add_option(role.map(|role| user_entity::Column::Role.eq(role.to_string())))
+        .add_option(created_from.map(|from| user_entity::Column::CreatedAt.gte(from)))
+        .add_option(created_until.map(|until| user_entity::Column::CreatedAt.lt(until)))
+        .add_option(
+            email_prefix
+                .as_deref()
+                .map(|prefix| user_entity::Column::Email.like(prefix_pattern(prefix))),
+        )
+        .add_option(
+            username_prefix
+                .as_deref()
+                .map(|prefix| user_entity::Column::Username.like(prefix_pattern(prefix))),
+        )
+}
+
+/// 前方一致の LIKE パターン。入力に含まれるワイルドカードは文字としてエスケープする
+fn prefix_pattern(prefix: &str) -> LikeExpr {
+    let escaped = prefix
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    LikeExpr::new(format!("{escaped}%")).escape('\\')
+}
+
+/// カーソルのユーザーより後ろの行に絞り込む条件
+///
+/// 並び替えの値が同じ行は ID で順序を決めるため、`(並び替えの値, ID)` の組で比較する
+fn after_cursor_condition(
+    column: user_entity::Column,
+    cursor_value: Value,
+    cursor_id: UserId,
+    direction: SortDirection,
+) -> Condition {
+    let (value_after, id_after) = match direction {
+        SortDirection::Asc => (
+            column.gt(cursor_value.clone()),
+            user_entity::Column::Id.gt(Uuid::from(cursor_id)),
+        ),
+        SortDirection::Desc => (
+            column.lt(cursor_value.clone()),
+            user_entity::Column::Id.lt(Uuid::from(cursor_id)),
+        ),
+    };
+
+    Condition::any()
+        .add(value_after)
+        .add(Condition::all().add(column.eq(cursor_value)).add(id_after))
+}
+
 trait StateStr {
     fn state_str(&self) -> &str;
 }
@@ -118,6 +215,19 @@ where
         }
     }
 
+    async fn find_by_id_for_update(&self, id: UserId) -> Result<Option<User>, UserRepositoryError> {
+        let model = user_entity::Entity::find_by_id(id)
+            .lock_exclusive()
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| UserRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, UserRepositoryError> {
         let model = user_entity::Entity::find()
             .filter(user_entity::Column::Email.eq(email))
@@ -148,6 +258,13 @@ where
     async fn save(&self, user: User) -> Result<User, UserRepositoryError> {
         let username = user.username();
         let email = user.email();
+        let login_failures = user.login_failures();
+        let mfa = user.mfa();
+        let recovery_codes: Vec<&str> = mfa
+            .recovery_codes()
+            .iter()
+            .map(|hash| hash.as_ref().as_str())
+            .collect();
 
         let active_model = user_entity::ActiveModel {
             id: Set(user.id().into()),
@@ -156,6 +273,20 @@ where
             password_hash: Set(user.password().to_string()),
             status: Set(user.state_str().to_string()),
             role: Set(user.role().to_string()),
+            failed_login_attempts: Set(login_failures
+                .failed_attempts()
+                .try_into()
+                .unwrap_or(i32::MAX)),
+            first_failed_login_at: Set(login_failures.first_failed_at().map(Into::into)),
+            locked_until: Set(login_failures.locked_until().map(Into::into)),
+            mfa_status: Set(mfa.kind().to_string()),
+            totp_secret: Set(mfa.totp_secret().map(|secret| secret.as_str().to_string())),
+            mfa_recovery_codes: Set(serde_json::json!(recovery_codes)),
+            totp_last_used_step: Set(mfa
+                .last_used_step()
+                .map(|step| step.try_into().unwrap_or(i64::MAX))),
+            mfa_enabled_at: Set(mfa.enabled_at().map(Into::into)),
+            token_epoch: Set(user.token_epoch().try_into().unwrap_or(i32::MAX)),
             created_at: Set(user.created_at().into()), // 新規作成時は引数の値、更新時は無視される
             updated_at: Set(user.updated_at().into()),
         };
@@ -170,6 +301,15 @@ where
                         user_entity::Column::PasswordHash,
                         user_entity::Column::Role,
                         user_entity::Column::Status,
+                        user_entity::Column::FailedLoginAttempts,
+                        user_entity::Column::FirstFailedLoginAt,
+                        user_entity::Column::LockedUntil,
+                        user_entity::Column::MfaStatus,
+                        user_entity::Column::TotpSecret,
+                        user_entity::Column::MfaRecoveryCodes,
+                        user_entity::Column::TotpLastUsedStep,
+                        user_entity::Column::MfaEnabledAt,
+                        user_entity::Column::TokenEpoch,
                         user_entity::Column::UpdatedAt, // 更新時は日時を更新
                     ])
                     .to_owned(),
@@ -183,8 +323,85 @@ where
         self.map_to_domain(saved_model)
     }
 
-    async fn find_all(&self) -> Result<Vec<User>, UserRepositoryError> {
+    async fn find_page(&self, query: &UserListQuery) -> Result<UserListPage, UserRepositoryError> {
+        let filtered = user_entity::Entity::find().filter(filter_condition(&query.filter));
+
+        // 総数はカーソルの位置によらず、絞り込み条件に一致するすべての行を数える
+        let total = filtered
+            .clone()
+            .count(self.conn.connect())
+            .await
+            .map_err(|e| UserRepositoryError::Persistence(e.into()))?;
+
+        let column = match query.sort.key {
+            UserListSortKey::CreatedAt => user_entity::Column::CreatedAt,
+            UserListSortKey::Username => user_entity::Column::Username,
+        };
+        let order = match query.sort.direction {
+            SortDirection::Asc => Order::Asc,
+            SortDirection::Desc => Order::Desc,
+        };
+
+        let mut select = filtered
+            .order_by(column, order.clone())
+            .order_by(user_entity::Column::Id, order);
+
+        if let Some(cursor) = query.cursor {
+            let cursor_model = user_entity::Entity::find_by_id(cursor)
+                .one(self.conn.connect())
+                .await
+                .map_err(|e| UserRepositoryError::Persistence(e.into()))?
+                .ok_or(UserRepositoryError::CursorNotFound { cursor })?;
+
+            let cursor_value: Value = match query.sort.key {
+                UserListSortKey::CreatedAt => cursor_model.created_at.into(),
+                UserListSortKey::Username => cursor_model.username.into(),
+            };
+
+            select = select.filter(after_cursor_condition(
+                column,
+                cursor_value,
+                cursor,
+                query.sort.direction,
+            ));
+        }
+
+        // 次のページがあるかを判定するため、1件多く取得する
+        let mut models = select
+            .limit(query.limit + 1)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| UserRepositoryError::Persistence(e.into()))?;
+
+        let has_next = models.len() as u64 > query.limit;
+        models.truncate(query.limit as usize);
+
+        let users = models
+            .into_iter()
+            .map(|m| self.map_to_domain(m))
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = if has_next {
+            users.last().map(User::id)
+        } else {
+            None
+        };
+
+        Ok(UserListPage {
+            users,
+            next_cursor,
+            total,
+        })
+    }
+
+    async fn find_by_role_for_update(
+        &self,
+        role: UserRole,
+    ) -> Result<Vec<User>, UserRepositoryError> {
         let models = user_entity::Entity::find()
+            .filter(user_entity::Column::Role.eq(role.to_string()))
+            // 同時に実行されたロールの変更同士がデッドロックしないよう、常に ID の順に行をロックする
+            .order_by_asc(user_entity::Column::Id)
+            .lock_exclusive()
             .all(self.conn.connect())
             .await
             .map_err(|e| UserRepositoryError::Persistence(e.into()))?;
@@ -192,3 +409,227 @@ where
         models.into_iter().map(|m| self.map_to_domain(m)).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone as _, Utc};
+    use domain::shared::outbox_event::OutboxEventIdGeneratorFactory as _;
+    use domain::user::{UserListSort, UserStateKind};
+    use sea_orm::{ActiveModelTrait as _, DatabaseConnection, DbBackend, QueryTrait as _};
+
+    use super::*;
+    use crate::{
+        outbox_event::outbox_event_id_generator::UuidOutboxEventIdGeneratorFactory,
+        persistence::seaorm::test_db, shared::clock::SystemClock,
+    };
+
+    const PASSWORD_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNo";
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    async fn database() -> DatabaseConnection {
+        let db = test_db::connect().await;
+        test_db::create_table(&db, user_entity::Entity).await;
+        db
+    }
+
+    fn repository(
+        db: &DatabaseConnection,
+    ) -> SeaOrmUserRepository<DatabaseConnection, DatabaseConnection> {
+        let id_generator = UuidOutboxEventIdGeneratorFactory::new(Arc::new(SystemClock))
+            .create_outbox_event_id_generator();
+        SeaOrmUserRepository::new(db.clone(), Arc::new(EntityTracker::new(id_generator)))
+    }
+
+    /// 指定したユーザー名・登録日時のユーザーを登録する
+    async fn insert_user(
+        db: &DatabaseConnection,
+        username: &str,
+        created_at: DateTime<Utc>,
+        role: &str,
+    ) -> Uuid {
+        let id = Uuid::now_v7();
+        user_entity::ActiveModel {
+            id: Set(id),
+            username: Set(username.to_string()),
+            email: Set(format!("{}@example.com", id.simple())),
+            password_hash: Set(PASSWORD_HASH.to_string()),
+            created_at: Set(created_at.into()),
+            updated_at: Set(created_at.into()),
+            role: Set(role.to_string()),
+            status: Set("active".to_string()),
+            failed_login_attempts: Set(0),
+            first_failed_login_at: Set(None),
+            locked_until: Set(None),
+            mfa_status: Set("disabled".to_string()),
+            totp_secret: Set(None),
+            mfa_recovery_codes: Set(serde_json::json!([])),
+            totp_last_used_step: Set(None),
+            mfa_enabled_at: Set(None),
+            token_epoch: Set(0),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+        id
+    }
+
+    fn query(filter: UserListFilter, sort: UserListSort, limit: u64) -> UserListQuery {
+        UserListQuery {
+            filter,
+            sort,
+            cursor: None,
+            limit,
+        }
+    }
+
+    fn usernames(page: &UserListPage) -> Vec<String> {
+        page.users
+            .iter()
+            .map(|user| user.username().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_prefix_pattern_escapes_wildcards() {
+        let sql = user_entity::Entity::find()
+            .filter(user_entity::Column::Username.like(prefix_pattern(r"50%_off\")))
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        // PostgreSQL の文字列リテラルでは `\` がさらにエスケープされる
+        assert!(
+            sql.ends_with(r#"LIKE E'50\\%\\_off\\\\%' ESCAPE E'\\'"#),
+            "{sql}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_prefix_filter_treats_wildcards_as_literals() {
+        let db = database().await;
+        let repo = repository(&db);
+        for username in ["a_b", "axb", "100%off", "1000", r"back\slash", "backxslash"] {
+            insert_user(&db, username, base_time(), "user").await;
+        }
+
+        for (prefix, expected) in [
+            ("a_", vec!["a_b"]),
+            ("100%", vec!["100%off"]),
+            (r"back\", vec![r"back\slash"]),
+        ] {
+            let filter = UserListFilter {
+                username_prefix: Some(prefix.to_string()),
+                ..Default::default()
+            };
+            let page = repo
+                .find_page(&query(filter, UserListSort::default(), 20))
+                .await
+                .unwrap();
+
+            assert_eq!(usernames(&page), expected, "prefix: {prefix}");
+            assert_eq!(page.total, 1);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_find_page_follows_cursor_across_pages() {
+        let db = database().await;
+        let repo = repository(&db);
+        // 登録日時が同じユーザーは ID の順に並ぶ
+        for (i, username) in ["carol", "alice", "dave", "bob", "erin"].iter().enumerate() {
+            let created_at = base_time() + Duration::minutes(i as i64 / 2);
+            insert_user(&db, username, created_at, "user").await;
+        }
+
+        for (sort, expected) in [
+            (
+                UserListSort::default(),
+                ["carol", "alice", "dave", "bob", "erin"],
+            ),
+            (
+                UserListSort {
+                    key: UserListSortKey::Username,
+                    direction: SortDirection::Desc,
+                },
+                ["erin", "dave", "carol", "bob", "alice"],
+            ),
+        ] {
+            let mut collected = Vec::new();
+            let mut query = query(UserListFilter::default(), sort, 2);
+            loop {
+                let page = repo.find_page(&query).await.unwrap();
+                assert_eq!(page.total, 5);
+                collected.extend(usernames(&page));
+
+                match page.next_cursor {
+                    Some(cursor) => query.cursor = Some(cursor),
+                    None => break,
+                }
+            }
+
+            assert_eq!(collected, expected);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_find_page_rejects_unknown_cursor() {
+        let db = database().await;
+        let repo = repository(&db);
+        insert_user(&db, "alice", base_time(), "user").await;
+
+        let unknown = UserId::from(Uuid::now_v7());
+        let mut query = query(UserListFilter::default(), UserListSort::default(), 20);
+        query.cursor = Some(unknown);
+
+        assert!(matches!(
+            repo.find_page(&query).await,
+            Err(UserRepositoryError::CursorNotFound { cursor }) if cursor == unknown
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_find_page_filters_by_role_state_and_created_at() {
+        let db = database().await;
+        let repo = repository(&db);
+        insert_user(&db, "early", base_time(), "admin").await;
+        insert_user(&db, "admin", base_time() + Duration::hours(1), "admin").await;
+        insert_user(&db, "member", base_time() + Duration::hours(1), "user").await;
+        insert_user(&db, "late", base_time() + Duration::hours(2), "admin").await;
+
+        // 下限は含み、上限は含まない
+        let filter = UserListFilter {
+            state: Some(UserStateKind::Active),
+            role: Some(UserRole::Admin),
+            created_from: Some(base_time() + Duration::hours(1)),
+            created_until: Some(base_time() + Duration::hours(2)),
+            ..Default::default()
+        };
+        let page = repo
+            .find_page(&query(filter, UserListSort::default(), 20))
+            .await
+            .unwrap();
+
+        assert_eq!(usernames(&page), vec!["admin"]);
+        assert_eq!(page.total, 1);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[actix_web::test]
+    async fn test_find_by_role_for_update_returns_rows_in_id_order() {
+        let db = database().await;
+        let repo = repository(&db);
+        let mut admin_ids = Vec::new();
+        for username in ["carol", "alice", "bob"] {
+            admin_ids.push(insert_user(&db, username, base_time(), "admin").await);
+        }
+        insert_user(&db, "member", base_time(), "user").await;
+
+        let admins = repo.find_by_role_for_update(UserRole::Admin).await.unwrap();
+
+        admin_ids.sort();
+        let ids: Vec<Uuid> = admins.iter().map(|user| user.id().into()).collect();
+        assert_eq!(ids, admin_ids);
+    }
+}
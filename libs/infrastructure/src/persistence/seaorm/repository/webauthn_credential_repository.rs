@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set, sea_query::OnConflict};
+
+use super::super::entities::webauthn_credential as webauthn_credential_entity;
+use crate::persistence::seaorm::connect::Connectable;
+use domain::{
+    auth::webauthn_credential::{
+        CredentialId, CredentialPublicKey, WebAuthnCredential, WebAuthnCredentialId,
+        WebAuthnCredentialRepository, WebAuthnCredentialRepositoryError,
+    },
+    user::UserId,
+};
+
+pub struct SeaOrmWebAuthnCredentialRepository<C, T>
+where
+    C: Connectable<T>,
+    T: sea_orm::ConnectionTrait,
+{
+    conn: C,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<C: Connectable<T>, T: sea_orm::ConnectionTrait> SeaOrmWebAuthnCredentialRepository<C, T> {
+    pub fn new(conn: C) -> Self {
+        Self {
+            conn,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// DBモデルからドメインモデルへの変換
+    fn map_to_domain(
+        &self,
+        model: webauthn_credential_entity::Model,
+    ) -> Result<WebAuthnCredential, WebAuthnCredentialRepositoryError> {
+        let webauthn_credential_entity::Model {
+            id,
+            user_id,
+            credential_id,
+            public_key,
+            sign_count,
+            name,
+            created_at,
+            last_used_at,
+        } = model;
+
+        let credential = WebAuthnCredential::reconstruct(
+            id.into(),
+            user_id.into(),
+            CredentialId::from_raw_str(&credential_id),
+            CredentialPublicKey::from_bytes(public_key),
+            sign_count,
+            name,
+            created_at.into(),
+            last_used_at.map(Into::into),
+        )?;
+        Ok(credential)
+    }
+}
+
+#[async_trait]
+impl<C, T> WebAuthnCredentialRepository for SeaOrmWebAuthnCredentialRepository<C, T>
+where
+    C: Connectable<T> + Send + Sync,
+    T: sea_orm::ConnectionTrait + Send + Sync,
+{
+    async fn find_by_id(
+        &self,
+        id: WebAuthnCredentialId,
+    ) -> Result<Option<WebAuthnCredential>, WebAuthnCredentialRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        let model = webauthn_credential_entity::Entity::find_by_id(id)
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| WebAuthnCredentialRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_credential_id(
+        &self,
+        credential_id: &CredentialId,
+    ) -> Result<Option<WebAuthnCredential>, WebAuthnCredentialRepositoryError> {
+        let model = webauthn_credential_entity::Entity::find()
+            .filter(webauthn_credential_entity::Column::CredentialId.eq(credential_id.as_str()))
+            .one(self.conn.connect())
+            .await
+            .map_err(|e| WebAuthnCredentialRepositoryError::Persistence(e.into()))?;
+
+        match model {
+            Some(m) => Ok(Some(self.map_to_domain(m)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<WebAuthnCredential>, WebAuthnCredentialRepositoryError> {
+        let user_id: uuid::Uuid = user_id.into();
+
+        let models = webauthn_credential_entity::Entity::find()
+            .filter(webauthn_credential_entity::Column::UserId.eq(user_id))
+            .order_by_asc(webauthn_credential_entity::Column::CreatedAt)
+            .all(self.conn.connect())
+            .await
+            .map_err(|e| WebAuthnCredentialRepositoryError::Persistence(e.into()))?;
+
+        models.into_iter().map(|m| self.map_to_domain(m)).collect()
+    }
+
+    /// 保存（新規作成 or 認証時の更新）を行うメソッド
+    async fn save(
+        &self,
+        credential: WebAuthnCredential,
+    ) -> Result<WebAuthnCredential, WebAuthnCredentialRepositoryError> {
+        let active_model = webauthn_credential_entity::ActiveModel {
+            id: Set(credential.id().into()),
+            user_id: Set(credential.user_id().into()),
+            credential_id: Set(credential.credential_id().to_string()),
+            public_key: Set(credential.public_key().as_bytes().to_vec()),
+            sign_count: Set(credential.sign_count().into()),
+            name: Set(credential.name().to_string()),
+            created_at: Set(credential.created_at().into()),
+            last_used_at: Set(credential.last_used_at().map(Into::into)),
+        };
+
+        // ON CONFLICT (id) DO UPDATE ...
+        // 登録後に変化し得るのは認証時に更新するカラムのみ
+        let saved_model = webauthn_credential_entity::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(webauthn_credential_entity::Column::Id)
+                    .update_columns([
+                        webauthn_credential_entity::Column::SignCount,
+                        webauthn_credential_entity::Column::LastUsedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(self.conn.connect())
+            .await
+            .map_err(|e| WebAuthnCredentialRepositoryError::Persistence(e.into()))?;
+
+        self.map_to_domain(saved_model)
+    }
+
+    async fn delete(
+        &self,
+        id: WebAuthnCredentialId,
+    ) -> Result<(), WebAuthnCredentialRepositoryError> {
+        let id: uuid::Uuid = id.into();
+
+        webauthn_credential_entity::Entity::delete_by_id(id)
+            .exec(self.conn.connect())
+            .await
+            .map_err(|e| WebAuthnCredentialRepositoryError::Persistence(e.into()))?;
+
+        Ok(())
+    }
+}
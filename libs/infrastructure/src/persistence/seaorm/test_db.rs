@@ -0,0 +1,21 @@
+//! テスト用のインメモリデータベース
+
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, EntityTrait, Schema};
+
+/// SQLite のインメモリデータベースに接続する
+///
+/// インメモリデータベースは接続ごとに別のデータベースになるため、接続プールを1本に制限する
+pub(crate) async fn connect() -> DatabaseConnection {
+    let mut options = ConnectOptions::new("sqlite::memory:");
+    options.max_connections(1).sqlx_logging(false);
+
+    Database::connect(options).await.unwrap()
+}
+
+/// エンティティの定義からテーブルを作成する
+pub(crate) async fn create_table<E: EntityTrait>(db: &DatabaseConnection, entity: E) {
+    let backend = db.get_database_backend();
+    let statement = Schema::new(backend).create_table_from_entity(entity);
+
+    db.execute(backend.build(&statement)).await.unwrap();
+}
@@ -1,10 +1,24 @@
 use std::fmt::Debug;
 use std::sync::{Arc, Mutex};
 
+use crate::persistence::seaorm::repository::api_key_repository::SeaOrmApiKeyRepository;
+use crate::persistence::seaorm::repository::federated_identity_repository::SeaOrmFederatedIdentityRepository;
 use crate::persistence::seaorm::repository::outbox_repository::SeaOrmPostgresOutboxRepository;
+use crate::persistence::seaorm::repository::password_reset_token_repository::SeaOrmPasswordResetTokenRepository;
+use crate::persistence::seaorm::repository::refresh_token_repository::SeaOrmRefreshTokenRepository;
+use crate::persistence::seaorm::repository::service_account_repository::SeaOrmServiceAccountRepository;
+use crate::persistence::seaorm::repository::session_repository::SeaOrmSessionRepository;
+use crate::persistence::seaorm::repository::webauthn_credential_repository::SeaOrmWebAuthnCredentialRepository;
 
 use super::repository::user_repository::SeaOrmUserRepository;
 use async_trait::async_trait;
+use domain::auth::api_key::ApiKeyRepository;
+use domain::auth::federated_identity::FederatedIdentityRepository;
+use domain::auth::password_reset_token::PasswordResetTokenRepository;
+use domain::auth::refresh_token::RefreshTokenRepository;
+use domain::auth::service_account::ServiceAccountRepository;
+use domain::auth::session::SessionRepository;
+use domain::auth::webauthn_credential::WebAuthnCredentialRepository;
 use domain::repository::RepositoryFactory;
 use domain::shared::outbox_event::{
     EntityWithEvents, OutboxEvent, OutboxEventIdGenerationError, OutboxEventIdGenerator,
@@ -67,6 +81,34 @@ impl<'a> RepositoryFactory<'a> for SeaOrmRepositoryFactory<'a> {
     fn outbox_repository(&self) -> Arc<dyn OutboxRepository + 'a> {
         Arc::new(SeaOrmPostgresOutboxRepository::new(self.txn))
     }
+
+    fn refresh_token_repository(&self) -> Arc<dyn RefreshTokenRepository + 'a> {
+        Arc::new(SeaOrmRefreshTokenRepository::new(self.txn))
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository + 'a> {
+        Arc::new(SeaOrmPasswordResetTokenRepository::new(self.txn))
+    }
+
+    fn webauthn_credential_repository(&self) -> Arc<dyn WebAuthnCredentialRepository + 'a> {
+        Arc::new(SeaOrmWebAuthnCredentialRepository::new(self.txn))
+    }
+
+    fn federated_identity_repository(&self) -> Arc<dyn FederatedIdentityRepository + 'a> {
+        Arc::new(SeaOrmFederatedIdentityRepository::new(self.txn))
+    }
+
+    fn api_key_repository(&self) -> Arc<dyn ApiKeyRepository + 'a> {
+        Arc::new(SeaOrmApiKeyRepository::new(self.txn))
+    }
+
+    fn service_account_repository(&self) -> Arc<dyn ServiceAccountRepository + 'a> {
+        Arc::new(SeaOrmServiceAccountRepository::new(self.txn))
+    }
+
+    fn session_repository(&self) -> Arc<dyn SessionRepository + 'a> {
+        Arc::new(SeaOrmSessionRepository::new(self.txn))
+    }
 }
 
 pub struct SeaOrmTransactionManager {
@@ -0,0 +1,67 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use domain::shared::service::clock::Clock;
+use usecase::shared::rate_limiter::{RateLimiter, RateLimiterError};
+
+/// 直近 `window` の間の試行日時をキーごとにメモリ上に保持するスライディングウィンドウ方式の実装
+///
+/// 再起動で記録が失われ、複数インスタンス間でも共有されないため、インスタンスごとの上限となる
+pub struct InMemoryRateLimiter {
+    max_attempts: u32,
+    window: Duration,
+    attempts: Mutex<HashMap<String, Vec<DateTime<Utc>>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl InMemoryRateLimiter {
+    pub fn new(max_attempts: u32, window: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            max_attempts,
+            window,
+            attempts: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for InMemoryRateLimiter {
+    async fn try_acquire(&self, key: &str) -> Result<bool, RateLimiterError> {
+        let now = self.clock.now();
+        let window_start = now - self.window;
+        let mut attempts = self.attempts.lock().unwrap();
+
+        // ウィンドウ外の記録を削除し、記録が残っていないキーも取り除く
+        attempts.retain(|_, timestamps| {
+            timestamps.retain(|attempted_at| *attempted_at > window_start);
+            !timestamps.is_empty()
+        });
+
+        let timestamps = attempts.entry(key.to_string()).or_default();
+        if timestamps.len() >= self.max_attempts as usize {
+            return Ok(false);
+        }
+
+        timestamps.push(now);
+        Ok(true)
+    }
+
+    async fn is_limited(&self, key: &str) -> Result<bool, RateLimiterError> {
+        let window_start = self.clock.now() - self.window;
+        let attempts = self.attempts.lock().unwrap();
+
+        let recent_attempts = attempts.get(key).map_or(0, |timestamps| {
+            timestamps
+                .iter()
+                .filter(|attempted_at| **attempted_at > window_start)
+                .count()
+        });
+
+        Ok(recent_attempts >= self.max_attempts as usize)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod clock;
+pub mod in_memory_rate_limiter;
 pub mod uuid;
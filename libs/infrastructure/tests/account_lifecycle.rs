@@ -0,0 +1,166 @@
+//! SQLite のインメモリデータベースを使って、パスワードを再確認する退会・利用再開の失敗回数の制限を検証する
+
+mod common;
+
+use common::{PASSWORD, TestApp};
+use usecase::{
+    shared::identity::{ActorKindData, ApiKeyScopeData, Identity, UserRoleData},
+    usecase_error::UseCaseError,
+    user::dto::{DeactivateUserInput, ReactivateUserInput},
+};
+use uuid::Uuid;
+
+const WRONG_PASSWORD: &str = "wrong password";
+const IP_ADDRESS: &str = "192.0.2.1";
+
+/// アカウント単位のロックアウトまでの失敗回数
+const MAX_FAILED_ATTEMPTS: u32 = 3;
+
+#[derive(Debug)]
+struct TestIdentity {
+    user_id: Uuid,
+}
+
+impl Identity for TestIdentity {
+    fn actor_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    fn actor_kind(&self) -> ActorKindData {
+        ActorKindData::User
+    }
+
+    fn actor_role(&self) -> UserRoleData {
+        UserRoleData::User
+    }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>> {
+        None
+    }
+
+    fn impersonator_id(&self) -> Option<Uuid> {
+        None
+    }
+}
+
+/// `ip_max_failed_attempts` は接続元 IP アドレス単位の失敗回数の上限
+async fn test_app(ip_max_failed_attempts: u32) -> TestApp {
+    TestApp::with_login_limits(MAX_FAILED_ATTEMPTS, ip_max_failed_attempts).await
+}
+
+async fn deactivate(app: &TestApp, user_id: Uuid, password: &str) -> Result<(), UseCaseError> {
+    app.registry
+        .user_service
+        .deactivate_user(
+            Box::new(TestIdentity { user_id }),
+            DeactivateUserInput {
+                target_id: user_id,
+                password: password.to_string(),
+                ip_address: Some(IP_ADDRESS.to_string()),
+            },
+        )
+        .await
+}
+
+async fn reactivate(app: &TestApp, password: &str) -> Result<(), UseCaseError> {
+    app.registry
+        .user_service
+        .reactivate_user(ReactivateUserInput {
+            email: common::EMAIL.to_string(),
+            password: password.to_string(),
+            ip_address: Some(IP_ADDRESS.to_string()),
+        })
+        .await
+}
+
+#[actix_web::test]
+async fn test_deactivate_user_with_correct_password() {
+    let app = test_app(10).await;
+    let user_id = app.insert_user("active").await;
+
+    deactivate(&app, user_id, PASSWORD).await.unwrap();
+
+    let user = app.find_user(user_id).await;
+    assert_eq!(user.status, "deactivated_by_user");
+    assert_eq!(user.token_epoch, 1);
+}
+
+#[actix_web::test]
+async fn test_deactivate_user_records_wrong_password_and_locks_out() {
+    let app = test_app(10).await;
+    let user_id = app.insert_user("active").await;
+
+    for _ in 1..MAX_FAILED_ATTEMPTS {
+        let result = deactivate(&app, user_id, WRONG_PASSWORD).await;
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+    }
+    assert_eq!(
+        app.find_user(user_id).await.failed_login_attempts,
+        MAX_FAILED_ATTEMPTS as i32 - 1
+    );
+
+    // 上限に達した時点でロックアウトする
+    let result = deactivate(&app, user_id, WRONG_PASSWORD).await;
+    assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+    assert!(app.find_user(user_id).await.locked_until.is_some());
+
+    // ロックアウト中は正しいパスワードでも退会できない
+    let result = deactivate(&app, user_id, PASSWORD).await;
+    assert!(matches!(result, Err(UseCaseError::TooManyRequests { .. })));
+    assert_eq!(app.find_user(user_id).await.status, "active");
+}
+
+#[actix_web::test]
+async fn test_deactivate_user_is_throttled_per_ip_address() {
+    let app = test_app(2).await;
+    let user_id = app.insert_user("active").await;
+
+    for _ in 0..2 {
+        let result = deactivate(&app, user_id, WRONG_PASSWORD).await;
+        assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+    }
+
+    // 接続元 IP アドレスの上限に達した後は、パスワードを確認せずに拒否する
+    let result = deactivate(&app, user_id, PASSWORD).await;
+    assert!(matches!(result, Err(UseCaseError::TooManyRequests { .. })));
+    assert_eq!(app.find_user(user_id).await.failed_login_attempts, 2);
+}
+
+#[actix_web::test]
+async fn test_reactivate_user_with_correct_password() {
+    let app = test_app(10).await;
+    let user_id = app.insert_user("deactivated_by_user").await;
+
+    reactivate(&app, PASSWORD).await.unwrap();
+
+    // メールアドレスの再確認が必要な状態で再開する
+    assert_eq!(
+        app.find_user(user_id).await.status,
+        "active_with_unverified_email"
+    );
+}
+
+#[actix_web::test]
+async fn test_reactivate_user_records_wrong_password_and_locks_out() {
+    let app = test_app(10).await;
+    let user_id = app.insert_user("deactivated_by_user").await;
+
+    for _ in 1..MAX_FAILED_ATTEMPTS {
+        let result = reactivate(&app, WRONG_PASSWORD).await;
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+    assert_eq!(
+        app.find_user(user_id).await.failed_login_attempts,
+        MAX_FAILED_ATTEMPTS as i32 - 1
+    );
+
+    // 上限に達した時点でロックアウトする
+    let result = reactivate(&app, WRONG_PASSWORD).await;
+    assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    assert!(app.find_user(user_id).await.locked_until.is_some());
+
+    // ロックアウト中は正しいパスワードでも再開できない
+    let result = reactivate(&app, PASSWORD).await;
+    assert!(matches!(result, Err(UseCaseError::TooManyRequests { .. })));
+    assert_eq!(app.find_user(user_id).await.status, "deactivated_by_user");
+}
@@ -0,0 +1,44 @@
+//! SQLite のインメモリデータベースを使って、同時に失敗したログインがすべてアカウントの失敗回数に記録されることを検証する
+
+mod common;
+
+use common::{MAX_FAILED_ATTEMPTS, PASSWORD, TestApp};
+use futures_util::future::join_all;
+use usecase::usecase_error::UseCaseError;
+
+const WRONG_PASSWORD: &str = "wrong password";
+
+#[actix_web::test]
+async fn test_concurrent_failed_logins_are_all_counted() {
+    let app = TestApp::new().await;
+    let user_id = app.insert_user("active").await;
+
+    // 接続元 IP アドレス単位の制限に掛からないよう、リクエストごとに異なる IP アドレスから失敗させる
+    let ip_addresses: Vec<_> = (1..MAX_FAILED_ATTEMPTS)
+        .map(|i| format!("192.0.2.{i}"))
+        .collect();
+    let results = join_all(
+        ip_addresses
+            .iter()
+            .map(|ip_address| app.login(WRONG_PASSWORD, Some(ip_address))),
+    )
+    .await;
+
+    assert!(
+        results
+            .iter()
+            .all(|result| matches!(result, Err(UseCaseError::Unauthorized)))
+    );
+    assert_eq!(
+        app.find_user(user_id).await.failed_login_attempts,
+        MAX_FAILED_ATTEMPTS as i32 - 1
+    );
+
+    // 上限に達した時点でロックアウトし、正しいパスワードでもログインできない
+    let result = app.login(WRONG_PASSWORD, Some("198.51.100.1")).await;
+    assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    assert!(app.find_user(user_id).await.locked_until.is_some());
+
+    let result = app.login(PASSWORD, Some("198.51.100.2")).await;
+    assert!(matches!(result, Err(UseCaseError::TooManyRequests { .. })));
+}
@@ -0,0 +1,74 @@
+//! SQLite のインメモリデータベースを使って、リセットトークンが一度しか使用できないことを検証する
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use common::TestApp;
+use futures_util::future::join_all;
+use infrastructure::{
+    persistence::seaorm::entities::password_reset_token, shared::clock::SystemClock,
+};
+use sea_orm::{ActiveModelTrait as _, ActiveValue::Set, EntityTrait as _};
+use usecase::auth::{
+    dto::ConfirmPasswordResetInput, password_reset_token_interactor::PasswordResetTokenInteractor,
+    password_reset_token_service::PasswordResetTokenService as _,
+};
+use uuid::Uuid;
+
+/// ユーザーにリセットトークンを発行し、リセットリンクに記載されるトークンの平文を返す
+async fn issue_reset_token(app: &TestApp, user_id: Uuid) -> String {
+    let service = PasswordResetTokenInteractor::new(
+        Arc::new(common::password_reset_config()),
+        Arc::new(SystemClock),
+    );
+    let token_id = Uuid::now_v7();
+    let issued = service.issue_reset_token(token_id.into()).unwrap();
+
+    password_reset_token::ActiveModel {
+        id: Set(token_id),
+        user_id: Set(user_id),
+        token_hash: Set(issued.token_hash.to_string()),
+        status: Set("active".to_string()),
+        issued_at: Set(Utc::now().into()),
+        expires_at: Set((Utc::now() + Duration::hours(1)).into()),
+        used_at: Set(None),
+        revoked_at: Set(None),
+    }
+    .insert(&app.db)
+    .await
+    .unwrap();
+
+    let link = service.reset_link(token_id.into());
+    let (_, token) = link.split_once("token=").unwrap();
+    token.to_string()
+}
+
+#[actix_web::test]
+async fn test_concurrent_confirms_with_same_token_succeed_only_once() {
+    let app = TestApp::new().await;
+    let user_id = app.insert_user("active").await;
+    let token = issue_reset_token(&app, user_id).await;
+
+    let new_passwords = ["first new password", "second new password"];
+    let results = join_all(new_passwords.iter().map(|new_password| {
+        app.registry
+            .auth_service
+            .confirm_password_reset(ConfirmPasswordResetInput {
+                token: token.clone(),
+                new_password: new_password.to_string(),
+            })
+    }))
+    .await;
+
+    assert_eq!(results.iter().filter(|result| result.is_ok()).count(), 1);
+
+    let token = password_reset_token::Entity::find()
+        .one(&app.db)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(token.status, "used");
+    assert_eq!(app.find_user(user_id).await.token_epoch, 1);
+}
@@ -0,0 +1,101 @@
+//! SQLite のインメモリデータベースを使って、トークン世代番号が進んだ後のリフレッシュトークンの扱いを検証する
+
+mod common;
+
+use common::{PASSWORD, TestApp};
+use infrastructure::persistence::seaorm::entities::{refresh_token, session, user};
+use sea_orm::{
+    ActiveModelTrait as _, ActiveValue::Set, ColumnTrait as _, EntityTrait as _, QueryFilter as _,
+};
+use usecase::{
+    auth::dto::{LoginOutput, RefreshTokenInput},
+    usecase_error::UseCaseError,
+};
+use uuid::Uuid;
+
+async fn login(app: &TestApp) -> String {
+    match app.login(PASSWORD, None).await.unwrap() {
+        LoginOutput::Authenticated(tokens) => tokens.refresh_token,
+        other => panic!("unexpected login output: {other:?}"),
+    }
+}
+
+async fn refresh(app: &TestApp, refresh_token: String) -> Result<String, UseCaseError> {
+    app.registry
+        .auth_service
+        .refresh(RefreshTokenInput { refresh_token })
+        .await
+        .map(|output| output.refresh_token)
+}
+
+/// パスワードの変更などによるトークン世代番号の更新を再現する
+async fn bump_token_epoch(app: &TestApp, user_id: Uuid) {
+    let mut model: user::ActiveModel = app.find_user(user_id).await.into();
+    model.token_epoch = Set(1);
+    model.update(&app.db).await.unwrap();
+}
+
+async fn refresh_tokens(app: &TestApp, user_id: Uuid) -> Vec<refresh_token::Model> {
+    refresh_token::Entity::find()
+        .filter(refresh_token::Column::UserId.eq(user_id))
+        .all(&app.db)
+        .await
+        .unwrap()
+}
+
+async fn sessions(app: &TestApp, user_id: Uuid) -> Vec<session::Model> {
+    session::Entity::find()
+        .filter(session::Column::UserId.eq(user_id))
+        .all(&app.db)
+        .await
+        .unwrap()
+}
+
+#[actix_web::test]
+async fn test_refresh_carries_token_epoch_over_rotation() {
+    let app = TestApp::new().await;
+    let user_id = app.insert_user("active").await;
+
+    let refresh_token = login(&app).await;
+    let rotated = refresh(&app, refresh_token).await.unwrap();
+    refresh(&app, rotated).await.unwrap();
+
+    let tokens = refresh_tokens(&app, user_id).await;
+    assert_eq!(tokens.len(), 3);
+    assert!(tokens.iter().all(|token| token.token_epoch == 0));
+}
+
+#[actix_web::test]
+async fn test_refresh_after_token_epoch_bump_ends_session() {
+    let app = TestApp::new().await;
+    let user_id = app.insert_user("active").await;
+
+    let refresh_token = login(&app).await;
+    bump_token_epoch(&app, user_id).await;
+
+    let result = refresh(&app, refresh_token).await;
+    assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+
+    // ファミリーのトークンはすべて失効し、後継トークンは発行されない
+    let tokens = refresh_tokens(&app, user_id).await;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].status, "revoked");
+
+    let sessions = sessions(&app, user_id).await;
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].revoked_at.is_some());
+}
+
+#[actix_web::test]
+async fn test_login_after_token_epoch_bump_issues_refreshable_token() {
+    let app = TestApp::new().await;
+    let user_id = app.insert_user("active").await;
+
+    bump_token_epoch(&app, user_id).await;
+
+    let refresh_token = login(&app).await;
+    assert!(refresh(&app, refresh_token).await.is_ok());
+
+    let tokens = refresh_tokens(&app, user_id).await;
+    assert!(tokens.iter().all(|token| token.token_epoch == 1));
+}
@@ -0,0 +1,32 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// API キーに関する設定
+pub struct ApiKeyConfig {
+    /// 発行時に指定できる有効期間の上限。キーは漏洩しても期限が来れば使用できなくなる
+    max_ttl: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum ApiKeyConfigError {
+    #[error("Invalid configuration for ApiKeyConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl ApiKeyConfig {
+    pub fn new(max_ttl_days: i64) -> Result<Self, ApiKeyConfigError> {
+        if max_ttl_days <= 0 {
+            return Err(ApiKeyConfigError::InvalidConfig(
+                "max_ttl_days must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            max_ttl: Duration::days(max_ttl_days),
+        })
+    }
+
+    pub fn max_ttl(&self) -> Duration {
+        self.max_ttl
+    }
+}
@@ -0,0 +1,112 @@
+use domain::auth::api_key::{ApiKeyPrefix, ApiKeySecretHash};
+use rand::RngCore as _;
+
+use crate::auth::{
+    api_key_service::{API_KEY_TOKEN_PREFIX, ApiKeyService, IssuedApiKey, ParsedApiKey},
+    opaque_token,
+};
+
+// 検索用の識別子に含めるランダムなバイト数 (16 進数で 16 文字)
+const API_KEY_PREFIX_BYTES: usize = 8;
+
+/// `pat_<識別子>_<秘密>` の形式の API キーを扱う
+///
+/// 識別子でキーを検索し、秘密の部分はリフレッシュトークンと同様にハッシュ値で照合する
+#[derive(Clone, Default)]
+pub struct ApiKeyInteractor;
+
+impl ApiKeyInteractor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ApiKeyService for ApiKeyInteractor {
+    fn issue_api_key(&self) -> IssuedApiKey {
+        let mut bytes = [0u8; API_KEY_PREFIX_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        let prefix: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+
+        let secret = opaque_token::generate();
+
+        IssuedApiKey {
+            api_key: format!("{API_KEY_TOKEN_PREFIX}{prefix}_{secret}"),
+            prefix: ApiKeyPrefix::from_raw_str(&prefix),
+            secret_hash: ApiKeySecretHash::from_raw_str(&opaque_token::hash(&secret)),
+        }
+    }
+
+    fn parse_api_key(&self, api_key: &str) -> Option<ParsedApiKey> {
+        // 識別子は 16 進数のみで構成されるため、最初の `_` までを識別子とする
+        // (秘密の部分は Base64URL のため `_` を含み得る)
+        let (prefix, secret) = api_key
+            .strip_prefix(API_KEY_TOKEN_PREFIX)?
+            .split_once('_')?;
+
+        if prefix.len() != API_KEY_PREFIX_BYTES * 2
+            || !prefix.chars().all(|c| c.is_ascii_hexdigit())
+            || secret.is_empty()
+        {
+            return None;
+        }
+
+        Some(ParsedApiKey {
+            prefix: ApiKeyPrefix::from_raw_str(prefix),
+            secret_hash: ApiKeySecretHash::from_raw_str(&opaque_token::hash(secret)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_parse_api_key() {
+        let interactor = ApiKeyInteractor::new();
+
+        let issued = interactor.issue_api_key();
+        assert!(issued.api_key.starts_with("pat_"));
+        assert!(!issued.api_key.contains(issued.secret_hash.as_ref()));
+
+        // 提示された平文から、保存した識別子とハッシュ値を再計算できる
+        let parsed = interactor.parse_api_key(&issued.api_key).unwrap();
+        assert_eq!(parsed.prefix, issued.prefix);
+        assert_eq!(parsed.secret_hash, issued.secret_hash);
+
+        // 発行のたびに異なるキーとなる
+        let other = interactor.issue_api_key();
+        assert_ne!(other.prefix, issued.prefix);
+        assert_ne!(other.secret_hash, issued.secret_hash);
+    }
+
+    #[test]
+    fn test_parse_api_key_rejects_malformed_keys() {
+        let interactor = ApiKeyInteractor::new();
+
+        for api_key in [
+            "",
+            "eyJhbGciOiJFZERTQSJ9.e30.sig",
+            "pat_",
+            "pat_0123456789abcdef",
+            "pat_0123456789abcdef_",
+            "pat_0123456789abcde_secret",
+            "pat_0123456789abcdeg_secret",
+            "sk_0123456789abcdef_secret",
+        ] {
+            assert!(
+                interactor.parse_api_key(api_key).is_none(),
+                "{api_key} should be rejected"
+            );
+        }
+
+        let parsed = interactor
+            .parse_api_key("pat_0123456789abcdef_se_cr-et")
+            .unwrap();
+        assert_eq!(parsed.prefix.as_str(), "0123456789abcdef");
+        assert_eq!(
+            parsed.secret_hash,
+            ApiKeySecretHash::from_raw_str(&opaque_token::hash("se_cr-et"))
+        );
+    }
+}
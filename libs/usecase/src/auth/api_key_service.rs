@@ -0,0 +1,28 @@
+use domain::auth::api_key::{ApiKeyPrefix, ApiKeySecretHash};
+
+/// API キーの平文の先頭に付ける文字列。アクセストークン (JWT) と区別するために使用する
+pub const API_KEY_TOKEN_PREFIX: &str = "pat_";
+
+#[derive(derive_more::Debug)]
+pub struct IssuedApiKey {
+    /// 利用者に一度だけ表示するキーの平文
+    #[debug(skip)]
+    pub api_key: String,
+    pub prefix: ApiKeyPrefix,
+    pub secret_hash: ApiKeySecretHash,
+}
+
+/// 提示された API キーを、検索用の識別子と秘密の部分のハッシュ値に分解したもの
+#[derive(Debug)]
+pub struct ParsedApiKey {
+    pub prefix: ApiKeyPrefix,
+    pub secret_hash: ApiKeySecretHash,
+}
+
+pub trait ApiKeyService: Send + Sync {
+    /// API キーを生成する。平文は発行時にのみ本人に渡し、サーバー側にはハッシュ値のみを保存する
+    fn issue_api_key(&self) -> IssuedApiKey;
+
+    /// `pat_<識別子>_<秘密>` の形式でないキーには `None` を返す
+    fn parse_api_key(&self, api_key: &str) -> Option<ParsedApiKey>;
+}
@@ -1,7 +1,18 @@
+use chrono::{DateTime, Utc};
 use domain::user::User;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::{
+    auth::{
+        oidc_service::IssuedOidcAuthorization,
+        webauthn_service::{
+            AssertionCredential, IssuedAuthenticationChallenge, PublicKeyCredentialRequestOptions,
+        },
+    },
+    shared::identity::{AccessScopeData, ApiKeyScopeData, UserRoleData},
+};
+
 #[derive(derive_more::Debug, Deserialize, Serialize)]
 pub struct SignupInput {
     pub username: String,
@@ -30,10 +41,258 @@ pub struct LoginInput {
     pub email: String,
     #[debug(skip)]
     pub password: String,
+    /// 接続元の IP アドレス。IP アドレス単位の失敗回数の制限と、セッションの記録に使用する
+    pub ip_address: Option<String>,
+    /// セッションの記録に使用する User-Agent
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum LoginOutput {
+    /// ログインが完了し、トークンを発行した
+    Authenticated(LoginTokens),
+    /// 2 段階認証が有効なため、認証コードの入力を求める
+    MfaRequired(MfaChallengeOutput),
+}
+
+#[derive(derive_more::Debug, Serialize)]
+pub struct LoginTokens {
+    #[debug(skip)]
+    pub access_token: String,
+    #[debug(skip)]
+    pub refresh_token: String,
+    pub expires_in: i64,
+    pub scope: AccessScopeData,
+}
+
+#[derive(derive_more::Debug, Serialize)]
+pub struct MfaChallengeOutput {
+    /// `/auth/login/mfa` で認証コードとともに提示するチャレンジトークン
+    #[debug(skip)]
+    pub mfa_token: String,
+    pub expires_in: i64,
+    /// 2 段階目の認証に使用できる方法
+    pub methods: Vec<MfaMethod>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MfaMethod {
+    /// 認証アプリの認証コード、またはリカバリーコード
+    Totp,
+    /// 登録済みのパスキー
+    Passkey,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct MfaLoginInput {
+    #[debug(skip)]
+    pub mfa_token: String,
+    /// 認証アプリに表示された 6 桁の認証コード、またはリカバリーコード
+    #[debug(skip)]
+    pub code: String,
+    /// 接続元の IP アドレス。IP アドレス単位の失敗回数の制限と、セッションの記録に使用する
+    pub ip_address: Option<String>,
+    /// セッションの記録に使用する User-Agent
+    pub user_agent: Option<String>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginPasskeyLoginInput {
+    /// 2 段階目の認証としてパスキーを使用する場合に、ログイン時に発行したチャレンジトークンを指定する。
+    /// 指定しない場合はパスキーのみでログインする
+    #[debug(skip)]
+    pub mfa_token: Option<String>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginPasskeyLoginOutput {
+    /// ログインの完了時に認証器の応答とともに提示するセレモニートークン
+    #[debug(skip)]
+    pub ceremony_token: String,
+    /// `navigator.credentials.get()` に渡すオプション
+    pub options: PublicKeyCredentialRequestOptions,
+}
+
+impl From<IssuedAuthenticationChallenge> for BeginPasskeyLoginOutput {
+    fn from(issued: IssuedAuthenticationChallenge) -> Self {
+        BeginPasskeyLoginOutput {
+            ceremony_token: issued.ceremony_token,
+            options: issued.options,
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct PasskeyLoginInput {
+    #[debug(skip)]
+    pub ceremony_token: String,
+    /// 2 段階目の認証の場合は、セレモニーの開始時と同じチャレンジトークンを指定する
+    #[debug(skip)]
+    pub mfa_token: Option<String>,
+    #[debug(skip)]
+    pub credential: AssertionCredential,
+    /// 接続元の IP アドレス。IP アドレス単位の失敗回数の制限と、セッションの記録に使用する
+    pub ip_address: Option<String>,
+    /// セッションの記録に使用する User-Agent
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct BeginOidcLoginInput {
+    /// 設定した ID プロバイダーの識別子
+    pub provider: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginOidcLoginOutput {
+    /// ユーザーをリダイレクトさせる ID プロバイダーの認可エンドポイントの URL
+    pub authorization_url: String,
+    /// コールバックの受信時に提示する状態トークン (クライアント側で保持する)
+    #[debug(skip)]
+    pub state_token: String,
+}
+
+impl From<IssuedOidcAuthorization> for BeginOidcLoginOutput {
+    fn from(issued: IssuedOidcAuthorization) -> Self {
+        BeginOidcLoginOutput {
+            authorization_url: issued.authorization_url,
+            state_token: issued.state_token,
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct OidcLoginInput {
+    pub provider: String,
+    /// ID プロバイダーからリダイレクトで受け取った認可コード
+    #[debug(skip)]
+    pub code: String,
+    /// ID プロバイダーからリダイレクトで受け取った `state`
+    #[debug(skip)]
+    pub state: String,
+    #[debug(skip)]
+    pub state_token: String,
+    /// セッションの記録に使用する接続元の IP アドレス
+    pub ip_address: Option<String>,
+    /// セッションの記録に使用する User-Agent
+    pub user_agent: Option<String>,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct RequestMagicLinkInput {
+    #[debug(skip)]
+    pub email: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct MagicLinkLoginInput {
+    /// ログインメールのリンクに含まれるログイントークン
+    #[debug(skip)]
+    pub token: String,
+    /// セッションの記録に使用する接続元の IP アドレス
+    pub ip_address: Option<String>,
+    /// セッションの記録に使用する User-Agent
+    pub user_agent: Option<String>,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct RefreshTokenInput {
+    #[debug(skip)]
+    pub refresh_token: String,
 }
 
 #[derive(derive_more::Debug, Serialize)]
-pub struct LoginOutput {
+pub struct RefreshTokenOutput {
+    #[debug(skip)]
+    pub access_token: String,
+    #[debug(skip)]
+    pub refresh_token: String,
+    pub expires_in: i64,
+    pub scope: AccessScopeData,
+}
+
+#[derive(Debug)]
+pub struct VerifyTokenEpochInput {
+    /// 署名を検証済みのアクセストークンに含まれるユーザーの ID
+    pub user_id: Uuid,
+    /// アクセストークンに含まれるトークン世代番号
+    pub token_epoch: u32,
+}
+
+/// クライアントクレデンシャルグラント (RFC 6749 §4.4) によるアクセストークンの要求
+#[derive(derive_more::Debug)]
+pub struct ClientCredentialsInput {
+    pub client_id: String,
+    #[debug(skip)]
+    pub client_secret: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ClientCredentialsOutput {
+    #[debug(skip)]
+    pub access_token: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug)]
+pub struct AuthenticateServiceAccountInput {
+    /// 検証済みのアクセストークンに含まれるサービスアカウントの ID
+    pub service_account_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct ServiceAccountPrincipal {
+    pub service_account_id: Uuid,
+    pub role: UserRoleData,
+}
+
+#[derive(Debug)]
+pub struct LogoutInput {
+    pub user_id: Uuid,
+    pub token_id: Uuid,
+    pub session_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    /// なりすましのトークンの場合に、なりすましを行っている管理者の ID
+    pub impersonator_id: Option<Uuid>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct AuthenticateApiKeyInput {
+    #[debug(skip)]
+    pub api_key: String,
+}
+
+/// API キーで認証されたリクエストの主体
+#[derive(Debug)]
+pub struct ApiKeyPrincipal {
+    pub user_id: Uuid,
+    pub user_role: UserRoleData,
+    pub api_key_id: Uuid,
+    pub scopes: Vec<ApiKeyScopeData>,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct VerifyEmailInput {
     #[debug(skip)]
     pub token: String,
 }
+
+#[derive(Debug)]
+pub struct ResendVerificationEmailInput {
+    pub user_id: Uuid,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct RequestPasswordResetInput {
+    #[debug(skip)]
+    pub email: String,
+}
+
+#[derive(derive_more::Debug, Deserialize)]
+pub struct ConfirmPasswordResetInput {
+    #[debug(skip)]
+    pub token: String,
+    #[debug(skip)]
+    pub new_password: String,
+}
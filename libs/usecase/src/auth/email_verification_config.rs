@@ -0,0 +1,79 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// メールアドレス確認トークンと確認メールの再送に関する設定
+pub struct EmailVerificationConfig {
+    /// 確認トークンの有効期間
+    token_ttl: Duration,
+
+    /// 確認メールに記載するリンクのベース URL。トークンはクエリパラメータ `token` として付与する
+    verification_url: String,
+
+    /// `resend_window` の間に確認メールを再送できる回数
+    resend_max_attempts: u32,
+
+    /// 再送回数を数える期間
+    resend_window: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum EmailVerificationConfigError {
+    #[error("Invalid configuration for EmailVerificationConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl EmailVerificationConfig {
+    pub fn new(
+        token_ttl_secs: i64,
+        verification_url: String,
+        resend_max_attempts: u32,
+        resend_window_secs: i64,
+    ) -> Result<Self, EmailVerificationConfigError> {
+        if token_ttl_secs <= 0 {
+            return Err(EmailVerificationConfigError::InvalidConfig(
+                "token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        if verification_url.is_empty() {
+            return Err(EmailVerificationConfigError::InvalidConfig(
+                "verification_url must not be empty".to_string(),
+            ));
+        }
+
+        if resend_max_attempts == 0 {
+            return Err(EmailVerificationConfigError::InvalidConfig(
+                "resend_max_attempts must be positive".to_string(),
+            ));
+        }
+
+        if resend_window_secs <= 0 {
+            return Err(EmailVerificationConfigError::InvalidConfig(
+                "resend_window_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            token_ttl: Duration::seconds(token_ttl_secs),
+            verification_url,
+            resend_max_attempts,
+            resend_window: Duration::seconds(resend_window_secs),
+        })
+    }
+
+    pub fn token_ttl(&self) -> Duration {
+        self.token_ttl
+    }
+
+    pub fn verification_url(&self) -> &str {
+        &self.verification_url
+    }
+
+    pub fn resend_max_attempts(&self) -> u32 {
+        self.resend_max_attempts
+    }
+
+    pub fn resend_window(&self) -> Duration {
+        self.resend_window
+    }
+}
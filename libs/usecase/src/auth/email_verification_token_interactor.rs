@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use domain::{
+    shared::service::clock::Clock,
+    user::{EmailTrait, EmailVerificationClaim, EmailVerificationError, UnverifiedEmail, UserId},
+};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode, errors::ErrorKind};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    auth::{
+        email_verification_config::EmailVerificationConfig,
+        email_verification_token_service::EmailVerificationTokenService, jwt_key::JwtKeySet,
+    },
+    usecase_error::UseCaseError,
+};
+
+// アクセストークンと取り違えられないよう、ヘッダーの typ で用途を区別する
+const EMAIL_VERIFICATION_TOKEN_TYPE: &str = "email-verification+jwt";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EmailVerificationTokenClaims {
+    sub: UserId,
+    email: String,
+    exp: i64,
+    iat: i64,
+}
+
+/// アクセストークンと同じ鍵で署名した JWT を確認トークンとして使用する
+///
+/// トークン自体に宛先のメールアドレスと有効期限を含めるため、サーバー側に状態を持たない
+#[derive(Clone)]
+pub struct EmailVerificationTokenInteractor {
+    key_set: Arc<JwtKeySet>,
+    config: Arc<EmailVerificationConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl EmailVerificationTokenInteractor {
+    pub fn new(
+        key_set: Arc<JwtKeySet>,
+        config: Arc<EmailVerificationConfig>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            key_set,
+            config,
+            clock,
+        }
+    }
+
+    fn issue_token(
+        &self,
+        user_id: UserId,
+        email: &UnverifiedEmail,
+    ) -> Result<String, UseCaseError> {
+        let now = self.clock.now();
+        let expiration = now
+            .checked_add_signed(self.config.token_ttl())
+            .expect("valid timestamp");
+
+        let claims = EmailVerificationTokenClaims {
+            sub: user_id,
+            email: email.as_str().to_string(),
+            exp: expiration.timestamp(),
+            iat: now.timestamp(),
+        };
+
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            typ: Some(EMAIL_VERIFICATION_TOKEN_TYPE.to_string()),
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))
+    }
+}
+
+impl EmailVerificationTokenService for EmailVerificationTokenInteractor {
+    fn issue_verification_link(
+        &self,
+        user_id: UserId,
+        email: &UnverifiedEmail,
+    ) -> Result<String, UseCaseError> {
+        let token = self.issue_token(user_id, email)?;
+
+        let url = self.config.verification_url();
+        let separator = if url.contains('?') { '&' } else { '?' };
+
+        Ok(format!("{url}{separator}token={token}"))
+    }
+
+    fn verify(&self, token: &str) -> Result<EmailVerificationClaim, EmailVerificationError> {
+        let header = decode_header(token).map_err(|_| EmailVerificationError::Tampered)?;
+
+        if header.typ.as_deref() != Some(EMAIL_VERIFICATION_TOKEN_TYPE) {
+            return Err(EmailVerificationError::Tampered);
+        }
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(EmailVerificationError::Tampered)?;
+
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        validation.validate_aud = false;
+
+        let token_data = decode::<EmailVerificationTokenClaims>(
+            token,
+            verification_key.decoding_key(),
+            &validation,
+        )
+        .map_err(|e| match e.kind() {
+            ErrorKind::ExpiredSignature => EmailVerificationError::Expired,
+            _ => EmailVerificationError::Tampered,
+        })?;
+
+        let EmailVerificationTokenClaims { sub, email, .. } = token_data.claims;
+
+        Ok(EmailVerificationClaim {
+            user_id: sub,
+            email,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    use crate::auth::jwt_key::{
+        JwtAlgorithm, JwtSigningKey, JwtVerificationKey,
+        test_keys::{KEY_A_PRIVATE, KEY_A_PUBLIC},
+    };
+
+    use super::*;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn interactor(now: DateTime<Utc>) -> EmailVerificationTokenInteractor {
+        let signing_key =
+            JwtSigningKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PRIVATE.as_bytes()).unwrap();
+        let verification_key =
+            JwtVerificationKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PUBLIC.as_bytes())
+                .unwrap();
+        let key_set = JwtKeySet::new(signing_key, vec![verification_key]).unwrap();
+        let config = EmailVerificationConfig::new(
+            3600,
+            "https://app.example.com/verify-email".to_string(),
+            3,
+            3600,
+        )
+        .unwrap();
+
+        EmailVerificationTokenInteractor::new(
+            Arc::new(key_set),
+            Arc::new(config),
+            Arc::new(FixedClock(now)),
+        )
+    }
+
+    fn email() -> UnverifiedEmail {
+        UnverifiedEmail::new("user@example.com").unwrap()
+    }
+
+    fn token_from_link(link: &str) -> &str {
+        link.strip_prefix("https://app.example.com/verify-email?token=")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_issued_token() {
+        let interactor = interactor(Utc::now());
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let link = interactor
+            .issue_verification_link(user_id, &email())
+            .unwrap();
+        let claim = interactor.verify(token_from_link(&link)).unwrap();
+
+        assert_eq!(
+            claim,
+            EmailVerificationClaim {
+                user_id,
+                email: "user@example.com".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_expired_token() {
+        let issued_at = Utc::now() - Duration::hours(2);
+        let link = interactor(issued_at)
+            .issue_verification_link(Uuid::now_v7().into(), &email())
+            .unwrap();
+
+        let result = interactor(Utc::now()).verify(token_from_link(&link));
+
+        assert_eq!(result, Err(EmailVerificationError::Expired));
+    }
+
+    #[test]
+    fn test_verify_tampered_token() {
+        let interactor = interactor(Utc::now());
+        let link = interactor
+            .issue_verification_link(Uuid::now_v7().into(), &email())
+            .unwrap();
+        let token = token_from_link(&link);
+
+        // ペイロードを別のトークンのものに差し替える
+        let other_link = interactor
+            .issue_verification_link(
+                Uuid::now_v7().into(),
+                &UnverifiedEmail::new("attacker@example.com").unwrap(),
+            )
+            .unwrap();
+        let other_payload = token_from_link(&other_link).split('.').nth(1).unwrap();
+        let parts: Vec<&str> = token.split('.').collect();
+        let tampered = format!("{}.{}.{}", parts[0], other_payload, parts[2]);
+
+        assert_eq!(
+            interactor.verify(&tampered),
+            Err(EmailVerificationError::Tampered)
+        );
+        assert_eq!(
+            interactor.verify("not-a-token"),
+            Err(EmailVerificationError::Tampered)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use domain::user::{EmailVerificationClaim, EmailVerificationError, UnverifiedEmail, UserId};
+
+use crate::usecase_error::UseCaseError;
+
+pub trait EmailVerificationTokenService: Send + Sync {
+    /// 確認トークンを発行し、確認メールに記載するリンクを返す
+    fn issue_verification_link(
+        &self,
+        user_id: UserId,
+        email: &UnverifiedEmail,
+    ) -> Result<String, UseCaseError>;
+
+    /// 確認トークンの署名と有効期限を検証し、トークンの内容を返す
+    fn verify(&self, token: &str) -> Result<EmailVerificationClaim, EmailVerificationError>;
+}
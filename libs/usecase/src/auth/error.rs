@@ -1,6 +1,31 @@
-use domain::user::PasswordHashingError;
+use domain::{
+    auth::api_key::{ApiKeyIdGenerationError, ApiKeyIssueError, ApiKeyRepositoryError},
+    auth::federated_identity::{
+        FederatedIdentityIdGenerationError, FederatedIdentityRepositoryError,
+    },
+    auth::password_reset_token::{
+        PasswordResetTokenConsumptionError, PasswordResetTokenIdGenerationError,
+        PasswordResetTokenRepositoryError,
+    },
+    auth::refresh_token::{
+        RefreshTokenIdGenerationError, RefreshTokenRepositoryError, RefreshTokenRotationError,
+    },
+    auth::service_account::{ServiceAccountIdGenerationError, ServiceAccountRepositoryError},
+    auth::session::SessionRepositoryError,
+    auth::webauthn_credential::{
+        WebAuthnCredentialIdGenerationError, WebAuthnCredentialRepositoryError,
+    },
+    user::PasswordHashingError,
+};
 
-use crate::usecase_error::UseCaseError;
+use crate::{
+    auth::{
+        oidc_client::OidcClientError, token_epoch_cache::TokenEpochCacheError,
+        token_revocation_store::TokenRevocationStoreError,
+    },
+    shared::rate_limiter::RateLimiterError,
+    usecase_error::{UseCaseError, ValidationError},
+};
 
 impl From<PasswordHashingError> for UseCaseError {
     fn from(error: PasswordHashingError) -> Self {
@@ -9,3 +34,184 @@ impl From<PasswordHashingError> for UseCaseError {
         }
     }
 }
+
+impl From<RefreshTokenRepositoryError> for UseCaseError {
+    fn from(error: RefreshTokenRepositoryError) -> Self {
+        match error {
+            RefreshTokenRepositoryError::ReconstructionError(reconstruction_error) => {
+                UseCaseError::Internal(reconstruction_error.into())
+            }
+            RefreshTokenRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            RefreshTokenRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<RefreshTokenIdGenerationError> for UseCaseError {
+    fn from(error: RefreshTokenIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<RefreshTokenRotationError> for UseCaseError {
+    fn from(_: RefreshTokenRotationError) -> Self {
+        UseCaseError::Unauthorized
+    }
+}
+
+impl From<PasswordResetTokenRepositoryError> for UseCaseError {
+    fn from(error: PasswordResetTokenRepositoryError) -> Self {
+        match error {
+            PasswordResetTokenRepositoryError::ReconstructionError(reconstruction_error) => {
+                UseCaseError::Internal(reconstruction_error.into())
+            }
+            PasswordResetTokenRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            PasswordResetTokenRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<PasswordResetTokenIdGenerationError> for UseCaseError {
+    fn from(error: PasswordResetTokenIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<PasswordResetTokenConsumptionError> for UseCaseError {
+    fn from(error: PasswordResetTokenConsumptionError) -> Self {
+        let message = match error {
+            PasswordResetTokenConsumptionError::Expired => {
+                "リセットリンクの有効期限が切れています。再度パスワードリセットを申請してください"
+            }
+            PasswordResetTokenConsumptionError::AlreadyUsed
+            | PasswordResetTokenConsumptionError::Revoked => "リセットリンクは無効です",
+        };
+
+        UseCaseError::InvalidInput(vec![ValidationError::new("token", message)].into())
+    }
+}
+
+impl From<WebAuthnCredentialRepositoryError> for UseCaseError {
+    fn from(error: WebAuthnCredentialRepositoryError) -> Self {
+        match error {
+            WebAuthnCredentialRepositoryError::ReconstructionError(reconstruction_error) => {
+                UseCaseError::Internal(reconstruction_error.into())
+            }
+            WebAuthnCredentialRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            WebAuthnCredentialRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<WebAuthnCredentialIdGenerationError> for UseCaseError {
+    fn from(error: WebAuthnCredentialIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<FederatedIdentityRepositoryError> for UseCaseError {
+    fn from(error: FederatedIdentityRepositoryError) -> Self {
+        match error {
+            FederatedIdentityRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            FederatedIdentityRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<FederatedIdentityIdGenerationError> for UseCaseError {
+    fn from(error: FederatedIdentityIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<ApiKeyRepositoryError> for UseCaseError {
+    fn from(error: ApiKeyRepositoryError) -> Self {
+        match error {
+            ApiKeyRepositoryError::ReconstructionError(reconstruction_error) => {
+                UseCaseError::Internal(reconstruction_error.into())
+            }
+            ApiKeyRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            ApiKeyRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<ApiKeyIdGenerationError> for UseCaseError {
+    fn from(error: ApiKeyIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<ApiKeyIssueError> for UseCaseError {
+    fn from(error: ApiKeyIssueError) -> Self {
+        let (field, message) = match error {
+            ApiKeyIssueError::EmptyScopes => ("scopes", "スコープを1つ以上指定してください"),
+            ApiKeyIssueError::InvalidExpiry { .. } => {
+                ("expires_in_days", "有効期限が正しくありません")
+            }
+        };
+
+        UseCaseError::InvalidInput(vec![ValidationError::new(field, message)].into())
+    }
+}
+
+impl From<ServiceAccountRepositoryError> for UseCaseError {
+    fn from(error: ServiceAccountRepositoryError) -> Self {
+        match error {
+            ServiceAccountRepositoryError::IdGenerationError(id_generation_error) => {
+                id_generation_error.into()
+            }
+            ServiceAccountRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<ServiceAccountIdGenerationError> for UseCaseError {
+    fn from(error: ServiceAccountIdGenerationError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<OidcClientError> for UseCaseError {
+    fn from(error: OidcClientError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<TokenRevocationStoreError> for UseCaseError {
+    fn from(error: TokenRevocationStoreError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<SessionRepositoryError> for UseCaseError {
+    fn from(error: SessionRepositoryError) -> Self {
+        match error {
+            SessionRepositoryError::Persistence(error) => UseCaseError::Internal(error),
+        }
+    }
+}
+
+impl From<TokenEpochCacheError> for UseCaseError {
+    fn from(error: TokenEpochCacheError) -> Self {
+        UseCaseError::Internal(error.into())
+    }
+}
+
+impl From<RateLimiterError> for UseCaseError {
+    fn from(error: RateLimiterError) -> Self {
+        match error {
+            RateLimiterError::Storage(e) => UseCaseError::Internal(e),
+        }
+    }
+}
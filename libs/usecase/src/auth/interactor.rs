@@ -1,53 +1,481 @@
 use crate::{
     auth::{
-        dto::{LoginInput, LoginOutput, SignupInput, SignupOutput},
+        api_key_service::ApiKeyService,
+        dto::{
+            ApiKeyPrincipal, AuthenticateApiKeyInput, AuthenticateServiceAccountInput,
+            BeginOidcLoginInput, BeginOidcLoginOutput, BeginPasskeyLoginInput,
+            BeginPasskeyLoginOutput, ClientCredentialsInput, ClientCredentialsOutput,
+            ConfirmPasswordResetInput, LoginInput, LoginOutput, LoginTokens, LogoutInput,
+            MagicLinkLoginInput, MfaChallengeOutput, MfaLoginInput, MfaMethod, OidcLoginInput,
+            PasskeyLoginInput, RefreshTokenInput, RefreshTokenOutput, RequestMagicLinkInput,
+            RequestPasswordResetInput, ResendVerificationEmailInput, ServiceAccountPrincipal,
+            SignupInput, SignupOutput, VerifyEmailInput, VerifyTokenEpochInput,
+        },
+        email_verification_token_service::EmailVerificationTokenService,
+        login_attempt::{self, locked_out},
+        magic_link_token_service::MagicLinkTokenService,
+        mfa_challenge_token_service::{MfaChallengeClaim, MfaChallengeTokenService},
+        oidc_service::{OidcIdentity, OidcService},
+        opaque_token,
+        password_reset_token_service::PasswordResetTokenService,
         service::AuthService,
+        session_revocation::{deny_session_access_tokens, end_all_sessions, end_session},
+        token_epoch_cache::TokenEpochCache,
+        token_revocation_store::TokenRevocationStore,
         token_service::TokenService,
+        totp_service::TotpService,
+        webauthn_service::{WebAuthnCeremony, WebAuthnService},
     },
-    usecase_error::UseCaseError,
+    shared::rate_limiter::RateLimiter,
+    usecase_error::{UseCaseError, ValidationError},
+    user::error::password_policy_violations_for_field,
 };
 use async_trait::async_trait;
 use domain::{
+    auth::federated_identity::{
+        FederatedIdentity, FederatedIdentityIdGeneratorFactory, IdentityProviderEmailVerification,
+    },
+    auth::login_policy::{AccessScope, LoginPolicy},
+    auth::password_reset_token::{PasswordResetToken, PasswordResetTokenIdGeneratorFactory},
+    auth::refresh_token::{
+        RefreshToken, RefreshTokenFamilyId, RefreshTokenIdGeneratorFactory,
+        RefreshTokenRotationError,
+    },
+    auth::service_account::ServiceAccountSecretHash,
+    auth::session::{Session, SessionClient, SessionLimit},
+    auth::webauthn_credential::CredentialId,
+    shared::service::clock::Clock,
     transaction::TransactionManager,
     tx,
     user::{
-        EmailTrait, HashedPassword, PasswordHasher, RawPassword, UnverifiedEmail, User,
-        UserFactory, UserIdGeneratorFactory, UserUniquenessService,
+        Email, EmailTrait, EmailVerificationClaim, HashedPassword, LoginLockoutConfig, MfaState,
+        PasswordHasher, PasswordOwner, PasswordPolicy, RawPassword, UnverifiedEmail, User,
+        UserFactory, UserId, UserIdGeneratorFactory, UserRepository, UserRole, UserState,
+        UserUniquenessService,
     },
 };
+use rand::Rng as _;
 use std::sync::Arc;
+use uuid::Uuid;
+
+// 外部アカウントから作成するユーザーのユーザー名 (`^[a-zA-Z0-9_]{3,20}$`) の組み立てに使用する
+const GENERATED_USERNAME_BASE_MAX_LEN: usize = 15;
+const GENERATED_USERNAME_ATTEMPTS: usize = 5;
 
 pub struct AuthInteractor<TM> {
     transaction_manager: Arc<TM>,
     password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicy>,
+    login_lockout_config: Arc<LoginLockoutConfig>,
+    session_limit: Arc<SessionLimit>,
     token_service: Arc<dyn TokenService>,
+    token_revocation_store: Arc<dyn TokenRevocationStore>,
+    token_epoch_cache: Arc<dyn TokenEpochCache>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+    email_verification_resend_limiter: Arc<dyn RateLimiter>,
+    login_ip_rate_limiter: Arc<dyn RateLimiter>,
+    password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+    magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+    mfa_challenge_token_service: Arc<dyn MfaChallengeTokenService>,
+    totp_service: Arc<dyn TotpService>,
+    webauthn_service: Arc<dyn WebAuthnService>,
+    oidc_service: Arc<dyn OidcService>,
+    api_key_service: Arc<dyn ApiKeyService>,
     user_factory: Arc<UserFactory>,
     user_id_generator_factory: Arc<dyn UserIdGeneratorFactory>,
+    refresh_token_id_generator_factory: Arc<dyn RefreshTokenIdGeneratorFactory>,
+    password_reset_token_id_generator_factory: Arc<dyn PasswordResetTokenIdGeneratorFactory>,
+    federated_identity_id_generator_factory: Arc<dyn FederatedIdentityIdGeneratorFactory>,
+    clock: Arc<dyn Clock>,
     dummy_hash: HashedPassword,
 }
 
+// リフレッシュトークンのローテーション結果
+// 再利用を検知した場合もファミリーの失効はコミットしたいため、エラーではなく結果として返す
+enum RefreshOutcome {
+    Rotated {
+        user_id: UserId,
+        role: UserRole,
+        token_epoch: u32,
+        scope: AccessScope,
+        family_id: RefreshTokenFamilyId,
+    },
+    ReuseDetected {
+        family_id: RefreshTokenFamilyId,
+    },
+    Rejected(RefreshTokenRotationError),
+    NotFound,
+}
+
+// 2 段階認証の認証コード・パスキーの検証結果
+// 検証に失敗した場合も失敗回数の記録は別のトランザクションで行うため、エラーではなく結果として返す
+enum VerificationOutcome {
+    Verified {
+        user_id: UserId,
+        role: UserRole,
+        token_epoch: u32,
+        scope: AccessScope,
+    },
+    /// 対象のユーザーを特定できなかった場合は `user_id` を `None` とする
+    Rejected {
+        user_id: Option<UserId>,
+    },
+    LockedOut,
+}
+
 impl<TM> AuthInteractor<TM> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         transaction_manager: Arc<TM>,
         password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicy>,
+        login_lockout_config: Arc<LoginLockoutConfig>,
+        session_limit: Arc<SessionLimit>,
         token_service: Arc<dyn TokenService>,
+        token_revocation_store: Arc<dyn TokenRevocationStore>,
+        token_epoch_cache: Arc<dyn TokenEpochCache>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+        email_verification_resend_limiter: Arc<dyn RateLimiter>,
+        login_ip_rate_limiter: Arc<dyn RateLimiter>,
+        password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+        magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+        mfa_challenge_token_service: Arc<dyn MfaChallengeTokenService>,
+        totp_service: Arc<dyn TotpService>,
+        webauthn_service: Arc<dyn WebAuthnService>,
+        oidc_service: Arc<dyn OidcService>,
+        api_key_service: Arc<dyn ApiKeyService>,
         user_factory: Arc<UserFactory>,
         user_id_generator_factory: Arc<dyn UserIdGeneratorFactory>,
+        refresh_token_id_generator_factory: Arc<dyn RefreshTokenIdGeneratorFactory>,
+        password_reset_token_id_generator_factory: Arc<dyn PasswordResetTokenIdGeneratorFactory>,
+        federated_identity_id_generator_factory: Arc<dyn FederatedIdentityIdGeneratorFactory>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
-        let dummy_password = RawPassword::new("dummy_password_for_timing_attack").unwrap();
+        let dummy_password = RawPassword::new("dummy_password_for_timing_attack");
         let dummy_hash = password_hasher.hash(&dummy_password).unwrap();
 
         Self {
             transaction_manager,
             password_hasher,
+            password_policy,
+            login_lockout_config,
+            session_limit,
             token_service,
+            token_revocation_store,
+            token_epoch_cache,
+            email_verification_token_service,
+            email_verification_resend_limiter,
+            login_ip_rate_limiter,
+            password_reset_token_service,
+            magic_link_token_service,
+            mfa_challenge_token_service,
+            totp_service,
+            webauthn_service,
+            oidc_service,
+            api_key_service,
             user_factory,
             user_id_generator_factory,
+            refresh_token_id_generator_factory,
+            password_reset_token_id_generator_factory,
+            federated_identity_id_generator_factory,
+            clock,
             dummy_hash,
         }
     }
 }
 
+impl<TM: TransactionManager> AuthInteractor<TM> {
+    /// 2 段階認証のチャレンジトークンを検証する (使用済みのトークンは拒否する)
+    async fn verify_mfa_challenge(
+        &self,
+        mfa_token: &str,
+    ) -> Result<MfaChallengeClaim, UseCaseError> {
+        let claim = self
+            .mfa_challenge_token_service
+            .verify_challenge_token(mfa_token)?;
+        if self
+            .token_revocation_store
+            .is_revoked(claim.token_id)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        Ok(claim)
+    }
+
+    /// 接続元 IP アドレス単位の失敗回数の上限に達していないことを確認する
+    async fn ensure_ip_not_throttled(&self, ip_address: Option<&str>) -> Result<(), UseCaseError> {
+        login_attempt::ensure_ip_not_throttled(self.login_ip_rate_limiter.as_ref(), ip_address)
+            .await
+    }
+
+    /// ログインの失敗を接続元 IP アドレスとアカウントのそれぞれに記録する
+    async fn record_failed_login(
+        &self,
+        ip_address: Option<&str>,
+        user_id: Option<UserId>,
+    ) -> Result<(), UseCaseError> {
+        login_attempt::record_failed_login(
+            self.transaction_manager.as_ref(),
+            self.login_ip_rate_limiter.as_ref(),
+            self.login_lockout_config.clone(),
+            self.clock.clone(),
+            ip_address,
+            user_id,
+        )
+        .await
+    }
+
+    /// パスワード認証の成功時に、失敗の記録を消去し、必要であればパスワードを再ハッシュする
+    ///
+    /// 2 段階認証が有効な場合、失敗の記録は認証コードの確認後に消去するため `clear_failures` を `false` とする。
+    /// `password_to_rehash` には、検証に使用したハッシュと検証済みの平文を渡す
+    async fn record_successful_login(
+        &self,
+        user_id: UserId,
+        clear_failures: bool,
+        password_to_rehash: Option<(&HashedPassword, &RawPassword)>,
+    ) -> Result<(), UseCaseError> {
+        let rehash = password_to_rehash
+            .map(|(verified_password, password)| {
+                self.password_hasher
+                    .hash(password)
+                    .map(|rehashed_password| (verified_password.clone(), rehashed_password))
+            })
+            .transpose()?;
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // 検証後に行われたパスワードの変更・失敗の記録を上書きしないよう、行をロックしてから更新する
+            let mut user = user_repo
+                .find_by_id_for_update(user_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+            if clear_failures {
+                user.record_successful_login();
+            }
+            if let Some((verified_password, rehashed_password)) = rehash {
+                user.upgrade_password_hash(&verified_password, rehashed_password);
+            }
+
+            user_repo.save(user).await?;
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    /// 2 段階認証が有効なユーザーに、トークンの代わりにチャレンジトークンを発行する
+    async fn issue_mfa_challenge(&self, user_id: UserId) -> Result<LoginOutput, UseCaseError> {
+        let challenge = self
+            .mfa_challenge_token_service
+            .issue_challenge_token(user_id)?;
+
+        // パスキーを登録済みであれば、認証コードの代わりにパスキーでも認証できる
+        let has_passkey = tx!(self.transaction_manager, |factory| {
+            let credential_repo = factory.webauthn_credential_repository();
+            Ok::<_, UseCaseError>(!credential_repo.find_by_user_id(user_id).await?.is_empty())
+        })
+        .await?;
+
+        let mut methods = vec![MfaMethod::Totp];
+        if has_passkey {
+            methods.push(MfaMethod::Passkey);
+        }
+
+        Ok(LoginOutput::MfaRequired(MfaChallengeOutput {
+            mfa_token: challenge.token,
+            expires_in: challenge.expires_in,
+            methods,
+        }))
+    }
+
+    /// 初めてログインした外部アカウントを、ID プロバイダーで確認済みのメールアドレスによってユーザーに紐づける
+    ///
+    /// 同じメールアドレスのユーザーがいない場合は、新しいユーザーを作成する
+    async fn link_federated_identity(&self, identity: OidcIdentity) -> Result<User, UseCaseError> {
+        let OidcIdentity {
+            provider,
+            subject,
+            email,
+            email_verified,
+        } = identity;
+
+        // 所有を確認していないメールアドレスで紐づけると、他人のアカウントを乗っ取れてしまう
+        let email = match email {
+            Some(email) if email_verified => UnverifiedEmail::new(&email)?,
+            _ => {
+                return Err(UseCaseError::Forbidden {
+                    message:
+                        "ID プロバイダーで確認済みのメールアドレスがないため、ログインできません"
+                            .to_string(),
+                });
+            }
+        };
+
+        // 新しいユーザーには誰も知らないランダムなパスワードを設定する
+        // (パスワードでもログインしたい場合は、パスワードリセットで設定する)
+        let placeholder_password = self
+            .password_hasher
+            .hash(&RawPassword::new(&opaque_token::generate()))?;
+        let base_username = username_from_email(email.as_str());
+
+        let user_factory = self.user_factory.clone();
+        let user_id_generator_factory = self.user_id_generator_factory.clone();
+        let federated_identity_id_generator_factory =
+            self.federated_identity_id_generator_factory.clone();
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let federated_identity_repo = factory.federated_identity_repository();
+
+            let user = match user_repo.find_by_email(email.as_str()).await? {
+                Some(user) => {
+                    // 未確認のメールアドレスのユーザーは、第三者が先回りして登録したものの可能性があるため紐づけない
+                    if !matches!(user.email(), Email::Verified(_)) {
+                        return Err(UseCaseError::Conflict {
+                            message: "同じメールアドレスのアカウントが存在します。パスワードでログインしてメールアドレスを確認してから、再度お試しください"
+                                .to_string(),
+                        });
+                    }
+                    user
+                }
+                None => {
+                    let username = find_available_username(user_repo.as_ref(), &base_username).await?;
+                    let user_uniqueness_service = UserUniquenessService::new(user_repo.clone());
+                    let user_info = user_uniqueness_service
+                        .ensure_unique(&username, email.as_str())
+                        .await?;
+
+                    let user_id_generator = user_id_generator_factory.create_user_id_generator();
+                    let mut user = user_factory.create_new_user(
+                        user_id_generator,
+                        user_info,
+                        placeholder_password,
+                    )?;
+
+                    // メールアドレスは ID プロバイダーが確認済みのため、確認メールを待たずに有効にする
+                    let verification = IdentityProviderEmailVerification {
+                        email: email.as_str().to_string(),
+                    };
+                    user.verify_email(&verification, clock.as_ref())?;
+
+                    user_repo.save(user).await?
+                }
+            };
+
+            if user.is_locked_out(clock.now()) {
+                return Err(locked_out());
+            }
+
+            let federated_identity_id = federated_identity_id_generator_factory
+                .create_federated_identity_id_generator()
+                .generate()?;
+            let federated_identity = FederatedIdentity::link(
+                federated_identity_id,
+                user.id(),
+                provider,
+                subject,
+                email.as_str().to_string(),
+                clock.as_ref(),
+            );
+            federated_identity_repo.save(federated_identity).await?;
+
+            Ok::<_, UseCaseError>(user)
+        })
+        .await
+    }
+
+    /// 新しいログインセッション (リフレッシュトークンのファミリー) を開始し、トークンを発行する
+    ///
+    /// 同時に保持できるセッション数の上限を超える場合は、古いセッションから終了させる
+    async fn issue_login_tokens(
+        &self,
+        user_id: UserId,
+        role: UserRole,
+        token_epoch: u32,
+        scope: AccessScope,
+        client: SessionClient,
+    ) -> Result<LoginTokens, UseCaseError> {
+        // リフレッシュトークンの発行と保存 (新しいファミリーを開始する)
+        let issued = self.token_service.issue_refresh_token()?;
+        let refresh_token_id_generator_factory = self.refresh_token_id_generator_factory.clone();
+        let session_limit = self.session_limit.clone();
+        let clock = self.clock.clone();
+
+        let (refresh_token, evicted_session_ids) = tx!(self.transaction_manager, |factory| {
+            let refresh_token_repo = factory.refresh_token_repository();
+            let session_repo = factory.session_repository();
+
+            let active_sessions = session_repo
+                .find_active_by_user_id(user_id, clock.now())
+                .await?;
+            let evicted_session_ids = session_limit.sessions_to_evict(&active_sessions);
+            for session_id in &evicted_session_ids {
+                end_session(
+                    *session_id,
+                    session_repo.as_ref(),
+                    refresh_token_repo.as_ref(),
+                    clock.as_ref(),
+                )
+                .await?;
+            }
+
+            let refresh_token_id = refresh_token_id_generator_factory
+                .create_refresh_token_id_generator()
+                .generate()?;
+
+            let refresh_token = RefreshToken::issue(
+                refresh_token_id,
+                user_id,
+                token_epoch,
+                issued.token_hash,
+                issued.expires_at,
+                clock.as_ref(),
+            );
+            let refresh_token = refresh_token_repo.save(refresh_token).await?;
+
+            let session = Session::start(
+                refresh_token.family_id().into(),
+                user_id,
+                client,
+                refresh_token.expires_at(),
+                clock.as_ref(),
+            );
+            session_repo.save(session).await?;
+
+            Ok::<_, UseCaseError>((refresh_token, evicted_session_ids))
+        })
+        .await?;
+
+        deny_session_access_tokens(
+            &evicted_session_ids,
+            self.token_revocation_store.as_ref(),
+            self.token_service.as_ref(),
+            self.clock.as_ref(),
+        )
+        .await?;
+
+        // JWT アクセストークンの生成
+        let access_token = self.token_service.issue_access_token(
+            user_id,
+            role,
+            refresh_token.family_id(),
+            token_epoch,
+            scope,
+        )?;
+
+        Ok(LoginTokens {
+            access_token: access_token.token,
+            refresh_token: issued.token,
+            expires_in: access_token.expires_in,
+            scope: scope.into(),
+        })
+    }
+}
+
 #[async_trait]
 impl<TM: TransactionManager> AuthService for AuthInteractor<TM> {
     /// サインアップ（ユーザー登録）
@@ -56,7 +484,16 @@ impl<TM: TransactionManager> AuthService for AuthInteractor<TM> {
         // ここでDTOからValueObjectへの変換を行う
         let username = input.username;
         let email = input.email;
-        let password = RawPassword::new(&input.password)?;
+        let password = RawPassword::new(&input.password);
+
+        // パスワードポリシーの検証
+        self.password_policy.validate(
+            &password,
+            PasswordOwner {
+                username: &username,
+                email: &email,
+            },
+        )?;
 
         // パスワードのハッシュ化
         let hashed_password = self.password_hasher.hash(&password)?;
@@ -91,7 +528,13 @@ impl<TM: TransactionManager> AuthService for AuthInteractor<TM> {
     async fn login(&self, input: LoginInput) -> Result<LoginOutput, UseCaseError> {
         // ここでDTOからValueObjectへの変換を行う
         let email = UnverifiedEmail::new(&input.email)?;
-        let password = RawPassword::new(&input.password)?;
+        let password = RawPassword::new(&input.password);
+        let ip_address = input.ip_address;
+        let user_agent = input.user_agent;
+
+        // 0. 接続元 IP アドレス単位の失敗回数の制限
+        self.ensure_ip_not_throttled(ip_address.as_deref()).await?;
+
         // 1. ユーザーを検索
         let user_opt: Option<User> = tx!(self.transaction_manager, |factory| {
             let user_repo = factory.user_repository();
@@ -114,15 +557,1181 @@ impl<TM: TransactionManager> AuthService for AuthInteractor<TM> {
             }
         };
 
-        if !is_valid || user.is_none() {
+        // ロックアウト中はパスワードの正誤に関わらず拒否する
+        if let Some(user) = &user
+            && user.is_locked_out(self.clock.now())
+        {
+            return Err(locked_out());
+        }
+
+        let user = match user {
+            Some(user) if is_valid => user,
+            user => {
+                self.record_failed_login(ip_address.as_deref(), user.map(|u| u.id()))
+                    .await?;
+                return Err(UseCaseError::Unauthorized);
+            }
+        };
+
+        // アカウントの状態によるログインの可否は、パスワードの確認後に判定する
+        // (パスワードを知らない第三者に、アカウントの状態を知られないようにするため)
+        let scope = LoginPolicy::evaluate(user.state())?;
+
+        // 失敗の記録を消去する。また、旧アルゴリズム・旧パラメータのハッシュであれば、
+        // 平文を保持している今のうちに再ハッシュする
+        // 失敗してもログイン自体は継続し、次回のログインで再度試みる
+        let mfa_required = user.mfa().is_enabled();
+        let needs_rehash = self.password_hasher.needs_rehash(user.password());
+        // 2 段階認証が有効な場合、パスワードの再入力で認証コードの失敗回数を消去できないよう、
+        // 失敗の記録は認証コードの確認後に消去する
+        let clear_failures = !mfa_required && !user.login_failures().is_clear();
+        if (needs_rehash || clear_failures)
+            && let Err(e) = self
+                .record_successful_login(
+                    user.id(),
+                    clear_failures,
+                    needs_rehash.then_some((user.password(), &password)),
+                )
+                .await
+        {
+            tracing::warn!(error = ?e, user_id = %user.id(), "ログイン成功時のユーザーの更新に失敗しました");
+        }
+
+        // 3. 2 段階認証が有効な場合は、トークンの代わりにチャレンジトークンを発行する
+        if mfa_required {
+            return self.issue_mfa_challenge(user.id()).await;
+        }
+
+        // 4. リフレッシュトークン・アクセストークンの発行
+        let tokens = self
+            .issue_login_tokens(
+                user.id(),
+                user.role(),
+                user.token_epoch(),
+                scope,
+                SessionClient {
+                    user_agent,
+                    ip_address,
+                },
+            )
+            .await?;
+
+        Ok(LoginOutput::Authenticated(tokens))
+    }
+
+    /// 2 段階認証の認証コードによるログインの完了
+    ///
+    /// 認証コードの誤りはパスワードの誤りと同様に、接続元 IP アドレスとアカウントの失敗回数に数える
+    #[tracing::instrument(skip(self))]
+    async fn complete_mfa_login(&self, input: MfaLoginInput) -> Result<LoginTokens, UseCaseError> {
+        let MfaLoginInput {
+            mfa_token,
+            code,
+            ip_address,
+            user_agent,
+        } = input;
+
+        self.ensure_ip_not_throttled(ip_address.as_deref()).await?;
+
+        // 1. チャレンジトークンの検証 (使用済みのトークンは拒否する)
+        let claim = self.verify_mfa_challenge(&mfa_token).await?;
+
+        // 2. 認証コードまたはリカバリーコードの検証
+        let totp_service = self.totp_service.clone();
+        let clock = self.clock.clone();
+        let user_id = claim.user_id;
+
+        let outcome = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let mut user = user_repo
+                .find_by_id(user_id)
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            if user.is_locked_out(clock.now()) {
+                return Ok(VerificationOutcome::LockedOut);
+            }
+
+            let MfaState::Enabled { secret, .. } = user.mfa() else {
+                // チャレンジトークンの発行後に 2 段階認証が解除された場合は、ログインをやり直させる
+                return Err(UseCaseError::Unauthorized);
+            };
+
+            // 認証アプリの認証コードとして一致しなければ、リカバリーコードとして照合する
+            let verified = match totp_service.verify_code(secret, &code) {
+                Some(step) => user.record_totp_use(step).is_ok(),
+                None => user
+                    .consume_recovery_code(&totp_service.hash_recovery_code(&code))
+                    .is_ok(),
+            };
+
+            if !verified {
+                return Ok(VerificationOutcome::Rejected {
+                    user_id: Some(user_id),
+                });
+            }
+
+            // チャレンジトークンの発行後に利用停止・退会した場合も拒否する
+            let scope = LoginPolicy::evaluate(user.state())?;
+
+            user.record_successful_login();
+            let user = user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(VerificationOutcome::Verified {
+                user_id: user.id(),
+                role: user.role(),
+                token_epoch: user.token_epoch(),
+                scope,
+            })
+        })
+        .await?;
+
+        let (user_id, role, token_epoch, scope) = match outcome {
+            VerificationOutcome::Verified {
+                user_id,
+                role,
+                token_epoch,
+                scope,
+            } => (user_id, role, token_epoch, scope),
+            VerificationOutcome::Rejected { user_id } => {
+                self.record_failed_login(ip_address.as_deref(), user_id)
+                    .await?;
+                return Err(UseCaseError::Unauthorized);
+            }
+            VerificationOutcome::LockedOut => return Err(locked_out()),
+        };
+
+        // 3. チャレンジトークンを使用済みにする (並行して使用された場合は 1 件のみ成功させる)
+        if !self
+            .token_revocation_store
+            .revoke_if_absent(claim.token_id, claim.expires_at)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        // 4. リフレッシュトークン・アクセストークンの発行
+        self.issue_login_tokens(
+            user_id,
+            role,
+            token_epoch,
+            scope,
+            SessionClient {
+                user_agent,
+                ip_address,
+            },
+        )
+        .await
+    }
+
+    /// パスキーによるログインの開始
+    ///
+    /// チャレンジトークンを指定した場合は、パスワード認証に続く 2 段階目の認証として、
+    /// そのユーザーが登録したパスキーのみを選択させる
+    #[tracing::instrument(skip(self))]
+    async fn begin_passkey_login(
+        &self,
+        input: BeginPasskeyLoginInput,
+    ) -> Result<BeginPasskeyLoginOutput, UseCaseError> {
+        let Some(mfa_token) = input.mfa_token else {
+            let issued = self
+                .webauthn_service
+                .issue_authentication_challenge(None, &[])?;
+            return Ok(issued.into());
+        };
+
+        let mfa_claim = self.verify_mfa_challenge(&mfa_token).await?;
+        let user_id = mfa_claim.user_id;
+
+        let credentials = tx!(self.transaction_manager, |factory| {
+            let credential_repo = factory.webauthn_credential_repository();
+            Ok::<_, UseCaseError>(credential_repo.find_by_user_id(user_id).await?)
+        })
+        .await?;
+
+        if credentials.is_empty() {
+            return Err(UseCaseError::Conflict {
+                message: "パスキーが登録されていません。認証コードを入力してください".to_string(),
+            });
+        }
+
+        let allow_credentials: Vec<_> = credentials
+            .iter()
+            .map(|credential| credential.credential_id().clone())
+            .collect();
+
+        let issued = self
+            .webauthn_service
+            .issue_authentication_challenge(Some(user_id), &allow_credentials)?;
+
+        Ok(issued.into())
+    }
+
+    /// パスキーによるログインの完了
+    ///
+    /// 検証の失敗はパスワードの誤りと同様に、接続元 IP アドレスとアカウントの失敗回数に数える
+    #[tracing::instrument(skip(self))]
+    async fn complete_passkey_login(
+        &self,
+        input: PasskeyLoginInput,
+    ) -> Result<LoginTokens, UseCaseError> {
+        let PasskeyLoginInput {
+            ceremony_token,
+            mfa_token,
+            credential,
+            ip_address,
+            user_agent,
+        } = input;
+
+        self.ensure_ip_not_throttled(ip_address.as_deref()).await?;
+
+        // 1. セレモニートークンの検証 (使用済みのトークンは拒否する)
+        let claim = self
+            .webauthn_service
+            .verify_ceremony_token(&ceremony_token, WebAuthnCeremony::Authentication)?;
+        if self
+            .token_revocation_store
+            .is_revoked(claim.token_id)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        // 2 段階目の認証として開始したセレモニーは、パスワード認証済みであることを示す
+        // チャレンジトークンなしでは完了できない (ユーザー検証を必須としていないため)
+        let mfa_claim = match (claim.user_id, mfa_token) {
+            (None, _) => None,
+            (Some(user_id), Some(mfa_token)) => {
+                let mfa_claim = self.verify_mfa_challenge(&mfa_token).await?;
+                if mfa_claim.user_id != user_id {
+                    return Err(UseCaseError::Unauthorized);
+                }
+                Some(mfa_claim)
+            }
+            (Some(_), None) => return Err(UseCaseError::Unauthorized),
+        };
+
+        // 2. 認証器の応答を、登録済みのクレデンシャルの公開鍵で検証する
+        let (ceremony_token_id, ceremony_expires_at) = (claim.token_id, claim.expires_at);
+        let webauthn_service = self.webauthn_service.clone();
+        let clock = self.clock.clone();
+        let credential_id = CredentialId::from_raw_str(credential.id.trim_end_matches('='));
+
+        let outcome = tx!(self.transaction_manager, |factory| {
+            let credential_repo = factory.webauthn_credential_repository();
+            let user_repo = factory.user_repository();
+
+            let Some(mut stored) = credential_repo.find_by_credential_id(&credential_id).await?
+            else {
+                return Ok(VerificationOutcome::Rejected {
+                    user_id: claim.user_id,
+                });
+            };
+
+            let mut user = user_repo
+                .find_by_id(stored.user_id())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            if user.is_locked_out(clock.now()) {
+                return Ok(VerificationOutcome::LockedOut);
+            }
+
+            let rejected = VerificationOutcome::Rejected {
+                user_id: Some(user.id()),
+            };
+
+            let Ok(verified) =
+                webauthn_service.verify_assertion(&claim, user.id(), stored.public_key(), &credential)
+            else {
+                return Ok(rejected);
+            };
+
+            // 署名カウンターが増加していない場合は、クレデンシャルの複製を疑う
+            if let Err(e) = stored.record_authentication(verified.sign_count, clock.as_ref()) {
+                tracing::warn!(error = %e, user_id = %user.id(), "パスキーの署名カウンターが増加していないため、認証を拒否しました");
+                return Ok(rejected);
+            }
+
+            let scope = LoginPolicy::evaluate(user.state())?;
+
+            user.record_successful_login();
+            credential_repo.save(stored).await?;
+            let user = user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(VerificationOutcome::Verified {
+                user_id: user.id(),
+                role: user.role(),
+                token_epoch: user.token_epoch(),
+                scope,
+            })
+        })
+        .await?;
+
+        let (user_id, role, token_epoch, scope) = match outcome {
+            VerificationOutcome::Verified {
+                user_id,
+                role,
+                token_epoch,
+                scope,
+            } => (user_id, role, token_epoch, scope),
+            VerificationOutcome::Rejected { user_id } => {
+                self.record_failed_login(ip_address.as_deref(), user_id)
+                    .await?;
+                return Err(UseCaseError::Unauthorized);
+            }
+            VerificationOutcome::LockedOut => return Err(locked_out()),
+        };
+
+        // 3. セレモニートークン・チャレンジトークンを使用済みにする
+        // (並行して使用された場合は 1 件のみ成功させる)
+        if !self
+            .token_revocation_store
+            .revoke_if_absent(ceremony_token_id, ceremony_expires_at)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+        if let Some(mfa_claim) = mfa_claim
+            && !self
+                .token_revocation_store
+                .revoke_if_absent(mfa_claim.token_id, mfa_claim.expires_at)
+                .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        // 4. リフレッシュトークン・アクセストークンの発行
+        self.issue_login_tokens(
+            user_id,
+            role,
+            token_epoch,
+            scope,
+            SessionClient {
+                user_agent,
+                ip_address,
+            },
+        )
+        .await
+    }
+
+    /// 外部 ID プロバイダーによるログインの開始
+    #[tracing::instrument(skip(self))]
+    async fn begin_oidc_login(
+        &self,
+        input: BeginOidcLoginInput,
+    ) -> Result<BeginOidcLoginOutput, UseCaseError> {
+        let issued = self
+            .oidc_service
+            .begin_authorization(&input.provider)
+            .await?;
+
+        Ok(issued.into())
+    }
+
+    /// 外部 ID プロバイダーによるログインの完了
+    ///
+    /// 2 段階認証が有効なユーザーには、パスワードでのログインと同様にチャレンジトークンを発行する
+    #[tracing::instrument(skip(self))]
+    async fn complete_oidc_login(
+        &self,
+        input: OidcLoginInput,
+    ) -> Result<LoginOutput, UseCaseError> {
+        let OidcLoginInput {
+            provider,
+            code,
+            state,
+            state_token,
+            ip_address,
+            user_agent,
+        } = input;
+
+        // 1. 状態トークンの検証 (使用済みのトークンは拒否する)
+        let claim = self
+            .oidc_service
+            .verify_state_token(&state_token, &provider, &state)?;
+        if !self
+            .token_revocation_store
+            .revoke_if_absent(claim.token_id, claim.expires_at)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        // 2. 認可コードを ID トークンと交換し、外部アカウントを特定する
+        let identity = self.oidc_service.authenticate(&claim, &code).await?;
+
+        // 3. 紐づけ済みの外部アカウントであれば、紐づけたユーザーとしてログインする
+        let provider = identity.provider.clone();
+        let subject = identity.subject.clone();
+        let clock = self.clock.clone();
+
+        let linked_user = tx!(self.transaction_manager, |factory| {
+            let federated_identity_repo = factory.federated_identity_repository();
+            let user_repo = factory.user_repository();
+
+            let Some(mut federated_identity) = federated_identity_repo
+                .find_by_provider_subject(&provider, &subject)
+                .await?
+            else {
+                return Ok(None);
+            };
+
+            let user = user_repo
+                .find_by_id(federated_identity.user_id())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            if user.is_locked_out(clock.now()) {
+                return Err(locked_out());
+            }
+
+            federated_identity.record_login(clock.as_ref());
+            federated_identity_repo.save(federated_identity).await?;
+
+            Ok::<_, UseCaseError>(Some(user))
+        })
+        .await?;
+
+        // 4. 初めてログインした外部アカウントであれば、ユーザーに紐づける
+        let user = match linked_user {
+            Some(user) => user,
+            None => self.link_federated_identity(identity).await?,
+        };
+
+        // 5. アカウントの状態によるログインの可否の判定
+        let scope = LoginPolicy::evaluate(user.state())?;
+
+        // 6. 2 段階認証が有効な場合は、トークンの代わりにチャレンジトークンを発行する
+        if user.mfa().is_enabled() {
+            return self.issue_mfa_challenge(user.id()).await;
+        }
+
+        // 7. リフレッシュトークン・アクセストークンの発行
+        let tokens = self
+            .issue_login_tokens(
+                user.id(),
+                user.role(),
+                user.token_epoch(),
+                scope,
+                SessionClient {
+                    user_agent,
+                    ip_address,
+                },
+            )
+            .await?;
+
+        Ok(LoginOutput::Authenticated(tokens))
+    }
+
+    /// ログインリンクの申請
+    ///
+    /// アカウントの存在有無を推測されないよう、ログインできないアカウントの場合も成功として扱う
+    #[tracing::instrument(skip(self))]
+    async fn request_magic_link(&self, input: RequestMagicLinkInput) -> Result<(), UseCaseError> {
+        let email = UnverifiedEmail::new(&input.email)?;
+
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let Some(mut user) = user_repo.find_by_email(email.as_str()).await? else {
+                return Ok(());
+            };
+
+            // ログインメールの送信はリレーワーカーがイベントを処理する際に行う
+            if let Err(e) = user.request_magic_link(clock.as_ref()) {
+                tracing::info!(user_id = %user.id(), error = %e, "ログインできない状態のため、ログインリンクの申請を無視しました");
+                return Ok(());
+            }
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    /// ログインリンクによるログイン
+    ///
+    /// ログイントークンは一度だけ使用でき、2 段階認証が有効なユーザーにはチャレンジトークンを発行する
+    #[tracing::instrument(skip(self))]
+    async fn complete_magic_link_login(
+        &self,
+        input: MagicLinkLoginInput,
+    ) -> Result<LoginOutput, UseCaseError> {
+        let MagicLinkLoginInput {
+            token,
+            ip_address,
+            user_agent,
+        } = input;
+
+        // 1. ログイントークンの検証 (使用済みのトークンは拒否する)
+        let claim = self.magic_link_token_service.verify(&token)?;
+        if !self
+            .token_revocation_store
+            .revoke_if_absent(claim.token_id, claim.expires_at)
+            .await?
+        {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        // 2. メールアドレスの確認 (リンクを受け取れたことで、メールアドレスの所有を確認できる)
+        let clock = self.clock.clone();
+
+        let user = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let mut user = user_repo
+                .find_by_id(claim.user_id)
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            // トークンの発行後にメールアドレスが変更されていた場合は、古いアドレス宛てのリンクとして拒否する
+            if user.email().as_str() != claim.email {
+                return Err(UseCaseError::Unauthorized);
+            }
+
+            if user.is_locked_out(clock.now()) {
+                return Err(locked_out());
+            }
+
+            // アカウントの状態によるログインの可否の判定 (利用停止中・退会済みのアカウントは確認もしない)
+            LoginPolicy::evaluate(user.state())?;
+
+            if !matches!(user.email(), Email::Verified(_)) {
+                let verification = EmailVerificationClaim {
+                    user_id: claim.user_id,
+                    email: claim.email.clone(),
+                };
+                user.verify_email(&verification, clock.as_ref())?;
+                user = user_repo.save(user).await?;
+            }
+
+            Ok::<_, UseCaseError>(user)
+        })
+        .await?;
+
+        // 3. 確認後の状態で、発行するトークンの範囲を決める
+        let scope = LoginPolicy::evaluate(user.state())?;
+
+        // 4. 2 段階認証が有効な場合は、トークンの代わりにチャレンジトークンを発行する
+        if user.mfa().is_enabled() {
+            return self.issue_mfa_challenge(user.id()).await;
+        }
+
+        // 5. リフレッシュトークン・アクセストークンの発行
+        let tokens = self
+            .issue_login_tokens(
+                user.id(),
+                user.role(),
+                user.token_epoch(),
+                scope,
+                SessionClient {
+                    user_agent,
+                    ip_address,
+                },
+            )
+            .await?;
+
+        Ok(LoginOutput::Authenticated(tokens))
+    }
+
+    /// アクセストークンの再発行 (リフレッシュトークンのローテーション)
+    #[tracing::instrument(skip(self))]
+    async fn refresh(&self, input: RefreshTokenInput) -> Result<RefreshTokenOutput, UseCaseError> {
+        let token_hash = self.token_service.hash_refresh_token(&input.refresh_token);
+        let issued = self.token_service.issue_refresh_token()?;
+
+        let refresh_token_id_generator_factory = self.refresh_token_id_generator_factory.clone();
+        let clock = self.clock.clone();
+
+        let outcome = tx!(self.transaction_manager, |factory| {
+            let refresh_token_repo = factory.refresh_token_repository();
+            let user_repo = factory.user_repository();
+            let session_repo = factory.session_repository();
+
+            // 同じトークンによる同時のローテーションは、先に行をロックした方の完了後に使用済みとして扱われる
+            let Some(mut current) = refresh_token_repo
+                .find_by_token_hash_for_update(&token_hash)
+                .await?
+            else {
+                return Ok(RefreshOutcome::NotFound);
+            };
+
+            // ロール・アカウントの状態・トークン世代番号の変更を反映するため、ユーザーを再取得する
+            // (メールアドレスの認証を終えたユーザーには、制限のないトークンを発行する)
+            let Some(user) = user_repo.find_by_id(current.user_id()).await? else {
+                return Ok(RefreshOutcome::NotFound);
+            };
+
+            let next_id = refresh_token_id_generator_factory
+                .create_refresh_token_id_generator()
+                .generate()?;
+
+            match current.rotate(
+                next_id,
+                issued.token_hash,
+                issued.expires_at,
+                user.token_epoch(),
+                clock.as_ref(),
+            ) {
+                Ok(next) => {
+                    let scope = LoginPolicy::evaluate(user.state())?;
+
+                    let family_id = current.family_id();
+
+                    // セッションの最終利用日時と有効期限を更新する
+                    if let Some(mut session) = session_repo.find_by_id(family_id.into()).await? {
+                        session.record_activity(next.expires_at(), clock.as_ref());
+                        session_repo.save(session).await?;
+                    }
+
+                    refresh_token_repo.save(current).await?;
+                    refresh_token_repo.save(next).await?;
+
+                    Ok(RefreshOutcome::Rotated {
+                        user_id: user.id(),
+                        role: user.role(),
+                        token_epoch: user.token_epoch(),
+                        scope,
+                        family_id,
+                    })
+                }
+                Err(RefreshTokenRotationError::Reused) => {
+                    // 使用済みトークンの再利用は漏洩の兆候とみなし、ファミリー全体を失効させる
+                    let family_id = current.family_id();
+                    let family = refresh_token_repo.find_by_family_id(family_id).await?;
+                    for mut token in family {
+                        token.revoke(clock.as_ref());
+                        refresh_token_repo.save(token).await?;
+                    }
+
+                    Ok(RefreshOutcome::ReuseDetected { family_id })
+                }
+                Err(RefreshTokenRotationError::EpochMismatch) => {
+                    // パスワードの変更などでトークン世代番号が進んだ後のセッションは、継続させずに終了させる
+                    end_session(
+                        current.family_id().into(),
+                        session_repo.as_ref(),
+                        refresh_token_repo.as_ref(),
+                        clock.as_ref(),
+                    )
+                    .await?;
+
+                    Ok(RefreshOutcome::Rejected(
+                        RefreshTokenRotationError::EpochMismatch,
+                    ))
+                }
+                Err(e) => Ok::<_, UseCaseError>(RefreshOutcome::Rejected(e)),
+            }
+        })
+        .await?;
+
+        let (user_id, role, token_epoch, scope, family_id) = match outcome {
+            RefreshOutcome::Rotated {
+                user_id,
+                role,
+                token_epoch,
+                scope,
+                family_id,
+            } => (user_id, role, token_epoch, scope, family_id),
+            RefreshOutcome::ReuseDetected { family_id } => {
+                tracing::warn!(%family_id, "リフレッシュトークンの再利用を検知したため、ファミリーを失効させました");
+                return Err(RefreshTokenRotationError::Reused.into());
+            }
+            RefreshOutcome::Rejected(e) => return Err(e.into()),
+            RefreshOutcome::NotFound => return Err(UseCaseError::Unauthorized),
+        };
+
+        let access_token =
+            self.token_service
+                .issue_access_token(user_id, role, family_id, token_epoch, scope)?;
+
+        Ok(RefreshTokenOutput {
+            access_token: access_token.token,
+            refresh_token: issued.token,
+            expires_in: access_token.expires_in,
+            scope: scope.into(),
+        })
+    }
+
+    /// アクセストークンのトークン世代番号の検証
+    ///
+    /// ユーザーの現在の世代番号はキャッシュを優先し、キャッシュにない場合のみデータベースから取得する
+    async fn verify_token_epoch(&self, input: VerifyTokenEpochInput) -> Result<(), UseCaseError> {
+        let VerifyTokenEpochInput {
+            user_id,
+            token_epoch,
+        } = input;
+        let user_id: UserId = user_id.into();
+
+        let current_epoch = match self.token_epoch_cache.get(user_id).await? {
+            Some(current_epoch) => current_epoch,
+            None => {
+                let current_epoch = tx!(self.transaction_manager, |factory| {
+                    let user_repo = factory.user_repository();
+
+                    let user = user_repo
+                        .find_by_id(user_id)
+                        .await?
+                        .ok_or(UseCaseError::Unauthorized)?;
+
+                    Ok::<_, UseCaseError>(user.token_epoch())
+                })
+                .await?;
+
+                self.token_epoch_cache.put(user_id, current_epoch).await?;
+                current_epoch
+            }
+        };
+
+        // 利用停止・パスワードの変更などの前に発行されたトークンは拒否する
+        if token_epoch != current_epoch {
             return Err(UseCaseError::Unauthorized);
         }
 
-        let user = user.unwrap();
+        Ok(())
+    }
+
+    /// API キーによる認証
+    ///
+    /// 識別子でキーを検索して秘密の部分のハッシュ値を照合し、最終使用日時を記録する
+    #[tracing::instrument(skip(self))]
+    async fn authenticate_api_key(
+        &self,
+        input: AuthenticateApiKeyInput,
+    ) -> Result<ApiKeyPrincipal, UseCaseError> {
+        let parsed = self
+            .api_key_service
+            .parse_api_key(&input.api_key)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let api_key_repo = factory.api_key_repository();
+            let user_repo = factory.user_repository();
+
+            let mut api_key = api_key_repo
+                .find_by_prefix(&parsed.prefix)
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            if !api_key.authenticate(&parsed.secret_hash, clock.as_ref()) {
+                return Err(UseCaseError::Unauthorized);
+            }
+
+            let user = user_repo
+                .find_by_id(api_key.user_id())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            // 利用停止中・退会済みのユーザーのキーは使用できない
+            if matches!(
+                user.state(),
+                UserState::SuspendedByAdmin { .. } | UserState::DeactivatedByUser { .. }
+            ) {
+                return Err(UseCaseError::Unauthorized);
+            }
+
+            let api_key = api_key_repo.save(api_key).await?;
+
+            Ok::<_, UseCaseError>(ApiKeyPrincipal {
+                user_id: user.id().into(),
+                user_role: user.role().into(),
+                api_key_id: api_key.id().into(),
+                scopes: api_key.scopes().iter().copied().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    /// クライアントクレデンシャルグラントによるサービスアカウントのアクセストークンの発行
+    ///
+    /// クライアント ID (サービスアカウントの ID) とシークレットのハッシュ値を照合する
+    #[tracing::instrument(skip(self))]
+    async fn issue_client_credentials_token(
+        &self,
+        input: ClientCredentialsInput,
+    ) -> Result<ClientCredentialsOutput, UseCaseError> {
+        let ClientCredentialsInput {
+            client_id,
+            client_secret,
+        } = input;
+
+        let service_account_id =
+            Uuid::parse_str(&client_id).map_err(|_| UseCaseError::Unauthorized)?;
+        let secret_hash =
+            ServiceAccountSecretHash::from_raw_str(&opaque_token::hash(&client_secret));
+
+        let clock = self.clock.clone();
+
+        let service_account = tx!(self.transaction_manager, |factory| {
+            let service_account_repo = factory.service_account_repository();
+
+            let mut service_account = service_account_repo
+                .find_by_id(service_account_id.into())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            if !service_account.authenticate(&secret_hash, clock.as_ref()) {
+                return Err(UseCaseError::Unauthorized);
+            }
+
+            let service_account = service_account_repo.save(service_account).await?;
+
+            Ok::<_, UseCaseError>(service_account)
+        })
+        .await?;
+
+        let access_token = self
+            .token_service
+            .issue_service_account_token(service_account.id(), service_account.role())?;
+
+        Ok(ClientCredentialsOutput {
+            access_token: access_token.token,
+            expires_in: access_token.expires_in,
+        })
+    }
+
+    /// サービスアカウントのアクセストークンによる認証
+    ///
+    /// トークンの発行後に削除されたサービスアカウントを拒否し、現在のロールを返す
+    #[tracing::instrument(skip(self))]
+    async fn authenticate_service_account(
+        &self,
+        input: AuthenticateServiceAccountInput,
+    ) -> Result<ServiceAccountPrincipal, UseCaseError> {
+        let AuthenticateServiceAccountInput { service_account_id } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let service_account_repo = factory.service_account_repository();
+
+            let service_account = service_account_repo
+                .find_by_id(service_account_id.into())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            Ok::<_, UseCaseError>(ServiceAccountPrincipal {
+                service_account_id: service_account.id().into(),
+                role: service_account.role().into(),
+            })
+        })
+        .await
+    }
+
+    /// ログアウト
+    ///
+    /// 使用中のアクセストークンを失効させ、同じログインセッションのリフレッシュトークンもすべて失効させる
+    ///
+    /// なりすましのトークンの場合は、なりすましの終了を記録する
+    #[tracing::instrument(skip(self))]
+    async fn logout(&self, input: LogoutInput) -> Result<(), UseCaseError> {
+        let LogoutInput {
+            user_id,
+            token_id,
+            session_id,
+            expires_at,
+            impersonator_id,
+        } = input;
+
+        let session_id = session_id.into();
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let refresh_token_repo = factory.refresh_token_repository();
+            let session_repo = factory.session_repository();
+            let user_repo = factory.user_repository();
+
+            end_session(
+                session_id,
+                session_repo.as_ref(),
+                refresh_token_repo.as_ref(),
+                clock.as_ref(),
+            )
+            .await?;
+
+            if let Some(impersonator_id) = impersonator_id {
+                let mut user = user_repo
+                    .find_by_id(user_id.into())
+                    .await?
+                    .ok_or(UseCaseError::NotFound)?;
+                user.record_impersonation_ended(impersonator_id.into(), session_id, clock.as_ref());
+                user_repo.save(user).await?;
+            }
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await?;
+
+        self.token_revocation_store
+            .revoke(token_id, expires_at)
+            .await?;
+
+        // 同じセッションで発行済みの他のアクセストークンも拒否する
+        deny_session_access_tokens(
+            &[session_id],
+            self.token_revocation_store.as_ref(),
+            self.token_service.as_ref(),
+            self.clock.as_ref(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// メールアドレスの確認
+    ///
+    /// 確認メールのリンクに含まれるトークンを検証し、ユーザーのメールアドレスを確認済みにする
+    #[tracing::instrument(skip(self))]
+    async fn verify_email(&self, input: VerifyEmailInput) -> Result<(), UseCaseError> {
+        // 署名と有効期限の検証 (メールアドレスの一致はドメインモデルで検証する)
+        let claim = self.email_verification_token_service.verify(&input.token)?;
+
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let mut user = user_repo
+                .find_by_id(claim.user_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            user.verify_email(&claim, clock.as_ref())?;
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    /// 確認メールの再送
+    ///
+    /// メール送信の濫用を防ぐため、ユーザーごとに一定期間内の再送回数を制限する
+    #[tracing::instrument(skip(self))]
+    async fn resend_verification_email(
+        &self,
+        input: ResendVerificationEmailInput,
+    ) -> Result<(), UseCaseError> {
+        let user_id: UserId = input.user_id.into();
+
+        let acquired = self
+            .email_verification_resend_limiter
+            .try_acquire(&user_id.to_string())
+            .await?;
+        if !acquired {
+            return Err(UseCaseError::TooManyRequests {
+                message: "確認メールの再送回数が上限に達しました。しばらく時間をおいてから再度お試しください"
+                    .to_string(),
+            });
+        }
+
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let mut user = user_repo
+                .find_by_id(user_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 確認メールの送信はリレーワーカーがイベントを処理する際に行う
+            user.request_email_verification(clock.as_ref())?;
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    /// パスワードリセットの申請
+    ///
+    /// アカウントの存在を推測されないよう、該当するユーザーがいない場合やリセットできない状態の場合も成功として扱う
+    #[tracing::instrument(skip(self))]
+    async fn request_password_reset(
+        &self,
+        input: RequestPasswordResetInput,
+    ) -> Result<(), UseCaseError> {
+        let email = UnverifiedEmail::new(&input.email)?;
+
+        let password_reset_token_service = self.password_reset_token_service.clone();
+        let password_reset_token_id_generator_factory =
+            self.password_reset_token_id_generator_factory.clone();
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let password_reset_token_repo = factory.password_reset_token_repository();
+
+            let Some(mut user) = user_repo.find_by_email(email.as_str()).await? else {
+                return Ok(());
+            };
+
+            let password_reset_token_id = password_reset_token_id_generator_factory
+                .create_password_reset_token_id_generator()
+                .generate()?;
+            let issued = password_reset_token_service.issue_reset_token(password_reset_token_id)?;
+
+            // リセットメールの送信はリレーワーカーがイベントを処理する際に行う
+            // (イベントには ID のみを含め、トークンの平文はアウトボックスに保存しない)
+            if let Err(e) = user.request_password_reset(
+                password_reset_token_id,
+                issued.expires_at,
+                clock.as_ref(),
+            ) {
+                tracing::info!(user_id = %user.id(), error = %e, "パスワードリセットを受け付けられない状態のため、申請を無視しました");
+                return Ok(());
+            }
+
+            // 有効なリセットリンクは常に最新の 1 件のみとする
+            let active_tokens = password_reset_token_repo
+                .find_active_by_user_id(user.id())
+                .await?;
+            for mut token in active_tokens {
+                token.revoke(clock.as_ref());
+                password_reset_token_repo.save(token).await?;
+            }
+
+            let password_reset_token = PasswordResetToken::issue(
+                password_reset_token_id,
+                user.id(),
+                issued.token_hash,
+                issued.expires_at,
+                clock.as_ref(),
+            );
+
+            password_reset_token_repo.save(password_reset_token).await?;
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    /// パスワードリセットの確定
+    ///
+    /// リセットメールのリンクに含まれるトークンを使用済みにし、新しいパスワードを設定する
+    ///
+    /// パスワードを知った第三者のセッションを残さないよう、ユーザーのすべてのセッションを終了させる
+    #[tracing::instrument(skip(self))]
+    async fn confirm_password_reset(
+        &self,
+        input: ConfirmPasswordResetInput,
+    ) -> Result<(), UseCaseError> {
+        let token_hash = self
+            .password_reset_token_service
+            .hash_reset_token(&input.token);
+        let new_password = RawPassword::new(&input.new_password);
+
+        let password_policy = self.password_policy.clone();
+        let password_hasher = self.password_hasher.clone();
+        let clock = self.clock.clone();
 
-        // 3. JWT トークンの生成
-        let token = self.token_service.issue_token(user.id(), user.role())?;
+        let (user_id, ended_session_ids) = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let password_reset_token_repo = factory.password_reset_token_repository();
+            let session_repo = factory.session_repository();
+            let refresh_token_repo = factory.refresh_token_repository();
+
+            // 同じトークンによる同時のリセットは、先に行をロックした方の完了後に使用済みとして扱われる
+            let mut token = password_reset_token_repo
+                .find_by_token_hash_for_update(&token_hash)
+                .await?
+                .ok_or_else(|| {
+                    UseCaseError::InvalidInput(
+                        vec![ValidationError::new("token", "リセットリンクは無効です")].into(),
+                    )
+                })?;
+
+            token.consume(clock.as_ref())?;
+
+            let mut user = user_repo
+                .find_by_id(token.user_id())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // パスワードポリシーの検証 (ユーザー名・メールアドレスの包含チェックのため、ユーザーの取得後に行う)
+            password_policy
+                .validate(&new_password, user.password_owner())
+                .map_err(|e| password_policy_violations_for_field("new_password", e))?;
+            let hashed_password = password_hasher.hash(&new_password)?;
+
+            user.reset_password(hashed_password, clock.as_ref())?;
+
+            // 同じユーザーに発行済みの他のリセットリンクも無効にする
+            let other_tokens = password_reset_token_repo
+                .find_active_by_user_id(user.id())
+                .await?;
+            for mut other in other_tokens.into_iter().filter(|t| t.id() != token.id()) {
+                other.revoke(clock.as_ref());
+                password_reset_token_repo.save(other).await?;
+            }
+
+            password_reset_token_repo.save(token).await?;
+            let user = user_repo.save(user).await?;
+
+            let ended_session_ids = end_all_sessions(
+                user.id(),
+                session_repo.as_ref(),
+                refresh_token_repo.as_ref(),
+                clock.as_ref(),
+            )
+            .await?;
+
+            Ok::<_, UseCaseError>((user.id(), ended_session_ids))
+        })
+        .await?;
+
+        // 変更前のパスワードで取得したアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(user_id).await?;
+        deny_session_access_tokens(
+            &ended_session_ids,
+            self.token_revocation_store.as_ref(),
+            self.token_service.as_ref(),
+            self.clock.as_ref(),
+        )
+        .await
+    }
+}
+
+/// メールアドレスのローカル部から、外部アカウントから作成するユーザーのユーザー名の候補を組み立てる
+fn username_from_email(email: &str) -> String {
+    let local_part = email.split('@').next().unwrap_or_default();
+    let username: String = local_part
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .take(GENERATED_USERNAME_BASE_MAX_LEN)
+        .collect();
 
-        Ok(LoginOutput { token })
+    if username.len() < 3 {
+        "user".to_string()
+    } else {
+        username
     }
 }
+
+/// 使用されていないユーザー名を探す。候補が使用済みの場合は、ランダムな接尾辞を付けて再試行する
+async fn find_available_username(
+    user_repo: &dyn UserRepository,
+    base_username: &str,
+) -> Result<String, UseCaseError> {
+    let mut candidate = base_username.to_string();
+
+    for _ in 0..GENERATED_USERNAME_ATTEMPTS {
+        if user_repo.find_by_username(&candidate).await?.is_none() {
+            return Ok(candidate);
+        }
+        candidate = format!("{base_username}_{:04x}", rand::rng().random::<u16>());
+    }
+
+    Err(UseCaseError::Conflict {
+        message: "ユーザー名を決定できませんでした。もう一度お試しください".to_string(),
+    })
+}
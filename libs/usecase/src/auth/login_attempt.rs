@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use domain::{
+    shared::service::clock::Clock,
+    transaction::TransactionManager,
+    tx,
+    user::{LoginLockoutConfig, UserId},
+};
+
+use crate::{shared::rate_limiter::RateLimiter, usecase_error::UseCaseError};
+
+/// 接続元 IP アドレス単位の失敗回数の上限に達していないことを確認する
+pub(crate) async fn ensure_ip_not_throttled(
+    login_ip_rate_limiter: &dyn RateLimiter,
+    ip_address: Option<&str>,
+) -> Result<(), UseCaseError> {
+    if let Some(ip_address) = ip_address
+        && login_ip_rate_limiter.is_limited(ip_address).await?
+    {
+        return Err(UseCaseError::TooManyRequests {
+            message: "ログインの失敗が多すぎます。しばらく時間をおいてから再度お試しください"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// パスワードの誤りを接続元 IP アドレスとアカウントのそれぞれに記録する
+///
+/// ログイン以外でパスワードを確認する場合も同じ上限を適用し、ログインの失敗回数の制限を迂回できないようにする
+pub(crate) async fn record_failed_login<TM: TransactionManager>(
+    transaction_manager: &TM,
+    login_ip_rate_limiter: &dyn RateLimiter,
+    login_lockout_config: Arc<LoginLockoutConfig>,
+    clock: Arc<dyn Clock>,
+    ip_address: Option<&str>,
+    user_id: Option<UserId>,
+) -> Result<(), UseCaseError> {
+    if let Some(ip_address) = ip_address {
+        login_ip_rate_limiter.try_acquire(ip_address).await?;
+    }
+
+    let Some(user_id) = user_id else {
+        return Ok(());
+    };
+
+    tx!(transaction_manager, |factory| {
+        let user_repo = factory.user_repository();
+
+        // 同時に失敗したリクエストの記録が上書きし合わないよう、行をロックしてから失敗回数を更新する
+        let mut user = user_repo
+            .find_by_id_for_update(user_id)
+            .await?
+            .ok_or(UseCaseError::NotFound)?;
+        user.record_failed_login(&login_lockout_config, clock.as_ref());
+
+        user_repo.save(user).await?;
+        Ok::<_, UseCaseError>(())
+    })
+    .await
+}
+
+pub(crate) fn locked_out() -> UseCaseError {
+    UseCaseError::TooManyRequests {
+        message: "ログインの失敗が続いたため、アカウントは一時的にロックされています。しばらく時間をおいてから再度お試しください"
+            .to_string(),
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// 接続元 IP アドレス単位のログイン失敗回数の制限に関する設定
+///
+/// アカウント単位のロックアウトは `LoginLockoutConfig` で設定する
+pub struct LoginThrottleConfig {
+    /// `ip_failure_window` の間に 1 つの IP アドレスから許容するログインの失敗回数
+    ip_max_failed_attempts: u32,
+
+    /// 失敗回数を数える期間
+    ip_failure_window: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum LoginThrottleConfigError {
+    #[error("Invalid configuration for LoginThrottleConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl LoginThrottleConfig {
+    pub fn new(
+        ip_max_failed_attempts: u32,
+        ip_failure_window_secs: i64,
+    ) -> Result<Self, LoginThrottleConfigError> {
+        if ip_max_failed_attempts == 0 {
+            return Err(LoginThrottleConfigError::InvalidConfig(
+                "ip_max_failed_attempts must be positive".to_string(),
+            ));
+        }
+
+        if ip_failure_window_secs <= 0 {
+            return Err(LoginThrottleConfigError::InvalidConfig(
+                "ip_failure_window_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            ip_max_failed_attempts,
+            ip_failure_window: Duration::seconds(ip_failure_window_secs),
+        })
+    }
+
+    pub fn ip_max_failed_attempts(&self) -> u32 {
+        self.ip_max_failed_attempts
+    }
+
+    pub fn ip_failure_window(&self) -> Duration {
+        self.ip_failure_window
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// メールで送信するログインリンク (マジックリンク) に関する設定
+pub struct MagicLinkConfig {
+    /// ログイントークンの有効期間。パスワードの代わりとなるため、短く設定すること
+    token_ttl: Duration,
+
+    /// ログインメールに記載するリンクのベース URL。トークンはクエリパラメータ `token` として付与する
+    login_url: String,
+}
+
+#[derive(Debug, Error)]
+pub enum MagicLinkConfigError {
+    #[error("Invalid configuration for MagicLinkConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl MagicLinkConfig {
+    pub fn new(token_ttl_secs: i64, login_url: String) -> Result<Self, MagicLinkConfigError> {
+        if token_ttl_secs <= 0 {
+            return Err(MagicLinkConfigError::InvalidConfig(
+                "token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        if login_url.is_empty() {
+            return Err(MagicLinkConfigError::InvalidConfig(
+                "login_url must not be empty".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            token_ttl: Duration::seconds(token_ttl_secs),
+            login_url,
+        })
+    }
+
+    pub fn token_ttl(&self) -> Duration {
+        self.token_ttl
+    }
+
+    pub fn login_url(&self) -> &str {
+        &self.login_url
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use chrono::DateTime;
+use domain::{
+    shared::service::clock::Clock,
+    user::{Email, UserId},
+};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        jwt_key::JwtKeySet,
+        magic_link_config::MagicLinkConfig,
+        magic_link_token_service::{MagicLinkClaim, MagicLinkTokenService},
+    },
+    usecase_error::UseCaseError,
+};
+
+// アクセストークンと取り違えられないよう、ヘッダーの typ で用途を区別する
+const MAGIC_LINK_TOKEN_TYPE: &str = "magic-link+jwt";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MagicLinkTokenClaims {
+    sub: UserId,
+    email: String,
+    jti: Uuid,
+    exp: i64,
+    iat: i64,
+}
+
+/// アクセストークンと同じ鍵で署名した JWT をログイントークンとして使用する
+///
+/// 使用済みのログイントークンは、チャレンジトークンと同様に失効ストアで管理する
+#[derive(Clone)]
+pub struct MagicLinkTokenInteractor {
+    key_set: Arc<JwtKeySet>,
+    config: Arc<MagicLinkConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MagicLinkTokenInteractor {
+    pub fn new(
+        key_set: Arc<JwtKeySet>,
+        config: Arc<MagicLinkConfig>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            key_set,
+            config,
+            clock,
+        }
+    }
+}
+
+impl MagicLinkTokenService for MagicLinkTokenInteractor {
+    fn issue_login_link(&self, user_id: UserId, email: &Email) -> Result<String, UseCaseError> {
+        let now = self.clock.now();
+        let expiration = now
+            .checked_add_signed(self.config.token_ttl())
+            .expect("valid timestamp");
+
+        let claims = MagicLinkTokenClaims {
+            sub: user_id,
+            email: email.as_str().to_string(),
+            jti: Uuid::now_v7(),
+            exp: expiration.timestamp(),
+            iat: now.timestamp(),
+        };
+
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            typ: Some(MAGIC_LINK_TOKEN_TYPE.to_string()),
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        let token = encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))?;
+
+        let url = self.config.login_url();
+        let separator = if url.contains('?') { '&' } else { '?' };
+
+        Ok(format!("{url}{separator}token={token}"))
+    }
+
+    fn verify(&self, token: &str) -> Result<MagicLinkClaim, UseCaseError> {
+        let header = decode_header(token).map_err(|_| UseCaseError::Unauthorized)?;
+
+        if header.typ.as_deref() != Some(MAGIC_LINK_TOKEN_TYPE) {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        validation.validate_aud = false;
+
+        let token_data =
+            decode::<MagicLinkTokenClaims>(token, verification_key.decoding_key(), &validation)
+                .map_err(|_| UseCaseError::Unauthorized)?;
+
+        let MagicLinkTokenClaims {
+            sub,
+            email,
+            jti,
+            exp,
+            ..
+        } = token_data.claims;
+
+        Ok(MagicLinkClaim {
+            user_id: sub,
+            email,
+            token_id: jti,
+            expires_at: DateTime::from_timestamp(exp, 0).expect("valid timestamp"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use domain::user::{EmailTrait as _, UnverifiedEmail};
+
+    use crate::auth::{
+        jwt_key::{
+            JwtAlgorithm, JwtSigningKey, JwtVerificationKey,
+            test_keys::{KEY_A_PRIVATE, KEY_A_PUBLIC},
+        },
+        mfa_challenge_token_interactor::MfaChallengeTokenInteractor,
+        mfa_challenge_token_service::MfaChallengeTokenService,
+        mfa_config::MfaConfig,
+    };
+
+    use super::*;
+
+    const LOGIN_URL: &str = "https://app.example.com/magic-link";
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn key_set() -> Arc<JwtKeySet> {
+        let signing_key =
+            JwtSigningKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PRIVATE.as_bytes()).unwrap();
+        let verification_key =
+            JwtVerificationKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PUBLIC.as_bytes())
+                .unwrap();
+        Arc::new(JwtKeySet::new(signing_key, vec![verification_key]).unwrap())
+    }
+
+    fn interactor(now: DateTime<Utc>) -> MagicLinkTokenInteractor {
+        let config = MagicLinkConfig::new(600, LOGIN_URL.to_string()).unwrap();
+        MagicLinkTokenInteractor::new(key_set(), Arc::new(config), Arc::new(FixedClock(now)))
+    }
+
+    fn email() -> Email {
+        Email::Unverified(UnverifiedEmail::new("user@example.com").unwrap())
+    }
+
+    fn token_of(link: &str) -> &str {
+        link.strip_prefix(&format!("{LOGIN_URL}?token=")).unwrap()
+    }
+
+    #[test]
+    fn test_verify_issued_login_link() {
+        let now = Utc::now();
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let link = interactor(now).issue_login_link(user_id, &email()).unwrap();
+        let claim = interactor(now).verify(token_of(&link)).unwrap();
+
+        assert_eq!(claim.user_id, user_id);
+        assert_eq!(claim.email, "user@example.com");
+        assert_eq!(
+            claim.expires_at.timestamp(),
+            (now + Duration::seconds(600)).timestamp()
+        );
+    }
+
+    #[test]
+    fn test_issue_unique_token_per_link() {
+        // 使用済みの管理はトークンごとに行うため、同じユーザーへのリンクでも ID は重複しない
+        let interactor = interactor(Utc::now());
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let first = interactor.issue_login_link(user_id, &email()).unwrap();
+        let second = interactor.issue_login_link(user_id, &email()).unwrap();
+
+        assert_ne!(
+            interactor.verify(token_of(&first)).unwrap().token_id,
+            interactor.verify(token_of(&second)).unwrap().token_id
+        );
+    }
+
+    #[test]
+    fn test_verify_expired_login_link() {
+        let link = interactor(Utc::now() - Duration::minutes(30))
+            .issue_login_link(Uuid::now_v7().into(), &email())
+            .unwrap();
+
+        let result = interactor(Utc::now()).verify(token_of(&link));
+
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_verify_rejects_other_token_types() {
+        // 同じ鍵で署名された別用途のトークンは受け付けない
+        let mfa_config = MfaConfig::new("Example App".to_string(), 300).unwrap();
+        let issued = MfaChallengeTokenInteractor::new(
+            key_set(),
+            Arc::new(mfa_config),
+            Arc::new(FixedClock(Utc::now())),
+        )
+        .issue_challenge_token(Uuid::now_v7().into())
+        .unwrap();
+
+        let result = interactor(Utc::now()).verify(&issued.token);
+
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+}
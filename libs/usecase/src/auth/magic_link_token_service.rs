@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use domain::user::{Email, UserId};
+use uuid::Uuid;
+
+use crate::usecase_error::UseCaseError;
+
+/// 署名と有効期限の検証を終えたログイントークンの内容
+#[derive(derive_more::Debug, PartialEq, Eq)]
+pub struct MagicLinkClaim {
+    pub user_id: UserId,
+    /// 送信先のメールアドレス。トークンの発行後にメールアドレスが変更されていた場合は拒否する
+    #[debug(skip)]
+    pub email: String,
+    /// ログイントークン自体を識別する ID (使用済みにする際に使用する)
+    pub token_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub trait MagicLinkTokenService: Send + Sync {
+    /// ログイントークンを発行し、ログインメールに記載するリンクを返す
+    fn issue_login_link(&self, user_id: UserId, email: &Email) -> Result<String, UseCaseError>;
+
+    /// ログイントークンの署名と有効期限を検証し、トークンの内容を返す
+    fn verify(&self, token: &str) -> Result<MagicLinkClaim, UseCaseError>;
+}
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use chrono::DateTime;
+use domain::{shared::service::clock::Clock, user::UserId};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        jwt_key::JwtKeySet,
+        mfa_challenge_token_service::{
+            IssuedMfaChallengeToken, MfaChallengeClaim, MfaChallengeTokenService,
+        },
+        mfa_config::MfaConfig,
+    },
+    usecase_error::UseCaseError,
+};
+
+// アクセストークンと取り違えられないよう、ヘッダーの typ で用途を区別する
+const MFA_CHALLENGE_TOKEN_TYPE: &str = "mfa-challenge+jwt";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MfaChallengeTokenClaims {
+    sub: UserId,
+    jti: Uuid,
+    exp: i64,
+    iat: i64,
+}
+
+/// アクセストークンと同じ鍵で署名した JWT をチャレンジトークンとして使用する
+///
+/// 使用済みのチャレンジトークンは、アクセストークンと同様に失効ストアで管理する
+#[derive(Clone)]
+pub struct MfaChallengeTokenInteractor {
+    key_set: Arc<JwtKeySet>,
+    config: Arc<MfaConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MfaChallengeTokenInteractor {
+    pub fn new(key_set: Arc<JwtKeySet>, config: Arc<MfaConfig>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            key_set,
+            config,
+            clock,
+        }
+    }
+}
+
+impl MfaChallengeTokenService for MfaChallengeTokenInteractor {
+    fn issue_challenge_token(
+        &self,
+        user_id: UserId,
+    ) -> Result<IssuedMfaChallengeToken, UseCaseError> {
+        let now = self.clock.now();
+        let ttl = self.config.challenge_token_ttl();
+        let expiration = now.checked_add_signed(ttl).expect("valid timestamp");
+
+        let claims = MfaChallengeTokenClaims {
+            sub: user_id,
+            jti: Uuid::now_v7(),
+            exp: expiration.timestamp(),
+            iat: now.timestamp(),
+        };
+
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            typ: Some(MFA_CHALLENGE_TOKEN_TYPE.to_string()),
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        let token = encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))?;
+
+        Ok(IssuedMfaChallengeToken {
+            token,
+            expires_in: ttl.num_seconds(),
+        })
+    }
+
+    fn verify_challenge_token(&self, token: &str) -> Result<MfaChallengeClaim, UseCaseError> {
+        let header = decode_header(token).map_err(|_| UseCaseError::Unauthorized)?;
+
+        if header.typ.as_deref() != Some(MFA_CHALLENGE_TOKEN_TYPE) {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        validation.validate_aud = false;
+
+        let token_data =
+            decode::<MfaChallengeTokenClaims>(token, verification_key.decoding_key(), &validation)
+                .map_err(|_| UseCaseError::Unauthorized)?;
+
+        let MfaChallengeTokenClaims { sub, jti, exp, .. } = token_data.claims;
+
+        Ok(MfaChallengeClaim {
+            user_id: sub,
+            token_id: jti,
+            expires_at: DateTime::from_timestamp(exp, 0).expect("valid timestamp"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use crate::auth::{
+        email_verification_config::EmailVerificationConfig,
+        email_verification_token_interactor::EmailVerificationTokenInteractor,
+        email_verification_token_service::EmailVerificationTokenService,
+        jwt_key::{
+            JwtAlgorithm, JwtSigningKey, JwtVerificationKey,
+            test_keys::{KEY_A_PRIVATE, KEY_A_PUBLIC},
+        },
+    };
+    use domain::user::{EmailTrait as _, UnverifiedEmail};
+
+    use super::*;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn key_set() -> Arc<JwtKeySet> {
+        let signing_key =
+            JwtSigningKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PRIVATE.as_bytes()).unwrap();
+        let verification_key =
+            JwtVerificationKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PUBLIC.as_bytes())
+                .unwrap();
+        Arc::new(JwtKeySet::new(signing_key, vec![verification_key]).unwrap())
+    }
+
+    fn interactor(now: DateTime<Utc>) -> MfaChallengeTokenInteractor {
+        let config = MfaConfig::new("Example App".to_string(), 300).unwrap();
+        MfaChallengeTokenInteractor::new(key_set(), Arc::new(config), Arc::new(FixedClock(now)))
+    }
+
+    #[test]
+    fn test_verify_issued_challenge_token() {
+        let interactor = interactor(Utc::now());
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let issued = interactor.issue_challenge_token(user_id).unwrap();
+        let claim = interactor.verify_challenge_token(&issued.token).unwrap();
+
+        assert_eq!(claim.user_id, user_id);
+        assert_eq!(issued.expires_in, 300);
+    }
+
+    #[test]
+    fn test_verify_expired_challenge_token() {
+        let issued = interactor(Utc::now() - Duration::minutes(10))
+            .issue_challenge_token(Uuid::now_v7().into())
+            .unwrap();
+
+        let result = interactor(Utc::now()).verify_challenge_token(&issued.token);
+
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_verify_rejects_other_token_types() {
+        // 同じ鍵で署名された別用途のトークンは受け付けない
+        let email_verification_config = EmailVerificationConfig::new(
+            3600,
+            "https://app.example.com/verify-email".to_string(),
+            3,
+            3600,
+        )
+        .unwrap();
+        let link = EmailVerificationTokenInteractor::new(
+            key_set(),
+            Arc::new(email_verification_config),
+            Arc::new(FixedClock(Utc::now())),
+        )
+        .issue_verification_link(
+            Uuid::now_v7().into(),
+            &UnverifiedEmail::new("user@example.com").unwrap(),
+        )
+        .unwrap();
+        let token = link
+            .strip_prefix("https://app.example.com/verify-email?token=")
+            .unwrap();
+
+        let result = interactor(Utc::now()).verify_challenge_token(token);
+
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+}
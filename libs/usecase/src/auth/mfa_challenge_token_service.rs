@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use domain::user::UserId;
+use uuid::Uuid;
+
+use crate::usecase_error::UseCaseError;
+
+/// パスワード認証に成功し、2 段階認証の認証コードを待っていることを示すチャレンジトークン
+#[derive(derive_more::Debug)]
+pub struct IssuedMfaChallengeToken {
+    #[debug(skip)]
+    pub token: String,
+    /// 有効期間(秒)
+    pub expires_in: i64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MfaChallengeClaim {
+    pub user_id: UserId,
+    /// チャレンジトークン自体を識別する ID (使用済みにする際に使用する)
+    pub token_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub trait MfaChallengeTokenService: Send + Sync {
+    fn issue_challenge_token(
+        &self,
+        user_id: UserId,
+    ) -> Result<IssuedMfaChallengeToken, UseCaseError>;
+
+    /// チャレンジトークンの署名と有効期限を検証し、トークンの内容を返す
+    fn verify_challenge_token(&self, token: &str) -> Result<MfaChallengeClaim, UseCaseError>;
+}
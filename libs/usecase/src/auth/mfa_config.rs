@@ -0,0 +1,53 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// 2 段階認証 (TOTP) とログイン時のチャレンジトークンに関する設定
+pub struct MfaConfig {
+    /// 認証アプリに表示される発行者名 (プロビジョニング URI の `issuer`)
+    totp_issuer: String,
+
+    /// パスワード認証の成功後、認証コードの入力を待つチャレンジトークンの有効期間
+    challenge_token_ttl: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum MfaConfigError {
+    #[error("Invalid configuration for MfaConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl MfaConfig {
+    pub fn new(totp_issuer: String, challenge_token_ttl_secs: i64) -> Result<Self, MfaConfigError> {
+        if totp_issuer.is_empty() {
+            return Err(MfaConfigError::InvalidConfig(
+                "totp_issuer must not be empty".to_string(),
+            ));
+        }
+
+        // issuer はプロビジョニング URI のラベルで `issuer:account` の区切りとして扱われる
+        if totp_issuer.contains(':') {
+            return Err(MfaConfigError::InvalidConfig(
+                "totp_issuer must not contain ':'".to_string(),
+            ));
+        }
+
+        if challenge_token_ttl_secs <= 0 {
+            return Err(MfaConfigError::InvalidConfig(
+                "challenge_token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            totp_issuer,
+            challenge_token_ttl: Duration::seconds(challenge_token_ttl_secs),
+        })
+    }
+
+    pub fn totp_issuer(&self) -> &str {
+        &self.totp_issuer
+    }
+
+    pub fn challenge_token_ttl(&self) -> Duration {
+        self.challenge_token_ttl
+    }
+}
@@ -1,6 +1,38 @@
+pub mod api_key_config;
+pub mod api_key_interactor;
+pub mod api_key_service;
 pub mod dto;
+pub mod email_verification_config;
+pub mod email_verification_token_interactor;
+pub mod email_verification_token_service;
 pub mod error;
 pub mod interactor;
+pub mod jwt_key;
+pub(crate) mod login_attempt;
+pub mod login_throttle_config;
+pub mod magic_link_config;
+pub mod magic_link_token_interactor;
+pub mod magic_link_token_service;
+pub mod mfa_challenge_token_interactor;
+pub mod mfa_challenge_token_service;
+pub mod mfa_config;
+pub mod oidc_client;
+pub mod oidc_config;
+pub mod oidc_interactor;
+pub mod oidc_service;
+pub(crate) mod opaque_token;
+pub mod password_reset_config;
+pub mod password_reset_token_interactor;
+pub mod password_reset_token_service;
 pub mod service;
+pub(crate) mod session_revocation;
+pub mod token_config;
+pub mod token_epoch_cache;
 pub mod token_interactor;
+pub mod token_revocation_store;
 pub mod token_service;
+pub mod totp_interactor;
+pub mod totp_service;
+pub mod webauthn_config;
+pub mod webauthn_interactor;
+pub mod webauthn_service;
@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use jsonwebtoken::jwk::JwkSet;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// ディスカバリードキュメントのうち、認可コードフローに必要な項目
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// トークンエンドポイントに送る認可コードの交換リクエスト
+#[derive(derive_more::Debug)]
+pub struct OidcTokenRequest<'a> {
+    #[debug(skip)]
+    pub code: &'a str,
+    pub redirect_uri: &'a str,
+    pub client_id: &'a str,
+    #[debug(skip)]
+    pub client_secret: &'a str,
+    /// PKCE のコード検証子
+    #[debug(skip)]
+    pub code_verifier: &'a str,
+}
+
+/// トークンエンドポイントの応答のうち、ログインに使用する項目
+#[derive(derive_more::Debug, Deserialize)]
+pub struct OidcTokenResponse {
+    #[debug(skip)]
+    pub id_token: String,
+}
+
+#[derive(Debug, Error)]
+pub enum OidcClientError {
+    #[error("ID プロバイダーへのリクエストに失敗しました: {0}")]
+    Request(#[source] anyhow::Error),
+
+    #[error("ID プロバイダーがリクエストを拒否しました (status: {status})")]
+    Rejected { status: u16 },
+
+    #[error("ID プロバイダーの応答を解釈できませんでした: {0}")]
+    InvalidResponse(#[source] anyhow::Error),
+}
+
+/// 外部 ID プロバイダーとの HTTP 通信
+#[async_trait]
+pub trait OidcClient: Send + Sync {
+    async fn fetch_provider_metadata(
+        &self,
+        discovery_url: &str,
+    ) -> Result<OidcProviderMetadata, OidcClientError>;
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, OidcClientError>;
+
+    /// 認可コードを ID トークンと交換する (クライアント認証は `client_secret_basic`)
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        request: OidcTokenRequest<'_>,
+    ) -> Result<OidcTokenResponse, OidcClientError>;
+}
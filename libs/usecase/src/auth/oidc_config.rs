@@ -0,0 +1,162 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// OpenID Connect でログインに使用する外部 ID プロバイダーの設定
+pub struct OidcProviderConfig {
+    /// URL やデータベースでプロバイダーを識別するための名前 (例: `google`)
+    id: String,
+
+    /// ID トークンの `iss` と一致する発行者の URL。ディスカバリードキュメントの取得にも使用する
+    issuer: String,
+
+    client_id: String,
+
+    client_secret: String,
+
+    /// ID プロバイダーに登録したリダイレクト先の URL
+    redirect_uri: String,
+
+    /// 要求するスコープ (`openid` を含む)
+    scopes: Vec<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum OidcConfigError {
+    #[error("Invalid configuration for OidcConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl OidcProviderConfig {
+    pub fn new(
+        id: String,
+        issuer: String,
+        client_id: String,
+        client_secret: String,
+        redirect_uri: String,
+        scopes: Vec<String>,
+    ) -> Result<Self, OidcConfigError> {
+        if id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(OidcConfigError::InvalidConfig(format!(
+                "provider id must consist of lowercase letters, digits, '-' or '_': {id}"
+            )));
+        }
+
+        // ローカルの ID プロバイダー (開発・テスト用) を除き、https を必須とする
+        for (name, url) in [("issuer", &issuer), ("redirect_uri", &redirect_uri)] {
+            if !is_secure_url(url) {
+                return Err(OidcConfigError::InvalidConfig(format!(
+                    "{name} of provider '{id}' must use https (or http://localhost): {url}"
+                )));
+            }
+        }
+
+        if client_id.is_empty() || client_secret.is_empty() {
+            return Err(OidcConfigError::InvalidConfig(format!(
+                "client_id and client_secret of provider '{id}' must not be empty"
+            )));
+        }
+
+        if !scopes.iter().any(|scope| scope == "openid") {
+            return Err(OidcConfigError::InvalidConfig(format!(
+                "scopes of provider '{id}' must include 'openid'"
+            )));
+        }
+
+        Ok(Self {
+            id,
+            issuer,
+            client_id,
+            client_secret,
+            redirect_uri,
+            scopes,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn client_secret(&self) -> &str {
+        &self.client_secret
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    /// 発行者の URL から求めたディスカバリードキュメントの URL (OpenID Connect Discovery 1.0 §4)
+    pub fn discovery_url(&self) -> String {
+        format!(
+            "{}/.well-known/openid-configuration",
+            self.issuer.trim_end_matches('/')
+        )
+    }
+}
+
+/// OpenID Connect によるログインの設定
+pub struct OidcConfig {
+    /// 設定したプロバイダーの一覧。空の場合は OpenID Connect によるログインを受け付けない
+    providers: Vec<OidcProviderConfig>,
+
+    /// 認可リクエストを開始してから、コールバックを受け付ける期間
+    state_ttl: Duration,
+}
+
+impl OidcConfig {
+    pub fn new(
+        providers: Vec<OidcProviderConfig>,
+        state_ttl_secs: i64,
+    ) -> Result<Self, OidcConfigError> {
+        if let Some((i, provider)) = providers
+            .iter()
+            .enumerate()
+            .find(|(i, provider)| providers[..*i].iter().any(|p| p.id == provider.id))
+        {
+            return Err(OidcConfigError::InvalidConfig(format!(
+                "provider id is duplicated at index {i}: {}",
+                provider.id
+            )));
+        }
+
+        if state_ttl_secs <= 0 {
+            return Err(OidcConfigError::InvalidConfig(
+                "state_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            providers,
+            state_ttl: Duration::seconds(state_ttl_secs),
+        })
+    }
+
+    pub fn provider(&self, id: &str) -> Option<&OidcProviderConfig> {
+        self.providers.iter().find(|provider| provider.id == id)
+    }
+
+    pub fn state_ttl(&self) -> Duration {
+        self.state_ttl
+    }
+}
+
+fn is_secure_url(url: &str) -> bool {
+    url.starts_with("https://")
+        || url.starts_with("http://localhost")
+        || url.starts_with("http://127.0.0.1")
+}
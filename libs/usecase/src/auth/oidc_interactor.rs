@@ -0,0 +1,377 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::DateTime;
+use domain::{
+    auth::federated_identity::{ExternalSubject, IdentityProviderId},
+    shared::service::clock::Clock,
+};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, Header, Validation, decode, decode_header, encode, jwk::JwkSet,
+};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        jwt_key::JwtKeySet,
+        oidc_client::{OidcClient, OidcClientError, OidcProviderMetadata, OidcTokenRequest},
+        oidc_config::{OidcConfig, OidcProviderConfig},
+        oidc_service::{IssuedOidcAuthorization, OidcIdentity, OidcService, OidcStateClaim},
+        opaque_token,
+    },
+    usecase_error::UseCaseError,
+};
+
+// アクセストークンと取り違えられないよう、ヘッダーの typ で用途を区別する
+const OIDC_STATE_TOKEN_TYPE: &str = "oidc-state+jwt";
+
+// ID トークンの署名として受け付けるアルゴリズム (共通鍵によるアルゴリズムは受け付けない)
+const ALLOWED_ID_TOKEN_ALGORITHMS: [Algorithm; 8] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::EdDSA,
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OidcStateTokenClaims {
+    jti: Uuid,
+    exp: i64,
+    iat: i64,
+    provider: String,
+    state: String,
+    nonce: String,
+    code_verifier: String,
+}
+
+/// ID トークンのクレームのうち、検証とログインに使用する項目
+#[derive(Debug, Deserialize)]
+struct IdTokenClaims {
+    sub: String,
+    nonce: Option<String>,
+    azp: Option<String>,
+    email: Option<String>,
+    email_verified: Option<EmailVerifiedClaim>,
+}
+
+// `email_verified` を文字列 ("true") で返すプロバイダーもあるため、両方の形式を受け付ける
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum EmailVerifiedClaim {
+    Bool(bool),
+    String(String),
+}
+
+impl EmailVerifiedClaim {
+    fn is_verified(&self) -> bool {
+        match self {
+            EmailVerifiedClaim::Bool(verified) => *verified,
+            EmailVerifiedClaim::String(verified) => verified.eq_ignore_ascii_case("true"),
+        }
+    }
+}
+
+/// ID プロバイダーとのやり取りや ID トークンの検証に失敗した理由
+///
+/// クライアントには詳細を返さず、ログにのみ出力する
+#[derive(Debug, Error)]
+enum OidcVerificationError {
+    #[error("ディスカバリードキュメントの issuer が設定と一致しません: {0}")]
+    IssuerMismatch(String),
+    #[error("認可コードの交換に失敗しました: {0}")]
+    CodeExchangeFailed(#[source] OidcClientError),
+    #[error("ID トークンのヘッダーを解釈できません")]
+    MalformedIdToken,
+    #[error("ID トークンの署名アルゴリズムは受け付けていません: {0:?}")]
+    UnsupportedAlgorithm(Algorithm),
+    #[error("ID トークンの kid に対応する公開鍵がありません: {0:?}")]
+    UnknownKey(Option<String>),
+    #[error("ID トークンの検証に失敗しました: {0}")]
+    InvalidIdToken(#[source] jsonwebtoken::errors::Error),
+    #[error("ID トークンの nonce が一致しません")]
+    NonceMismatch,
+    #[error("ID トークンの azp がクライアント ID と一致しません")]
+    AuthorizedPartyMismatch,
+}
+
+/// OpenID Connect の認可コードフロー (PKCE 付き) で外部 ID プロバイダーの認証結果を受け取る
+///
+/// 状態トークンはアクセストークンと同じ鍵で署名した JWT とし、使用済みのものは失効ストアで管理する。
+/// ディスカバリードキュメントと公開鍵は、プロバイダーの鍵のローテーションに追従するため都度取得する
+#[derive(Clone)]
+pub struct OidcInteractor {
+    key_set: Arc<JwtKeySet>,
+    config: Arc<OidcConfig>,
+    client: Arc<dyn OidcClient>,
+    clock: Arc<dyn Clock>,
+}
+
+impl OidcInteractor {
+    pub fn new(
+        key_set: Arc<JwtKeySet>,
+        config: Arc<OidcConfig>,
+        client: Arc<dyn OidcClient>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            key_set,
+            config,
+            client,
+            clock,
+        }
+    }
+
+    fn provider(&self, provider_id: &str) -> Result<&OidcProviderConfig, UseCaseError> {
+        self.config
+            .provider(provider_id)
+            .ok_or(UseCaseError::NotFound)
+    }
+
+    /// ディスカバリードキュメントを取得し、発行者が設定と一致することを確認する (OpenID Connect Discovery 1.0 §4.3)
+    async fn fetch_provider_metadata(
+        &self,
+        provider: &OidcProviderConfig,
+    ) -> Result<OidcProviderMetadata, UseCaseError> {
+        let metadata = self
+            .client
+            .fetch_provider_metadata(&provider.discovery_url())
+            .await?;
+
+        if metadata.issuer != provider.issuer() {
+            let e = OidcVerificationError::IssuerMismatch(metadata.issuer);
+            return Err(UseCaseError::Internal(e.into()));
+        }
+
+        Ok(metadata)
+    }
+
+    fn verify_id_token(
+        &self,
+        provider: &OidcProviderConfig,
+        metadata: &OidcProviderMetadata,
+        jwks: &JwkSet,
+        claim: &OidcStateClaim,
+        id_token: &str,
+    ) -> Result<IdTokenClaims, OidcVerificationError> {
+        let header =
+            decode_header(id_token).map_err(|_| OidcVerificationError::MalformedIdToken)?;
+
+        if !ALLOWED_ID_TOKEN_ALGORITHMS.contains(&header.alg) {
+            return Err(OidcVerificationError::UnsupportedAlgorithm(header.alg));
+        }
+
+        let decoding_key = find_decoding_key(jwks, &header)?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[provider.client_id()]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let token_data = decode::<IdTokenClaims>(id_token, &decoding_key, &validation)
+            .map_err(OidcVerificationError::InvalidIdToken)?;
+        let claims = token_data.claims;
+
+        // 認可リクエストに含めた nonce と一致しない ID トークンは、リプレイされたものとして拒否する
+        if claims.nonce.as_deref() != Some(claim.nonce.as_str()) {
+            return Err(OidcVerificationError::NonceMismatch);
+        }
+
+        if claims
+            .azp
+            .as_deref()
+            .is_some_and(|azp| azp != provider.client_id())
+        {
+            return Err(OidcVerificationError::AuthorizedPartyMismatch);
+        }
+
+        Ok(claims)
+    }
+}
+
+#[async_trait]
+impl OidcService for OidcInteractor {
+    async fn begin_authorization(
+        &self,
+        provider_id: &str,
+    ) -> Result<IssuedOidcAuthorization, UseCaseError> {
+        let provider = self.provider(provider_id)?;
+        let metadata = self.fetch_provider_metadata(provider).await?;
+
+        let state = opaque_token::generate();
+        let nonce = opaque_token::generate();
+        let code_verifier = opaque_token::generate();
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+        let now = self.clock.now();
+        let expiration = now
+            .checked_add_signed(self.config.state_ttl())
+            .expect("valid timestamp");
+
+        let claims = OidcStateTokenClaims {
+            jti: Uuid::now_v7(),
+            exp: expiration.timestamp(),
+            iat: now.timestamp(),
+            provider: provider.id().to_string(),
+            state: state.clone(),
+            nonce: nonce.clone(),
+            code_verifier,
+        };
+
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            typ: Some(OIDC_STATE_TOKEN_TYPE.to_string()),
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        let state_token = encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))?;
+
+        let scope = provider.scopes().join(" ");
+        let query = [
+            ("response_type", "code"),
+            ("client_id", provider.client_id()),
+            ("redirect_uri", provider.redirect_uri()),
+            ("scope", &scope),
+            ("state", &state),
+            ("nonce", &nonce),
+            ("code_challenge", &code_challenge),
+            ("code_challenge_method", "S256"),
+        ]
+        .iter()
+        .map(|(key, value)| format!("{key}={}", utf8_percent_encode(value, NON_ALPHANUMERIC)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+        // 認可エンドポイントの URL にクエリが含まれている場合はそれを残す
+        let separator = if metadata.authorization_endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+
+        Ok(IssuedOidcAuthorization {
+            authorization_url: format!("{}{separator}{query}", metadata.authorization_endpoint),
+            state_token,
+        })
+    }
+
+    fn verify_state_token(
+        &self,
+        token: &str,
+        provider_id: &str,
+        state: &str,
+    ) -> Result<OidcStateClaim, UseCaseError> {
+        let header = decode_header(token).map_err(|_| UseCaseError::Unauthorized)?;
+
+        if header.typ.as_deref() != Some(OIDC_STATE_TOKEN_TYPE) {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        validation.validate_aud = false;
+
+        let token_data =
+            decode::<OidcStateTokenClaims>(token, verification_key.decoding_key(), &validation)
+                .map_err(|_| UseCaseError::Unauthorized)?;
+
+        let OidcStateTokenClaims {
+            jti,
+            exp,
+            provider,
+            state: expected_state,
+            nonce,
+            code_verifier,
+            ..
+        } = token_data.claims;
+
+        // 別のプロバイダー向けに開始したフローや、別の認可リクエストへの応答は受け付けない
+        if provider != provider_id || expected_state != state {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        Ok(OidcStateClaim {
+            provider: IdentityProviderId::from_raw_str(&provider),
+            nonce,
+            code_verifier,
+            token_id: jti,
+            expires_at: DateTime::from_timestamp(exp, 0).expect("valid timestamp"),
+        })
+    }
+
+    async fn authenticate(
+        &self,
+        claim: &OidcStateClaim,
+        code: &str,
+    ) -> Result<OidcIdentity, UseCaseError> {
+        let provider = self.provider(claim.provider.as_str())?;
+        let metadata = self.fetch_provider_metadata(provider).await?;
+
+        let request = OidcTokenRequest {
+            code,
+            redirect_uri: provider.redirect_uri(),
+            client_id: provider.client_id(),
+            client_secret: provider.client_secret(),
+            code_verifier: &claim.code_verifier,
+        };
+
+        let verified = match self
+            .client
+            .exchange_code(&metadata.token_endpoint, request)
+            .await
+        {
+            // 認可コードの誤りや期限切れはプロバイダーが拒否する
+            Err(e @ OidcClientError::Rejected { .. }) => {
+                Err(OidcVerificationError::CodeExchangeFailed(e))
+            }
+            Err(e) => return Err(e.into()),
+            Ok(response) => {
+                let jwks = self.client.fetch_jwks(&metadata.jwks_uri).await?;
+                self.verify_id_token(provider, &metadata, &jwks, claim, &response.id_token)
+            }
+        };
+
+        let claims = verified.map_err(|e| {
+            tracing::info!(error = %e, provider = %claim.provider, "OpenID Connect による認証の検証に失敗しました");
+            UseCaseError::Unauthorized
+        })?;
+
+        Ok(OidcIdentity {
+            provider: claim.provider.clone(),
+            subject: ExternalSubject::from_raw_str(&claims.sub),
+            email_verified: claims
+                .email_verified
+                .as_ref()
+                .is_some_and(EmailVerifiedClaim::is_verified),
+            email: claims.email,
+        })
+    }
+}
+
+/// ID トークンのヘッダーの kid に対応する公開鍵
+///
+/// kid を含まない ID トークンは、プロバイダーの公開鍵が 1 つの場合に限り受け付ける
+fn find_decoding_key(jwks: &JwkSet, header: &Header) -> Result<DecodingKey, OidcVerificationError> {
+    let jwk = match header.kid.as_deref() {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| OidcVerificationError::UnknownKey(header.kid.clone()))?;
+
+    DecodingKey::from_jwk(jwk).map_err(OidcVerificationError::InvalidIdToken)
+}
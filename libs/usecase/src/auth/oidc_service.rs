@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::auth::federated_identity::{ExternalSubject, IdentityProviderId};
+use uuid::Uuid;
+
+use crate::usecase_error::UseCaseError;
+
+#[derive(derive_more::Debug)]
+pub struct IssuedOidcAuthorization {
+    /// ユーザーをリダイレクトさせる ID プロバイダーの認可エンドポイントの URL
+    pub authorization_url: String,
+    /// コールバックの受信時に `state`・認可コードとともに提示する状態トークン
+    #[debug(skip)]
+    pub state_token: String,
+}
+
+/// 状態トークンの内容
+///
+/// サーバー側で認可リクエストの状態を保持する代わりに、署名付きのトークンに nonce と PKCE のコード検証子を含める
+#[derive(derive_more::Debug)]
+pub struct OidcStateClaim {
+    pub provider: IdentityProviderId,
+    #[debug(skip)]
+    pub nonce: String,
+    #[debug(skip)]
+    pub code_verifier: String,
+    /// 状態トークン自体を識別する ID (使用済みにする際に使用する)
+    pub token_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// ID トークンの検証を終えた外部アカウントの情報
+#[derive(derive_more::Debug)]
+pub struct OidcIdentity {
+    pub provider: IdentityProviderId,
+    pub subject: ExternalSubject,
+    #[debug(skip)]
+    pub email: Option<String>,
+    /// ID プロバイダーがメールアドレスの所有を確認済みか
+    pub email_verified: bool,
+}
+
+#[async_trait]
+pub trait OidcService: Send + Sync {
+    /// 認可コードフローを開始し、認可エンドポイントの URL と状態トークンを発行する
+    async fn begin_authorization(
+        &self,
+        provider_id: &str,
+    ) -> Result<IssuedOidcAuthorization, UseCaseError>;
+
+    /// 状態トークンの署名・有効期限・プロバイダーを検証し、コールバックの `state` と照合する
+    fn verify_state_token(
+        &self,
+        token: &str,
+        provider_id: &str,
+        state: &str,
+    ) -> Result<OidcStateClaim, UseCaseError>;
+
+    /// 認可コードを ID トークンと交換し、ID トークンをプロバイダーの公開鍵で検証する
+    async fn authenticate(
+        &self,
+        claim: &OidcStateClaim,
+        code: &str,
+    ) -> Result<OidcIdentity, UseCaseError>;
+}
@@ -0,0 +1,23 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use rand::RngCore as _;
+use sha2::{Digest as _, Sha256};
+
+// 平文に含めるランダムなバイト数
+const OPAQUE_TOKEN_BYTES: usize = 32;
+
+/// 推測不可能なランダム文字列を生成する
+///
+/// リフレッシュトークンやパスワードリセットトークンなど、サーバー側でハッシュ値と照合するトークンに使用する
+pub(crate) fn generate() -> String {
+    let mut bytes = [0u8; OPAQUE_TOKEN_BYTES];
+    rand::rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// トークンのハッシュ化
+///
+/// トークン自体が十分なエントロピーを持つため、ソルトなしの SHA-256 で十分(検索にも使用する)
+pub(crate) fn hash(token: &str) -> String {
+    let digest = Sha256::digest(token.as_bytes());
+    format!("{digest:x}")
+}
@@ -0,0 +1,69 @@
+use chrono::Duration;
+use thiserror::Error;
+
+// リセットトークンを導出する鍵の最小のバイト数 (HMAC-SHA256 の出力長)
+const MIN_TOKEN_SECRET_BYTES: usize = 32;
+
+/// パスワードリセットトークンとリセットメールに関する設定
+pub struct PasswordResetConfig {
+    /// リセットトークンの有効期間。トークンの平文はメールで送信されるため、短く設定すること
+    token_ttl: Duration,
+
+    /// リセットメールに記載するリンクのベース URL。トークンはクエリパラメータ `token` として付与する
+    reset_url: String,
+
+    /// リセットトークンの ID からトークンを導出するための鍵
+    ///
+    /// データベースにはトークンのハッシュ値と ID のみが残るため、この鍵がなければトークンを復元できない
+    token_secret: Vec<u8>,
+}
+
+#[derive(Debug, Error)]
+pub enum PasswordResetConfigError {
+    #[error("Invalid configuration for PasswordResetConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl PasswordResetConfig {
+    pub fn new(
+        token_ttl_secs: i64,
+        reset_url: String,
+        token_secret: Vec<u8>,
+    ) -> Result<Self, PasswordResetConfigError> {
+        if token_ttl_secs <= 0 {
+            return Err(PasswordResetConfigError::InvalidConfig(
+                "token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        if reset_url.is_empty() {
+            return Err(PasswordResetConfigError::InvalidConfig(
+                "reset_url must not be empty".to_string(),
+            ));
+        }
+
+        if token_secret.len() < MIN_TOKEN_SECRET_BYTES {
+            return Err(PasswordResetConfigError::InvalidConfig(format!(
+                "token_secret must be at least {MIN_TOKEN_SECRET_BYTES} bytes"
+            )));
+        }
+
+        Ok(Self {
+            token_ttl: Duration::seconds(token_ttl_secs),
+            reset_url,
+            token_secret,
+        })
+    }
+
+    pub fn token_ttl(&self) -> Duration {
+        self.token_ttl
+    }
+
+    pub fn reset_url(&self) -> &str {
+        &self.reset_url
+    }
+
+    pub fn token_secret(&self) -> &[u8] {
+        &self.token_secret
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use domain::{
+    auth::password_reset_token::{PasswordResetTokenHash, PasswordResetTokenId},
+    shared::service::clock::Clock,
+};
+use hmac::{Hmac, Mac as _};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        opaque_token,
+        password_reset_config::PasswordResetConfig,
+        password_reset_token_service::{IssuedPasswordResetToken, PasswordResetTokenService},
+    },
+    usecase_error::UseCaseError,
+};
+
+/// リセットトークンの ID と鍵から導出した推測不可能な文字列を、リセットトークンとして使用する
+///
+/// 使い捨てにするため、検証はサーバー側に保存したハッシュ値との照合で行う
+#[derive(Clone)]
+pub struct PasswordResetTokenInteractor {
+    config: Arc<PasswordResetConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl PasswordResetTokenInteractor {
+    pub fn new(config: Arc<PasswordResetConfig>, clock: Arc<dyn Clock>) -> Self {
+        Self { config, clock }
+    }
+
+    /// ID に対する HMAC-SHA256 をトークンとする
+    fn derive_token(&self, token_id: PasswordResetTokenId) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.config.token_secret())
+            .expect("HMAC can take key of any size");
+        mac.update(Uuid::from(token_id).as_bytes());
+        URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+    }
+}
+
+impl PasswordResetTokenService for PasswordResetTokenInteractor {
+    fn issue_reset_token(
+        &self,
+        token_id: PasswordResetTokenId,
+    ) -> Result<IssuedPasswordResetToken, UseCaseError> {
+        let expires_at = self
+            .clock
+            .now()
+            .checked_add_signed(self.config.token_ttl())
+            .expect("valid timestamp");
+
+        Ok(IssuedPasswordResetToken {
+            token_hash: self.hash_reset_token(&self.derive_token(token_id)),
+            expires_at,
+        })
+    }
+
+    fn hash_reset_token(&self, token: &str) -> PasswordResetTokenHash {
+        PasswordResetTokenHash::from_raw_str(&opaque_token::hash(token))
+    }
+
+    fn reset_link(&self, token_id: PasswordResetTokenId) -> String {
+        let token = self.derive_token(token_id);
+        let reset_url = self.config.reset_url();
+        let separator = if reset_url.contains('?') { '&' } else { '?' };
+        format!("{reset_url}{separator}token={token}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone as _, Utc};
+
+    use super::*;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn interactor_with(reset_url: &str, secret: &[u8]) -> PasswordResetTokenInteractor {
+        let config =
+            PasswordResetConfig::new(1800, reset_url.to_string(), secret.to_vec()).unwrap();
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap();
+        PasswordResetTokenInteractor::new(Arc::new(config), Arc::new(FixedClock(now)))
+    }
+
+    fn interactor(reset_url: &str) -> PasswordResetTokenInteractor {
+        interactor_with(reset_url, SECRET)
+    }
+
+    /// リンクのクエリパラメータからトークンを取り出す
+    fn token_in(link: &str) -> &str {
+        link.split("token=").nth(1).unwrap()
+    }
+
+    #[test]
+    fn test_issue_reset_token() {
+        let interactor = interactor("https://app.example.com/reset-password");
+        let token_id: PasswordResetTokenId = Uuid::now_v7().into();
+
+        let issued = interactor.issue_reset_token(token_id).unwrap();
+
+        // 保存するハッシュ値は、メールのリンクに記載したトークンから再計算できる
+        let link = interactor.reset_link(token_id);
+        assert_eq!(
+            interactor.hash_reset_token(token_in(&link)),
+            issued.token_hash
+        );
+        assert_ne!(issued.token_hash.as_ref(), token_in(&link));
+        assert_eq!(
+            issued.expires_at,
+            Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap() + Duration::minutes(30)
+        );
+
+        // ID ごとに異なるトークンとなる
+        let other = interactor.issue_reset_token(Uuid::now_v7().into()).unwrap();
+        assert_ne!(other.token_hash, issued.token_hash);
+    }
+
+    #[test]
+    fn test_token_depends_on_secret() {
+        let token_id: PasswordResetTokenId = Uuid::now_v7().into();
+        let url = "https://app.example.com/reset-password";
+
+        // ID とハッシュ値を知っていても、鍵がなければ同じトークンを導出できない
+        let link = interactor(url).reset_link(token_id);
+        let other_link =
+            interactor_with(url, b"fedcba9876543210fedcba9876543210").reset_link(token_id);
+
+        assert_ne!(token_in(&link), token_in(&other_link));
+    }
+
+    #[test]
+    fn test_reset_link() {
+        let token_id: PasswordResetTokenId = Uuid::now_v7().into();
+
+        let link = interactor("https://app.example.com/reset-password").reset_link(token_id);
+        assert!(link.starts_with("https://app.example.com/reset-password?token="));
+
+        let link = interactor("https://app.example.com/reset?lang=ja").reset_link(token_id);
+        assert!(link.starts_with("https://app.example.com/reset?lang=ja&token="));
+    }
+
+    #[test]
+    fn test_config_rejects_short_secret() {
+        let result = PasswordResetConfig::new(
+            1800,
+            "https://app.example.com/reset-password".to_string(),
+            b"too-short".to_vec(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use domain::auth::password_reset_token::{PasswordResetTokenHash, PasswordResetTokenId};
+
+use crate::usecase_error::UseCaseError;
+
+#[derive(derive_more::Debug)]
+pub struct IssuedPasswordResetToken {
+    pub token_hash: PasswordResetTokenHash,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub trait PasswordResetTokenService: Send + Sync {
+    /// リセットトークンを発行する。サーバー側にはハッシュ値のみを保存する
+    ///
+    /// トークンの平文は ID から導出できるため、申請時には扱わず、メールの送信時にのみ導出する
+    fn issue_reset_token(
+        &self,
+        token_id: PasswordResetTokenId,
+    ) -> Result<IssuedPasswordResetToken, UseCaseError>;
+
+    fn hash_reset_token(&self, token: &str) -> PasswordResetTokenHash;
+
+    /// リセットメールに記載するリンク
+    fn reset_link(&self, token_id: PasswordResetTokenId) -> String;
+}
@@ -1,7 +1,15 @@
 use async_trait::async_trait;
 
 use crate::{
-    auth::dto::{LoginInput, LoginOutput, SignupInput, SignupOutput},
+    auth::dto::{
+        ApiKeyPrincipal, AuthenticateApiKeyInput, AuthenticateServiceAccountInput,
+        BeginOidcLoginInput, BeginOidcLoginOutput, BeginPasskeyLoginInput, BeginPasskeyLoginOutput,
+        ClientCredentialsInput, ClientCredentialsOutput, ConfirmPasswordResetInput, LoginInput,
+        LoginOutput, LoginTokens, LogoutInput, MagicLinkLoginInput, MfaLoginInput, OidcLoginInput,
+        PasskeyLoginInput, RefreshTokenInput, RefreshTokenOutput, RequestMagicLinkInput,
+        RequestPasswordResetInput, ResendVerificationEmailInput, ServiceAccountPrincipal,
+        SignupInput, SignupOutput, VerifyEmailInput, VerifyTokenEpochInput,
+    },
     usecase_error::UseCaseError,
 };
 
@@ -9,4 +17,54 @@ use crate::{
 pub trait AuthService: Send + Sync {
     async fn signup(&self, input: SignupInput) -> Result<SignupOutput, UseCaseError>;
     async fn login(&self, input: LoginInput) -> Result<LoginOutput, UseCaseError>;
+    async fn complete_mfa_login(&self, input: MfaLoginInput) -> Result<LoginTokens, UseCaseError>;
+    async fn begin_passkey_login(
+        &self,
+        input: BeginPasskeyLoginInput,
+    ) -> Result<BeginPasskeyLoginOutput, UseCaseError>;
+    async fn complete_passkey_login(
+        &self,
+        input: PasskeyLoginInput,
+    ) -> Result<LoginTokens, UseCaseError>;
+    async fn begin_oidc_login(
+        &self,
+        input: BeginOidcLoginInput,
+    ) -> Result<BeginOidcLoginOutput, UseCaseError>;
+    async fn complete_oidc_login(&self, input: OidcLoginInput)
+    -> Result<LoginOutput, UseCaseError>;
+    /// ログインリンクをメールで送信する (アカウントの存在有無は呼び出し元に伝えない)
+    async fn request_magic_link(&self, input: RequestMagicLinkInput) -> Result<(), UseCaseError>;
+    async fn complete_magic_link_login(
+        &self,
+        input: MagicLinkLoginInput,
+    ) -> Result<LoginOutput, UseCaseError>;
+    async fn refresh(&self, input: RefreshTokenInput) -> Result<RefreshTokenOutput, UseCaseError>;
+    /// アクセストークンのトークン世代番号が、ユーザーの現在の値と一致することを確認する
+    async fn verify_token_epoch(&self, input: VerifyTokenEpochInput) -> Result<(), UseCaseError>;
+    async fn authenticate_api_key(
+        &self,
+        input: AuthenticateApiKeyInput,
+    ) -> Result<ApiKeyPrincipal, UseCaseError>;
+    async fn issue_client_credentials_token(
+        &self,
+        input: ClientCredentialsInput,
+    ) -> Result<ClientCredentialsOutput, UseCaseError>;
+    async fn authenticate_service_account(
+        &self,
+        input: AuthenticateServiceAccountInput,
+    ) -> Result<ServiceAccountPrincipal, UseCaseError>;
+    async fn logout(&self, input: LogoutInput) -> Result<(), UseCaseError>;
+    async fn verify_email(&self, input: VerifyEmailInput) -> Result<(), UseCaseError>;
+    async fn resend_verification_email(
+        &self,
+        input: ResendVerificationEmailInput,
+    ) -> Result<(), UseCaseError>;
+    async fn request_password_reset(
+        &self,
+        input: RequestPasswordResetInput,
+    ) -> Result<(), UseCaseError>;
+    async fn confirm_password_reset(
+        &self,
+        input: ConfirmPasswordResetInput,
+    ) -> Result<(), UseCaseError>;
 }
@@ -0,0 +1,90 @@
+use domain::{
+    auth::{
+        refresh_token::RefreshTokenRepository,
+        session::{Session, SessionId, SessionRepository},
+    },
+    shared::service::clock::Clock,
+    user::UserId,
+};
+
+use crate::{
+    auth::{token_revocation_store::TokenRevocationStore, token_service::TokenService},
+    usecase_error::UseCaseError,
+};
+
+/// セッションを終了し、そのセッションのリフレッシュトークンをすべて失効させる (トランザクション内で呼び出す)
+///
+/// セッションの記録がない (記録の導入前に開始した) セッションも、リフレッシュトークンは失効させる
+pub(crate) async fn end_session(
+    session_id: SessionId,
+    session_repo: &dyn SessionRepository,
+    refresh_token_repo: &dyn RefreshTokenRepository,
+    clock: &dyn Clock,
+) -> Result<(), UseCaseError> {
+    if let Some(mut session) = session_repo.find_by_id(session_id).await? {
+        session.revoke(clock);
+        session_repo.save(session).await?;
+    }
+
+    let family = refresh_token_repo
+        .find_by_family_id(session_id.into())
+        .await?;
+    for mut token in family {
+        token.revoke(clock);
+        refresh_token_repo.save(token).await?;
+    }
+
+    Ok(())
+}
+
+/// ユーザーのすべてのセッションを終了し、リフレッシュトークンをすべて失効させる (トランザクション内で呼び出す)
+///
+/// 終了させたセッションの ID を返すため、コミット後に [`deny_session_access_tokens`] に渡すこと
+pub(crate) async fn end_all_sessions(
+    user_id: UserId,
+    session_repo: &dyn SessionRepository,
+    refresh_token_repo: &dyn RefreshTokenRepository,
+    clock: &dyn Clock,
+) -> Result<Vec<SessionId>, UseCaseError> {
+    let mut session_ids: Vec<SessionId> = session_repo
+        .find_active_by_user_id(user_id, clock.now())
+        .await?
+        .iter()
+        .map(Session::id)
+        .collect();
+
+    // セッションの記録がないファミリーのリフレッシュトークンも失効させる
+    for token in refresh_token_repo.find_active_by_user_id(user_id).await? {
+        let session_id: SessionId = token.family_id().into();
+        if !session_ids.contains(&session_id) {
+            session_ids.push(session_id);
+        }
+    }
+
+    for session_id in &session_ids {
+        end_session(*session_id, session_repo, refresh_token_repo, clock).await?;
+    }
+
+    Ok(session_ids)
+}
+
+/// 終了したセッションで発行済みのアクセストークンを、有効期限内であっても拒否させる (コミット後に呼び出す)
+///
+/// セッションの ID を失効ストアに登録し、アクセストークンの検証時に `sid` と照合する
+pub(crate) async fn deny_session_access_tokens(
+    session_ids: &[SessionId],
+    token_revocation_store: &dyn TokenRevocationStore,
+    token_service: &dyn TokenService,
+    clock: &dyn Clock,
+) -> Result<(), UseCaseError> {
+    // 終了後にアクセストークンは発行されないため、アクセストークンの有効期間が過ぎれば登録は不要になる
+    let expires_at = clock.now() + token_service.access_token_ttl();
+
+    for session_id in session_ids {
+        token_revocation_store
+            .revoke((*session_id).into(), expires_at)
+            .await?;
+    }
+
+    Ok(())
+}
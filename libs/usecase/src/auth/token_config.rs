@@ -0,0 +1,100 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// トークンの有効期限と、発行者・受信者の検証に関する設定
+pub struct TokenConfig {
+    /// アクセストークン(JWT)の有効期間。短くするほど漏洩時の影響を抑えられる
+    access_token_ttl: Duration,
+
+    /// リフレッシュトークンの有効期間。ローテーションのたびに発行時点から数え直す
+    refresh_token_ttl: Duration,
+
+    /// `iss` クレーム。設定した場合は発行時に付与し、検証時にも一致を要求する
+    issuer: Option<String>,
+
+    /// `aud` クレーム。設定した場合は発行時に付与し、検証時にも一致を要求する
+    audience: Option<String>,
+
+    /// ユーザーの現在のトークン世代番号をキャッシュする期間。0 の場合はリクエストのたびにデータベースから取得する
+    ///
+    /// 長くするほどデータベースへの負荷は減るが、利用停止などの反映がその分だけ遅れる場合がある
+    token_epoch_cache_ttl: Duration,
+
+    /// 管理者によるなりすましのアクセストークンの有効期間。リフレッシュトークンは発行しないため、この期間でなりすましは終了する
+    impersonation_token_ttl: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum TokenConfigError {
+    #[error("Invalid configuration for TokenConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl TokenConfig {
+    pub fn new(
+        access_token_ttl_secs: i64,
+        refresh_token_ttl_secs: i64,
+        issuer: Option<String>,
+        audience: Option<String>,
+        token_epoch_cache_ttl_secs: i64,
+        impersonation_token_ttl_secs: i64,
+    ) -> Result<Self, TokenConfigError> {
+        if access_token_ttl_secs <= 0 {
+            return Err(TokenConfigError::InvalidConfig(
+                "access_token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        if refresh_token_ttl_secs <= access_token_ttl_secs {
+            return Err(TokenConfigError::InvalidConfig(
+                "refresh_token_ttl_secs must be greater than access_token_ttl_secs".to_string(),
+            ));
+        }
+
+        if token_epoch_cache_ttl_secs < 0 || token_epoch_cache_ttl_secs >= access_token_ttl_secs {
+            return Err(TokenConfigError::InvalidConfig(
+                "token_epoch_cache_ttl_secs must be between 0 and access_token_ttl_secs (exclusive)"
+                    .to_string(),
+            ));
+        }
+
+        if impersonation_token_ttl_secs <= 0 {
+            return Err(TokenConfigError::InvalidConfig(
+                "impersonation_token_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            access_token_ttl: Duration::seconds(access_token_ttl_secs),
+            refresh_token_ttl: Duration::seconds(refresh_token_ttl_secs),
+            issuer,
+            audience,
+            token_epoch_cache_ttl: Duration::seconds(token_epoch_cache_ttl_secs),
+            impersonation_token_ttl: Duration::seconds(impersonation_token_ttl_secs),
+        })
+    }
+
+    pub fn access_token_ttl(&self) -> Duration {
+        self.access_token_ttl
+    }
+
+    pub fn refresh_token_ttl(&self) -> Duration {
+        self.refresh_token_ttl
+    }
+
+    pub fn issuer(&self) -> Option<&str> {
+        self.issuer.as_deref()
+    }
+
+    pub fn audience(&self) -> Option<&str> {
+        self.audience.as_deref()
+    }
+
+    pub fn token_epoch_cache_ttl(&self) -> Duration {
+        self.token_epoch_cache_ttl
+    }
+
+    pub fn impersonation_token_ttl(&self) -> Duration {
+        self.impersonation_token_ttl
+    }
+}
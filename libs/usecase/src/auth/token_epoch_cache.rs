@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use domain::user::UserId;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TokenEpochCacheError {
+    #[error("トークン世代番号のキャッシュの読み書きに失敗しました: {0}")]
+    Storage(#[source] anyhow::Error),
+}
+
+/// ユーザーの現在のトークン世代番号を短期間保持するキャッシュ
+///
+/// アクセストークンの検証のたびにユーザーを取得する負荷を抑えるために使用する。
+/// 世代番号を更新したインスタンスでは `invalidate` で即座に反映し、他のインスタンスにはエントリの期限切れまでに反映される
+#[async_trait]
+pub trait TokenEpochCache: Send + Sync {
+    async fn get(&self, user_id: UserId) -> Result<Option<u32>, TokenEpochCacheError>;
+    async fn put(&self, user_id: UserId, token_epoch: u32) -> Result<(), TokenEpochCacheError>;
+    async fn invalidate(&self, user_id: UserId) -> Result<(), TokenEpochCacheError>;
+}
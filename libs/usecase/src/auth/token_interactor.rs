@@ -1,54 +1,513 @@
 use std::sync::Arc;
 
 use crate::{
-    auth::token_service::{Claims, TokenService},
+    auth::{
+        jwt_key::JwtKeySet,
+        opaque_token,
+        token_config::TokenConfig,
+        token_service::{
+            AccessToken, Claims, IssuedRefreshToken, JwkSet, ServiceAccountClaims, TokenService,
+        },
+    },
     usecase_error::UseCaseError,
 };
 
-use chrono::Duration;
 use domain::{
+    auth::{
+        login_policy::AccessScope,
+        refresh_token::{RefreshTokenFamilyId, RefreshTokenHash},
+        service_account::ServiceAccountId,
+    },
     shared::service::clock::Clock,
     user::{UserId, UserRole},
 };
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use serde::{Serialize, de::DeserializeOwned};
+use uuid::Uuid;
 
 #[derive(Clone)] // Clone可能にしておく（ActixのStateで共有するため）
 pub struct TokenInteractor {
-    jwt_secret: String,
+    key_set: Arc<JwtKeySet>,
+    token_config: Arc<TokenConfig>,
     clock: Arc<dyn Clock>,
 }
 
 impl TokenInteractor {
-    pub fn new(jwt_secret: String, clock: Arc<dyn Clock>) -> Self {
-        Self { jwt_secret, clock }
+    pub fn new(key_set: Arc<JwtKeySet>, token_config: TokenConfig, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            key_set,
+            token_config: Arc::new(token_config),
+            clock,
+        }
+    }
+
+    /// 現在の署名鍵でクレームに署名する
+    fn sign(&self, claims: &impl Serialize) -> Result<String, UseCaseError> {
+        // 検証側が鍵を選べるよう、ヘッダーに kid を含める
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        encode(&header, claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))
+    }
+
+    /// 署名・有効期限・iss/aud を検証し、クレームを取り出す
+    fn verify<T: DeserializeOwned>(&self, token: &str) -> Result<T, UseCaseError> {
+        let header = decode_header(token).map_err(|_| UseCaseError::Unauthorized)?;
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        // アルゴリズムは鍵ごとに固定し、ヘッダーの alg は信用しない
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        let mut required_claims = vec!["exp"];
+        // iss/aud は設定されている場合のみ、クレームの存在と値の一致を要求する
+        if let Some(issuer) = self.token_config.issuer() {
+            validation.set_issuer(&[issuer]);
+            required_claims.push("iss");
+        }
+        match self.token_config.audience() {
+            Some(audience) => {
+                validation.set_audience(&[audience]);
+                required_claims.push("aud");
+            }
+            None => validation.validate_aud = false,
+        }
+        validation.set_required_spec_claims(&required_claims);
+
+        let token_data = decode::<T>(token, verification_key.decoding_key(), &validation)
+            .map_err(|_| UseCaseError::Unauthorized)?;
+
+        Ok(token_data.claims)
     }
 }
 
 impl TokenService for TokenInteractor {
-    /// トークンの発行 (Login時に使用)
-    fn issue_token(&self, user_id: UserId, role: UserRole) -> Result<String, UseCaseError> {
+    /// アクセストークンの発行 (Login, Refresh時に使用)
+    fn issue_access_token(
+        &self,
+        user_id: UserId,
+        role: UserRole,
+        session_id: RefreshTokenFamilyId,
+        token_epoch: u32,
+        scope: AccessScope,
+    ) -> Result<AccessToken, UseCaseError> {
         let now = self.clock.now();
+        let ttl = self.token_config.access_token_ttl();
 
-        let expiration = now
-            .checked_add_signed(Duration::hours(24))
+        let expiration = now.checked_add_signed(ttl).expect("valid timestamp");
+
+        let claims = Claims::new(
+            user_id,
+            role,
+            session_id,
+            token_epoch,
+            Uuid::now_v7(),
+            now,
+            expiration,
+        )
+        .with_access_scope(scope)
+        .with_issuer_and_audience(self.token_config.issuer(), self.token_config.audience());
+
+        Ok(AccessToken {
+            token: self.sign(&claims)?,
+            expires_in: ttl.num_seconds(),
+        })
+    }
+
+    fn access_token_ttl(&self) -> chrono::Duration {
+        self.token_config.access_token_ttl()
+    }
+
+    /// リフレッシュトークンの発行
+    ///
+    /// JWT ではなく推測不可能なランダム文字列を発行し、検証はサーバー側に保存したハッシュ値との照合で行う
+    fn issue_refresh_token(&self) -> Result<IssuedRefreshToken, UseCaseError> {
+        let token = opaque_token::generate();
+
+        let expires_at = self
+            .clock
+            .now()
+            .checked_add_signed(self.token_config.refresh_token_ttl())
             .expect("valid timestamp");
 
-        let claims = Claims::new(user_id, role, now, expiration);
+        Ok(IssuedRefreshToken {
+            token_hash: self.hash_refresh_token(&token),
+            token,
+            expires_at,
+        })
+    }
 
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
-        )
-        .map_err(|e| UseCaseError::Internal(e.into()))
+    /// リフレッシュトークンのハッシュ化
+    fn hash_refresh_token(&self, token: &str) -> RefreshTokenHash {
+        RefreshTokenHash::from_raw_str(&opaque_token::hash(token))
     }
 
     /// トークンの検証 (Middlewareで使用)
     fn verify_token(&self, token: &str) -> Result<Claims, UseCaseError> {
-        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
-        let token_data = decode::<Claims>(token, &decoding_key, &Validation::default())
-            .map_err(|_| UseCaseError::Unauthorized)?;
+        self.verify(token)
+    }
 
-        Ok(token_data.claims)
+    /// なりすましのアクセストークンの発行
+    fn issue_impersonation_token(
+        &self,
+        user_id: UserId,
+        role: UserRole,
+        impersonator_id: UserId,
+        session_id: RefreshTokenFamilyId,
+        token_epoch: u32,
+        scope: AccessScope,
+    ) -> Result<AccessToken, UseCaseError> {
+        let now = self.clock.now();
+        let ttl = self.token_config.impersonation_token_ttl();
+
+        let expiration = now.checked_add_signed(ttl).expect("valid timestamp");
+
+        let claims = Claims::new(
+            user_id,
+            role,
+            session_id,
+            token_epoch,
+            Uuid::now_v7(),
+            now,
+            expiration,
+        )
+        .with_access_scope(scope)
+        .with_impersonator(impersonator_id)
+        .with_issuer_and_audience(self.token_config.issuer(), self.token_config.audience());
+
+        Ok(AccessToken {
+            token: self.sign(&claims)?,
+            expires_in: ttl.num_seconds(),
+        })
+    }
+
+    fn issue_service_account_token(
+        &self,
+        service_account_id: ServiceAccountId,
+        role: UserRole,
+    ) -> Result<AccessToken, UseCaseError> {
+        let now = self.clock.now();
+        let ttl = self.token_config.access_token_ttl();
+
+        let expiration = now.checked_add_signed(ttl).expect("valid timestamp");
+
+        let claims =
+            ServiceAccountClaims::new(service_account_id, role, Uuid::now_v7(), now, expiration)
+                .with_issuer_and_audience(self.token_config.issuer(), self.token_config.audience());
+
+        Ok(AccessToken {
+            token: self.sign(&claims)?,
+            expires_in: ttl.num_seconds(),
+        })
+    }
+
+    fn verify_service_account_token(
+        &self,
+        token: &str,
+    ) -> Result<ServiceAccountClaims, UseCaseError> {
+        self.verify(token)
+    }
+
+    /// 検証用の公開鍵の一覧 (JWKS エンドポイントで使用)
+    fn jwks(&self) -> JwkSet {
+        self.key_set.jwks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    use crate::{
+        auth::jwt_key::{
+            JwtAlgorithm, JwtSigningKey, JwtVerificationKey,
+            test_keys::{KEY_A_PRIVATE, KEY_A_PUBLIC, KEY_B_PRIVATE, KEY_B_PUBLIC},
+        },
+        shared::identity::AccessScopeData,
+    };
+
+    use super::*;
+
+    struct SystemClock;
+
+    impl Clock for SystemClock {
+        fn now(&self) -> DateTime<Utc> {
+            Utc::now()
+        }
+    }
+
+    fn verification_key(kid: &str, public_pem: &str) -> JwtVerificationKey {
+        JwtVerificationKey::from_pem(kid, JwtAlgorithm::EdDsa, public_pem.as_bytes()).unwrap()
+    }
+
+    fn interactor(
+        signing_kid: &str,
+        signing_pem: &str,
+        verification_keys: Vec<JwtVerificationKey>,
+        issuer: Option<&str>,
+        audience: Option<&str>,
+    ) -> TokenInteractor {
+        let signing_key =
+            JwtSigningKey::from_pem(signing_kid, JwtAlgorithm::EdDsa, signing_pem.as_bytes())
+                .unwrap();
+        let key_set = JwtKeySet::new(signing_key, verification_keys).unwrap();
+        let token_config = TokenConfig::new(
+            900,
+            3600,
+            issuer.map(str::to_string),
+            audience.map(str::to_string),
+            0,
+            1800,
+        )
+        .unwrap();
+
+        TokenInteractor::new(Arc::new(key_set), token_config, Arc::new(SystemClock))
+    }
+
+    fn issue(interactor: &TokenInteractor) -> String {
+        interactor
+            .issue_access_token(
+                Uuid::now_v7().into(),
+                UserRole::User,
+                Uuid::now_v7().into(),
+                0,
+                AccessScope::Full,
+            )
+            .unwrap()
+            .token
+    }
+
+    #[test]
+    fn test_verify_token_issued_with_previous_key_after_rotation() {
+        // 旧鍵 (a) で発行されたトークン
+        let old = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let token = issue(&old);
+
+        // 署名鍵を b に切り替えた後も、a が検証鍵に残っていれば受け付ける
+        let rotated = interactor(
+            "b",
+            KEY_B_PRIVATE,
+            vec![
+                verification_key("b", KEY_B_PUBLIC),
+                verification_key("a", KEY_A_PUBLIC),
+            ],
+            None,
+            None,
+        );
+        assert!(rotated.verify_token(&token).is_ok());
+        assert!(rotated.verify_token(&issue(&rotated)).is_ok());
+
+        // a を検証鍵から取り除くと拒否される
+        let retired = interactor(
+            "b",
+            KEY_B_PRIVATE,
+            vec![verification_key("b", KEY_B_PUBLIC)],
+            None,
+            None,
+        );
+        assert!(matches!(
+            retired.verify_token(&token),
+            Err(UseCaseError::Unauthorized)
+        ));
+    }
+
+    #[test]
+    fn test_verify_token_rejects_key_with_mismatched_kid() {
+        // kid "a" として b の公開鍵が登録されている場合は署名検証に失敗する
+        let signer = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let verifier = interactor(
+            "a",
+            KEY_B_PRIVATE,
+            vec![verification_key("a", KEY_B_PUBLIC)],
+            None,
+            None,
+        );
+
+        assert!(verifier.verify_token(&issue(&signer)).is_err());
+    }
+
+    #[test]
+    fn test_verify_token_validates_issuer_and_audience() {
+        let keys = || vec![verification_key("a", KEY_A_PUBLIC)];
+        let issuer = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            keys(),
+            Some("https://auth.example.com"),
+            Some("api"),
+        );
+        let token = issue(&issuer);
+
+        assert!(issuer.verify_token(&token).is_ok());
+
+        let other_audience = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            keys(),
+            Some("https://auth.example.com"),
+            Some("other-api"),
+        );
+        assert!(other_audience.verify_token(&token).is_err());
+
+        let other_issuer = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            keys(),
+            Some("https://other.example.com"),
+            Some("api"),
+        );
+        assert!(other_issuer.verify_token(&token).is_err());
+
+        // iss/aud を付与していないトークンは、検証側で要求されていれば拒否する
+        let without_claims = issue(&interactor("a", KEY_A_PRIVATE, keys(), None, None));
+        assert!(issuer.verify_token(&without_claims).is_err());
+    }
+
+    #[test]
+    fn test_user_and_service_account_tokens_are_not_interchangeable() {
+        let interactor = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let user_token = issue(&interactor);
+        let service_account_id = Uuid::now_v7();
+        let service_account_token = interactor
+            .issue_service_account_token(service_account_id.into(), UserRole::Admin)
+            .unwrap()
+            .token;
+
+        let claims = interactor
+            .verify_service_account_token(&service_account_token)
+            .unwrap();
+        assert_eq!(claims.service_account_id(), service_account_id);
+
+        assert!(interactor.verify_token(&service_account_token).is_err());
+        assert!(
+            interactor
+                .verify_service_account_token(&user_token)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_token_epoch_is_carried_in_claims() {
+        let interactor = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let token = interactor
+            .issue_access_token(
+                Uuid::now_v7().into(),
+                UserRole::User,
+                Uuid::now_v7().into(),
+                3,
+                AccessScope::Full,
+            )
+            .unwrap()
+            .token;
+
+        let claims = interactor.verify_token(&token).unwrap();
+        assert_eq!(claims.token_epoch(), 3);
+    }
+
+    #[test]
+    fn test_access_scope_is_carried_in_claims() {
+        let interactor = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let token = interactor
+            .issue_access_token(
+                Uuid::now_v7().into(),
+                UserRole::User,
+                Uuid::now_v7().into(),
+                0,
+                AccessScope::PendingVerification,
+            )
+            .unwrap()
+            .token;
+
+        let claims = interactor.verify_token(&token).unwrap();
+        assert_eq!(claims.access_scope(), AccessScopeData::PendingVerification);
+    }
+
+    #[test]
+    fn test_impersonator_is_carried_in_claims() {
+        let interactor = interactor(
+            "a",
+            KEY_A_PRIVATE,
+            vec![verification_key("a", KEY_A_PUBLIC)],
+            None,
+            None,
+        );
+        let user_id = Uuid::now_v7();
+        let impersonator_id = Uuid::now_v7();
+
+        let access_token = interactor
+            .issue_impersonation_token(
+                user_id.into(),
+                UserRole::User,
+                impersonator_id.into(),
+                Uuid::now_v7().into(),
+                0,
+                AccessScope::Full,
+            )
+            .unwrap();
+        assert_eq!(access_token.expires_in, 1800);
+
+        let claims = interactor.verify_token(&access_token.token).unwrap();
+        assert_eq!(claims.user_id(), user_id);
+        assert_eq!(claims.impersonator_id(), Some(impersonator_id));
+
+        // 通常のアクセストークンにはなりすましの主体を含めない
+        let claims = interactor.verify_token(&issue(&interactor)).unwrap();
+        assert_eq!(claims.impersonator_id(), None);
+    }
+
+    #[test]
+    fn test_jwks_exposes_all_verification_keys() {
+        let interactor = interactor(
+            "b",
+            KEY_B_PRIVATE,
+            vec![
+                verification_key("b", KEY_B_PUBLIC),
+                verification_key("a", KEY_A_PUBLIC),
+            ],
+            None,
+            None,
+        );
+
+        let jwks = interactor.jwks();
+        assert!(jwks.find("a").is_some());
+        assert!(jwks.find("b").is_some());
+        assert_eq!(jwks.keys.len(), 2);
     }
 }
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum TokenRevocationStoreError {
+    #[error("トークンの失効情報の読み書きに失敗しました: {0}")]
+    Storage(#[source] anyhow::Error),
+}
+
+/// 有効期限前に失効させたアクセストークンの `jti` を保持するストア (denylist)
+///
+/// 有効期限を過ぎたエントリは署名検証の時点で弾かれるため、実装側で自動的に削除してよい
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    async fn revoke(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), TokenRevocationStoreError>;
+    async fn is_revoked(&self, token_id: Uuid) -> Result<bool, TokenRevocationStoreError>;
+    /// 失効済みでなければ失効させ、今回の呼び出しで失効させた場合に `true` を返す
+    ///
+    /// 一度だけ使用できるトークンを使用済みにする際に使う。確認と書き込みを不可分に行うため、
+    /// 同じトークンで並行してリクエストされても `true` を受け取るのは 1 件のみとなる
+    async fn revoke_if_absent(
+        &self,
+        token_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, TokenRevocationStoreError>;
+}
@@ -1,28 +1,92 @@
-use chrono::{DateTime, Utc};
-use domain::user::{UserId, UserRole};
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    auth::{
+        login_policy::AccessScope,
+        refresh_token::{RefreshTokenFamilyId, RefreshTokenHash},
+        service_account::ServiceAccountId,
+    },
+    user::{UserId, UserRole},
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{shared::identity::UserRoleData, usecase_error::UseCaseError};
+pub use jsonwebtoken::jwk::{Jwk, JwkSet};
+
+use crate::{
+    shared::identity::{AccessScopeData, UserRoleData},
+    usecase_error::UseCaseError,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     sub: UserId,
     role: UserRole,
+    sid: RefreshTokenFamilyId,
+    /// 発行時点のユーザーのトークン世代番号
+    ///
+    /// この値を持たない (世代番号の導入前に発行された) トークンは、初期値の 0 として扱う
+    #[serde(default)]
+    epoch: u32,
+    /// 許可する操作の範囲
+    ///
+    /// この値を持たない (導入前に発行された) トークンは、制限のないトークンとして扱う
+    #[serde(default)]
+    scope: AccessScope,
+    /// 管理者によるなりすましの場合に、実際に操作している管理者 (RFC 8693 §4.1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    act: Option<ActorClaim>,
+    jti: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aud: Option<String>,
     exp: i64,
     iat: i64,
 }
 
 impl Claims {
-    pub(crate) fn new(sub: UserId, role: UserRole, iat: DateTime<Utc>, exp: DateTime<Utc>) -> Self {
+    pub(crate) fn new(
+        sub: UserId,
+        role: UserRole,
+        sid: RefreshTokenFamilyId,
+        epoch: u32,
+        jti: Uuid,
+        iat: DateTime<Utc>,
+        exp: DateTime<Utc>,
+    ) -> Self {
         Self {
             sub,
             role,
+            sid,
+            epoch,
+            scope: AccessScope::Full,
+            act: None,
+            jti,
+            iss: None,
+            aud: None,
             exp: exp.timestamp(),
             iat: iat.timestamp(),
         }
     }
 
+    pub(crate) fn with_access_scope(mut self, scope: AccessScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    pub(crate) fn with_impersonator(mut self, impersonator_id: UserId) -> Self {
+        self.act = Some(ActorClaim {
+            sub: impersonator_id,
+        });
+        self
+    }
+
+    pub(crate) fn with_issuer_and_audience(mut self, iss: Option<&str>, aud: Option<&str>) -> Self {
+        self.iss = iss.map(str::to_string);
+        self.aud = aud.map(str::to_string);
+        self
+    }
+
     pub fn user_id(&self) -> Uuid {
         self.sub.into()
     }
@@ -30,9 +94,155 @@ impl Claims {
     pub fn user_role(&self) -> UserRoleData {
         self.role.into()
     }
+
+    /// このアクセストークンの発行元となったログインセッション(リフレッシュトークンのファミリー)の ID
+    pub fn session_id(&self) -> Uuid {
+        self.sid.into()
+    }
+
+    /// 発行時点のユーザーのトークン世代番号。現在の値と一致しないトークンは拒否する
+    pub fn token_epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// 許可する操作の範囲。メールアドレスの認証前に発行したトークンは、認証に必要な操作のみ許可する
+    pub fn access_scope(&self) -> AccessScopeData {
+        self.scope.into()
+    }
+
+    /// なりすましのトークンの場合に、実際に操作している管理者の ID
+    pub fn impersonator_id(&self) -> Option<Uuid> {
+        self.act.as_ref().map(|act| act.sub.into())
+    }
+
+    /// アクセストークン自体を識別する ID (失効管理に使用する)
+    pub fn token_id(&self) -> Uuid {
+        self.jti
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.exp, 0).expect("valid timestamp")
+    }
+}
+
+/// トークンの主体に代わって実際に操作している主体 (`act` クレーム)
+#[derive(Debug, Serialize, Deserialize)]
+struct ActorClaim {
+    sub: UserId,
+}
+
+/// サービスアカウントに発行したアクセストークンのクレーム
+///
+/// ユーザーのアクセストークンとは `sid` (ログインセッション) と `client_id` の有無で区別され、互いの検証には通らない
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ServiceAccountClaims {
+    sub: ServiceAccountId,
+    /// トークンを取得したクライアントの ID (RFC 9068 §2.2)。サービスアカウントの ID と同じ値
+    client_id: String,
+    role: UserRole,
+    jti: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    iss: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aud: Option<String>,
+    exp: i64,
+    iat: i64,
+}
+
+impl ServiceAccountClaims {
+    pub(crate) fn new(
+        sub: ServiceAccountId,
+        role: UserRole,
+        jti: Uuid,
+        iat: DateTime<Utc>,
+        exp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            sub,
+            client_id: sub.to_string(),
+            role,
+            jti,
+            iss: None,
+            aud: None,
+            exp: exp.timestamp(),
+            iat: iat.timestamp(),
+        }
+    }
+
+    pub(crate) fn with_issuer_and_audience(mut self, iss: Option<&str>, aud: Option<&str>) -> Self {
+        self.iss = iss.map(str::to_string);
+        self.aud = aud.map(str::to_string);
+        self
+    }
+
+    pub fn service_account_id(&self) -> Uuid {
+        self.sub.into()
+    }
+
+    pub fn role(&self) -> UserRoleData {
+        self.role.into()
+    }
+
+    /// アクセストークン自体を識別する ID (失効管理に使用する)
+    pub fn token_id(&self) -> Uuid {
+        self.jti
+    }
+}
+
+/// 発行済みのアクセストークン
+pub struct AccessToken {
+    pub token: String,
+    /// 有効期間(秒)
+    pub expires_in: i64,
+}
+
+/// 新たに発行したリフレッシュトークン
+///
+/// 平文の `token` はクライアントに返却し、サーバー側には `token_hash` のみを保存する
+pub struct IssuedRefreshToken {
+    pub token: String,
+    pub token_hash: RefreshTokenHash,
+    pub expires_at: DateTime<Utc>,
 }
 
 pub trait TokenService: Send + Sync {
-    fn issue_token(&self, user_id: UserId, role: UserRole) -> Result<String, UseCaseError>;
+    fn issue_access_token(
+        &self,
+        user_id: UserId,
+        role: UserRole,
+        session_id: RefreshTokenFamilyId,
+        token_epoch: u32,
+        scope: AccessScope,
+    ) -> Result<AccessToken, UseCaseError>;
+    /// アクセストークンの有効期間
+    fn access_token_ttl(&self) -> Duration;
+    fn issue_refresh_token(&self) -> Result<IssuedRefreshToken, UseCaseError>;
+    fn hash_refresh_token(&self, token: &str) -> RefreshTokenHash;
     fn verify_token(&self, token: &str) -> Result<Claims, UseCaseError>;
+    /// 管理者によるなりすましのアクセストークンの発行
+    ///
+    /// リフレッシュトークンは発行せず、有効期限が切れた時点でなりすましを終了する
+    fn issue_impersonation_token(
+        &self,
+        user_id: UserId,
+        role: UserRole,
+        impersonator_id: UserId,
+        session_id: RefreshTokenFamilyId,
+        token_epoch: u32,
+        scope: AccessScope,
+    ) -> Result<AccessToken, UseCaseError>;
+    /// サービスアカウントのアクセストークンの発行 (クライアントクレデンシャルグラントで使用)
+    ///
+    /// リフレッシュトークンは発行せず、期限が切れた場合はクライアントが再度取得する
+    fn issue_service_account_token(
+        &self,
+        service_account_id: ServiceAccountId,
+        role: UserRole,
+    ) -> Result<AccessToken, UseCaseError>;
+    fn verify_service_account_token(
+        &self,
+        token: &str,
+    ) -> Result<ServiceAccountClaims, UseCaseError>;
+    /// 検証用の公開鍵の一覧 (JWKS)
+    fn jwks(&self) -> JwkSet;
 }
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use data_encoding::BASE32_NOPAD;
+use domain::{
+    shared::service::clock::Clock,
+    user::{RecoveryCodeHash, TotpSecret},
+};
+use hmac::{Hmac, Mac as _};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use rand::{Rng as _, RngCore as _};
+use sha1::Sha1;
+use subtle::ConstantTimeEq as _;
+
+use crate::auth::{
+    mfa_config::MfaConfig,
+    opaque_token,
+    totp_service::{IssuedRecoveryCodes, TotpService},
+};
+
+// RFC 4226 の推奨に従い、シークレットは 160 ビットとする
+const TOTP_SECRET_BYTES: usize = 20;
+// 多くの認証アプリが前提とする既定値 (SHA-1・6 桁・30 秒)
+const TOTP_DIGITS: u32 = 6;
+const TOTP_PERIOD_SECS: i64 = 30;
+// 端末の時刻のずれとして許容するステップ数
+const TOTP_ALLOWED_SKEW: u64 = 1;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+// 書き写しやすいよう、紛らわしい文字 (0/o, 1/l/i) を除いた英小文字と数字を使用する
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+const RECOVERY_CODE_GROUP_LEN: usize = 5;
+
+/// RFC 6238 の TOTP による 2 段階認証の実装
+#[derive(Clone)]
+pub struct TotpInteractor {
+    config: Arc<MfaConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl TotpInteractor {
+    pub fn new(config: Arc<MfaConfig>, clock: Arc<dyn Clock>) -> Self {
+        Self { config, clock }
+    }
+
+    fn current_step(&self) -> u64 {
+        u64::try_from(self.clock.now().timestamp() / TOTP_PERIOD_SECS).unwrap_or_default()
+    }
+}
+
+/// RFC 4226 の HOTP 値を計算する
+fn hotp(key: &[u8], counter: u64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(&counter.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+
+    // 動的切り捨て
+    let offset = usize::from(digest[digest.len() - 1] & 0x0f);
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+
+    binary % 10u32.pow(TOTP_DIGITS)
+}
+
+/// 入力揺れを吸収するため、区切り文字と空白を取り除き小文字に揃える
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+impl TotpService for TotpInteractor {
+    fn generate_secret(&self) -> TotpSecret {
+        let mut bytes = [0u8; TOTP_SECRET_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        TotpSecret::from_raw_str(&BASE32_NOPAD.encode(&bytes))
+    }
+
+    fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String {
+        let issuer = utf8_percent_encode(self.config.totp_issuer(), NON_ALPHANUMERIC);
+        let account_name = utf8_percent_encode(account_name, NON_ALPHANUMERIC);
+
+        format!(
+            "otpauth://totp/{issuer}:{account_name}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={TOTP_DIGITS}&period={TOTP_PERIOD_SECS}",
+            secret = secret.as_str(),
+        )
+    }
+
+    fn verify_code(&self, secret: &TotpSecret, code: &str) -> Option<u64> {
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        if code.len() != TOTP_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let key = BASE32_NOPAD.decode(secret.as_str().as_bytes()).ok()?;
+        let current_step = self.current_step();
+
+        (current_step.saturating_sub(TOTP_ALLOWED_SKEW)..=current_step + TOTP_ALLOWED_SKEW).find(
+            |step| {
+                let expected = format!(
+                    "{:0width$}",
+                    hotp(&key, *step),
+                    width = TOTP_DIGITS as usize
+                );
+                bool::from(expected.as_bytes().ct_eq(code.as_bytes()))
+            },
+        )
+    }
+
+    fn issue_recovery_codes(&self) -> IssuedRecoveryCodes {
+        let mut rng = rand::rng();
+
+        let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| {
+                let chars: String = (0..RECOVERY_CODE_GROUP_LEN * 2)
+                    .map(|_| {
+                        char::from(
+                            RECOVERY_CODE_ALPHABET
+                                [rng.random_range(0..RECOVERY_CODE_ALPHABET.len())],
+                        )
+                    })
+                    .collect();
+                let (first, second) = chars.split_at(RECOVERY_CODE_GROUP_LEN);
+                format!("{first}-{second}")
+            })
+            .collect();
+
+        IssuedRecoveryCodes {
+            code_hashes: codes
+                .iter()
+                .map(|code| self.hash_recovery_code(code))
+                .collect(),
+            codes,
+        }
+    }
+
+    fn hash_recovery_code(&self, code: &str) -> RecoveryCodeHash {
+        RecoveryCodeHash::from_raw_str(&opaque_token::hash(&normalize_recovery_code(code)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone as _, Utc};
+
+    use super::*;
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    // RFC 6238 付録 B の SHA-1 用テストシークレット ("12345678901234567890")
+    const RFC6238_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    fn interactor_at(timestamp: i64) -> TotpInteractor {
+        let config = MfaConfig::new("Example App".to_string(), 300).unwrap();
+        let now = Utc.timestamp_opt(timestamp, 0).unwrap();
+        TotpInteractor::new(Arc::new(config), Arc::new(FixedClock(now)))
+    }
+
+    #[test]
+    fn test_hotp_matches_rfc6238_test_vectors() {
+        let key = BASE32_NOPAD.decode(RFC6238_SECRET.as_bytes()).unwrap();
+
+        // RFC 6238 のテストベクタ (8 桁) の下 6 桁
+        for (timestamp, expected) in [
+            (59, 287082),
+            (1111111109, 81804),
+            (1234567890, 5924),
+            (2000000000, 279037),
+        ] {
+            assert_eq!(hotp(&key, timestamp / 30), expected);
+        }
+    }
+
+    #[test]
+    fn test_verify_code() {
+        let secret = TotpSecret::from_raw_str(RFC6238_SECRET);
+        let interactor = interactor_at(1111111109);
+
+        assert_eq!(
+            interactor.verify_code(&secret, "081804"),
+            Some(1111111109 / 30)
+        );
+        // 1 ステップ前のコードは時刻のずれとして受け付ける
+        assert_eq!(
+            interactor_at(1111111109 + 30).verify_code(&secret, "081 804"),
+            Some(1111111109 / 30)
+        );
+        // 2 ステップ以上離れたコードや不正な形式は拒否する
+        assert_eq!(
+            interactor_at(1111111109 + 60).verify_code(&secret, "081804"),
+            None
+        );
+        assert_eq!(interactor.verify_code(&secret, "81804"), None);
+        assert_eq!(interactor.verify_code(&secret, "abcdef"), None);
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let interactor = interactor_at(0);
+        let secret = TotpSecret::from_raw_str("JBSWY3DPEHPK3PXP");
+
+        assert_eq!(
+            interactor.provisioning_uri(&secret, "user@example.com"),
+            "otpauth://totp/Example%20App:user%40example%2Ecom?secret=JBSWY3DPEHPK3PXP&issuer=Example%20App&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_generated_secret_can_be_verified() {
+        let interactor = interactor_at(1111111109);
+        let secret = interactor.generate_secret();
+
+        let key = BASE32_NOPAD.decode(secret.as_str().as_bytes()).unwrap();
+        assert_eq!(key.len(), TOTP_SECRET_BYTES);
+
+        let code = format!("{:06}", hotp(&key, 1111111109 / 30));
+        assert_eq!(
+            interactor.verify_code(&secret, &code),
+            Some(1111111109 / 30)
+        );
+    }
+
+    #[test]
+    fn test_issue_recovery_codes() {
+        let interactor = interactor_at(0);
+
+        let issued = interactor.issue_recovery_codes();
+
+        assert_eq!(issued.codes.len(), RECOVERY_CODE_COUNT);
+        assert_eq!(issued.code_hashes.len(), RECOVERY_CODE_COUNT);
+        for (code, hash) in issued.codes.iter().zip(&issued.code_hashes) {
+            // 区切り文字や大文字・小文字の違いがあっても同じハッシュ値になる
+            let typed = code.replace('-', "").to_uppercase();
+            assert_eq!(&interactor.hash_recovery_code(&typed), hash);
+        }
+    }
+}
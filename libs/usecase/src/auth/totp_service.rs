@@ -0,0 +1,29 @@
+use domain::user::{RecoveryCodeHash, TotpSecret};
+
+/// 2 段階認証の確定時に発行するリカバリーコード
+///
+/// 平文の `codes` は一度だけ本人に提示し、サーバー側には `code_hashes` のみを保存する
+#[derive(derive_more::Debug)]
+pub struct IssuedRecoveryCodes {
+    #[debug(skip)]
+    pub codes: Vec<String>,
+    pub code_hashes: Vec<RecoveryCodeHash>,
+}
+
+pub trait TotpService: Send + Sync {
+    /// 新しい共有シークレットを生成する
+    fn generate_secret(&self) -> TotpSecret;
+
+    /// 認証アプリに登録するためのプロビジョニング URI (`otpauth://totp/...`)
+    fn provisioning_uri(&self, secret: &TotpSecret, account_name: &str) -> String;
+
+    /// 認証コードを検証し、一致したタイムステップを返す
+    ///
+    /// 端末の時刻のずれを考慮し、前後 1 ステップのコードも受け付ける
+    fn verify_code(&self, secret: &TotpSecret, code: &str) -> Option<u64>;
+
+    /// 認証アプリを利用できなくなった場合に使用する、使い捨てのリカバリーコードを発行する
+    fn issue_recovery_codes(&self) -> IssuedRecoveryCodes;
+
+    fn hash_recovery_code(&self, code: &str) -> RecoveryCodeHash;
+}
@@ -0,0 +1,95 @@
+use chrono::Duration;
+use thiserror::Error;
+
+/// パスキー (WebAuthn) の Relying Party とセレモニーに関する設定
+pub struct WebAuthnConfig {
+    /// Relying Party ID。パスキーはこのドメイン (およびそのサブドメイン) に紐づく
+    rp_id: String,
+
+    /// 認証器に表示されるサービス名
+    rp_name: String,
+
+    /// クライアントデータの `origin` として受け付けるオリジンの一覧
+    allowed_origins: Vec<String>,
+
+    /// 登録・認証のオプションを発行してから、認証器の応答を受け付ける期間
+    challenge_ttl: Duration,
+}
+
+#[derive(Debug, Error)]
+pub enum WebAuthnConfigError {
+    #[error("Invalid configuration for WebAuthnConfig: {0}")]
+    InvalidConfig(String),
+}
+
+impl WebAuthnConfig {
+    pub fn new(
+        rp_id: String,
+        rp_name: String,
+        allowed_origins: Vec<String>,
+        challenge_ttl_secs: i64,
+    ) -> Result<Self, WebAuthnConfigError> {
+        // RP ID はスキームやポートを含まないドメイン名
+        if rp_id.is_empty() || rp_id.contains(['/', ':']) {
+            return Err(WebAuthnConfigError::InvalidConfig(
+                "rp_id must be a domain name without scheme or port".to_string(),
+            ));
+        }
+
+        if rp_name.is_empty() {
+            return Err(WebAuthnConfigError::InvalidConfig(
+                "rp_name must not be empty".to_string(),
+            ));
+        }
+
+        if allowed_origins.is_empty() {
+            return Err(WebAuthnConfigError::InvalidConfig(
+                "allowed_origins must not be empty".to_string(),
+            ));
+        }
+
+        // クライアントデータの origin は末尾のスラッシュを含まないため、完全一致で比較できる形に限る
+        if let Some(origin) = allowed_origins.iter().find(|origin| {
+            !origin.starts_with("https://") && !origin.starts_with("http://localhost")
+        }) {
+            return Err(WebAuthnConfigError::InvalidConfig(format!(
+                "origin must use https (or http://localhost): {origin}"
+            )));
+        }
+
+        if let Some(origin) = allowed_origins.iter().find(|origin| origin.ends_with('/')) {
+            return Err(WebAuthnConfigError::InvalidConfig(format!(
+                "origin must not end with '/': {origin}"
+            )));
+        }
+
+        if challenge_ttl_secs <= 0 {
+            return Err(WebAuthnConfigError::InvalidConfig(
+                "challenge_ttl_secs must be positive".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            rp_id,
+            rp_name,
+            allowed_origins,
+            challenge_ttl: Duration::seconds(challenge_ttl_secs),
+        })
+    }
+
+    pub fn rp_id(&self) -> &str {
+        &self.rp_id
+    }
+
+    pub fn rp_name(&self) -> &str {
+        &self.rp_name
+    }
+
+    pub fn is_allowed_origin(&self, origin: &str) -> bool {
+        self.allowed_origins.iter().any(|allowed| allowed == origin)
+    }
+
+    pub fn challenge_ttl(&self) -> Duration {
+        self.challenge_ttl
+    }
+}
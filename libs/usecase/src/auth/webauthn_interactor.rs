@@ -0,0 +1,1076 @@
+use std::sync::Arc;
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::DateTime;
+use ciborium::Value;
+use domain::{
+    auth::webauthn_credential::{CredentialId, CredentialPublicKey},
+    shared::service::clock::Clock,
+    user::UserId,
+};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
+use p256::ecdsa::signature::Verifier as _;
+use rand::RngCore as _;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{
+    auth::{
+        jwt_key::JwtKeySet,
+        webauthn_config::WebAuthnConfig,
+        webauthn_service::{
+            AssertionCredential, AuthenticatorSelection, IssuedAuthenticationChallenge,
+            IssuedRegistrationChallenge, PublicKeyCredentialCreationOptions,
+            PublicKeyCredentialDescriptor, PublicKeyCredentialParameters,
+            PublicKeyCredentialRequestOptions, PublicKeyCredentialUser, RegistrationCredential,
+            RelyingParty, VerifiedAssertion, VerifiedRegistration, WebAuthnCeremony,
+            WebAuthnCeremonyClaim, WebAuthnService, WebAuthnUser,
+        },
+    },
+    usecase_error::{UseCaseError, ValidationError},
+};
+
+// アクセストークンと取り違えられないよう、ヘッダーの typ で用途を区別する
+const WEBAUTHN_CEREMONY_TOKEN_TYPE: &str = "webauthn-ceremony+jwt";
+
+// チャレンジに含めるランダムなバイト数
+const CHALLENGE_BYTES: usize = 32;
+
+const PUBLIC_KEY_CREDENTIAL_TYPE: &str = "public-key";
+
+// COSE アルゴリズム識別子
+const COSE_ALG_ES256: i64 = -7;
+const COSE_ALG_EDDSA: i64 = -8;
+const COSE_ALG_RS256: i64 = -257;
+
+// authenticatorData のフラグ
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WebAuthnCeremonyTokenClaims {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<UserId>,
+    jti: Uuid,
+    exp: i64,
+    iat: i64,
+    ceremony: WebAuthnCeremony,
+    challenge: String,
+    uv: bool,
+}
+
+/// 認証器の応答の検証に失敗した理由
+///
+/// クライアントには詳細を返さず、ログにのみ出力する
+#[derive(Debug, Error)]
+enum WebAuthnVerificationError {
+    #[error("Base64URL としてデコードできない値が含まれています")]
+    InvalidEncoding,
+    #[error("クライアントデータの形式が不正です")]
+    MalformedClientData,
+    #[error("クライアントデータの type が一致しません: {0}")]
+    CeremonyTypeMismatch(String),
+    #[error("チャレンジが一致しません")]
+    ChallengeMismatch,
+    #[error("許可されていないオリジンです: {0}")]
+    OriginNotAllowed(String),
+    #[error("クロスオリジンの iframe からのセレモニーは受け付けません")]
+    CrossOrigin,
+    #[error("attestation object の形式が不正です")]
+    MalformedAttestationObject,
+    #[error("サポートされていない attestation の形式です: {0}")]
+    UnsupportedAttestationFormat(String),
+    #[error("authenticatorData の形式が不正です")]
+    MalformedAuthenticatorData,
+    #[error("RP ID のハッシュ値が一致しません")]
+    RpIdMismatch,
+    #[error("ユーザーの存在確認 (UP) が行われていません")]
+    UserNotPresent,
+    #[error("ユーザー検証 (UV) が行われていません")]
+    UserNotVerified,
+    #[error("クレデンシャル ID が一致しません")]
+    CredentialIdMismatch,
+    #[error("公開鍵の形式が不正、またはサポートされていないアルゴリズムです")]
+    UnsupportedPublicKey,
+    #[error("ユーザーハンドルが一致しません")]
+    UserHandleMismatch,
+    #[error("署名が一致しません")]
+    InvalidSignature,
+}
+
+/// アクセストークンと同じ鍵で署名した JWT をセレモニートークンとして使用する
+///
+/// 認証器の応答は attestation の形式 `none` (認証器の証明書を要求しない) のみ受け付け、
+/// 公開鍵のアルゴリズムは ES256・EdDSA (Ed25519)・RS256 に対応する
+#[derive(Clone)]
+pub struct WebAuthnInteractor {
+    key_set: Arc<JwtKeySet>,
+    config: Arc<WebAuthnConfig>,
+    clock: Arc<dyn Clock>,
+}
+
+impl WebAuthnInteractor {
+    pub fn new(
+        key_set: Arc<JwtKeySet>,
+        config: Arc<WebAuthnConfig>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            key_set,
+            config,
+            clock,
+        }
+    }
+
+    fn issue_ceremony_token(
+        &self,
+        ceremony: WebAuthnCeremony,
+        user_id: Option<UserId>,
+        user_verification_required: bool,
+    ) -> Result<(String, String), UseCaseError> {
+        let mut bytes = [0u8; CHALLENGE_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        let challenge = URL_SAFE_NO_PAD.encode(bytes);
+
+        let now = self.clock.now();
+        let expiration = now
+            .checked_add_signed(self.config.challenge_ttl())
+            .expect("valid timestamp");
+
+        let claims = WebAuthnCeremonyTokenClaims {
+            sub: user_id,
+            jti: Uuid::now_v7(),
+            exp: expiration.timestamp(),
+            iat: now.timestamp(),
+            ceremony,
+            challenge: challenge.clone(),
+            uv: user_verification_required,
+        };
+
+        let signing_key = self.key_set.signing_key();
+        let header = Header {
+            typ: Some(WEBAUTHN_CEREMONY_TOKEN_TYPE.to_string()),
+            kid: Some(signing_key.kid().to_string()),
+            ..Header::new(signing_key.algorithm().into())
+        };
+
+        let token = encode(&header, &claims, signing_key.encoding_key())
+            .map_err(|e| UseCaseError::Internal(e.into()))?;
+
+        Ok((token, challenge))
+    }
+
+    fn timeout_millis(&self) -> i64 {
+        self.config.challenge_ttl().num_milliseconds()
+    }
+
+    /// クライアントデータ (clientDataJSON) を検証し、署名対象に含めるハッシュ値を返す
+    fn verify_client_data(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        client_data_json: &[u8],
+    ) -> Result<[u8; 32], WebAuthnVerificationError> {
+        let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+            .map_err(|_| WebAuthnVerificationError::MalformedClientData)?;
+
+        let expected_type = match claim.ceremony {
+            WebAuthnCeremony::Registration => "webauthn.create",
+            WebAuthnCeremony::Authentication => "webauthn.get",
+        };
+        if client_data.client_data_type != expected_type {
+            return Err(WebAuthnVerificationError::CeremonyTypeMismatch(
+                client_data.client_data_type,
+            ));
+        }
+
+        if client_data.challenge != claim.challenge {
+            return Err(WebAuthnVerificationError::ChallengeMismatch);
+        }
+
+        if !self.config.is_allowed_origin(&client_data.origin) {
+            return Err(WebAuthnVerificationError::OriginNotAllowed(
+                client_data.origin,
+            ));
+        }
+
+        if client_data.cross_origin {
+            return Err(WebAuthnVerificationError::CrossOrigin);
+        }
+
+        Ok(Sha256::digest(client_data_json).into())
+    }
+
+    /// authenticatorData の RP ID とフラグを検証する
+    fn verify_authenticator_data(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        authenticator_data: &AuthenticatorData,
+    ) -> Result<(), WebAuthnVerificationError> {
+        let rp_id_hash: [u8; 32] = Sha256::digest(self.config.rp_id().as_bytes()).into();
+        if authenticator_data.rp_id_hash != rp_id_hash {
+            return Err(WebAuthnVerificationError::RpIdMismatch);
+        }
+
+        if authenticator_data.flags & FLAG_USER_PRESENT == 0 {
+            return Err(WebAuthnVerificationError::UserNotPresent);
+        }
+
+        if claim.user_verification_required && authenticator_data.flags & FLAG_USER_VERIFIED == 0 {
+            return Err(WebAuthnVerificationError::UserNotVerified);
+        }
+
+        Ok(())
+    }
+
+    fn verify_registration_response(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        credential: &RegistrationCredential,
+    ) -> Result<VerifiedRegistration, WebAuthnVerificationError> {
+        let client_data_json = decode_base64url(&credential.client_data_json)?;
+        self.verify_client_data(claim, &client_data_json)?;
+
+        let attestation_object = decode_base64url(&credential.attestation_object)?;
+        let attestation_object: Value = ciborium::from_reader(attestation_object.as_slice())
+            .map_err(|_| WebAuthnVerificationError::MalformedAttestationObject)?;
+
+        let fmt = text_entry(&attestation_object, "fmt")
+            .and_then(Value::as_text)
+            .ok_or(WebAuthnVerificationError::MalformedAttestationObject)?;
+        let att_stmt = text_entry(&attestation_object, "attStmt")
+            .and_then(Value::as_map)
+            .ok_or(WebAuthnVerificationError::MalformedAttestationObject)?;
+        let auth_data = text_entry(&attestation_object, "authData")
+            .and_then(Value::as_bytes)
+            .ok_or(WebAuthnVerificationError::MalformedAttestationObject)?;
+
+        // 認証器の証明書は要求していない (attestation: "none") ため、それ以外の形式は受け付けない
+        if fmt != "none" || !att_stmt.is_empty() {
+            return Err(WebAuthnVerificationError::UnsupportedAttestationFormat(
+                fmt.to_string(),
+            ));
+        }
+
+        let authenticator_data = AuthenticatorData::parse(auth_data)?;
+        self.verify_authenticator_data(claim, &authenticator_data)?;
+
+        let attested = authenticator_data
+            .attested_credential
+            .ok_or(WebAuthnVerificationError::MalformedAuthenticatorData)?;
+
+        let credential_id = URL_SAFE_NO_PAD.encode(&attested.credential_id);
+        if credential_id != credential.id.trim_end_matches('=') {
+            return Err(WebAuthnVerificationError::CredentialIdMismatch);
+        }
+
+        // 対応していないアルゴリズムの公開鍵は、認証時に検証できないため登録時に拒否する
+        CosePublicKey::parse(&attested.public_key)?;
+
+        Ok(VerifiedRegistration {
+            credential_id: CredentialId::from_raw_str(&credential_id),
+            public_key: CredentialPublicKey::from_bytes(attested.public_key),
+            sign_count: authenticator_data.sign_count,
+        })
+    }
+
+    fn verify_assertion_response(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        owner_id: UserId,
+        public_key: &CredentialPublicKey,
+        credential: &AssertionCredential,
+    ) -> Result<VerifiedAssertion, WebAuthnVerificationError> {
+        let client_data_json = decode_base64url(&credential.client_data_json)?;
+        let client_data_hash = self.verify_client_data(claim, &client_data_json)?;
+
+        let auth_data = decode_base64url(&credential.authenticator_data)?;
+        let authenticator_data = AuthenticatorData::parse(&auth_data)?;
+        self.verify_authenticator_data(claim, &authenticator_data)?;
+
+        // 2 段階目の認証では、セレモニーを開始したユーザーのクレデンシャルでなければならない
+        if claim.user_id.is_some_and(|user_id| user_id != owner_id) {
+            return Err(WebAuthnVerificationError::UserHandleMismatch);
+        }
+
+        // ユーザーを特定せずに開始した認証では、認証器が返すユーザーハンドルで所有者を確認する
+        let user_handle = credential
+            .user_handle
+            .as_deref()
+            .filter(|user_handle| !user_handle.is_empty());
+        let user_handle_matches = match user_handle {
+            Some(user_handle) => decode_base64url(user_handle)? == user_handle_bytes(owner_id),
+            None => claim.user_id.is_some(),
+        };
+        if !user_handle_matches {
+            return Err(WebAuthnVerificationError::UserHandleMismatch);
+        }
+
+        let signature = decode_base64url(&credential.signature)?;
+        let signed_data = [auth_data.as_slice(), &client_data_hash].concat();
+        CosePublicKey::parse(public_key.as_bytes())?.verify(&signed_data, &signature)?;
+
+        Ok(VerifiedAssertion {
+            sign_count: authenticator_data.sign_count,
+        })
+    }
+}
+
+impl WebAuthnService for WebAuthnInteractor {
+    fn issue_registration_challenge(
+        &self,
+        user: WebAuthnUser<'_>,
+        exclude_credentials: &[CredentialId],
+    ) -> Result<IssuedRegistrationChallenge, UseCaseError> {
+        // 登録するパスキーはパスキーのみでのログインにも使用するため、ユーザー検証を必須とする
+        let (ceremony_token, challenge) =
+            self.issue_ceremony_token(WebAuthnCeremony::Registration, Some(user.id), true)?;
+
+        let options = PublicKeyCredentialCreationOptions {
+            rp: RelyingParty {
+                id: self.config.rp_id().to_string(),
+                name: self.config.rp_name().to_string(),
+            },
+            user: PublicKeyCredentialUser {
+                id: URL_SAFE_NO_PAD.encode(user_handle_bytes(user.id)),
+                name: user.name.to_string(),
+                display_name: user.display_name.to_string(),
+            },
+            challenge,
+            pub_key_cred_params: [COSE_ALG_ES256, COSE_ALG_EDDSA, COSE_ALG_RS256]
+                .into_iter()
+                .map(|alg| PublicKeyCredentialParameters {
+                    credential_type: PUBLIC_KEY_CREDENTIAL_TYPE,
+                    alg,
+                })
+                .collect(),
+            timeout: self.timeout_millis(),
+            exclude_credentials: credential_descriptors(exclude_credentials),
+            authenticator_selection: AuthenticatorSelection {
+                resident_key: "required",
+                require_resident_key: true,
+                user_verification: "required",
+            },
+            attestation: "none",
+        };
+
+        Ok(IssuedRegistrationChallenge {
+            ceremony_token,
+            options,
+        })
+    }
+
+    fn issue_authentication_challenge(
+        &self,
+        user_id: Option<UserId>,
+        allow_credentials: &[CredentialId],
+    ) -> Result<IssuedAuthenticationChallenge, UseCaseError> {
+        // パスキーのみでのログインでは、所持とユーザー検証の 2 要素を満たすためにユーザー検証を必須とする
+        let user_verification_required = user_id.is_none();
+        let (ceremony_token, challenge) = self.issue_ceremony_token(
+            WebAuthnCeremony::Authentication,
+            user_id,
+            user_verification_required,
+        )?;
+
+        let options = PublicKeyCredentialRequestOptions {
+            challenge,
+            timeout: self.timeout_millis(),
+            rp_id: self.config.rp_id().to_string(),
+            allow_credentials: credential_descriptors(allow_credentials),
+            user_verification: if user_verification_required {
+                "required"
+            } else {
+                "preferred"
+            },
+        };
+
+        Ok(IssuedAuthenticationChallenge {
+            ceremony_token,
+            options,
+        })
+    }
+
+    fn verify_ceremony_token(
+        &self,
+        token: &str,
+        ceremony: WebAuthnCeremony,
+    ) -> Result<WebAuthnCeremonyClaim, UseCaseError> {
+        let header = decode_header(token).map_err(|_| UseCaseError::Unauthorized)?;
+
+        if header.typ.as_deref() != Some(WEBAUTHN_CEREMONY_TOKEN_TYPE) {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        let verification_key = self
+            .key_set
+            .verification_key_for(&header)
+            .ok_or(UseCaseError::Unauthorized)?;
+
+        let mut validation = Validation::new(verification_key.algorithm().into());
+        validation.validate_aud = false;
+
+        let token_data = decode::<WebAuthnCeremonyTokenClaims>(
+            token,
+            verification_key.decoding_key(),
+            &validation,
+        )
+        .map_err(|_| UseCaseError::Unauthorized)?;
+
+        let WebAuthnCeremonyTokenClaims {
+            sub,
+            jti,
+            exp,
+            ceremony: token_ceremony,
+            challenge,
+            uv,
+            ..
+        } = token_data.claims;
+
+        if token_ceremony != ceremony {
+            return Err(UseCaseError::Unauthorized);
+        }
+
+        Ok(WebAuthnCeremonyClaim {
+            ceremony,
+            user_id: sub,
+            challenge,
+            user_verification_required: uv,
+            token_id: jti,
+            expires_at: DateTime::from_timestamp(exp, 0).expect("valid timestamp"),
+        })
+    }
+
+    fn verify_registration(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        credential: &RegistrationCredential,
+    ) -> Result<VerifiedRegistration, UseCaseError> {
+        self.verify_registration_response(claim, credential)
+            .map_err(|e| {
+                tracing::info!(error = %e, "パスキーの登録の検証に失敗しました");
+                UseCaseError::InvalidInput(
+                    vec![ValidationError::new(
+                        "credential",
+                        "パスキーを検証できませんでした。もう一度登録してください",
+                    )]
+                    .into(),
+                )
+            })
+    }
+
+    fn verify_assertion(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        owner_id: UserId,
+        public_key: &CredentialPublicKey,
+        credential: &AssertionCredential,
+    ) -> Result<VerifiedAssertion, UseCaseError> {
+        self.verify_assertion_response(claim, owner_id, public_key, credential)
+            .map_err(|e| {
+                tracing::info!(error = %e, "パスキーによる認証の検証に失敗しました");
+                UseCaseError::Unauthorized
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    client_data_type: String,
+    challenge: String,
+    origin: String,
+    #[serde(rename = "crossOrigin", default)]
+    cross_origin: bool,
+}
+
+struct AuthenticatorData {
+    rp_id_hash: [u8; 32],
+    flags: u8,
+    sign_count: u32,
+    attested_credential: Option<AttestedCredentialData>,
+}
+
+struct AttestedCredentialData {
+    credential_id: Vec<u8>,
+    /// COSE_Key 形式の公開鍵
+    public_key: Vec<u8>,
+}
+
+impl AuthenticatorData {
+    /// authenticatorData のバイト列をパースする (WebAuthn Level 2 §6.1)
+    ///
+    /// rpIdHash (32) | flags (1) | signCount (4) | attestedCredentialData (可変) | extensions (可変)
+    fn parse(bytes: &[u8]) -> Result<Self, WebAuthnVerificationError> {
+        const HEADER_LEN: usize = 37;
+        // aaguid (16) | credentialIdLength (2)
+        const ATTESTED_HEADER_LEN: usize = 18;
+
+        if bytes.len() < HEADER_LEN {
+            return Err(WebAuthnVerificationError::MalformedAuthenticatorData);
+        }
+
+        let rp_id_hash: [u8; 32] = bytes[..32].try_into().expect("length checked");
+        let flags = bytes[32];
+        let sign_count = u32::from_be_bytes(bytes[33..37].try_into().expect("length checked"));
+
+        let attested_credential = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+            let rest = &bytes[HEADER_LEN..];
+            if rest.len() < ATTESTED_HEADER_LEN {
+                return Err(WebAuthnVerificationError::MalformedAuthenticatorData);
+            }
+
+            let credential_id_len = usize::from(u16::from_be_bytes([rest[16], rest[17]]));
+            let rest = &rest[ATTESTED_HEADER_LEN..];
+            if rest.len() < credential_id_len {
+                return Err(WebAuthnVerificationError::MalformedAuthenticatorData);
+            }
+            let (credential_id, public_key_and_extensions) = rest.split_at(credential_id_len);
+
+            // 公開鍵の後ろに拡張データが続く場合があるため、CBOR の 1 要素分だけを読み取る
+            let mut reader = public_key_and_extensions;
+            let _: Value = ciborium::from_reader(&mut reader)
+                .map_err(|_| WebAuthnVerificationError::MalformedAuthenticatorData)?;
+            let public_key_len = public_key_and_extensions.len() - reader.len();
+
+            Some(AttestedCredentialData {
+                credential_id: credential_id.to_vec(),
+                public_key: public_key_and_extensions[..public_key_len].to_vec(),
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            attested_credential,
+        })
+    }
+}
+
+/// COSE_Key 形式の公開鍵 (RFC 9053)
+enum CosePublicKey {
+    Es256(p256::ecdsa::VerifyingKey),
+    EdDsa(ed25519_dalek::VerifyingKey),
+    Rs256(rsa::pkcs1v15::VerifyingKey<Sha256>),
+}
+
+impl CosePublicKey {
+    // COSE_Key のラベル
+    const KTY: i128 = 1;
+    const ALG: i128 = 3;
+    const CRV_OR_N: i128 = -1;
+    const X_OR_E: i128 = -2;
+    const Y: i128 = -3;
+
+    // kty / crv の値
+    const KTY_OKP: i128 = 1;
+    const KTY_EC2: i128 = 2;
+    const KTY_RSA: i128 = 3;
+    const CRV_P256: i128 = 1;
+    const CRV_ED25519: i128 = 6;
+
+    fn parse(bytes: &[u8]) -> Result<Self, WebAuthnVerificationError> {
+        let key: Value = ciborium::from_reader(bytes)
+            .map_err(|_| WebAuthnVerificationError::UnsupportedPublicKey)?;
+
+        let int = |label| {
+            integer_entry(&key, label)
+                .and_then(Value::as_integer)
+                .map(i128::from)
+        };
+        let bytes = |label| integer_entry(&key, label).and_then(Value::as_bytes);
+
+        let kty = int(Self::KTY);
+        let alg = int(Self::ALG).and_then(|alg| i64::try_from(alg).ok());
+        let crv = int(Self::CRV_OR_N);
+
+        match (kty, alg) {
+            (Some(Self::KTY_EC2), Some(COSE_ALG_ES256)) if crv == Some(Self::CRV_P256) => {
+                let (x, y) = bytes(Self::X_OR_E)
+                    .zip(bytes(Self::Y))
+                    .filter(|(x, y)| x.len() == 32 && y.len() == 32)
+                    .ok_or(WebAuthnVerificationError::UnsupportedPublicKey)?;
+                let point = p256::EncodedPoint::from_affine_coordinates(
+                    p256::FieldBytes::from_slice(x),
+                    p256::FieldBytes::from_slice(y),
+                    false,
+                );
+                p256::ecdsa::VerifyingKey::from_encoded_point(&point)
+                    .map(Self::Es256)
+                    .map_err(|_| WebAuthnVerificationError::UnsupportedPublicKey)
+            }
+            (Some(Self::KTY_OKP), Some(COSE_ALG_EDDSA)) if crv == Some(Self::CRV_ED25519) => {
+                let x: &[u8; 32] = bytes(Self::X_OR_E)
+                    .and_then(|x| x.as_slice().try_into().ok())
+                    .ok_or(WebAuthnVerificationError::UnsupportedPublicKey)?;
+                ed25519_dalek::VerifyingKey::from_bytes(x)
+                    .map(Self::EdDsa)
+                    .map_err(|_| WebAuthnVerificationError::UnsupportedPublicKey)
+            }
+            (Some(Self::KTY_RSA), Some(COSE_ALG_RS256)) => {
+                let (n, e) = bytes(Self::CRV_OR_N)
+                    .zip(bytes(Self::X_OR_E))
+                    .ok_or(WebAuthnVerificationError::UnsupportedPublicKey)?;
+                rsa::RsaPublicKey::new(
+                    rsa::BigUint::from_bytes_be(n),
+                    rsa::BigUint::from_bytes_be(e),
+                )
+                .map(|key| Self::Rs256(rsa::pkcs1v15::VerifyingKey::new(key)))
+                .map_err(|_| WebAuthnVerificationError::UnsupportedPublicKey)
+            }
+            _ => Err(WebAuthnVerificationError::UnsupportedPublicKey),
+        }
+    }
+
+    fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), WebAuthnVerificationError> {
+        let verified = match self {
+            // ES256 の署名は ASN.1 DER 形式で送られる
+            CosePublicKey::Es256(key) => p256::ecdsa::Signature::from_der(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+            CosePublicKey::EdDsa(key) => ed25519_dalek::Signature::from_slice(signature)
+                .is_ok_and(|signature| key.verify_strict(message, &signature).is_ok()),
+            CosePublicKey::Rs256(key) => rsa::pkcs1v15::Signature::try_from(signature)
+                .is_ok_and(|signature| key.verify(message, &signature).is_ok()),
+        };
+
+        if verified {
+            Ok(())
+        } else {
+            Err(WebAuthnVerificationError::InvalidSignature)
+        }
+    }
+}
+
+/// ユーザーハンドルにはユーザー ID (UUID) のバイト列を使用する
+fn user_handle_bytes(user_id: UserId) -> [u8; 16] {
+    let user_id: Uuid = user_id.into();
+    user_id.into_bytes()
+}
+
+fn credential_descriptors(credential_ids: &[CredentialId]) -> Vec<PublicKeyCredentialDescriptor> {
+    credential_ids
+        .iter()
+        .map(|credential_id| PublicKeyCredentialDescriptor {
+            credential_type: PUBLIC_KEY_CREDENTIAL_TYPE,
+            id: credential_id.to_string(),
+        })
+        .collect()
+}
+
+fn decode_base64url(value: &str) -> Result<Vec<u8>, WebAuthnVerificationError> {
+    // パディング付きで送ってくるクライアントも受け付ける
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|_| WebAuthnVerificationError::InvalidEncoding)
+}
+
+fn text_entry<'a>(map: &'a Value, key: &str) -> Option<&'a Value> {
+    map.as_map()?
+        .iter()
+        .find(|(k, _)| k.as_text() == Some(key))
+        .map(|(_, v)| v)
+}
+
+fn integer_entry(map: &Value, key: i128) -> Option<&Value> {
+    map.as_map()?
+        .iter()
+        .find(|(k, _)| k.as_integer().map(i128::from) == Some(key))
+        .map(|(_, v)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+    use p256::ecdsa::{SigningKey, signature::Signer as _};
+    use serde_json::json;
+
+    use crate::auth::jwt_key::{
+        JwtAlgorithm, JwtSigningKey, JwtVerificationKey,
+        test_keys::{KEY_A_PRIVATE, KEY_A_PUBLIC},
+    };
+
+    use super::*;
+
+    const RP_ID: &str = "app.example.com";
+    const ORIGIN: &str = "https://app.example.com";
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn key_set() -> Arc<JwtKeySet> {
+        let signing_key =
+            JwtSigningKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PRIVATE.as_bytes()).unwrap();
+        let verification_key =
+            JwtVerificationKey::from_pem("a", JwtAlgorithm::EdDsa, KEY_A_PUBLIC.as_bytes())
+                .unwrap();
+        Arc::new(JwtKeySet::new(signing_key, vec![verification_key]).unwrap())
+    }
+
+    fn interactor_at(now: DateTime<Utc>) -> WebAuthnInteractor {
+        let config = WebAuthnConfig::new(
+            RP_ID.to_string(),
+            "Example App".to_string(),
+            vec![ORIGIN.to_string()],
+            300,
+        )
+        .unwrap();
+        WebAuthnInteractor::new(key_set(), Arc::new(config), Arc::new(FixedClock(now)))
+    }
+
+    fn interactor() -> WebAuthnInteractor {
+        interactor_at(Utc::now())
+    }
+
+    enum AuthenticatorKey {
+        Es256(SigningKey),
+        EdDsa(ed25519_dalek::SigningKey),
+    }
+
+    /// テスト用のソフトウェア認証器
+    ///
+    /// ブラウザと認証器の役割をまとめて担い、`toJSON()` 相当の応答を生成する
+    struct SoftwareAuthenticator {
+        key: AuthenticatorKey,
+        credential_id: Vec<u8>,
+        sign_count: u32,
+        rp_id: String,
+        origin: String,
+        flags: u8,
+    }
+
+    impl SoftwareAuthenticator {
+        fn es256() -> Self {
+            Self::with_key(AuthenticatorKey::Es256(
+                SigningKey::from_slice(&[7u8; 32]).unwrap(),
+            ))
+        }
+
+        fn eddsa() -> Self {
+            Self::with_key(AuthenticatorKey::EdDsa(
+                ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]),
+            ))
+        }
+
+        fn with_key(key: AuthenticatorKey) -> Self {
+            Self {
+                key,
+                credential_id: b"software-credential".to_vec(),
+                sign_count: 0,
+                rp_id: RP_ID.to_string(),
+                origin: ORIGIN.to_string(),
+                flags: FLAG_USER_PRESENT | FLAG_USER_VERIFIED,
+            }
+        }
+
+        fn credential_id(&self) -> String {
+            URL_SAFE_NO_PAD.encode(&self.credential_id)
+        }
+
+        fn cose_public_key(&self) -> Vec<u8> {
+            let int = |value: i64| Value::Integer(value.into());
+            let key = match &self.key {
+                AuthenticatorKey::Es256(key) => {
+                    let point = key.verifying_key().to_encoded_point(false);
+                    Value::Map(vec![
+                        (int(1), int(2)),
+                        (int(3), int(COSE_ALG_ES256)),
+                        (int(-1), int(1)),
+                        (int(-2), Value::Bytes(point.x().unwrap().to_vec())),
+                        (int(-3), Value::Bytes(point.y().unwrap().to_vec())),
+                    ])
+                }
+                AuthenticatorKey::EdDsa(key) => Value::Map(vec![
+                    (int(1), int(1)),
+                    (int(3), int(COSE_ALG_EDDSA)),
+                    (int(-1), int(6)),
+                    (
+                        int(-2),
+                        Value::Bytes(key.verifying_key().to_bytes().to_vec()),
+                    ),
+                ]),
+            };
+
+            let mut bytes = Vec::new();
+            ciborium::into_writer(&key, &mut bytes).unwrap();
+            bytes
+        }
+
+        fn authenticator_data(&self, attested: bool) -> Vec<u8> {
+            let mut data = Sha256::digest(self.rp_id.as_bytes()).to_vec();
+            let flags = if attested {
+                self.flags | FLAG_ATTESTED_CREDENTIAL_DATA
+            } else {
+                self.flags
+            };
+            data.push(flags);
+            data.extend_from_slice(&self.sign_count.to_be_bytes());
+
+            if attested {
+                data.extend_from_slice(&[0u8; 16]);
+                data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+                data.extend_from_slice(&self.credential_id);
+                data.extend_from_slice(&self.cose_public_key());
+            }
+            data
+        }
+
+        fn client_data_json(&self, client_data_type: &str, challenge: &str) -> Vec<u8> {
+            serde_json::to_vec(&json!({
+                "type": client_data_type,
+                "challenge": challenge,
+                "origin": self.origin,
+                "crossOrigin": false,
+            }))
+            .unwrap()
+        }
+
+        fn sign(&self, message: &[u8]) -> Vec<u8> {
+            match &self.key {
+                AuthenticatorKey::Es256(key) => {
+                    let signature: p256::ecdsa::Signature = key.sign(message);
+                    signature.to_der().as_bytes().to_vec()
+                }
+                AuthenticatorKey::EdDsa(key) => ed25519_dalek::Signer::sign(key, message)
+                    .to_bytes()
+                    .to_vec(),
+            }
+        }
+
+        /// `navigator.credentials.create()` 相当
+        fn create(&self, options: &PublicKeyCredentialCreationOptions) -> RegistrationCredential {
+            let attestation_object = Value::Map(vec![
+                (Value::Text("fmt".into()), Value::Text("none".into())),
+                (Value::Text("attStmt".into()), Value::Map(vec![])),
+                (
+                    Value::Text("authData".into()),
+                    Value::Bytes(self.authenticator_data(true)),
+                ),
+            ]);
+            let mut attestation_object_bytes = Vec::new();
+            ciborium::into_writer(&attestation_object, &mut attestation_object_bytes).unwrap();
+
+            RegistrationCredential {
+                id: self.credential_id(),
+                client_data_json: URL_SAFE_NO_PAD
+                    .encode(self.client_data_json("webauthn.create", &options.challenge)),
+                attestation_object: URL_SAFE_NO_PAD.encode(attestation_object_bytes),
+            }
+        }
+
+        /// `navigator.credentials.get()` 相当
+        fn get(
+            &mut self,
+            options: &PublicKeyCredentialRequestOptions,
+            user_handle: Option<UserId>,
+        ) -> AssertionCredential {
+            self.sign_count += 1;
+
+            let authenticator_data = self.authenticator_data(false);
+            let client_data_json = self.client_data_json("webauthn.get", &options.challenge);
+            let signed_data = [
+                authenticator_data.as_slice(),
+                &Sha256::digest(&client_data_json),
+            ]
+            .concat();
+
+            AssertionCredential {
+                id: self.credential_id(),
+                client_data_json: URL_SAFE_NO_PAD.encode(client_data_json),
+                authenticator_data: URL_SAFE_NO_PAD.encode(authenticator_data),
+                signature: URL_SAFE_NO_PAD.encode(self.sign(&signed_data)),
+                user_handle: user_handle
+                    .map(|user_id| URL_SAFE_NO_PAD.encode(user_handle_bytes(user_id))),
+            }
+        }
+    }
+
+    fn register(
+        interactor: &WebAuthnInteractor,
+        authenticator: &SoftwareAuthenticator,
+        user_id: UserId,
+    ) -> VerifiedRegistration {
+        let issued = interactor
+            .issue_registration_challenge(
+                WebAuthnUser {
+                    id: user_id,
+                    name: "user@example.com",
+                    display_name: "user",
+                },
+                &[],
+            )
+            .unwrap();
+        let claim = interactor
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Registration)
+            .unwrap();
+
+        interactor
+            .verify_registration(&claim, &authenticator.create(&issued.options))
+            .unwrap()
+    }
+
+    fn authenticate(
+        interactor: &WebAuthnInteractor,
+        authenticator: &mut SoftwareAuthenticator,
+        registered: &VerifiedRegistration,
+        user_id: UserId,
+    ) -> Result<VerifiedAssertion, UseCaseError> {
+        let issued = interactor
+            .issue_authentication_challenge(None, &[])
+            .unwrap();
+        let claim = interactor
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Authentication)
+            .unwrap();
+
+        let assertion = authenticator.get(&issued.options, Some(user_id));
+        interactor.verify_assertion(&claim, user_id, &registered.public_key, &assertion)
+    }
+
+    #[test]
+    fn test_register_and_authenticate_with_es256() {
+        let interactor = interactor();
+        let mut authenticator = SoftwareAuthenticator::es256();
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let registered = register(&interactor, &authenticator, user_id);
+        assert_eq!(
+            registered.credential_id.as_str(),
+            authenticator.credential_id()
+        );
+        assert_eq!(registered.sign_count, 0);
+
+        let verified = authenticate(&interactor, &mut authenticator, &registered, user_id).unwrap();
+        assert_eq!(verified.sign_count, 1);
+    }
+
+    #[test]
+    fn test_register_and_authenticate_with_eddsa() {
+        let interactor = interactor();
+        let mut authenticator = SoftwareAuthenticator::eddsa();
+        let user_id: UserId = Uuid::now_v7().into();
+
+        let registered = register(&interactor, &authenticator, user_id);
+
+        assert!(authenticate(&interactor, &mut authenticator, &registered, user_id).is_ok());
+    }
+
+    #[test]
+    fn test_registration_rejects_other_origin_and_rp_id() {
+        let interactor = interactor();
+        let user_id: UserId = Uuid::now_v7().into();
+        let issued = interactor
+            .issue_registration_challenge(
+                WebAuthnUser {
+                    id: user_id,
+                    name: "user@example.com",
+                    display_name: "user",
+                },
+                &[],
+            )
+            .unwrap();
+        let claim = interactor
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Registration)
+            .unwrap();
+
+        let mut phishing_origin = SoftwareAuthenticator::es256();
+        phishing_origin.origin = "https://app.example.com.evil.test".to_string();
+        let mut other_rp = SoftwareAuthenticator::es256();
+        other_rp.rp_id = "evil.test".to_string();
+        let mut without_user_verification = SoftwareAuthenticator::es256();
+        without_user_verification.flags = FLAG_USER_PRESENT;
+
+        for authenticator in [phishing_origin, other_rp, without_user_verification] {
+            let result =
+                interactor.verify_registration(&claim, &authenticator.create(&issued.options));
+            assert!(matches!(result, Err(UseCaseError::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn test_assertion_rejects_tampered_or_replayed_response() {
+        let interactor = interactor();
+        let mut authenticator = SoftwareAuthenticator::es256();
+        let user_id: UserId = Uuid::now_v7().into();
+        let registered = register(&interactor, &authenticator, user_id);
+
+        let issued = interactor
+            .issue_authentication_challenge(None, &[])
+            .unwrap();
+        let claim = interactor
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Authentication)
+            .unwrap();
+        let assertion = authenticator.get(&issued.options, Some(user_id));
+
+        // 署名の改ざん
+        let mut tampered = authenticator.get(&issued.options, Some(user_id));
+        tampered.signature = assertion.signature.clone();
+        let result =
+            interactor.verify_assertion(&claim, user_id, &registered.public_key, &tampered);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+
+        // 別のセレモニーのチャレンジに対する応答の再利用
+        let other = interactor
+            .issue_authentication_challenge(None, &[])
+            .unwrap();
+        let other_claim = interactor
+            .verify_ceremony_token(&other.ceremony_token, WebAuthnCeremony::Authentication)
+            .unwrap();
+        let result =
+            interactor.verify_assertion(&other_claim, user_id, &registered.public_key, &assertion);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_assertion_rejects_user_handle_of_other_user() {
+        let interactor = interactor();
+        let mut authenticator = SoftwareAuthenticator::es256();
+        let user_id: UserId = Uuid::now_v7().into();
+        let registered = register(&interactor, &authenticator, user_id);
+
+        let issued = interactor
+            .issue_authentication_challenge(None, &[])
+            .unwrap();
+        let claim = interactor
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Authentication)
+            .unwrap();
+
+        // ユーザーハンドルを返さない応答はユーザーを特定できない
+        let without_handle = authenticator.get(&issued.options, None);
+        let result =
+            interactor.verify_assertion(&claim, user_id, &registered.public_key, &without_handle);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+
+        let other_handle = authenticator.get(&issued.options, Some(Uuid::now_v7().into()));
+        let result =
+            interactor.verify_assertion(&claim, user_id, &registered.public_key, &other_handle);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_verify_ceremony_token_rejects_other_ceremony_and_expired_token() {
+        let user_id: UserId = Uuid::now_v7().into();
+        let issued = interactor()
+            .issue_authentication_challenge(Some(user_id), &[])
+            .unwrap();
+
+        let result = interactor()
+            .verify_ceremony_token(&issued.ceremony_token, WebAuthnCeremony::Registration);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+
+        let expired = interactor_at(Utc::now() - Duration::minutes(10))
+            .issue_authentication_challenge(Some(user_id), &[])
+            .unwrap();
+        let result = interactor()
+            .verify_ceremony_token(&expired.ceremony_token, WebAuthnCeremony::Authentication);
+        assert!(matches!(result, Err(UseCaseError::Unauthorized)));
+    }
+}
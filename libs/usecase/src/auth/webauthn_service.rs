@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    auth::webauthn_credential::{CredentialId, CredentialPublicKey},
+    user::UserId,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::usecase_error::UseCaseError;
+
+/// WebAuthn のセレモニーの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebAuthnCeremony {
+    Registration,
+    Authentication,
+}
+
+/// セレモニートークンの内容
+///
+/// セレモニートークンはオプションとともにクライアントに渡し、認証器の応答とともに提示させる。
+/// サーバー側でチャレンジを保持する代わりに、署名付きのトークンにチャレンジを含める
+#[derive(Debug, PartialEq, Eq)]
+pub struct WebAuthnCeremonyClaim {
+    pub ceremony: WebAuthnCeremony,
+    /// 対象のユーザー。パスキーのみでのログイン (ユーザーを特定する前の認証) では `None`
+    pub user_id: Option<UserId>,
+    pub challenge: String,
+    /// 認証器によるユーザー検証 (生体認証・PIN) を必須とするか
+    pub user_verification_required: bool,
+    /// セレモニートークン自体を識別する ID (使用済みにする際に使用する)
+    pub token_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 登録するパスキーの所有者の情報
+pub struct WebAuthnUser<'a> {
+    pub id: UserId,
+    /// 認証器がアカウントを区別するための名前
+    pub name: &'a str,
+    /// 認証器に表示する名前
+    pub display_name: &'a str,
+}
+
+// 以下のオプションは `PublicKeyCredential.parseCreationOptionsFromJSON()` /
+// `parseRequestOptionsFromJSON()` でそのまま読み込める JSON 形式で出力する
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCredentialCreationOptions {
+    pub rp: RelyingParty,
+    pub user: PublicKeyCredentialUser,
+    pub challenge: String,
+    pub pub_key_cred_params: Vec<PublicKeyCredentialParameters>,
+    /// ミリ秒
+    pub timeout: i64,
+    pub exclude_credentials: Vec<PublicKeyCredentialDescriptor>,
+    pub authenticator_selection: AuthenticatorSelection,
+    pub attestation: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RelyingParty {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCredentialUser {
+    /// ユーザーハンドル (ユーザー ID のバイト列を Base64URL エンコードしたもの)
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicKeyCredentialParameters {
+    #[serde(rename = "type")]
+    pub credential_type: &'static str,
+    /// COSE アルゴリズム識別子
+    pub alg: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PublicKeyCredentialDescriptor {
+    #[serde(rename = "type")]
+    pub credential_type: &'static str,
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelection {
+    pub resident_key: &'static str,
+    pub require_resident_key: bool,
+    pub user_verification: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCredentialRequestOptions {
+    pub challenge: String,
+    /// ミリ秒
+    pub timeout: i64,
+    pub rp_id: String,
+    pub allow_credentials: Vec<PublicKeyCredentialDescriptor>,
+    pub user_verification: &'static str,
+}
+
+#[derive(derive_more::Debug)]
+pub struct IssuedRegistrationChallenge {
+    #[debug(skip)]
+    pub ceremony_token: String,
+    pub options: PublicKeyCredentialCreationOptions,
+}
+
+#[derive(derive_more::Debug)]
+pub struct IssuedAuthenticationChallenge {
+    #[debug(skip)]
+    pub ceremony_token: String,
+    pub options: PublicKeyCredentialRequestOptions,
+}
+
+/// 登録セレモニーでの認証器の応答 (各値は Base64URL エンコード済み)
+#[derive(Debug)]
+pub struct RegistrationCredential {
+    pub id: String,
+    pub client_data_json: String,
+    pub attestation_object: String,
+}
+
+/// 認証セレモニーでの認証器の応答 (各値は Base64URL エンコード済み)
+#[derive(Debug)]
+pub struct AssertionCredential {
+    pub id: String,
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+    pub user_handle: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct VerifiedRegistration {
+    pub credential_id: CredentialId,
+    pub public_key: CredentialPublicKey,
+    pub sign_count: u32,
+}
+
+#[derive(Debug)]
+pub struct VerifiedAssertion {
+    pub sign_count: u32,
+}
+
+pub trait WebAuthnService: Send + Sync {
+    /// 登録セレモニーを開始する
+    ///
+    /// `exclude_credentials` には登録済みのクレデンシャルを渡し、同じ認証器での二重登録を防ぐ
+    fn issue_registration_challenge(
+        &self,
+        user: WebAuthnUser<'_>,
+        exclude_credentials: &[CredentialId],
+    ) -> Result<IssuedRegistrationChallenge, UseCaseError>;
+
+    /// 認証セレモニーを開始する
+    ///
+    /// `user_id` が `None` の場合は、ユーザーを特定せずに認証器に保存されたパスキーを選択させる
+    /// (ユーザー検証を必須とする)。`Some` の場合は 2 段階目の認証として `allow_credentials` から選択させる
+    fn issue_authentication_challenge(
+        &self,
+        user_id: Option<UserId>,
+        allow_credentials: &[CredentialId],
+    ) -> Result<IssuedAuthenticationChallenge, UseCaseError>;
+
+    /// セレモニートークンの署名・有効期限・セレモニーの種類を検証し、トークンの内容を返す
+    fn verify_ceremony_token(
+        &self,
+        token: &str,
+        ceremony: WebAuthnCeremony,
+    ) -> Result<WebAuthnCeremonyClaim, UseCaseError>;
+
+    /// 登録セレモニーの応答 (attestation) を検証し、保存するクレデンシャルを返す
+    fn verify_registration(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        credential: &RegistrationCredential,
+    ) -> Result<VerifiedRegistration, UseCaseError>;
+
+    /// 認証セレモニーの応答 (assertion) を、保存済みのクレデンシャルの公開鍵で検証する
+    fn verify_assertion(
+        &self,
+        claim: &WebAuthnCeremonyClaim,
+        owner_id: UserId,
+        public_key: &CredentialPublicKey,
+        credential: &AssertionCredential,
+    ) -> Result<VerifiedAssertion, UseCaseError>;
+}
@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod relay;
+pub mod service_account;
 pub mod shared;
 pub mod usecase_error;
 pub mod user;
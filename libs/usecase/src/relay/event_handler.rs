@@ -7,6 +7,7 @@ use tracing_opentelemetry::OpenTelemetrySpanExt;
 use super::error::RelayError;
 
 // 共通のメタデータ保持用構造体
+#[derive(Clone, Copy)]
 pub struct HandlerContext {
     pub outbox_event_id: OutboxEventId,
     pub trace_id: Option<TraceId>,
@@ -1,15 +1,52 @@
+pub mod send_email_to_admins_when_user_demoted_from_admin;
+pub mod send_email_to_admins_when_user_promoted_to_admin;
 pub mod send_email_when_user_created;
 pub mod send_email_when_user_deactivated;
+pub mod send_email_when_user_demoted_from_admin;
 pub mod send_email_when_user_email_changed;
+pub mod send_email_when_user_email_verification_requested;
+pub mod send_email_when_user_locked_out;
+pub mod send_email_when_user_magic_link_requested;
+pub mod send_email_when_user_mfa_disabled;
+pub mod send_email_when_user_mfa_enabled;
+pub mod send_email_when_user_password_changed;
+pub mod send_email_when_user_password_reset;
+pub mod send_email_when_user_password_reset_requested;
+pub mod send_email_when_user_promoted_to_admin;
 pub mod send_email_when_user_reactivated;
 pub mod send_email_when_user_suspended;
 pub mod send_email_when_user_unlocked;
 pub mod send_email_when_user_username_changed;
 
+pub use send_email_to_admins_when_user_demoted_from_admin::SendEmailToAdminsWhenUserDemotedFromAdminHandler;
+pub use send_email_to_admins_when_user_promoted_to_admin::SendEmailToAdminsWhenUserPromotedToAdminHandler;
 pub use send_email_when_user_created::SendEmailWhenUserCreatedHandler;
 pub use send_email_when_user_deactivated::SendEmailWhenUserDeactivatedHandler;
+pub use send_email_when_user_demoted_from_admin::SendEmailWhenUserDemotedFromAdminHandler;
 pub use send_email_when_user_email_changed::SendEmailWhenUserEmailChangedHandler;
+pub use send_email_when_user_email_verification_requested::SendEmailWhenUserEmailVerificationRequestedHandler;
+pub use send_email_when_user_locked_out::SendEmailWhenUserLockedOutHandler;
+pub use send_email_when_user_magic_link_requested::SendEmailWhenUserMagicLinkRequestedHandler;
+pub use send_email_when_user_mfa_disabled::SendEmailWhenUserMfaDisabledHandler;
+pub use send_email_when_user_mfa_enabled::SendEmailWhenUserMfaEnabledHandler;
+pub use send_email_when_user_password_changed::SendEmailWhenUserPasswordChangedHandler;
+pub use send_email_when_user_password_reset::SendEmailWhenUserPasswordResetHandler;
+pub use send_email_when_user_password_reset_requested::SendEmailWhenUserPasswordResetRequestedHandler;
+pub use send_email_when_user_promoted_to_admin::SendEmailWhenUserPromotedToAdminHandler;
 pub use send_email_when_user_reactivated::SendEmailWhenUserReactivatedHandler;
 pub use send_email_when_user_suspended::SendEmailWhenUserSuspendedHandler;
 pub use send_email_when_user_unlocked::SendEmailWhenUserUnlockedHandler;
 pub use send_email_when_user_username_changed::SendEmailWhenUsernameChangedHandler;
+
+use domain::auth::actor::AuditActor;
+
+/// 管理者への通知に記載する、操作の主体の説明
+fn describe_actor(actor: &AuditActor) -> String {
+    match actor {
+        AuditActor::User { user_id } => format!("the administrator (user ID: {user_id})"),
+        AuditActor::ServiceAccount {
+            service_account_id: _,
+            name,
+        } => format!("the service account '{name}'"),
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::{EmailTrait, UserDemotedFromAdminEvent};
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::{
+    super::{error::RelayError, event_handler::EventHandler},
+    describe_actor,
+};
+
+pub struct SendEmailToAdminsWhenUserDemotedFromAdminHandler {
+    context: HandlerContext,
+    event: UserDemotedFromAdminEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailToAdminsWhenUserDemotedFromAdminHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserDemotedFromAdminEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailToAdminsWhenUserDemotedFromAdminHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserDemotedFromAdminEvent {
+            username,
+            email: _,
+            demoted_by,
+            other_admin_emails,
+            demoted_at,
+        } = &self.event;
+
+        let demoted_by = describe_actor(demoted_by);
+
+        for admin_email in other_admin_emails {
+            let to = admin_email.as_str().to_string();
+            let subject = "Administrator Privileges Have Been Removed From a User".to_string();
+            let body = format!(
+                "Hello,\n\nThe administrator privileges of {username} were removed by {demoted_by} at {demoted_at}.\n\nIf this change was not expected, please review it immediately.\n\nBest regards,\nThe Team",
+            );
+
+            let email_message = EmailMessage { to, subject, body };
+
+            self.email_service
+                .send_email(email_message)
+                .await
+                .map_err(|e| RelayError::ProcessingError(e.into()))?;
+        }
+
+        Ok(())
+    }
+}
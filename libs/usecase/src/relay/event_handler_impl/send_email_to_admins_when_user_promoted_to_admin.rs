@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::{EmailTrait, UserPromotedToAdminEvent};
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::{
+    super::{error::RelayError, event_handler::EventHandler},
+    describe_actor,
+};
+
+pub struct SendEmailToAdminsWhenUserPromotedToAdminHandler {
+    context: HandlerContext,
+    event: UserPromotedToAdminEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailToAdminsWhenUserPromotedToAdminHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserPromotedToAdminEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailToAdminsWhenUserPromotedToAdminHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserPromotedToAdminEvent {
+            username,
+            email: _,
+            promoted_by,
+            other_admin_emails,
+            promoted_at,
+        } = &self.event;
+
+        let promoted_by = promoted_by
+            .as_ref()
+            .map(describe_actor)
+            .unwrap_or_else(|| "an unknown actor".to_string());
+
+        for admin_email in other_admin_emails {
+            let to = admin_email.as_str().to_string();
+            let subject = "A User Has Been Granted Administrator Privileges".to_string();
+            let body = format!(
+                "Hello,\n\nThe user {username} was granted administrator privileges by {promoted_by} at {promoted_at}.\n\nIf this change was not expected, please review it immediately.\n\nBest regards,\nThe Team",
+            );
+
+            let email_message = EmailMessage { to, subject, body };
+
+            self.email_service
+                .send_email(email_message)
+                .await
+                .map_err(|e| RelayError::ProcessingError(e.into()))?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use domain::user::{EmailTrait, UserCreatedEvent};
 
 use crate::{
+    auth::email_verification_token_service::EmailVerificationTokenService,
     relay::event_handler::HandlerContext,
     shared::email_service::{EmailMessage, EmailService},
 };
@@ -14,6 +15,7 @@ pub struct SendEmailWhenUserCreatedHandler {
     context: HandlerContext,
     event: UserCreatedEvent,
     email_service: Arc<dyn EmailService>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
 }
 
 impl SendEmailWhenUserCreatedHandler {
@@ -21,11 +23,13 @@ impl SendEmailWhenUserCreatedHandler {
         context: HandlerContext,
         event: UserCreatedEvent,
         email_service: Arc<dyn EmailService>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
     ) -> Self {
         Self {
             context,
             event,
             email_service,
+            email_verification_token_service,
         }
     }
 }
@@ -38,15 +42,21 @@ impl EventHandler for SendEmailWhenUserCreatedHandler {
 
     async fn handle_event_raw(&self) -> Result<(), RelayError> {
         let UserCreatedEvent {
+            user_id,
             email,
             username,
             registered_at: _,
         } = &self.event;
 
+        let verification_link = self
+            .email_verification_token_service
+            .issue_verification_link(*user_id, email)
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
         let to = email.as_str().to_string();
         let subject = "Welcome to Our Service!".to_string();
         let body = format!(
-            "Dear {username},\n\nThank you for registering with us.\n\nBest regards,\nThe Team",
+            "Dear {username},\n\nThank you for registering with us.\n\nPlease verify your email address by visiting the following link:\n{verification_link}\n\nBest regards,\nThe Team",
         );
 
         let email_message = EmailMessage { to, subject, body };
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserDemotedFromAdminEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserDemotedFromAdminHandler {
+    context: HandlerContext,
+    event: UserDemotedFromAdminEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserDemotedFromAdminHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserDemotedFromAdminEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserDemotedFromAdminHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserDemotedFromAdminEvent {
+            username,
+            email,
+            demoted_by: _,
+            other_admin_emails: _,
+            demoted_at: _,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Your Administrator Privileges Have Been Removed".to_string();
+        let body = format!(
+            "Hello {username},\n\nYour administrator privileges have been removed. You can continue to use your account as a regular user.\n\nIf you did not expect this change, please contact support.\n\nBest regards,\nThe Team",
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
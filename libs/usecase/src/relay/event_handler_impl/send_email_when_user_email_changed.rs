@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use domain::user::{EmailTrait, UserEmailChangedEvent};
 
 use crate::{
+    auth::email_verification_token_service::EmailVerificationTokenService,
     relay::event_handler::HandlerContext,
     shared::email_service::{EmailMessage, EmailService},
 };
@@ -14,6 +15,7 @@ pub struct SendEmailWhenUserEmailChangedHandler {
     context: HandlerContext,
     event: UserEmailChangedEvent,
     email_service: Arc<dyn EmailService>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
 }
 
 impl SendEmailWhenUserEmailChangedHandler {
@@ -21,11 +23,13 @@ impl SendEmailWhenUserEmailChangedHandler {
         context: HandlerContext,
         event: UserEmailChangedEvent,
         email_service: Arc<dyn EmailService>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
     ) -> Self {
         Self {
             context,
             event,
             email_service,
+            email_verification_token_service,
         }
     }
 }
@@ -38,15 +42,21 @@ impl EventHandler for SendEmailWhenUserEmailChangedHandler {
 
     async fn handle_event_raw(&self) -> Result<(), RelayError> {
         let UserEmailChangedEvent {
+            user_id,
             new_email,
             username,
             changed_at: _,
         } = &self.event;
 
+        let verification_link = self
+            .email_verification_token_service
+            .issue_verification_link(*user_id, new_email)
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
         let to = new_email.as_str().to_string();
         let subject = "Your email has been changed".to_string();
         let body = format!(
-            "Hello {username},\n\nYour email has been changed to {new_email}.\n\nIf you did not make this change, please contact support immediately.",
+            "Hello {username},\n\nYour email has been changed to {new_email}.\n\nPlease verify your new email address by visiting the following link:\n{verification_link}\n\nIf you did not make this change, please contact support immediately.",
         );
 
         let email_message = EmailMessage { to, subject, body };
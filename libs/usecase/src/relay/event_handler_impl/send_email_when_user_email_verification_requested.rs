@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::{EmailTrait, UserEmailVerificationRequestedEvent};
+
+use crate::{
+    auth::email_verification_token_service::EmailVerificationTokenService,
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserEmailVerificationRequestedHandler {
+    context: HandlerContext,
+    event: UserEmailVerificationRequestedEvent,
+    email_service: Arc<dyn EmailService>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+}
+
+impl SendEmailWhenUserEmailVerificationRequestedHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserEmailVerificationRequestedEvent,
+        email_service: Arc<dyn EmailService>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+            email_verification_token_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserEmailVerificationRequestedHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserEmailVerificationRequestedEvent {
+            user_id,
+            email,
+            username,
+            requested_at: _,
+        } = &self.event;
+
+        let verification_link = self
+            .email_verification_token_service
+            .issue_verification_link(*user_id, email)
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        let to = email.as_str().to_string();
+        let subject = "Please verify your email address".to_string();
+        let body = format!(
+            "Hello {username},\n\nPlease verify your email address by visiting the following link:\n{verification_link}\n\nIf you did not request this email, you can safely ignore it.",
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserLockedOutEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserLockedOutHandler {
+    context: HandlerContext,
+    event: UserLockedOutEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserLockedOutHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserLockedOutEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserLockedOutHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserLockedOutEvent {
+            username,
+            email,
+            locked_until,
+            locked_at: _,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Your Account Has Been Temporarily Locked".to_string();
+        let body = format!(
+            "Dear {username},\n\nYour account has been temporarily locked after several failed login attempts. You can log in again after {locked_until}. If these attempts were not made by you, we recommend resetting your password.\n\nBest regards,\nThe Team"
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
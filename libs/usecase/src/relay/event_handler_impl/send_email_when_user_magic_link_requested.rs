@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserMagicLinkRequestedEvent;
+
+use crate::{
+    auth::magic_link_token_service::MagicLinkTokenService,
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserMagicLinkRequestedHandler {
+    context: HandlerContext,
+    event: UserMagicLinkRequestedEvent,
+    email_service: Arc<dyn EmailService>,
+    magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+}
+
+impl SendEmailWhenUserMagicLinkRequestedHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserMagicLinkRequestedEvent,
+        email_service: Arc<dyn EmailService>,
+        magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+            magic_link_token_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserMagicLinkRequestedHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserMagicLinkRequestedEvent {
+            user_id,
+            email,
+            username,
+            requested_at: _,
+        } = &self.event;
+
+        let login_link = self
+            .magic_link_token_service
+            .issue_login_link(*user_id, email)
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        let to = email.as_str().to_string();
+        let subject = "Your sign-in link".to_string();
+        let body = format!(
+            "Hello {username},\n\nYou can sign in by visiting the following link:\n{login_link}\n\nThis link can be used only once and expires shortly.\n\nIf you did not request this email, you can safely ignore it.",
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserMfaDisabledEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserMfaDisabledHandler {
+    context: HandlerContext,
+    event: UserMfaDisabledEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserMfaDisabledHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserMfaDisabledEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserMfaDisabledHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserMfaDisabledEvent {
+            username,
+            email,
+            disabled_at,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Two-Factor Authentication Has Been Disabled".to_string();
+        let body = format!(
+            "Dear {username},\n\nTwo-factor authentication was disabled for your account at {disabled_at}. If you did not make this change, please reset your password and contact support immediately.\n\nBest regards,\nThe Team"
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserMfaEnabledEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserMfaEnabledHandler {
+    context: HandlerContext,
+    event: UserMfaEnabledEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserMfaEnabledHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserMfaEnabledEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserMfaEnabledHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserMfaEnabledEvent {
+            username,
+            email,
+            enabled_at,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Two-Factor Authentication Has Been Enabled".to_string();
+        let body = format!(
+            "Dear {username},\n\nTwo-factor authentication was enabled for your account at {enabled_at}. From now on, you will be asked for a code from your authenticator app when you log in. If you did not make this change, please contact support immediately.\n\nBest regards,\nThe Team"
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserPasswordChangedEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserPasswordChangedHandler {
+    context: HandlerContext,
+    event: UserPasswordChangedEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserPasswordChangedHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserPasswordChangedEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserPasswordChangedHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserPasswordChangedEvent {
+            username,
+            email,
+            changed_at: _,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Your Password Has Been Changed".to_string();
+        let body = format!(
+            "Dear {username},\n\nThe password for your account has been changed. If you did not perform this action, please reset your password and contact support immediately.\n\nBest regards,\nThe Team"
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
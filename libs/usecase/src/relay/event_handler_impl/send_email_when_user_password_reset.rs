@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserPasswordResetEvent;
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserPasswordResetHandler {
+    context: HandlerContext,
+    event: UserPasswordResetEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserPasswordResetHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserPasswordResetEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserPasswordResetHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserPasswordResetEvent {
+            username,
+            email,
+            reset_at: _,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "Your Password Has Been Reset".to_string();
+        let body = format!(
+            "Dear {username},\n\nYour password has been successfully reset. If you did not perform this action, please contact support immediately.\n\nBest regards,\nThe Team"
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
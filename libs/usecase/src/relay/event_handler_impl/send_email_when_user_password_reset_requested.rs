@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::UserPasswordResetRequestedEvent;
+
+use crate::{
+    auth::password_reset_token_service::PasswordResetTokenService,
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserPasswordResetRequestedHandler {
+    context: HandlerContext,
+    event: UserPasswordResetRequestedEvent,
+    email_service: Arc<dyn EmailService>,
+    password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+}
+
+impl SendEmailWhenUserPasswordResetRequestedHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserPasswordResetRequestedEvent,
+        email_service: Arc<dyn EmailService>,
+        password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+            password_reset_token_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserPasswordResetRequestedHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserPasswordResetRequestedEvent {
+            user_id: _,
+            email,
+            username,
+            reset_token_id,
+            expires_at,
+            requested_at: _,
+        } = &self.event;
+
+        let reset_link = self
+            .password_reset_token_service
+            .reset_link(*reset_token_id);
+
+        let to = email.as_str().to_string();
+        let subject = "Reset your password".to_string();
+        let body = format!(
+            "Hello {username},\n\nWe received a request to reset your password. You can set a new password by visiting the following link:\n{reset_link}\n\nThis link can be used only once and expires at {expires_at}.\n\nIf you did not request a password reset, you can safely ignore this email.",
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::user::{EmailTrait, UserPromotedToAdminEvent};
+
+use crate::{
+    relay::event_handler::HandlerContext,
+    shared::email_service::{EmailMessage, EmailService},
+};
+
+use super::super::{error::RelayError, event_handler::EventHandler};
+
+pub struct SendEmailWhenUserPromotedToAdminHandler {
+    context: HandlerContext,
+    event: UserPromotedToAdminEvent,
+    email_service: Arc<dyn EmailService>,
+}
+
+impl SendEmailWhenUserPromotedToAdminHandler {
+    pub fn new(
+        context: HandlerContext,
+        event: UserPromotedToAdminEvent,
+        email_service: Arc<dyn EmailService>,
+    ) -> Self {
+        Self {
+            context,
+            event,
+            email_service,
+        }
+    }
+}
+
+#[async_trait]
+impl EventHandler for SendEmailWhenUserPromotedToAdminHandler {
+    fn context(&self) -> &HandlerContext {
+        &self.context
+    }
+
+    async fn handle_event_raw(&self) -> Result<(), RelayError> {
+        let UserPromotedToAdminEvent {
+            username,
+            email,
+            promoted_by: _,
+            other_admin_emails: _,
+            promoted_at: _,
+        } = &self.event;
+
+        let to = email.as_str().to_string();
+        let subject = "You Have Been Granted Administrator Privileges".to_string();
+        let body = format!(
+            "Hello {username},\n\nYour account has been granted administrator privileges. Please sign in again to use them.\n\nIf you did not expect this change, please contact support immediately.\n\nBest regards,\nThe Team",
+        );
+
+        let email_message = EmailMessage { to, subject, body };
+
+        self.email_service
+            .send_email(email_message)
+            .await
+            .map_err(|e| RelayError::ProcessingError(e.into()))?;
+
+        Ok(())
+    }
+}
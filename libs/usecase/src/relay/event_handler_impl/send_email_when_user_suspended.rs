@@ -40,6 +40,7 @@ impl EventHandler for SendEmailWhenUserSuspendedHandler {
         let UserSuspendedEvent {
             username,
             suspended_at: _,
+            suspended_by: _,
             reason,
             email,
         } = &self.event;
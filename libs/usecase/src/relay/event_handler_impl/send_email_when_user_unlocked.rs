@@ -40,6 +40,8 @@ impl EventHandler for SendEmailWhenUserUnlockedHandler {
         let UserUnlockedEvent {
             username,
             email,
+            reason: _,
+            unlocked_by: _,
             unlocked_at: _,
         } = &self.event;
 
@@ -13,9 +13,20 @@ pub struct EventMapper {
     user_deactivated_factory: Box<dyn HandlerFactory>,
     user_reactivated_factory: Box<dyn HandlerFactory>,
     user_promoted_to_admin_factory: Box<dyn HandlerFactory>,
+    user_demoted_from_admin_factory: Box<dyn HandlerFactory>,
     username_changed_factory: Box<dyn HandlerFactory>,
     user_email_changed_factory: Box<dyn HandlerFactory>,
     user_email_verified_factory: Box<dyn HandlerFactory>,
+    user_email_verification_requested_factory: Box<dyn HandlerFactory>,
+    user_password_reset_requested_factory: Box<dyn HandlerFactory>,
+    user_magic_link_requested_factory: Box<dyn HandlerFactory>,
+    user_password_reset_factory: Box<dyn HandlerFactory>,
+    user_password_changed_factory: Box<dyn HandlerFactory>,
+    user_locked_out_factory: Box<dyn HandlerFactory>,
+    user_mfa_enabled_factory: Box<dyn HandlerFactory>,
+    user_mfa_disabled_factory: Box<dyn HandlerFactory>,
+    user_impersonation_started_factory: Box<dyn HandlerFactory>,
+    user_impersonation_ended_factory: Box<dyn HandlerFactory>,
 }
 
 pub struct EventFactories {
@@ -25,9 +36,20 @@ pub struct EventFactories {
     pub user_deactivated: Box<dyn HandlerFactory>,
     pub user_reactivated: Box<dyn HandlerFactory>,
     pub user_promoted_to_admin: Box<dyn HandlerFactory>,
+    pub user_demoted_from_admin: Box<dyn HandlerFactory>,
     pub user_username_changed: Box<dyn HandlerFactory>,
     pub user_email_changed: Box<dyn HandlerFactory>,
     pub user_email_verified: Box<dyn HandlerFactory>,
+    pub user_email_verification_requested: Box<dyn HandlerFactory>,
+    pub user_password_reset_requested: Box<dyn HandlerFactory>,
+    pub user_magic_link_requested: Box<dyn HandlerFactory>,
+    pub user_password_reset: Box<dyn HandlerFactory>,
+    pub user_password_changed: Box<dyn HandlerFactory>,
+    pub user_locked_out: Box<dyn HandlerFactory>,
+    pub user_mfa_enabled: Box<dyn HandlerFactory>,
+    pub user_mfa_disabled: Box<dyn HandlerFactory>,
+    pub user_impersonation_started: Box<dyn HandlerFactory>,
+    pub user_impersonation_ended: Box<dyn HandlerFactory>,
 }
 
 impl EventMapper {
@@ -39,9 +61,20 @@ impl EventMapper {
             user_deactivated_factory: factories.user_deactivated,
             user_reactivated_factory: factories.user_reactivated,
             user_promoted_to_admin_factory: factories.user_promoted_to_admin,
+            user_demoted_from_admin_factory: factories.user_demoted_from_admin,
             username_changed_factory: factories.user_username_changed,
             user_email_changed_factory: factories.user_email_changed,
             user_email_verified_factory: factories.user_email_verified,
+            user_email_verification_requested_factory: factories.user_email_verification_requested,
+            user_password_reset_requested_factory: factories.user_password_reset_requested,
+            user_magic_link_requested_factory: factories.user_magic_link_requested,
+            user_password_reset_factory: factories.user_password_reset,
+            user_password_changed_factory: factories.user_password_changed,
+            user_locked_out_factory: factories.user_locked_out,
+            user_mfa_enabled_factory: factories.user_mfa_enabled,
+            user_mfa_disabled_factory: factories.user_mfa_disabled,
+            user_impersonation_started_factory: factories.user_impersonation_started,
+            user_impersonation_ended_factory: factories.user_impersonation_ended,
         }
     }
 }
@@ -67,6 +100,9 @@ impl EventMapper {
                 UserEvent::PromotedToAdmin(_) => {
                     self.user_promoted_to_admin_factory.create(event, context)
                 }
+                UserEvent::DemotedFromAdmin(_) => {
+                    self.user_demoted_from_admin_factory.create(event, context)
+                }
                 UserEvent::UsernameChanged(_) => {
                     self.username_changed_factory.create(event, context)
                 }
@@ -76,6 +112,30 @@ impl EventMapper {
                 UserEvent::EmailVerified(_) => {
                     self.user_email_verified_factory.create(event, context)
                 }
+                UserEvent::EmailVerificationRequested(_) => self
+                    .user_email_verification_requested_factory
+                    .create(event, context),
+                UserEvent::PasswordResetRequested(_) => self
+                    .user_password_reset_requested_factory
+                    .create(event, context),
+                UserEvent::MagicLinkRequested(_) => self
+                    .user_magic_link_requested_factory
+                    .create(event, context),
+                UserEvent::PasswordReset(_) => {
+                    self.user_password_reset_factory.create(event, context)
+                }
+                UserEvent::PasswordChanged(_) => {
+                    self.user_password_changed_factory.create(event, context)
+                }
+                UserEvent::LockedOut(_) => self.user_locked_out_factory.create(event, context),
+                UserEvent::MfaEnabled(_) => self.user_mfa_enabled_factory.create(event, context),
+                UserEvent::MfaDisabled(_) => self.user_mfa_disabled_factory.create(event, context),
+                UserEvent::ImpersonationStarted(_) => self
+                    .user_impersonation_started_factory
+                    .create(event, context),
+                UserEvent::ImpersonationEnded(_) => {
+                    self.user_impersonation_ended_factory.create(event, context)
+                }
             },
         }
     }
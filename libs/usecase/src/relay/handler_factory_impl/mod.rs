@@ -1,7 +1,18 @@
 pub mod user_created_factory;
 pub mod user_deactivated_factory;
+pub mod user_demoted_from_admin_factory;
 pub mod user_email_changed_factory;
+pub mod user_email_verification_requested_factory;
 pub mod user_email_verified_factory;
+pub mod user_impersonation_ended_factory;
+pub mod user_impersonation_started_factory;
+pub mod user_locked_out_factory;
+pub mod user_magic_link_requested_factory;
+pub mod user_mfa_disabled_factory;
+pub mod user_mfa_enabled_factory;
+pub mod user_password_changed_factory;
+pub mod user_password_reset_factory;
+pub mod user_password_reset_requested_factory;
 pub mod user_promoted_to_admin_factory;
 pub mod user_reactivated_factory;
 pub mod user_suspended_factory;
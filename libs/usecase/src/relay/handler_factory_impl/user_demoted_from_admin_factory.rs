@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::{
+            SendEmailToAdminsWhenUserDemotedFromAdminHandler,
+            SendEmailWhenUserDemotedFromAdminHandler,
+        },
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserDemotedFromAdminFactory {
+    email_service: Arc<dyn EmailService>,
+}
+
+impl UserDemotedFromAdminFactory {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
+    }
+}
+
+impl HandlerFactory for UserDemotedFromAdminFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::DemotedFromAdmin(user_demoted_from_admin_event)) =
+            event
+        {
+            // 本人と他の管理者の両方に通知する
+            vec![
+                Box::new(SendEmailWhenUserDemotedFromAdminHandler::new(
+                    context,
+                    user_demoted_from_admin_event.clone(),
+                    self.email_service.clone(),
+                )),
+                Box::new(SendEmailToAdminsWhenUserDemotedFromAdminHandler::new(
+                    context,
+                    user_demoted_from_admin_event.clone(),
+                    self.email_service.clone(),
+                )),
+            ]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
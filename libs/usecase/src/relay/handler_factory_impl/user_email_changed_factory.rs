@@ -3,6 +3,7 @@ use std::sync::Arc;
 use domain::{shared::domain_event::DomainEvent, user::UserEvent};
 
 use crate::{
+    auth::email_verification_token_service::EmailVerificationTokenService,
     relay::{
         event_handler::{EventHandler, HandlerContext},
         event_handler_impl::SendEmailWhenUserEmailChangedHandler,
@@ -13,11 +14,18 @@ use crate::{
 
 pub struct UserEmailChangedFactory {
     email_service: Arc<dyn EmailService>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
 }
 
 impl UserEmailChangedFactory {
-    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
-        Self { email_service }
+    pub fn new(
+        email_service: Arc<dyn EmailService>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+    ) -> Self {
+        Self {
+            email_service,
+            email_verification_token_service,
+        }
     }
 }
 
@@ -28,6 +36,7 @@ impl HandlerFactory for UserEmailChangedFactory {
                 context,
                 user_email_changed_event.clone(),
                 self.email_service.clone(),
+                self.email_verification_token_service.clone(),
             ))]
         } else {
             self.report_misconfiguration(event);
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    auth::email_verification_token_service::EmailVerificationTokenService,
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserEmailVerificationRequestedHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserEmailVerificationRequestedFactory {
+    email_service: Arc<dyn EmailService>,
+    email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+}
+
+impl UserEmailVerificationRequestedFactory {
+    pub fn new(
+        email_service: Arc<dyn EmailService>,
+        email_verification_token_service: Arc<dyn EmailVerificationTokenService>,
+    ) -> Self {
+        Self {
+            email_service,
+            email_verification_token_service,
+        }
+    }
+}
+
+impl HandlerFactory for UserEmailVerificationRequestedFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::EmailVerificationRequested(requested_event)) =
+            event
+        {
+            vec![Box::new(
+                SendEmailWhenUserEmailVerificationRequestedHandler::new(
+                    context,
+                    requested_event.clone(),
+                    self.email_service.clone(),
+                    self.email_verification_token_service.clone(),
+                ),
+            )]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
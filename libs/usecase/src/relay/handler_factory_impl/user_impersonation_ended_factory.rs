@@ -0,0 +1,35 @@
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::relay::{
+    event_handler::{EventHandler, HandlerContext},
+    handler_factory::HandlerFactory,
+};
+
+/// なりすましの終了は監査記録としてアウトボックスに残すのみで、後続の処理は行わない
+pub struct UserImpersonationEndedFactory {}
+
+impl UserImpersonationEndedFactory {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for UserImpersonationEndedFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandlerFactory for UserImpersonationEndedFactory {
+    fn create(&self, event: &DomainEvent, _context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::ImpersonationEnded(
+            _user_impersonation_ended_event,
+        )) = event
+        {
+            vec![]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserLockedOutHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserLockedOutFactory {
+    email_service: Arc<dyn EmailService>,
+}
+
+impl UserLockedOutFactory {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
+    }
+}
+
+impl HandlerFactory for UserLockedOutFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::LockedOut(user_locked_out_event)) = event {
+            vec![Box::new(SendEmailWhenUserLockedOutHandler::new(
+                context,
+                user_locked_out_event.clone(),
+                self.email_service.clone(),
+            ))]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
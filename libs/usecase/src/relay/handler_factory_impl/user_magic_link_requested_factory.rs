@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    auth::magic_link_token_service::MagicLinkTokenService,
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserMagicLinkRequestedHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserMagicLinkRequestedFactory {
+    email_service: Arc<dyn EmailService>,
+    magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+}
+
+impl UserMagicLinkRequestedFactory {
+    pub fn new(
+        email_service: Arc<dyn EmailService>,
+        magic_link_token_service: Arc<dyn MagicLinkTokenService>,
+    ) -> Self {
+        Self {
+            email_service,
+            magic_link_token_service,
+        }
+    }
+}
+
+impl HandlerFactory for UserMagicLinkRequestedFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::MagicLinkRequested(requested_event)) = event {
+            vec![Box::new(SendEmailWhenUserMagicLinkRequestedHandler::new(
+                context,
+                requested_event.clone(),
+                self.email_service.clone(),
+                self.magic_link_token_service.clone(),
+            ))]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserMfaEnabledHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserMfaEnabledFactory {
+    email_service: Arc<dyn EmailService>,
+}
+
+impl UserMfaEnabledFactory {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
+    }
+}
+
+impl HandlerFactory for UserMfaEnabledFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::MfaEnabled(user_mfa_enabled_event)) = event {
+            vec![Box::new(SendEmailWhenUserMfaEnabledHandler::new(
+                context,
+                user_mfa_enabled_event.clone(),
+                self.email_service.clone(),
+            ))]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
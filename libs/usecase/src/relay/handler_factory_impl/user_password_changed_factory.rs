@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserPasswordChangedHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserPasswordChangedFactory {
+    email_service: Arc<dyn EmailService>,
+}
+
+impl UserPasswordChangedFactory {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
+    }
+}
+
+impl HandlerFactory for UserPasswordChangedFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::PasswordChanged(user_password_changed_event)) =
+            event
+        {
+            vec![Box::new(SendEmailWhenUserPasswordChangedHandler::new(
+                context,
+                user_password_changed_event.clone(),
+                self.email_service.clone(),
+            ))]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
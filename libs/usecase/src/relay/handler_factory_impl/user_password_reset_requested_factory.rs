@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use domain::{shared::domain_event::DomainEvent, user::UserEvent};
+
+use crate::{
+    auth::password_reset_token_service::PasswordResetTokenService,
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::SendEmailWhenUserPasswordResetRequestedHandler,
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
+};
+
+pub struct UserPasswordResetRequestedFactory {
+    email_service: Arc<dyn EmailService>,
+    password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+}
+
+impl UserPasswordResetRequestedFactory {
+    pub fn new(
+        email_service: Arc<dyn EmailService>,
+        password_reset_token_service: Arc<dyn PasswordResetTokenService>,
+    ) -> Self {
+        Self {
+            email_service,
+            password_reset_token_service,
+        }
+    }
+}
+
+impl HandlerFactory for UserPasswordResetRequestedFactory {
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::PasswordResetRequested(requested_event)) = event {
+            vec![Box::new(
+                SendEmailWhenUserPasswordResetRequestedHandler::new(
+                    context,
+                    requested_event.clone(),
+                    self.email_service.clone(),
+                    self.password_reset_token_service.clone(),
+                ),
+            )]
+        } else {
+            self.report_misconfiguration(event);
+            vec![]
+        }
+    }
+}
@@ -1,30 +1,47 @@
+use std::sync::Arc;
+
 use domain::{shared::domain_event::DomainEvent, user::UserEvent};
 
-use crate::relay::{
-    event_handler::{EventHandler, HandlerContext},
-    handler_factory::HandlerFactory,
+use crate::{
+    relay::{
+        event_handler::{EventHandler, HandlerContext},
+        event_handler_impl::{
+            SendEmailToAdminsWhenUserPromotedToAdminHandler,
+            SendEmailWhenUserPromotedToAdminHandler,
+        },
+        handler_factory::HandlerFactory,
+    },
+    shared::email_service::EmailService,
 };
 
-pub struct UserPromotedToAdminFactory {}
-
-impl UserPromotedToAdminFactory {
-    pub fn new() -> Self {
-        Self {}
-    }
+pub struct UserPromotedToAdminFactory {
+    email_service: Arc<dyn EmailService>,
 }
 
-impl Default for UserPromotedToAdminFactory {
-    fn default() -> Self {
-        Self::new()
+impl UserPromotedToAdminFactory {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
     }
 }
 
 impl HandlerFactory for UserPromotedToAdminFactory {
-    fn create(&self, event: &DomainEvent, _context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
-        if let DomainEvent::UserEvent(UserEvent::PromotedToAdmin(_user_promoted_to_admin_event)) =
+    fn create(&self, event: &DomainEvent, context: HandlerContext) -> Vec<Box<dyn EventHandler>> {
+        if let DomainEvent::UserEvent(UserEvent::PromotedToAdmin(user_promoted_to_admin_event)) =
             event
         {
-            vec![]
+            // 本人と他の管理者の両方に通知する
+            vec![
+                Box::new(SendEmailWhenUserPromotedToAdminHandler::new(
+                    context,
+                    user_promoted_to_admin_event.clone(),
+                    self.email_service.clone(),
+                )),
+                Box::new(SendEmailToAdminsWhenUserPromotedToAdminHandler::new(
+                    context,
+                    user_promoted_to_admin_event.clone(),
+                    self.email_service.clone(),
+                )),
+            ]
         } else {
             self.report_misconfiguration(event);
             vec![]
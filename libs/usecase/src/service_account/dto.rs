@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use domain::auth::service_account::ServiceAccount;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::shared::identity::UserRoleData;
+
+#[derive(derive_more::Debug, Validate)]
+pub struct CreateServiceAccountInput {
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "サービスアカウントの名前は1～64文字で入力してください"
+    ))]
+    pub name: String,
+    pub role: UserRoleData,
+}
+
+#[derive(derive_more::Debug)]
+pub struct CreateServiceAccountOutput {
+    /// クライアントシークレットの平文。サーバー側には保存しないため、この応答でのみ取得できる
+    #[debug(skip)]
+    pub client_secret: String,
+    pub details: ServiceAccountItem,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListServiceAccountsOutput {
+    pub service_accounts: Vec<ServiceAccountItem>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ServiceAccountItem {
+    pub service_account_id: Uuid,
+    /// クライアントクレデンシャルグラントで提示するクライアント ID
+    pub client_id: String,
+    pub name: String,
+    pub role: UserRoleData,
+    pub created_at: DateTime<Utc>,
+    pub last_authenticated_at: Option<DateTime<Utc>>,
+}
+
+impl From<ServiceAccount> for ServiceAccountItem {
+    fn from(service_account: ServiceAccount) -> Self {
+        ServiceAccountItem {
+            service_account_id: service_account.id().into(),
+            client_id: service_account.id().to_string(),
+            name: service_account.name().to_string(),
+            role: service_account.role().into(),
+            created_at: service_account.created_at(),
+            last_authenticated_at: service_account.last_authenticated_at(),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct DeleteServiceAccountInput {
+    pub service_account_id: Uuid,
+}
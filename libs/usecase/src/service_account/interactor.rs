@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use domain::{
+    auth::{
+        policies::manage_service_accounts::ManageServiceAccountsPayload,
+        policy::{AuthorizationService, UserAction},
+        service_account::{
+            ServiceAccount, ServiceAccountIdGeneratorFactory, ServiceAccountSecretHash,
+        },
+    },
+    shared::service::clock::Clock,
+    transaction::TransactionManager,
+    tx,
+};
+use validator::Validate as _;
+
+use crate::{
+    auth::opaque_token,
+    service_account::{
+        dto::{
+            CreateServiceAccountInput, CreateServiceAccountOutput, DeleteServiceAccountInput,
+            ListServiceAccountsOutput,
+        },
+        service::ServiceAccountService,
+    },
+    shared::identity::{Identity, IdentityWrapper},
+    usecase_error::UseCaseError,
+};
+
+pub struct ServiceAccountInteractor<TM: TransactionManager> {
+    transaction_manager: Arc<TM>,
+    service_account_id_generator_factory: Arc<dyn ServiceAccountIdGeneratorFactory>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<TM: TransactionManager> ServiceAccountInteractor<TM> {
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        service_account_id_generator_factory: Arc<dyn ServiceAccountIdGeneratorFactory>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            transaction_manager,
+            service_account_id_generator_factory,
+            clock,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM: TransactionManager> ServiceAccountService for ServiceAccountInteractor<TM> {
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn create_service_account(
+        &self,
+        identity: Box<dyn Identity>,
+        input: CreateServiceAccountInput,
+    ) -> Result<CreateServiceAccountOutput, UseCaseError> {
+        input.validate()?;
+
+        let service_account_id_generator_factory =
+            self.service_account_id_generator_factory.clone();
+        let clock = self.clock.clone();
+        let CreateServiceAccountInput { name, role } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageServiceAccounts(ManageServiceAccountsPayload),
+            )?;
+
+            let id = service_account_id_generator_factory
+                .create_service_account_id_generator()
+                .generate()?;
+
+            // シークレット自体が十分なエントロピーを持つため、API キーと同様に SHA-256 で保存する
+            let client_secret = opaque_token::generate();
+            let secret_hash =
+                ServiceAccountSecretHash::from_raw_str(&opaque_token::hash(&client_secret));
+
+            let service_account =
+                ServiceAccount::create(id, name, role.into(), secret_hash, clock.as_ref());
+
+            let service_account = service_account_repo.save(service_account).await?;
+
+            Ok::<_, UseCaseError>(CreateServiceAccountOutput {
+                client_secret,
+                details: service_account.into(),
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn list_service_accounts(
+        &self,
+        identity: Box<dyn Identity>,
+    ) -> Result<ListServiceAccountsOutput, UseCaseError> {
+        tx!(self.transaction_manager, |factory| {
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageServiceAccounts(ManageServiceAccountsPayload),
+            )?;
+
+            let service_accounts = service_account_repo.find_all().await?;
+
+            Ok::<_, UseCaseError>(ListServiceAccountsOutput {
+                service_accounts: service_accounts.into_iter().map(Into::into).collect(),
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn delete_service_account(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeleteServiceAccountInput,
+    ) -> Result<(), UseCaseError> {
+        let DeleteServiceAccountInput { service_account_id } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageServiceAccounts(ManageServiceAccountsPayload),
+            )?;
+
+            let service_account = service_account_repo
+                .find_by_id(service_account_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 発行済みのアクセストークンも、認証時にサービスアカウントが見つからないため使用できなくなる
+            service_account_repo.delete(service_account.id()).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+}
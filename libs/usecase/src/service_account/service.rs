@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+use crate::{
+    service_account::dto::{
+        CreateServiceAccountInput, CreateServiceAccountOutput, DeleteServiceAccountInput,
+        ListServiceAccountsOutput,
+    },
+    shared::identity::Identity,
+    usecase_error::UseCaseError,
+};
+
+#[async_trait]
+pub trait ServiceAccountService: Send + Sync {
+    async fn create_service_account(
+        &self,
+        identity: Box<dyn Identity>,
+        input: CreateServiceAccountInput,
+    ) -> Result<CreateServiceAccountOutput, UseCaseError>;
+
+    async fn list_service_accounts(
+        &self,
+        identity: Box<dyn Identity>,
+    ) -> Result<ListServiceAccountsOutput, UseCaseError>;
+
+    async fn delete_service_account(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeleteServiceAccountInput,
+    ) -> Result<(), UseCaseError>;
+}
@@ -1,12 +1,27 @@
 use domain::{
-    auth::policy::Actor,
+    auth::{
+        actor::{ActorId, AuditActor},
+        api_key::ApiKeyScope,
+        login_policy::AccessScope,
+        policy::Actor,
+        service_account::ServiceAccountRepository,
+    },
     user::{UserId, UserRole},
 };
+use serde::Serialize;
 use uuid::Uuid;
 
+use crate::usecase_error::UseCaseError;
+
 pub trait Identity: std::fmt::Debug + Send + Sync {
+    /// 操作の主体の ID。`actor_kind` に応じてユーザーまたはサービスアカウントの ID を表す
     fn actor_id(&self) -> Uuid;
+    fn actor_kind(&self) -> ActorKindData;
     fn actor_role(&self) -> UserRoleData;
+    /// API キーで認証された場合に付与されているスコープ。対話的なログインの場合は `None`
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>>;
+    /// 管理者が他のユーザーになりすましている場合に、実際に操作している管理者 (本当の操作の主体) の ID
+    fn impersonator_id(&self) -> Option<Uuid>;
 }
 
 #[derive(derive_more::Debug)]
@@ -15,13 +30,27 @@ pub(crate) struct IdentityWrapper<T: Identity> {
 }
 
 impl<T: Identity> Actor for IdentityWrapper<T> {
-    fn actor_id(&self) -> UserId {
-        self.inner.actor_id().into()
+    fn actor_id(&self) -> ActorId {
+        let id = self.inner.actor_id();
+        match self.inner.actor_kind() {
+            ActorKindData::User => ActorId::User(id.into()),
+            ActorKindData::ServiceAccount => ActorId::ServiceAccount(id.into()),
+        }
     }
 
     fn actor_role(&self) -> UserRole {
         self.inner.actor_role().into()
     }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScope>> {
+        self.inner
+            .actor_scopes()
+            .map(|scopes| scopes.into_iter().map(Into::into).collect())
+    }
+
+    fn impersonator_id(&self) -> Option<UserId> {
+        self.inner.impersonator_id().map(Into::into)
+    }
 }
 
 impl Identity for &Box<dyn Identity> {
@@ -29,9 +58,21 @@ impl Identity for &Box<dyn Identity> {
         self.as_ref().actor_id()
     }
 
+    fn actor_kind(&self) -> ActorKindData {
+        self.as_ref().actor_kind()
+    }
+
     fn actor_role(&self) -> UserRoleData {
         self.as_ref().actor_role()
     }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScopeData>> {
+        self.as_ref().actor_scopes()
+    }
+
+    fn impersonator_id(&self) -> Option<Uuid> {
+        self.as_ref().impersonator_id()
+    }
 }
 
 impl<'a> From<&'a Box<dyn Identity>> for IdentityWrapper<&'a Box<dyn Identity>> {
@@ -40,6 +81,66 @@ impl<'a> From<&'a Box<dyn Identity>> for IdentityWrapper<&'a Box<dyn Identity>>
     }
 }
 
+/// パスワードの確認によって本人であることを確かめた、ログインしていないユーザー
+///
+/// 退会済みのアカウントなど、ログインできない状態のアカウントに対する本人の操作をポリシーで判定するために使用する
+pub(crate) struct PasswordVerifiedUser {
+    pub(crate) user_id: UserId,
+    pub(crate) role: UserRole,
+}
+
+impl Actor for PasswordVerifiedUser {
+    fn actor_id(&self) -> ActorId {
+        ActorId::User(self.user_id)
+    }
+
+    fn actor_role(&self) -> UserRole {
+        self.role
+    }
+
+    fn actor_scopes(&self) -> Option<Vec<ApiKeyScope>> {
+        None
+    }
+
+    fn impersonator_id(&self) -> Option<UserId> {
+        None
+    }
+}
+
+/// 監査記録に残す操作の主体を求める
+///
+/// サービスアカウントの場合は名前も記録するため、現在のサービスアカウントを取得する
+pub(crate) async fn audit_actor(
+    identity: &dyn Identity,
+    service_account_repo: &dyn ServiceAccountRepository,
+) -> Result<AuditActor, UseCaseError> {
+    match identity.actor_kind() {
+        ActorKindData::User => Ok(AuditActor::User {
+            user_id: identity.actor_id().into(),
+        }),
+        ActorKindData::ServiceAccount => {
+            // トークンの発行後に削除されたサービスアカウントによる操作は受け付けない
+            let service_account = service_account_repo
+                .find_by_id(identity.actor_id().into())
+                .await?
+                .ok_or(UseCaseError::Unauthorized)?;
+
+            Ok(AuditActor::ServiceAccount {
+                service_account_id: service_account.id(),
+                name: service_account.name().to_string(),
+            })
+        }
+    }
+}
+
+/// 操作の主体の種類
+#[derive(derive_more::Debug, Clone, Copy, strum::Display, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+pub enum ActorKindData {
+    User,
+    ServiceAccount,
+}
+
 #[derive(derive_more::Debug, Clone, Copy, strum::Display, PartialEq, Eq)]
 #[strum(serialize_all = "snake_case")]
 pub enum UserRoleData {
@@ -64,3 +165,56 @@ impl From<UserRole> for UserRoleData {
         }
     }
 }
+
+#[derive(derive_more::Debug, Clone, Copy, strum::Display, strum::EnumString, PartialEq, Eq)]
+pub enum ApiKeyScopeData {
+    #[strum(serialize = "profile:read")]
+    ProfileRead,
+    #[strum(serialize = "profile:write")]
+    ProfileWrite,
+    #[strum(serialize = "admin:read")]
+    AdminRead,
+    #[strum(serialize = "admin:write")]
+    AdminWrite,
+}
+
+impl From<ApiKeyScopeData> for ApiKeyScope {
+    fn from(scope: ApiKeyScopeData) -> Self {
+        match scope {
+            ApiKeyScopeData::ProfileRead => ApiKeyScope::ProfileRead,
+            ApiKeyScopeData::ProfileWrite => ApiKeyScope::ProfileWrite,
+            ApiKeyScopeData::AdminRead => ApiKeyScope::AdminRead,
+            ApiKeyScopeData::AdminWrite => ApiKeyScope::AdminWrite,
+        }
+    }
+}
+
+impl From<ApiKeyScope> for ApiKeyScopeData {
+    fn from(scope: ApiKeyScope) -> Self {
+        match scope {
+            ApiKeyScope::ProfileRead => ApiKeyScopeData::ProfileRead,
+            ApiKeyScope::ProfileWrite => ApiKeyScopeData::ProfileWrite,
+            ApiKeyScope::AdminRead => ApiKeyScopeData::AdminRead,
+            ApiKeyScope::AdminWrite => ApiKeyScopeData::AdminWrite,
+        }
+    }
+}
+
+/// ログインで発行したアクセストークンで許可する操作の範囲
+#[derive(derive_more::Debug, Clone, Copy, strum::Display, PartialEq, Eq, Serialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AccessScopeData {
+    Full,
+    /// メールアドレスの認証と自分のプロフィールの閲覧のみ
+    PendingVerification,
+}
+
+impl From<AccessScope> for AccessScopeData {
+    fn from(scope: AccessScope) -> Self {
+        match scope {
+            AccessScope::Full => AccessScopeData::Full,
+            AccessScope::PendingVerification => AccessScopeData::PendingVerification,
+        }
+    }
+}
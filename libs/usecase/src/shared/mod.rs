@@ -1,2 +1,3 @@
 pub mod email_service;
 pub mod identity;
+pub mod rate_limiter;
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RateLimiterError {
+    #[error("レート制限の状態の取得に失敗しました: {0}")]
+    Storage(#[source] anyhow::Error),
+}
+
+#[async_trait]
+pub trait RateLimiter: Send + Sync {
+    /// `key` ごとの試行を 1 回記録し、上限以内であれば `true` を返す
+    ///
+    /// 上限を超えた試行は記録せず `false` を返す
+    async fn try_acquire(&self, key: &str) -> Result<bool, RateLimiterError>;
+
+    /// `key` の試行回数が上限に達しているかを、試行を記録せずに返す
+    async fn is_limited(&self, key: &str) -> Result<bool, RateLimiterError>;
+}
@@ -1,4 +1,7 @@
-use domain::{auth::policy::AuthorizationError, transaction::IntoTxError};
+use domain::{
+    auth::{login_policy::LoginRejection, policy::AuthorizationError},
+    transaction::IntoTxError,
+};
 use serde::Serialize;
 use thiserror::Error;
 use validator::{ValidationErrors, ValidationErrorsKind};
@@ -15,10 +18,29 @@ pub enum UseCaseError {
     NotFound,
     #[error("リソースの競合が検知されました: {message}")]
     Conflict { message: String },
+    #[error("リクエストが多すぎます: {message}")]
+    TooManyRequests { message: String },
+    #[error("アカウントの状態により操作が制限されています: {message}")]
+    AccountRestricted {
+        reason: AccountRestrictionReason,
+        message: String,
+    },
     #[error("サーバー内部でエラーが発生しました: {0}")]
     Internal(#[source] anyhow::Error),
 }
 
+/// アカウントの状態による制限の理由 (クライアントが処理を分岐するためのコード)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountRestrictionReason {
+    /// 管理者による利用停止中
+    AccountSuspended,
+    /// ユーザーによる退会済み
+    AccountDeactivated,
+    /// メールアドレスの認証が完了していない
+    EmailVerificationRequired,
+}
+
 #[derive(derive_more::Debug, Error, Serialize)]
 #[debug("{:?}", _0)]
 #[error("{0:?}")]
@@ -60,6 +82,21 @@ impl From<AuthorizationError> for UseCaseError {
     }
 }
 
+impl From<LoginRejection> for UseCaseError {
+    fn from(rejection: LoginRejection) -> Self {
+        match rejection {
+            LoginRejection::Suspended => UseCaseError::AccountRestricted {
+                reason: AccountRestrictionReason::AccountSuspended,
+                message: rejection.to_string(),
+            },
+            LoginRejection::Deactivated => UseCaseError::AccountRestricted {
+                reason: AccountRestrictionReason::AccountDeactivated,
+                message: format!("{rejection}。アカウントを再開するとログインできます"),
+            },
+        }
+    }
+}
+
 impl From<validator::ValidationErrors> for ValidationErrorList {
     fn from(validation_errors: validator::ValidationErrors) -> Self {
         convert_validation_error(&validation_errors)
@@ -173,6 +210,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_get_then_check)]
     fn test_flatten_validation_errors() {
         #[derive(Validate)]
         struct Nested {
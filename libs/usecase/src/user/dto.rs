@@ -1,9 +1,21 @@
-use domain::user::User;
+use chrono::{DateTime, Utc};
+use domain::{
+    auth::{api_key::ApiKey, session::Session, webauthn_credential::WebAuthnCredential},
+    user::{
+        SortDirection, User, UserListFilter, UserListQuery, UserListSort, UserListSortKey,
+        UserStateKind,
+    },
+};
 use regex::Regex;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::shared::identity::UserRoleData;
+use crate::{
+    auth::webauthn_service::{
+        IssuedRegistrationChallenge, PublicKeyCredentialCreationOptions, RegistrationCredential,
+    },
+    shared::identity::{AccessScopeData, ApiKeyScopeData, UserRoleData},
+};
 
 #[derive(derive_more::Debug)]
 pub struct UserDetailedProfile {
@@ -33,14 +45,134 @@ pub struct GetProfileInput {
     pub user_id: Uuid,
 }
 
-#[derive(derive_more::Debug)]
+/// ユーザー一覧の1ページあたりの件数 (指定がない場合)
+pub const DEFAULT_LIST_USERS_LIMIT: u64 = 20;
+
+#[derive(derive_more::Debug, Validate)]
+#[validate(schema(function = "validate_created_at_range"))]
 pub struct ListUsersInput {
-    // Add fields for filtering, pagination, etc. if needed
+    pub state: Option<UserStateData>,
+    pub role: Option<UserRoleData>,
+    /// 登録日時の下限 (この日時を含む)
+    pub created_from: Option<DateTime<Utc>>,
+    /// 登録日時の上限 (この日時を含まない)
+    pub created_until: Option<DateTime<Utc>>,
+    #[debug(skip)]
+    pub email_prefix: Option<String>,
+    pub username_prefix: Option<String>,
+    pub sort_by: UserListSortKeyData,
+    pub sort_order: SortDirectionData,
+    /// 前のページの `next_cursor`
+    pub cursor: Option<Uuid>,
+    #[validate(range(min = 1, max = 100, message = "取得件数は1～100件で指定してください"))]
+    pub limit: Option<u64>,
+}
+
+fn validate_created_at_range(input: &ListUsersInput) -> Result<(), validator::ValidationError> {
+    if let (Some(from), Some(until)) = (input.created_from, input.created_until)
+        && from >= until
+    {
+        let mut error = validator::ValidationError::new("invalid_created_at_range");
+        error.message = Some("登録日時の範囲は開始を終了より前に指定してください".into());
+        return Err(error);
+    }
+    Ok(())
+}
+
+impl From<ListUsersInput> for UserListQuery {
+    fn from(input: ListUsersInput) -> Self {
+        let ListUsersInput {
+            state,
+            role,
+            created_from,
+            created_until,
+            email_prefix,
+            username_prefix,
+            sort_by,
+            sort_order,
+            cursor,
+            limit,
+        } = input;
+
+        UserListQuery {
+            filter: UserListFilter {
+                state: state.map(Into::into),
+                role: role.map(Into::into),
+                created_from,
+                created_until,
+                email_prefix,
+                username_prefix,
+            },
+            sort: UserListSort {
+                key: sort_by.into(),
+                direction: sort_order.into(),
+            },
+            cursor: cursor.map(Into::into),
+            limit: limit.unwrap_or(DEFAULT_LIST_USERS_LIMIT),
+        }
+    }
+}
+
+#[derive(derive_more::Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStateData {
+    Active,
+    SuspendedByAdmin,
+    DeactivatedByUser,
+    PendingVerification,
+    ActiveWithUnverifiedEmail,
+}
+
+impl From<UserStateData> for UserStateKind {
+    fn from(state: UserStateData) -> Self {
+        match state {
+            UserStateData::Active => UserStateKind::Active,
+            UserStateData::SuspendedByAdmin => UserStateKind::SuspendedByAdmin,
+            UserStateData::DeactivatedByUser => UserStateKind::DeactivatedByUser,
+            UserStateData::PendingVerification => UserStateKind::PendingVerification,
+            UserStateData::ActiveWithUnverifiedEmail => UserStateKind::ActiveWithUnverifiedEmail,
+        }
+    }
+}
+
+#[derive(derive_more::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UserListSortKeyData {
+    #[default]
+    CreatedAt,
+    Username,
+}
+
+impl From<UserListSortKeyData> for UserListSortKey {
+    fn from(key: UserListSortKeyData) -> Self {
+        match key {
+            UserListSortKeyData::CreatedAt => UserListSortKey::CreatedAt,
+            UserListSortKeyData::Username => UserListSortKey::Username,
+        }
+    }
+}
+
+#[derive(derive_more::Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirectionData {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl From<SortDirectionData> for SortDirection {
+    fn from(direction: SortDirectionData) -> Self {
+        match direction {
+            SortDirectionData::Asc => SortDirection::Asc,
+            SortDirectionData::Desc => SortDirection::Desc,
+        }
+    }
 }
 
 #[derive(derive_more::Debug)]
 pub struct ListUsersOutput {
     pub users: Vec<UserItem>,
+    /// 次のページを取得するためのカーソル。最後のページの場合は `None`
+    pub next_cursor: Option<Uuid>,
+    /// 絞り込み条件に一致するユーザーの総数
+    pub total: u64,
 }
 
 #[derive(derive_more::Debug)]
@@ -50,6 +182,7 @@ pub struct UserItem {
     #[debug(skip)]
     pub email: String,
     pub role: UserRoleData,
+    pub created_at: DateTime<Utc>,
 }
 
 impl From<User> for UserItem {
@@ -59,6 +192,7 @@ impl From<User> for UserItem {
             username: user.username().to_string(),
             email: user.email().as_str().to_string(),
             role: user.role().into(),
+            created_at: user.created_at(),
         }
     }
 }
@@ -109,6 +243,15 @@ pub struct UpdateUserEmailInput {
     pub new_email: String,
 }
 
+#[derive(derive_more::Debug)]
+pub struct ChangePasswordInput {
+    pub target_id: Uuid,
+    #[debug(skip)]
+    pub current_password: String,
+    #[debug(skip)]
+    pub new_password: String,
+}
+
 #[derive(derive_more::Debug, Validate)]
 pub struct SuspendUserInput {
     pub target_id: Uuid,
@@ -116,6 +259,288 @@ pub struct SuspendUserInput {
     pub reason: String,
 }
 
+#[derive(derive_more::Debug, Validate)]
+pub struct UnlockUserInput {
+    pub target_id: Uuid,
+    #[validate(length(min = 1, message = "理由を入力してください"))]
+    pub reason: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct DeactivateUserInput {
+    pub target_id: Uuid,
+    /// アクセストークンの漏洩だけで退会されないよう、パスワードの再入力を求める
+    #[debug(skip)]
+    pub password: String,
+    /// 接続元の IP アドレス。ログインと同じ IP アドレス単位の失敗回数の制限に使用する
+    pub ip_address: Option<String>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ReactivateUserInput {
+    #[debug(skip)]
+    pub email: String,
+    #[debug(skip)]
+    pub password: String,
+    /// 接続元の IP アドレス。ログインと同じ IP アドレス単位の失敗回数の制限に使用する
+    pub ip_address: Option<String>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct PromoteUserInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct DemoteUserInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug, Validate)]
+pub struct ImpersonateUserInput {
+    pub target_id: Uuid,
+    #[validate(length(min = 1, message = "理由を入力してください"))]
+    pub reason: String,
+}
+
+/// なりすましのアクセストークン
+///
+/// リフレッシュトークンは発行しないため、有効期限が切れた時点でなりすましは終了する
+#[derive(derive_more::Debug)]
+pub struct ImpersonateUserOutput {
+    #[debug(skip)]
+    pub access_token: String,
+    /// 有効期間(秒)
+    pub expires_in: i64,
+    pub scope: AccessScopeData,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ClearLoginLockoutInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginMfaEnrollmentInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginMfaEnrollmentOutput {
+    /// 認証アプリに手入力する場合の共有シークレット (Base32)
+    #[debug(skip)]
+    pub secret: String,
+    /// 認証アプリに QR コードで読み込ませるプロビジョニング URI
+    #[debug(skip)]
+    pub provisioning_uri: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ConfirmMfaEnrollmentInput {
+    pub target_id: Uuid,
+    #[debug(skip)]
+    pub code: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ConfirmMfaEnrollmentOutput {
+    /// 使い捨てのリカバリーコード。再表示はできないため、本人に保管を促す
+    #[debug(skip)]
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct DisableMfaInput {
+    pub target_id: Uuid,
+    /// 認証アプリの認証コード、またはリカバリーコード
+    #[debug(skip)]
+    pub code: String,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginPasskeyRegistrationInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct BeginPasskeyRegistrationOutput {
+    /// 登録の完了時に認証器の応答とともに提示するセレモニートークン
+    #[debug(skip)]
+    pub ceremony_token: String,
+    /// `navigator.credentials.create()` に渡すオプション
+    pub options: PublicKeyCredentialCreationOptions,
+}
+
+impl From<IssuedRegistrationChallenge> for BeginPasskeyRegistrationOutput {
+    fn from(issued: IssuedRegistrationChallenge) -> Self {
+        BeginPasskeyRegistrationOutput {
+            ceremony_token: issued.ceremony_token,
+            options: issued.options,
+        }
+    }
+}
+
+#[derive(derive_more::Debug, Validate)]
+pub struct FinishPasskeyRegistrationInput {
+    pub target_id: Uuid,
+    #[debug(skip)]
+    pub ceremony_token: String,
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "パスキーの名前は1～64文字で入力してください"
+    ))]
+    pub name: String,
+    pub credential: RegistrationCredential,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListPasskeysInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListPasskeysOutput {
+    pub passkeys: Vec<PasskeyItem>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct PasskeyItem {
+    pub passkey_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<WebAuthnCredential> for PasskeyItem {
+    fn from(credential: WebAuthnCredential) -> Self {
+        PasskeyItem {
+            passkey_id: credential.id().into(),
+            name: credential.name().to_string(),
+            created_at: credential.created_at(),
+            last_used_at: credential.last_used_at(),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct DeletePasskeyInput {
+    pub target_id: Uuid,
+    pub passkey_id: Uuid,
+}
+
+#[derive(derive_more::Debug, Validate)]
+pub struct CreateApiKeyInput {
+    pub target_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "API キーの名前は1～64文字で入力してください"
+    ))]
+    pub name: String,
+    /// 空の場合は発行時に拒否する
+    pub scopes: Vec<ApiKeyScopeData>,
+    #[validate(range(min = 1, message = "有効期限は1日以上で指定してください"))]
+    pub expires_in_days: i64,
+}
+
+#[derive(derive_more::Debug)]
+pub struct CreateApiKeyOutput {
+    /// 発行したキーの平文。サーバー側には保存しないため、この応答でのみ取得できる
+    #[debug(skip)]
+    pub api_key: String,
+    pub details: ApiKeyItem,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListApiKeysInput {
+    pub target_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListApiKeysOutput {
+    pub api_keys: Vec<ApiKeyItem>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ApiKeyItem {
+    pub api_key_id: Uuid,
+    pub name: String,
+    /// キーの平文の先頭に含まれる識別子 (一覧でキーを見分けるために使用する)
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScopeData>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyItem {
+    fn from(api_key: ApiKey) -> Self {
+        ApiKeyItem {
+            api_key_id: api_key.id().into(),
+            name: api_key.name().to_string(),
+            prefix: api_key.prefix().to_string(),
+            scopes: api_key.scopes().iter().copied().map(Into::into).collect(),
+            expires_at: api_key.expires_at(),
+            created_at: api_key.created_at(),
+            last_used_at: api_key.last_used_at(),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct RevokeApiKeyInput {
+    pub target_id: Uuid,
+    pub api_key_id: Uuid,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListSessionsInput {
+    pub target_id: Uuid,
+    /// リクエストに使用したアクセストークンのセッション ID (一覧で現在のセッションを示すために使用する)
+    pub current_session_id: Option<Uuid>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct ListSessionsOutput {
+    pub sessions: Vec<SessionItem>,
+}
+
+#[derive(derive_more::Debug)]
+pub struct SessionItem {
+    pub session_id: Uuid,
+    pub device_label: String,
+    pub user_agent: Option<String>,
+    #[debug(skip)]
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// リクエストに使用したアクセストークンのセッションか
+    pub current: bool,
+}
+
+impl SessionItem {
+    pub(crate) fn new(session: &Session, current_session_id: Option<Uuid>) -> Self {
+        let session_id: Uuid = session.id().into();
+
+        SessionItem {
+            session_id,
+            device_label: session.device_label().to_string(),
+            user_agent: session.user_agent().map(str::to_string),
+            ip_address: session.ip_address().map(str::to_string),
+            created_at: session.created_at(),
+            last_seen_at: session.last_seen_at(),
+            current: current_session_id == Some(session_id),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct RevokeSessionInput {
+    pub target_id: Uuid,
+    pub session_id: Uuid,
+}
+
 #[derive(derive_more::Debug)]
 pub struct SuspendUserOutput {
     pub user_id: Uuid,
@@ -131,6 +556,51 @@ impl From<User> for SuspendUserOutput {
     }
 }
 
+#[derive(derive_more::Debug)]
+pub struct UnlockUserOutput {
+    pub user_id: Uuid,
+    pub suspended: bool,
+}
+
+impl From<User> for UnlockUserOutput {
+    fn from(user: User) -> Self {
+        UnlockUserOutput {
+            user_id: user.id().into(),
+            suspended: user.is_suspended(),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct PromoteUserOutput {
+    pub user_id: Uuid,
+    pub role: UserRoleData,
+}
+
+impl From<User> for PromoteUserOutput {
+    fn from(user: User) -> Self {
+        PromoteUserOutput {
+            user_id: user.id().into(),
+            role: user.role().into(),
+        }
+    }
+}
+
+#[derive(derive_more::Debug)]
+pub struct DemoteUserOutput {
+    pub user_id: Uuid,
+    pub role: UserRoleData,
+}
+
+impl From<User> for DemoteUserOutput {
+    fn from(user: User) -> Self {
+        DemoteUserOutput {
+            user_id: user.id().into(),
+            role: user.role().into(),
+        }
+    }
+}
+
 #[derive(derive_more::Debug)]
 pub struct UserPublicProfile {
     pub user_id: Uuid,
@@ -178,3 +648,120 @@ impl From<User> for UpdateUserEmailOutput {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone as _};
+
+    use super::*;
+
+    fn base_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn list_users_input() -> ListUsersInput {
+        ListUsersInput {
+            state: None,
+            role: None,
+            created_from: None,
+            created_until: None,
+            email_prefix: None,
+            username_prefix: None,
+            sort_by: UserListSortKeyData::default(),
+            sort_order: SortDirectionData::default(),
+            cursor: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn test_list_users_limit_bounds() {
+        for (limit, is_valid) in [
+            (None, true),
+            (Some(0), false),
+            (Some(1), true),
+            (Some(100), true),
+            (Some(101), false),
+        ] {
+            let input = ListUsersInput {
+                limit,
+                ..list_users_input()
+            };
+
+            assert_eq!(input.validate().is_ok(), is_valid, "limit: {limit:?}");
+        }
+    }
+
+    #[test]
+    fn test_list_users_rejects_inverted_created_at_range() {
+        for (from, until, is_valid) in [
+            (base_time(), base_time() + Duration::days(1), true),
+            (base_time(), base_time(), false),
+            (base_time() + Duration::days(1), base_time(), false),
+        ] {
+            let input = ListUsersInput {
+                created_from: Some(from),
+                created_until: Some(until),
+                ..list_users_input()
+            };
+
+            assert_eq!(
+                input.validate().is_ok(),
+                is_valid,
+                "from: {from}, until: {until}"
+            );
+        }
+
+        // 片方のみの指定は常に有効
+        let input = ListUsersInput {
+            created_from: Some(base_time()),
+            ..list_users_input()
+        };
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_list_users_input_into_query() {
+        let cursor = Uuid::now_v7();
+        let input = ListUsersInput {
+            state: Some(UserStateData::SuspendedByAdmin),
+            role: Some(UserRoleData::Admin),
+            created_from: Some(base_time()),
+            created_until: Some(base_time() + Duration::days(1)),
+            email_prefix: Some("admin@".to_string()),
+            username_prefix: Some("ad".to_string()),
+            sort_by: UserListSortKeyData::Username,
+            sort_order: SortDirectionData::Desc,
+            cursor: Some(cursor),
+            limit: Some(50),
+        };
+
+        let query = UserListQuery::from(input);
+
+        assert_eq!(query.filter.state, Some(UserStateKind::SuspendedByAdmin));
+        assert_eq!(query.filter.role, Some(domain::user::UserRole::Admin));
+        assert_eq!(query.filter.created_from, Some(base_time()));
+        assert_eq!(
+            query.filter.created_until,
+            Some(base_time() + Duration::days(1))
+        );
+        assert_eq!(query.filter.email_prefix.as_deref(), Some("admin@"));
+        assert_eq!(query.filter.username_prefix.as_deref(), Some("ad"));
+        assert_eq!(query.sort.key, UserListSortKey::Username);
+        assert_eq!(query.sort.direction, SortDirection::Desc);
+        assert_eq!(query.cursor, Some(cursor.into()));
+        assert_eq!(query.limit, 50);
+    }
+
+    #[test]
+    fn test_list_users_input_into_query_uses_defaults() {
+        let query = UserListQuery::from(list_users_input());
+
+        assert_eq!(query.filter.state, None);
+        assert_eq!(query.filter.role, None);
+        assert_eq!(query.sort.key, UserListSortKey::CreatedAt);
+        assert_eq!(query.sort.direction, SortDirection::Asc);
+        assert_eq!(query.cursor, None);
+        assert_eq!(query.limit, DEFAULT_LIST_USERS_LIMIT);
+    }
+}
@@ -1,8 +1,9 @@
 use domain::{
     shared::outbox_event::OutboxEventIdGenerationError,
     user::{
-        EmailFormatError, EmailVerificationError, ModificationWithInvalidStateError,
-        PasswordPolicyViolation, UserDomainError, UserIdGenerationError, UserReconstructionError,
+        EmailFormatError, EmailVerificationError, LoginLockoutError, MfaError,
+        ModificationWithInvalidStateError, PasswordChangeError, PasswordPolicyViolations,
+        RoleChangeError, UserDomainError, UserIdGenerationError, UserReconstructionError,
         UserRepositoryError, UserStateTransitionError, UserUniqueConstraintViolation,
     },
 };
@@ -16,6 +17,13 @@ impl From<UserRepositoryError> for UseCaseError {
             UserRepositoryError::ReconstructionError(user_reconstruction_error) => {
                 user_reconstruction_error.into()
             }
+            UserRepositoryError::CursorNotFound { .. } => UseCaseError::InvalidInput(
+                vec![ValidationError::new(
+                    "cursor",
+                    "カーソルに指定したユーザーが存在しません",
+                )]
+                .into(),
+            ),
             UserRepositoryError::Persistence(error) => UseCaseError::Internal(error),
         }
     }
@@ -25,8 +33,8 @@ impl From<UserDomainError> for UseCaseError {
     fn from(domain_error: UserDomainError) -> Self {
         match domain_error {
             UserDomainError::InvalidEmail(email_format_error) => email_format_error.into(),
-            UserDomainError::PasswordPolicyViolation(password_policy_violation) => {
-                password_policy_violation.into()
+            UserDomainError::PasswordPolicyViolations(password_policy_violations) => {
+                password_policy_violations.into()
             }
             UserDomainError::AlreadyExists(user_unique_constraint_violation) => {
                 user_unique_constraint_violation.into()
@@ -73,20 +81,26 @@ impl From<EmailFormatError> for UseCaseError {
     }
 }
 
-impl From<PasswordPolicyViolation> for UseCaseError {
-    fn from(violation: PasswordPolicyViolation) -> Self {
-        match violation {
-            PasswordPolicyViolation::TooShort => UseCaseError::InvalidInput(
-                vec![ValidationError::new(
-                    "password",
-                    "パスワードが短すぎます".to_string(),
-                )]
-                .into(),
-            ),
-        }
+impl From<PasswordPolicyViolations> for UseCaseError {
+    fn from(violations: PasswordPolicyViolations) -> Self {
+        password_policy_violations_for_field("password", violations)
     }
 }
 
+/// 違反したルールごとのメッセージを、指定したフィールドの入力エラーとして返す
+pub(crate) fn password_policy_violations_for_field(
+    field: &str,
+    violations: PasswordPolicyViolations,
+) -> UseCaseError {
+    UseCaseError::InvalidInput(
+        violations
+            .into_iter()
+            .map(|violation| ValidationError::new(field, violation.to_string()))
+            .collect::<Vec<_>>()
+            .into(),
+    )
+}
+
 impl From<UserUniqueConstraintViolation> for UseCaseError {
     fn from(violation: UserUniqueConstraintViolation) -> Self {
         match violation {
@@ -102,7 +116,46 @@ impl From<UserUniqueConstraintViolation> for UseCaseError {
 
 impl From<EmailVerificationError> for UseCaseError {
     fn from(email_verification_error: EmailVerificationError) -> Self {
-        match email_verification_error {}
+        let message = match email_verification_error {
+            EmailVerificationError::Expired => {
+                "確認リンクの有効期限が切れています。確認メールを再送してください"
+            }
+            EmailVerificationError::Tampered => "確認リンクが不正です",
+            EmailVerificationError::EmailMismatch => {
+                "確認リンクのメールアドレスが現在のメールアドレスと一致しません。最新の確認メールのリンクを使用してください"
+            }
+            EmailVerificationError::AlreadyVerified => {
+                return UseCaseError::Conflict {
+                    message: "メールアドレスはすでに確認済みです".to_string(),
+                };
+            }
+        };
+
+        UseCaseError::InvalidInput(vec![ValidationError::new("token", message)].into())
+    }
+}
+
+impl From<PasswordChangeError> for UseCaseError {
+    fn from(password_change_error: PasswordChangeError) -> Self {
+        match password_change_error {
+            // 認証済みのリクエストであるため、401 ではなく入力エラーとして扱う
+            PasswordChangeError::IncorrectCurrentPassword => UseCaseError::InvalidInput(
+                vec![ValidationError::new(
+                    "current_password",
+                    "現在のパスワードが正しくありません",
+                )]
+                .into(),
+            ),
+            PasswordChangeError::PasswordPolicyViolations(password_policy_violations) => {
+                password_policy_violations_for_field("new_password", password_policy_violations)
+            }
+            PasswordChangeError::ModificationWithInvalidStateError(
+                modification_with_invalid_state_error,
+            ) => modification_with_invalid_state_error.into(),
+            PasswordChangeError::HashingError(password_hashing_error) => {
+                password_hashing_error.into()
+            }
+        }
     }
 }
 
@@ -110,7 +163,9 @@ impl From<ModificationWithInvalidStateError> for UseCaseError {
     fn from(invalid_state_error: ModificationWithInvalidStateError) -> Self {
         match invalid_state_error {
             ModificationWithInvalidStateError::EmailModification { state: _ }
-            | ModificationWithInvalidStateError::UsernameModification { state: _ } => {
+            | ModificationWithInvalidStateError::UsernameModification { state: _ }
+            | ModificationWithInvalidStateError::EmailVerificationRequest { state: _ }
+            | ModificationWithInvalidStateError::PasswordModification { state: _ } => {
                 UseCaseError::Conflict {
                     message: invalid_state_error.message_for_client().to_string(),
                 }
@@ -140,6 +195,37 @@ impl From<UserStateTransitionError> for UseCaseError {
     }
 }
 
+impl From<LoginLockoutError> for UseCaseError {
+    fn from(login_lockout_error: LoginLockoutError) -> Self {
+        match login_lockout_error {
+            LoginLockoutError::NotLockedOut => UseCaseError::Conflict {
+                message: "指定のユーザーはロックアウトされていません".to_string(),
+            },
+        }
+    }
+}
+
+impl From<MfaError> for UseCaseError {
+    fn from(mfa_error: MfaError) -> Self {
+        match mfa_error {
+            MfaError::AlreadyEnabled => UseCaseError::Conflict {
+                message: "2段階認証は既に有効です".to_string(),
+            },
+            MfaError::NotEnabled => UseCaseError::Conflict {
+                message: "2段階認証は有効になっていません".to_string(),
+            },
+            MfaError::EnrollmentNotStarted => UseCaseError::Conflict {
+                message: "2段階認証の登録が開始されていません".to_string(),
+            },
+            MfaError::CodeAlreadyUsed | MfaError::InvalidRecoveryCode => {
+                UseCaseError::InvalidInput(
+                    vec![ValidationError::new("code", "認証コードが正しくありません")].into(),
+                )
+            }
+        }
+    }
+}
+
 impl From<UserIdGenerationError> for UseCaseError {
     fn from(id_generation_error: UserIdGenerationError) -> Self {
         match id_generation_error {
@@ -155,3 +241,18 @@ impl From<OutboxEventIdGenerationError> for UseCaseError {
         }
     }
 }
+
+impl From<RoleChangeError> for UseCaseError {
+    fn from(role_change_error: RoleChangeError) -> Self {
+        let message = match role_change_error {
+            RoleChangeError::NotVerified { state: _ } => {
+                "メールアドレスを確認済みの利用中のユーザーのみ管理者に昇格できます"
+            }
+            RoleChangeError::LastAdmin => "最後の管理者を降格することはできません",
+        };
+
+        UseCaseError::Conflict {
+            message: message.to_string(),
+        }
+    }
+}
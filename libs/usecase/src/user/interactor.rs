@@ -1,37 +1,117 @@
-use crate::shared::identity::{Identity, IdentityWrapper};
-use crate::usecase_error::UseCaseError;
+use crate::auth::api_key_config::ApiKeyConfig;
+use crate::auth::api_key_service::ApiKeyService;
+use crate::auth::login_attempt::{self, locked_out};
+use crate::auth::session_revocation::{deny_session_access_tokens, end_all_sessions, end_session};
+use crate::auth::token_epoch_cache::TokenEpochCache;
+use crate::auth::token_revocation_store::TokenRevocationStore;
+use crate::auth::token_service::TokenService;
+use crate::auth::totp_service::TotpService;
+use crate::auth::webauthn_service::{WebAuthnCeremony, WebAuthnService, WebAuthnUser};
+use crate::shared::identity::{Identity, IdentityWrapper, PasswordVerifiedUser, audit_actor};
+use crate::shared::rate_limiter::RateLimiter;
+use crate::usecase_error::{UseCaseError, ValidationError};
 use crate::user::dto::{
-    GetOwnProfileInput, GetProfileInput, ListUsersInput, ListUsersOutput, SuspendUserInput,
-    SuspendUserOutput, UpdateUserEmailInput, UpdateUserEmailOutput, UpdateUserProfileInput,
+    BeginMfaEnrollmentInput, BeginMfaEnrollmentOutput, BeginPasskeyRegistrationInput,
+    BeginPasskeyRegistrationOutput, ChangePasswordInput, ClearLoginLockoutInput,
+    ConfirmMfaEnrollmentInput, ConfirmMfaEnrollmentOutput, CreateApiKeyInput, CreateApiKeyOutput,
+    DeactivateUserInput, DeletePasskeyInput, DemoteUserInput, DemoteUserOutput, DisableMfaInput,
+    FinishPasskeyRegistrationInput, GetOwnProfileInput, GetProfileInput, ImpersonateUserInput,
+    ImpersonateUserOutput, ListApiKeysInput, ListApiKeysOutput, ListPasskeysInput,
+    ListPasskeysOutput, ListSessionsInput, ListSessionsOutput, ListUsersInput, ListUsersOutput,
+    PasskeyItem, PromoteUserInput, PromoteUserOutput, ReactivateUserInput, RevokeApiKeyInput,
+    RevokeSessionInput, SessionItem, SuspendUserInput, SuspendUserOutput, UnlockUserInput,
+    UnlockUserOutput, UpdateUserEmailInput, UpdateUserEmailOutput, UpdateUserProfileInput,
     UpdateUserProfileOutput, UserDetailedProfile, UserPublicProfile,
 };
 use crate::user::service::UserService;
 use async_trait::async_trait;
+use chrono::Duration;
+use domain::auth::api_key::{ApiKey, ApiKeyIdGeneratorFactory};
+use domain::auth::login_policy::LoginPolicy;
 use domain::auth::policies::find_user_by_id_for_suspend::FindUserByIdForSuspendPayload;
 use domain::auth::policies::{
-    change_email::ChangeEmailPayload, list_users::ListUsersPayload,
-    suspend_user::SuspendUserPayload, update_profile::UpdateProfilePayload,
-    view_detailed_profile::ViewDetailedProfilePayload,
+    activate_user::ActivateUserPayload, change_email::ChangeEmailPayload,
+    change_password::ChangePasswordPayload, deactivate_user::DeactivateUserPayload,
+    demote_from_admin::DemoteFromAdminPayload, impersonate_user::ImpersonateUserPayload,
+    list_users::ListUsersPayload, manage_api_keys::ManageApiKeysPayload,
+    manage_mfa::ManageMfaPayload, manage_passkeys::ManagePasskeysPayload,
+    manage_sessions::ManageSessionsPayload, promote_to_admin::PromoteToAdminPayload,
+    suspend_user::SuspendUserPayload, unlock_user::UnlockUserPayload,
+    update_profile::UpdateProfilePayload, view_detailed_profile::ViewDetailedProfilePayload,
     view_public_profile::ViewPublicProfilePayload,
 };
 use domain::auth::policy::{AuthorizationService, UserAction};
+use domain::auth::session::SessionId;
+use domain::auth::webauthn_credential::{WebAuthnCredential, WebAuthnCredentialIdGeneratorFactory};
 use domain::shared::service::clock::Clock;
 use domain::transaction::TransactionManager;
 use domain::tx;
-use domain::user::UserUniquenessService;
+use domain::user::{
+    EmailTrait, HashedPassword, LoginLockoutConfig, MfaError, MfaState, PasswordHasher,
+    PasswordPolicy, RawPassword, UnverifiedEmail, UserListQuery, UserRole, UserUniquenessService,
+};
 use std::sync::Arc;
+use uuid::Uuid;
 use validator::Validate as _;
 
 pub struct UserInteractor<TM: TransactionManager> {
     transaction_manager: Arc<TM>,
+    password_hasher: Arc<dyn PasswordHasher>,
+    password_policy: Arc<PasswordPolicy>,
+    login_lockout_config: Arc<LoginLockoutConfig>,
+    login_ip_rate_limiter: Arc<dyn RateLimiter>,
+    totp_service: Arc<dyn TotpService>,
+    webauthn_service: Arc<dyn WebAuthnService>,
+    webauthn_credential_id_generator_factory: Arc<dyn WebAuthnCredentialIdGeneratorFactory>,
+    api_key_service: Arc<dyn ApiKeyService>,
+    api_key_config: Arc<ApiKeyConfig>,
+    api_key_id_generator_factory: Arc<dyn ApiKeyIdGeneratorFactory>,
+    token_service: Arc<dyn TokenService>,
+    token_revocation_store: Arc<dyn TokenRevocationStore>,
+    token_epoch_cache: Arc<dyn TokenEpochCache>,
     clock: Arc<dyn Clock>,
+    dummy_hash: HashedPassword,
 }
 
 impl<TM: TransactionManager> UserInteractor<TM> {
-    pub fn new(transaction_manager: Arc<TM>, clock: Arc<dyn Clock>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        transaction_manager: Arc<TM>,
+        password_hasher: Arc<dyn PasswordHasher>,
+        password_policy: Arc<PasswordPolicy>,
+        login_lockout_config: Arc<LoginLockoutConfig>,
+        login_ip_rate_limiter: Arc<dyn RateLimiter>,
+        totp_service: Arc<dyn TotpService>,
+        webauthn_service: Arc<dyn WebAuthnService>,
+        webauthn_credential_id_generator_factory: Arc<dyn WebAuthnCredentialIdGeneratorFactory>,
+        api_key_service: Arc<dyn ApiKeyService>,
+        api_key_config: Arc<ApiKeyConfig>,
+        api_key_id_generator_factory: Arc<dyn ApiKeyIdGeneratorFactory>,
+        token_service: Arc<dyn TokenService>,
+        token_revocation_store: Arc<dyn TokenRevocationStore>,
+        token_epoch_cache: Arc<dyn TokenEpochCache>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let dummy_password = RawPassword::new("dummy_password_for_timing_attack");
+        let dummy_hash = password_hasher.hash(&dummy_password).unwrap();
+
         Self {
             transaction_manager,
+            password_hasher,
+            password_policy,
+            login_lockout_config,
+            login_ip_rate_limiter,
+            totp_service,
+            webauthn_service,
+            webauthn_credential_id_generator_factory,
+            api_key_service,
+            api_key_config,
+            api_key_id_generator_factory,
+            token_service,
+            token_revocation_store,
+            token_epoch_cache,
             clock,
+            dummy_hash,
         }
     }
 }
@@ -40,14 +120,19 @@ impl<TM: TransactionManager> UserInteractor<TM> {
 impl<TM: TransactionManager> UserService for UserInteractor<TM> {
     #[tracing::instrument(skip(self, identity), fields(
         actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
         actor_role = %identity.actor_role(),
     ))]
     async fn list_users(
         &self,
         identity: Box<dyn Identity>,
-        _input: ListUsersInput,
+        input: ListUsersInput,
     ) -> Result<ListUsersOutput, UseCaseError> {
-        let users = tx!(self.transaction_manager, |factory| {
+        input.validate()?;
+
+        let query = UserListQuery::from(input);
+
+        let page = tx!(self.transaction_manager, |factory| {
             // ポリシーチェック
             AuthorizationService::can(
                 &IdentityWrapper::from(&identity),
@@ -55,12 +140,14 @@ impl<TM: TransactionManager> UserService for UserInteractor<TM> {
             )?;
 
             let user_repo = factory.user_repository();
-            Ok::<_, UseCaseError>(user_repo.find_all().await?)
+            Ok::<_, UseCaseError>(user_repo.find_page(&query).await?)
         })
         .await?;
 
         Ok(ListUsersOutput {
-            users: users.into_iter().map(|u| u.into()).collect(),
+            users: page.users.into_iter().map(|u| u.into()).collect(),
+            next_cursor: page.next_cursor.map(Into::into),
+            total: page.total,
         })
     }
 
@@ -233,51 +320,1180 @@ impl<TM: TransactionManager> UserService for UserInteractor<TM> {
         actor_id = %identity.actor_id(),
         actor_role = %identity.actor_role(),
     ))]
-    async fn suspend_user(
+    async fn change_password(
         &self,
         identity: Box<dyn Identity>,
-        input: SuspendUserInput,
-    ) -> Result<SuspendUserOutput, UseCaseError> {
+        input: ChangePasswordInput,
+    ) -> Result<(), UseCaseError> {
         let clock = self.clock.clone();
+        let password_hasher = self.password_hasher.clone();
+        let password_policy = self.password_policy.clone();
+        let target_id = input.target_id.into();
 
-        input.validate()?;
-
-        let SuspendUserInput { target_id, reason } = input;
+        let current_password = RawPassword::new(&input.current_password);
+        let new_password = RawPassword::new(&input.new_password);
 
-        let updated_user = tx!(self.transaction_manager, |factory| {
+        let ended_session_ids = tx!(self.transaction_manager, |factory| {
             let user_repo = factory.user_repository();
+            let session_repo = factory.session_repository();
+            let refresh_token_repo = factory.refresh_token_repository();
 
             // ポリシーチェック
             AuthorizationService::can(
                 &IdentityWrapper::from(&identity),
-                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
-                    target_id: target_id.into(),
-                }),
+                UserAction::ChangePassword(ChangePasswordPayload { target_id }),
             )?;
 
-            let mut target_user = user_repo
-                .find_by_id(target_id.into())
+            let mut user = user_repo
+                .find_by_id(target_id)
                 .await?
                 .ok_or(UseCaseError::NotFound)?;
 
+            // ドメインロジックの実行
+            user.change_password(
+                &current_password,
+                &new_password,
+                password_policy.as_ref(),
+                password_hasher.as_ref(),
+                clock.as_ref(),
+            )?;
+
+            // 変更の保存
+            user_repo.save(user).await?;
+
+            // 変更前のパスワードで開始したセッションはすべて終了させる
+            end_all_sessions(
+                target_id,
+                session_repo.as_ref(),
+                refresh_token_repo.as_ref(),
+                clock.as_ref(),
+            )
+            .await
+        })
+        .await?;
+
+        // 変更前のパスワードで取得したアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(target_id).await?;
+        deny_session_access_tokens(
+            &ended_session_ids,
+            self.token_revocation_store.as_ref(),
+            self.token_service.as_ref(),
+            self.clock.as_ref(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn deactivate_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeactivateUserInput,
+    ) -> Result<(), UseCaseError> {
+        let clock = self.clock.clone();
+        let target_id = input.target_id.into();
+        let password = RawPassword::new(&input.password);
+        let ip_address = input.ip_address;
+
+        // パスワードを確認する操作のため、ログインと同じ失敗回数の制限を適用する
+        login_attempt::ensure_ip_not_throttled(
+            self.login_ip_rate_limiter.as_ref(),
+            ip_address.as_deref(),
+        )
+        .await?;
+
+        let user = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
             AuthorizationService::can(
                 &IdentityWrapper::from(&identity),
-                UserAction::SuspendUser(SuspendUserPayload {
-                    target_id: target_user.id(),
-                    target_role: target_user.role(),
-                }),
+                UserAction::DeactivateUser(DeactivateUserPayload { target_id }),
             )?;
 
-            // ユーザーの状態を停止に変更
-            target_user.suspend(reason, clock.as_ref())?;
+            let user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
 
-            // 変更を保存
-            let updated_user = user_repo.save(target_user).await?;
+            Ok::<_, UseCaseError>(user)
+        })
+        .await?;
 
-            Ok::<_, UseCaseError>(updated_user)
+        if user.is_locked_out(self.clock.now()) {
+            return Err(locked_out());
+        }
+
+        // アクセストークンの漏洩だけで退会されないよう、パスワードを確認する
+        if !self.password_hasher.verify(&password, user.password()) {
+            login_attempt::record_failed_login(
+                self.transaction_manager.as_ref(),
+                self.login_ip_rate_limiter.as_ref(),
+                self.login_lockout_config.clone(),
+                self.clock.clone(),
+                ip_address.as_deref(),
+                Some(target_id),
+            )
+            .await?;
+            return Err(incorrect_password());
+        }
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            let mut user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // ドメインロジックの実行 (トークン世代番号も進める)
+            user.deactivate(clock.as_ref())?;
+
+            // 変更の保存
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
         })
         .await?;
 
-        Ok(updated_user.into())
+        // 退会前に発行したアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(target_id).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn reactivate_user(&self, input: ReactivateUserInput) -> Result<(), UseCaseError> {
+        let email = UnverifiedEmail::new(&input.email)?;
+        let password = RawPassword::new(&input.password);
+        let ip_address = input.ip_address;
+
+        // パスワードを確認する操作のため、ログインと同じ失敗回数の制限を適用する
+        login_attempt::ensure_ip_not_throttled(
+            self.login_ip_rate_limiter.as_ref(),
+            ip_address.as_deref(),
+        )
+        .await?;
+
+        let user_opt = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            Ok::<_, UseCaseError>(user_repo.find_by_email(email.as_str()).await?)
+        })
+        .await?;
+
+        // タイミング攻撃を避けるため、ユーザーの有無に関わらず検証処理を行う
+        let (is_valid, user) = match user_opt {
+            Some(user) => {
+                let is_valid = self.password_hasher.verify(&password, user.password());
+                (is_valid, Some(user))
+            }
+            None => {
+                let _ = self.password_hasher.verify(&password, &self.dummy_hash);
+                (false, None)
+            }
+        };
+
+        if let Some(user) = &user
+            && user.is_locked_out(self.clock.now())
+        {
+            return Err(locked_out());
+        }
+
+        let user = match user {
+            Some(user) if is_valid => user,
+            user => {
+                login_attempt::record_failed_login(
+                    self.transaction_manager.as_ref(),
+                    self.login_ip_rate_limiter.as_ref(),
+                    self.login_lockout_config.clone(),
+                    self.clock.clone(),
+                    ip_address.as_deref(),
+                    user.map(|u| u.id()),
+                )
+                .await?;
+                return Err(UseCaseError::Unauthorized);
+            }
+        };
+
+        // パスワードを確認できたため、本人による操作としてポリシーを判定する
+        let actor = PasswordVerifiedUser {
+            user_id: user.id(),
+            role: user.role(),
+        };
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let target_id = actor.user_id;
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &actor,
+                UserAction::ActivateUser(ActivateUserPayload { target_id }),
+            )?;
+
+            let mut user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 退会済みのアカウントを、メールアドレスの再確認が必要な状態で再開する
+            user.activate(clock.as_ref())?;
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn begin_mfa_enrollment(
+        &self,
+        identity: Box<dyn Identity>,
+        input: BeginMfaEnrollmentInput,
+    ) -> Result<BeginMfaEnrollmentOutput, UseCaseError> {
+        let totp_service = self.totp_service.clone();
+        let target_id = input.target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageMfa(ManageMfaPayload { target_id }),
+            )?;
+
+            let mut user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 認証コードによる確認が済むまでは 2 段階認証は有効にならない
+            let secret = totp_service.generate_secret();
+            user.begin_mfa_enrollment(secret.clone())?;
+
+            let provisioning_uri = totp_service.provisioning_uri(&secret, user.email().as_str());
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(BeginMfaEnrollmentOutput {
+                secret: secret.as_str().to_string(),
+                provisioning_uri,
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn confirm_mfa_enrollment(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ConfirmMfaEnrollmentInput,
+    ) -> Result<ConfirmMfaEnrollmentOutput, UseCaseError> {
+        let totp_service = self.totp_service.clone();
+        let clock = self.clock.clone();
+        let ConfirmMfaEnrollmentInput { target_id, code } = input;
+        let target_id = target_id.into();
+
+        let issued = self.totp_service.issue_recovery_codes();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageMfa(ManageMfaPayload { target_id }),
+            )?;
+
+            let mut user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            let secret = match user.mfa() {
+                MfaState::Pending { secret } => secret,
+                MfaState::Enabled { .. } => return Err(MfaError::AlreadyEnabled.into()),
+                MfaState::Disabled => return Err(MfaError::EnrollmentNotStarted.into()),
+            };
+
+            // 認証アプリにシークレットが正しく登録されたことを認証コードで確認する
+            let step = totp_service
+                .verify_code(secret, &code)
+                .ok_or_else(invalid_mfa_code)?;
+
+            user.confirm_mfa_enrollment(step, issued.code_hashes, clock.as_ref())?;
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(ConfirmMfaEnrollmentOutput {
+                recovery_codes: issued.codes,
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn disable_mfa(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DisableMfaInput,
+    ) -> Result<(), UseCaseError> {
+        let totp_service = self.totp_service.clone();
+        let clock = self.clock.clone();
+        let DisableMfaInput { target_id, code } = input;
+        let target_id = target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageMfa(ManageMfaPayload { target_id }),
+            )?;
+
+            let mut user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // アクセストークンの漏洩だけで解除されないよう、認証コードまたはリカバリーコードを要求する
+            if let MfaState::Enabled { secret, .. } = user.mfa() {
+                match totp_service.verify_code(secret, &code) {
+                    Some(step) => user.record_totp_use(step)?,
+                    None => user.consume_recovery_code(&totp_service.hash_recovery_code(&code))?,
+                }
+            }
+
+            user.disable_mfa(clock.as_ref())?;
+
+            user_repo.save(user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn begin_passkey_registration(
+        &self,
+        identity: Box<dyn Identity>,
+        input: BeginPasskeyRegistrationInput,
+    ) -> Result<BeginPasskeyRegistrationOutput, UseCaseError> {
+        let webauthn_service = self.webauthn_service.clone();
+        let target_id = input.target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let credential_repo = factory.webauthn_credential_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManagePasskeys(ManagePasskeysPayload { target_id }),
+            )?;
+
+            let user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 登録済みの認証器で重ねて登録しないよう、既存のクレデンシャルを除外させる
+            let registered: Vec<_> = credential_repo
+                .find_by_user_id(target_id)
+                .await?
+                .iter()
+                .map(|credential| credential.credential_id().clone())
+                .collect();
+
+            let issued = webauthn_service.issue_registration_challenge(
+                WebAuthnUser {
+                    id: user.id(),
+                    name: user.email().as_str(),
+                    display_name: user.username(),
+                },
+                &registered,
+            )?;
+
+            Ok::<_, UseCaseError>(issued.into())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn finish_passkey_registration(
+        &self,
+        identity: Box<dyn Identity>,
+        input: FinishPasskeyRegistrationInput,
+    ) -> Result<PasskeyItem, UseCaseError> {
+        input.validate()?;
+
+        let webauthn_service = self.webauthn_service.clone();
+        let webauthn_credential_id_generator_factory =
+            self.webauthn_credential_id_generator_factory.clone();
+        let clock = self.clock.clone();
+        let FinishPasskeyRegistrationInput {
+            target_id,
+            ceremony_token,
+            name,
+            credential,
+        } = input;
+        let target_id = target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let credential_repo = factory.webauthn_credential_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManagePasskeys(ManagePasskeysPayload { target_id }),
+            )?;
+
+            let user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // 登録セレモニーは開始したユーザー自身のパスキーとしてのみ完了できる
+            let claim = webauthn_service
+                .verify_ceremony_token(&ceremony_token, WebAuthnCeremony::Registration)?;
+            if claim.user_id != Some(user.id()) {
+                return Err(UseCaseError::Unauthorized);
+            }
+
+            let verified = webauthn_service.verify_registration(&claim, &credential)?;
+
+            // クレデンシャル ID は一意であるため、同じ応答の再送もここで拒否される
+            if credential_repo
+                .find_by_credential_id(&verified.credential_id)
+                .await?
+                .is_some()
+            {
+                return Err(UseCaseError::Conflict {
+                    message: "このパスキーは既に登録されています".to_string(),
+                });
+            }
+
+            let id = webauthn_credential_id_generator_factory
+                .create_webauthn_credential_id_generator()
+                .generate()?;
+
+            let credential = WebAuthnCredential::register(
+                id,
+                user.id(),
+                verified.credential_id,
+                verified.public_key,
+                verified.sign_count,
+                name,
+                clock.as_ref(),
+            );
+
+            let credential = credential_repo.save(credential).await?;
+
+            Ok::<_, UseCaseError>(credential.into())
+        })
+        .await
     }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn list_passkeys(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListPasskeysInput,
+    ) -> Result<ListPasskeysOutput, UseCaseError> {
+        let target_id = input.target_id.into();
+
+        let credentials = tx!(self.transaction_manager, |factory| {
+            let credential_repo = factory.webauthn_credential_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManagePasskeys(ManagePasskeysPayload { target_id }),
+            )?;
+
+            Ok::<_, UseCaseError>(credential_repo.find_by_user_id(target_id).await?)
+        })
+        .await?;
+
+        Ok(ListPasskeysOutput {
+            passkeys: credentials.into_iter().map(|c| c.into()).collect(),
+        })
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn delete_passkey(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeletePasskeyInput,
+    ) -> Result<(), UseCaseError> {
+        let DeletePasskeyInput {
+            target_id,
+            passkey_id,
+        } = input;
+        let target_id = target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let credential_repo = factory.webauthn_credential_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManagePasskeys(ManagePasskeysPayload { target_id }),
+            )?;
+
+            // 他のユーザーのパスキーは存在しないものとして扱う
+            let credential = credential_repo
+                .find_by_id(passkey_id.into())
+                .await?
+                .filter(|credential| credential.user_id() == target_id)
+                .ok_or(UseCaseError::NotFound)?;
+
+            credential_repo.delete(credential.id()).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn create_api_key(
+        &self,
+        identity: Box<dyn Identity>,
+        input: CreateApiKeyInput,
+    ) -> Result<CreateApiKeyOutput, UseCaseError> {
+        input.validate()?;
+
+        // 有効期限は設定した上限を超えて指定できない
+        let max_ttl = self.api_key_config.max_ttl();
+        if input.expires_in_days > max_ttl.num_days() {
+            return Err(UseCaseError::InvalidInput(
+                vec![ValidationError::new(
+                    "expires_in_days",
+                    format!("有効期限は{}日以内で指定してください", max_ttl.num_days()),
+                )]
+                .into(),
+            ));
+        }
+
+        let api_key_service = self.api_key_service.clone();
+        let api_key_id_generator_factory = self.api_key_id_generator_factory.clone();
+        let clock = self.clock.clone();
+        let CreateApiKeyInput {
+            target_id,
+            name,
+            scopes,
+            expires_in_days,
+        } = input;
+        let target_id = target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let api_key_repo = factory.api_key_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageApiKeys(ManageApiKeysPayload { target_id }),
+            )?;
+
+            let user = user_repo
+                .find_by_id(target_id)
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            let id = api_key_id_generator_factory
+                .create_api_key_id_generator()
+                .generate()?;
+
+            let issued = api_key_service.issue_api_key();
+
+            let expires_at = clock
+                .now()
+                .checked_add_signed(chrono::Duration::days(expires_in_days))
+                .expect("valid timestamp");
+
+            let api_key = ApiKey::issue(
+                id,
+                user.id(),
+                name,
+                issued.prefix,
+                issued.secret_hash,
+                scopes.into_iter().map(Into::into).collect(),
+                expires_at,
+                clock.as_ref(),
+            )?;
+
+            let api_key = api_key_repo.save(api_key).await?;
+
+            Ok::<_, UseCaseError>(CreateApiKeyOutput {
+                api_key: issued.api_key,
+                details: api_key.into(),
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn list_api_keys(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListApiKeysInput,
+    ) -> Result<ListApiKeysOutput, UseCaseError> {
+        let target_id = input.target_id.into();
+
+        let api_keys = tx!(self.transaction_manager, |factory| {
+            let api_key_repo = factory.api_key_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageApiKeys(ManageApiKeysPayload { target_id }),
+            )?;
+
+            Ok::<_, UseCaseError>(api_key_repo.find_by_user_id(target_id).await?)
+        })
+        .await?;
+
+        Ok(ListApiKeysOutput {
+            api_keys: api_keys.into_iter().map(|k| k.into()).collect(),
+        })
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn revoke_api_key(
+        &self,
+        identity: Box<dyn Identity>,
+        input: RevokeApiKeyInput,
+    ) -> Result<(), UseCaseError> {
+        let RevokeApiKeyInput {
+            target_id,
+            api_key_id,
+        } = input;
+        let target_id = target_id.into();
+
+        tx!(self.transaction_manager, |factory| {
+            let api_key_repo = factory.api_key_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageApiKeys(ManageApiKeysPayload { target_id }),
+            )?;
+
+            // 他のユーザーのキーは存在しないものとして扱う
+            let api_key = api_key_repo
+                .find_by_id(api_key_id.into())
+                .await?
+                .filter(|api_key| api_key.user_id() == target_id)
+                .ok_or(UseCaseError::NotFound)?;
+
+            api_key_repo.delete(api_key.id()).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn list_sessions(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListSessionsInput,
+    ) -> Result<ListSessionsOutput, UseCaseError> {
+        let ListSessionsInput {
+            target_id,
+            current_session_id,
+        } = input;
+        let target_id = target_id.into();
+        let clock = self.clock.clone();
+
+        let sessions = tx!(self.transaction_manager, |factory| {
+            let session_repo = factory.session_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageSessions(ManageSessionsPayload { target_id }),
+            )?;
+
+            Ok::<_, UseCaseError>(
+                session_repo
+                    .find_active_by_user_id(target_id, clock.now())
+                    .await?,
+            )
+        })
+        .await?;
+
+        Ok(ListSessionsOutput {
+            sessions: sessions
+                .iter()
+                .map(|session| SessionItem::new(session, current_session_id))
+                .collect(),
+        })
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn revoke_session(
+        &self,
+        identity: Box<dyn Identity>,
+        input: RevokeSessionInput,
+    ) -> Result<(), UseCaseError> {
+        let RevokeSessionInput {
+            target_id,
+            session_id,
+        } = input;
+        let target_id = target_id.into();
+        let session_id = session_id.into();
+        let clock = self.clock.clone();
+
+        tx!(self.transaction_manager, |factory| {
+            let session_repo = factory.session_repository();
+            let refresh_token_repo = factory.refresh_token_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ManageSessions(ManageSessionsPayload { target_id }),
+            )?;
+
+            // 他のユーザーのセッションや終了済みのセッションは存在しないものとして扱う
+            session_repo
+                .find_by_id(session_id)
+                .await?
+                .filter(|session| session.user_id() == target_id && session.is_active(clock.now()))
+                .ok_or(UseCaseError::NotFound)?;
+
+            end_session(
+                session_id,
+                session_repo.as_ref(),
+                refresh_token_repo.as_ref(),
+                clock.as_ref(),
+            )
+            .await
+        })
+        .await?;
+
+        deny_session_access_tokens(
+            &[session_id],
+            self.token_revocation_store.as_ref(),
+            self.token_service.as_ref(),
+            self.clock.as_ref(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn suspend_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: SuspendUserInput,
+    ) -> Result<SuspendUserOutput, UseCaseError> {
+        let clock = self.clock.clone();
+
+        input.validate()?;
+
+        let SuspendUserInput { target_id, reason } = input;
+
+        let updated_user = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::SuspendUser(SuspendUserPayload {
+                    target_id: target_user.id(),
+                    target_role: target_user.role(),
+                }),
+            )?;
+
+            // 停止を行った主体を監査記録に残す
+            let suspended_by = audit_actor(&*identity, service_account_repo.as_ref()).await?;
+
+            // ユーザーの状態を停止に変更
+            target_user.suspend(reason, suspended_by, clock.as_ref())?;
+
+            // 変更を保存
+            let updated_user = user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(updated_user)
+        })
+        .await?;
+
+        // 停止前に発行したアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(updated_user.id()).await?;
+
+        Ok(updated_user.into())
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn unlock_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: UnlockUserInput,
+    ) -> Result<UnlockUserOutput, UseCaseError> {
+        let clock = self.clock.clone();
+
+        input.validate()?;
+
+        let UnlockUserInput { target_id, reason } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::UnlockUser(UnlockUserPayload {
+                    target_id: target_user.id(),
+                }),
+            )?;
+
+            // 利用再開を行った主体を監査記録に残す
+            let unlocked_by = audit_actor(&*identity, service_account_repo.as_ref()).await?;
+
+            // ユーザーの状態を停止前の利用可能な状態に戻す
+            target_user.unlock_suspension(reason, unlocked_by, clock.as_ref())?;
+
+            // 変更を保存
+            let updated_user = user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(updated_user.into())
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn promote_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: PromoteUserInput,
+    ) -> Result<PromoteUserOutput, UseCaseError> {
+        let clock = self.clock.clone();
+        let target_id = input.target_id;
+
+        let updated_user = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            // 同時に行われる他のロールの変更と競合しないよう、管理者の行をロックしてから判定する
+            let admins = user_repo.find_by_role_for_update(UserRole::Admin).await?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::PromoteToAdmin(PromoteToAdminPayload {
+                    target_id: target_user.id(),
+                }),
+            )?;
+
+            // ロールを変更した主体を監査記録に残す
+            let promoted_by = audit_actor(&*identity, service_account_repo.as_ref()).await?;
+
+            // 管理者に昇格し、他の管理者への通知をイベントとして記録する
+            target_user.promote_to_admin(&admins, promoted_by, clock.as_ref())?;
+
+            // 変更を保存
+            let updated_user = user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(updated_user)
+        })
+        .await?;
+
+        // 変更前のロールを含むアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(updated_user.id()).await?;
+
+        Ok(updated_user.into())
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn demote_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DemoteUserInput,
+    ) -> Result<DemoteUserOutput, UseCaseError> {
+        let clock = self.clock.clone();
+        let target_id = input.target_id;
+
+        let updated_user = tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+            let service_account_repo = factory.service_account_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            // 同時に行われる他のロールの変更と競合しないよう、管理者の行をロックしてから判定する
+            let admins = user_repo.find_by_role_for_update(UserRole::Admin).await?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::DemoteFromAdmin(DemoteFromAdminPayload {
+                    target_id: target_user.id(),
+                }),
+            )?;
+
+            // ロールを変更した主体を監査記録に残す
+            let demoted_by = audit_actor(&*identity, service_account_repo.as_ref()).await?;
+
+            // 最後の管理者でないことを確認したうえで一般ユーザーに降格する
+            target_user.demote_from_admin(&admins, demoted_by, clock.as_ref())?;
+
+            // 変更を保存
+            let updated_user = user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(updated_user)
+        })
+        .await?;
+
+        // 変更前のロールを含むアクセストークンを、このインスタンスでは即座に拒否する
+        self.token_epoch_cache.invalidate(updated_user.id()).await?;
+
+        Ok(updated_user.into())
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn impersonate_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ImpersonateUserInput,
+    ) -> Result<ImpersonateUserOutput, UseCaseError> {
+        let token_service = self.token_service.clone();
+        let clock = self.clock.clone();
+
+        input.validate()?;
+
+        let ImpersonateUserInput { target_id, reason } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::ImpersonateUser(ImpersonateUserPayload {
+                    target_id: target_user.id(),
+                    target_role: target_user.role(),
+                }),
+            )?;
+
+            // 利用停止中・退会済みのアカウントにはなりすませない (ログインと同じ判定)
+            let scope = LoginPolicy::evaluate(target_user.state())?;
+
+            // ポリシーにより、なりすましを行えるのは管理者のユーザーのみ
+            let impersonator_id = identity.actor_id().into();
+
+            // なりすましごとに独立したセッション ID を割り当て、ログアウト時にこのトークンだけを失効させる
+            let session_id = SessionId::from(Uuid::now_v7());
+            let access_token = token_service.issue_impersonation_token(
+                target_user.id(),
+                target_user.role(),
+                impersonator_id,
+                session_id.into(),
+                target_user.token_epoch(),
+                scope,
+            )?;
+            let expires_at = clock.now() + Duration::seconds(access_token.expires_in);
+
+            target_user.record_impersonation_started(
+                impersonator_id,
+                reason,
+                session_id,
+                expires_at,
+                clock.as_ref(),
+            );
+            user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(ImpersonateUserOutput {
+                access_token: access_token.token,
+                expires_in: access_token.expires_in,
+                scope: scope.into(),
+            })
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self, identity), fields(
+        actor_id = %identity.actor_id(),
+        actor_kind = %identity.actor_kind(),
+        actor_role = %identity.actor_role(),
+    ))]
+    async fn clear_login_lockout(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ClearLoginLockoutInput,
+    ) -> Result<(), UseCaseError> {
+        let clock = self.clock.clone();
+
+        let ClearLoginLockoutInput { target_id } = input;
+
+        tx!(self.transaction_manager, |factory| {
+            let user_repo = factory.user_repository();
+
+            // ポリシーチェック
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::FindUserByIdForSuspend(FindUserByIdForSuspendPayload {
+                    target_id: target_id.into(),
+                }),
+            )?;
+
+            let mut target_user = user_repo
+                .find_by_id(target_id.into())
+                .await?
+                .ok_or(UseCaseError::NotFound)?;
+
+            // ロックの解除として扱う
+            AuthorizationService::can(
+                &IdentityWrapper::from(&identity),
+                UserAction::UnlockUser(UnlockUserPayload {
+                    target_id: target_user.id(),
+                }),
+            )?;
+
+            target_user.clear_login_lockout(clock.as_ref())?;
+
+            user_repo.save(target_user).await?;
+
+            Ok::<_, UseCaseError>(())
+        })
+        .await
+    }
+}
+
+fn incorrect_password() -> UseCaseError {
+    UseCaseError::InvalidInput(
+        vec![ValidationError::new(
+            "password",
+            "パスワードが正しくありません",
+        )]
+        .into(),
+    )
+}
+
+fn invalid_mfa_code() -> UseCaseError {
+    UseCaseError::InvalidInput(
+        vec![ValidationError::new("code", "認証コードが正しくありません")].into(),
+    )
 }
@@ -4,8 +4,17 @@ use crate::{
     shared::identity::Identity,
     usecase_error::UseCaseError,
     user::dto::{
-        GetOwnProfileInput, GetProfileInput, ListUsersInput, ListUsersOutput, SuspendUserInput,
-        SuspendUserOutput, UpdateUserEmailInput, UpdateUserEmailOutput, UpdateUserProfileInput,
+        BeginMfaEnrollmentInput, BeginMfaEnrollmentOutput, BeginPasskeyRegistrationInput,
+        BeginPasskeyRegistrationOutput, ChangePasswordInput, ClearLoginLockoutInput,
+        ConfirmMfaEnrollmentInput, ConfirmMfaEnrollmentOutput, CreateApiKeyInput,
+        CreateApiKeyOutput, DeactivateUserInput, DeletePasskeyInput, DemoteUserInput,
+        DemoteUserOutput, DisableMfaInput, FinishPasskeyRegistrationInput, GetOwnProfileInput,
+        GetProfileInput, ImpersonateUserInput, ImpersonateUserOutput, ListApiKeysInput,
+        ListApiKeysOutput, ListPasskeysInput, ListPasskeysOutput, ListSessionsInput,
+        ListSessionsOutput, ListUsersInput, ListUsersOutput, PasskeyItem, PromoteUserInput,
+        PromoteUserOutput, ReactivateUserInput, RevokeApiKeyInput, RevokeSessionInput,
+        SuspendUserInput, SuspendUserOutput, UnlockUserInput, UnlockUserOutput,
+        UpdateUserEmailInput, UpdateUserEmailOutput, UpdateUserProfileInput,
         UpdateUserProfileOutput, UserDetailedProfile, UserPublicProfile,
     },
 };
@@ -42,9 +51,128 @@ pub trait UserService: Send + Sync {
         input: UpdateUserEmailInput,
     ) -> Result<UpdateUserEmailOutput, UseCaseError>;
 
+    async fn change_password(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ChangePasswordInput,
+    ) -> Result<(), UseCaseError>;
+
+    async fn deactivate_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeactivateUserInput,
+    ) -> Result<(), UseCaseError>;
+
+    /// 退会済みのアカウントを、メールアドレスとパスワードによる本人確認のうえで再開する
+    ///
+    /// 退会済みのアカウントではログインできないため、アクセストークンを要求しない
+    async fn reactivate_user(&self, input: ReactivateUserInput) -> Result<(), UseCaseError>;
+
+    async fn begin_mfa_enrollment(
+        &self,
+        identity: Box<dyn Identity>,
+        input: BeginMfaEnrollmentInput,
+    ) -> Result<BeginMfaEnrollmentOutput, UseCaseError>;
+
+    async fn confirm_mfa_enrollment(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ConfirmMfaEnrollmentInput,
+    ) -> Result<ConfirmMfaEnrollmentOutput, UseCaseError>;
+
+    async fn disable_mfa(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DisableMfaInput,
+    ) -> Result<(), UseCaseError>;
+
+    async fn begin_passkey_registration(
+        &self,
+        identity: Box<dyn Identity>,
+        input: BeginPasskeyRegistrationInput,
+    ) -> Result<BeginPasskeyRegistrationOutput, UseCaseError>;
+
+    async fn finish_passkey_registration(
+        &self,
+        identity: Box<dyn Identity>,
+        input: FinishPasskeyRegistrationInput,
+    ) -> Result<PasskeyItem, UseCaseError>;
+
+    async fn list_passkeys(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListPasskeysInput,
+    ) -> Result<ListPasskeysOutput, UseCaseError>;
+
+    async fn delete_passkey(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DeletePasskeyInput,
+    ) -> Result<(), UseCaseError>;
+
+    async fn create_api_key(
+        &self,
+        identity: Box<dyn Identity>,
+        input: CreateApiKeyInput,
+    ) -> Result<CreateApiKeyOutput, UseCaseError>;
+
+    async fn list_api_keys(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListApiKeysInput,
+    ) -> Result<ListApiKeysOutput, UseCaseError>;
+
+    async fn revoke_api_key(
+        &self,
+        identity: Box<dyn Identity>,
+        input: RevokeApiKeyInput,
+    ) -> Result<(), UseCaseError>;
+
+    async fn list_sessions(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ListSessionsInput,
+    ) -> Result<ListSessionsOutput, UseCaseError>;
+
+    async fn revoke_session(
+        &self,
+        identity: Box<dyn Identity>,
+        input: RevokeSessionInput,
+    ) -> Result<(), UseCaseError>;
+
     async fn suspend_user(
         &self,
         identity: Box<dyn Identity>,
         input: SuspendUserInput,
     ) -> Result<SuspendUserOutput, UseCaseError>;
+
+    async fn unlock_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: UnlockUserInput,
+    ) -> Result<UnlockUserOutput, UseCaseError>;
+
+    async fn promote_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: PromoteUserInput,
+    ) -> Result<PromoteUserOutput, UseCaseError>;
+
+    async fn demote_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: DemoteUserInput,
+    ) -> Result<DemoteUserOutput, UseCaseError>;
+
+    async fn impersonate_user(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ImpersonateUserInput,
+    ) -> Result<ImpersonateUserOutput, UseCaseError>;
+
+    async fn clear_login_lockout(
+        &self,
+        identity: Box<dyn Identity>,
+        input: ClearLoginLockoutInput,
+    ) -> Result<(), UseCaseError>;
 }
@@ -3,4 +3,12 @@
 #[strum(prefix = "idx_")]
 pub enum Indices {
     OutboxProcessQueue,
+    RefreshTokenFamilyId,
+    RevokedAccessTokenExpiresAt,
+    PasswordResetTokenUserIdStatus,
+    WebauthnCredentialUserId,
+    FederatedIdentityUserId,
+    ApiKeyUserId,
+    SessionUserIdCreatedAt,
+    UserCreatedAtId,
 }
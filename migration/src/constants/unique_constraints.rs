@@ -3,4 +3,9 @@
 pub enum UniqueConstraints {
     UserEmailKey,
     UserUsernameKey,
+    RefreshTokenTokenHashKey,
+    PasswordResetTokenTokenHashKey,
+    WebauthnCredentialCredentialIdKey,
+    FederatedIdentityProviderSubjectKey,
+    ApiKeyPrefixKey,
 }
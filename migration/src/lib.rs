@@ -8,6 +8,19 @@ mod m20260104_042952_replace_is_active_with_status;
 mod m20260107_121138_create_outbox_table;
 mod m20260203_134756_add_retry_fields_to_outbox;
 mod m20260204_152948_normalize_outbox_status;
+mod m20260212_093015_create_refresh_token_table;
+mod m20260214_101532_create_revoked_access_token_table;
+mod m20260216_084210_create_password_reset_token_table;
+mod m20260218_091204_add_login_lockout_to_user;
+mod m20260220_103418_add_mfa_to_user;
+mod m20260223_140527_create_webauthn_credential_table;
+mod m20260226_091530_create_federated_identity_table;
+mod m20260302_104215_create_api_key_table;
+mod m20260305_093120_create_service_account_table;
+mod m20260309_081245_add_token_epoch_to_user;
+mod m20260312_094530_create_session_table;
+mod m20260316_102415_add_created_at_index_to_user;
+mod m20260320_091120_add_token_epoch_to_refresh_token;
 
 pub struct Migrator;
 
@@ -21,6 +34,19 @@ impl MigratorTrait for Migrator {
             Box::new(m20260107_121138_create_outbox_table::Migration),
             Box::new(m20260203_134756_add_retry_fields_to_outbox::Migration),
             Box::new(m20260204_152948_normalize_outbox_status::Migration),
+            Box::new(m20260212_093015_create_refresh_token_table::Migration),
+            Box::new(m20260214_101532_create_revoked_access_token_table::Migration),
+            Box::new(m20260216_084210_create_password_reset_token_table::Migration),
+            Box::new(m20260218_091204_add_login_lockout_to_user::Migration),
+            Box::new(m20260220_103418_add_mfa_to_user::Migration),
+            Box::new(m20260223_140527_create_webauthn_credential_table::Migration),
+            Box::new(m20260226_091530_create_federated_identity_table::Migration),
+            Box::new(m20260302_104215_create_api_key_table::Migration),
+            Box::new(m20260305_093120_create_service_account_table::Migration),
+            Box::new(m20260309_081245_add_token_epoch_to_user::Migration),
+            Box::new(m20260312_094530_create_session_table::Migration),
+            Box::new(m20260316_102415_add_created_at_index_to_user::Migration),
+            Box::new(m20260320_091120_add_token_epoch_to_refresh_token::Migration),
         ]
     }
 }
@@ -0,0 +1,128 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::{Indices, UniqueConstraints};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RefreshToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RefreshToken::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    // ローテーションで連なるトークン群を識別する ID (最初のトークンの ID)
+                    .col(ColumnDef::new(RefreshToken::FamilyId).uuid().not_null())
+                    .col(ColumnDef::new(RefreshToken::UserId).uuid().not_null())
+                    // トークンの平文は保存せず、ハッシュ値のみを保存する
+                    .col(ColumnDef::new(RefreshToken::TokenHash).string().not_null())
+                    .col(ColumnDef::new(RefreshToken::Status).string().not_null()) // active, rotated, revoked
+                    .col(
+                        ColumnDef::new(RefreshToken::IssuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshToken::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshToken::RotatedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(RefreshToken::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ユーザーが削除された場合はトークンも削除する
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RefreshToken::Table, RefreshToken::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // token_hash による検索とハッシュ値の重複防止のためのユニークインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(UniqueConstraints::RefreshTokenTokenHashKey.to_string())
+                    .table(RefreshToken::Table)
+                    .col(RefreshToken::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 再利用検知時にファミリー全体を失効させるためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::RefreshTokenFamilyId.into())
+                    .table(RefreshToken::Table)
+                    .col(RefreshToken::FamilyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::RefreshTokenFamilyId.into())
+                    .table(RefreshToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(UniqueConstraints::RefreshTokenTokenHashKey.to_string())
+                    .table(RefreshToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RefreshToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RefreshToken {
+    Table,
+    Id,
+    FamilyId,
+    UserId,
+    TokenHash,
+    Status,
+    IssuedAt,
+    ExpiresAt,
+    RotatedAt,
+    RevokedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::Indices;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RevokedAccessToken::Table)
+                    .if_not_exists()
+                    // アクセストークンの jti
+                    .col(
+                        ColumnDef::new(RevokedAccessToken::TokenId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    // 元のトークンの有効期限。これを過ぎたエントリは削除してよい
+                    .col(
+                        ColumnDef::new(RevokedAccessToken::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RevokedAccessToken::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 期限切れエントリの削除のためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::RevokedAccessTokenExpiresAt.into())
+                    .table(RevokedAccessToken::Table)
+                    .col(RevokedAccessToken::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::RevokedAccessTokenExpiresAt.into())
+                    .table(RevokedAccessToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(RevokedAccessToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RevokedAccessToken {
+    Table,
+    TokenId,
+    ExpiresAt,
+    RevokedAt,
+}
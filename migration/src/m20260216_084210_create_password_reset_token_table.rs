@@ -0,0 +1,134 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::{Indices, UniqueConstraints};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PasswordResetToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PasswordResetToken::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PasswordResetToken::UserId).uuid().not_null())
+                    // トークンの平文は保存せず、ハッシュ値のみを保存する
+                    .col(
+                        ColumnDef::new(PasswordResetToken::TokenHash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasswordResetToken::Status)
+                            .string()
+                            .not_null(),
+                    ) // active, used, revoked
+                    .col(
+                        ColumnDef::new(PasswordResetToken::IssuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasswordResetToken::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasswordResetToken::UsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(PasswordResetToken::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ユーザーが削除された場合はトークンも削除する
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(PasswordResetToken::Table, PasswordResetToken::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // token_hash による検索とハッシュ値の重複防止のためのユニークインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(UniqueConstraints::PasswordResetTokenTokenHashKey.to_string())
+                    .table(PasswordResetToken::Table)
+                    .col(PasswordResetToken::TokenHash)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // リセット完了時に同じユーザーの未使用トークンを失効させるためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::PasswordResetTokenUserIdStatus.into())
+                    .table(PasswordResetToken::Table)
+                    .col(PasswordResetToken::UserId)
+                    .col(PasswordResetToken::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::PasswordResetTokenUserIdStatus.into())
+                    .table(PasswordResetToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(UniqueConstraints::PasswordResetTokenTokenHashKey.to_string())
+                    .table(PasswordResetToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PasswordResetToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum PasswordResetToken {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    Status,
+    IssuedAt,
+    ExpiresAt,
+    UsedAt,
+    RevokedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
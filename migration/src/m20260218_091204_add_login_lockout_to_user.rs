@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    // 期間内のログイン失敗回数 (デフォルト0)
+                    .add_column(
+                        ColumnDef::new(User::FailedLoginAttempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    // 失敗回数を数え始めた時刻
+                    .add_column(
+                        ColumnDef::new(User::FirstFailedLoginAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ロックアウトの終了時刻
+                    .add_column(
+                        ColumnDef::new(User::LockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::LockedUntil)
+                    .drop_column(User::FirstFailedLoginAt)
+                    .drop_column(User::FailedLoginAttempts)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    FailedLoginAttempts,
+    FirstFailedLoginAt,
+    LockedUntil,
+}
@@ -0,0 +1,67 @@
+use domain::user::MfaStateKind;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    // 2段階認証の登録状態 (デフォルトは未登録)
+                    .add_column(
+                        ColumnDef::new(User::MfaStatus)
+                            .string()
+                            .not_null()
+                            .default(MfaStateKind::Disabled.to_string()),
+                    )
+                    // TOTP の共有シークレット (Base32)
+                    .add_column(ColumnDef::new(User::TotpSecret).string().null())
+                    // 未使用のリカバリーコードのハッシュ値の配列
+                    .add_column(
+                        ColumnDef::new(User::MfaRecoveryCodes)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'::jsonb")),
+                    )
+                    // 最後に使用された認証コードのタイムステップ
+                    .add_column(ColumnDef::new(User::TotpLastUsedStep).big_integer().null())
+                    // 2段階認証を有効にした時刻
+                    .add_column(
+                        ColumnDef::new(User::MfaEnabledAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::MfaEnabledAt)
+                    .drop_column(User::TotpLastUsedStep)
+                    .drop_column(User::MfaRecoveryCodes)
+                    .drop_column(User::TotpSecret)
+                    .drop_column(User::MfaStatus)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    MfaStatus,
+    TotpSecret,
+    MfaRecoveryCodes,
+    TotpLastUsedStep,
+    MfaEnabledAt,
+}
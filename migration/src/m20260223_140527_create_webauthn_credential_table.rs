@@ -0,0 +1,130 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::{Indices, UniqueConstraints};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebauthnCredential::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebauthnCredential::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(WebauthnCredential::UserId).uuid().not_null())
+                    // 認証器が発行したクレデンシャル ID (Base64URL)
+                    .col(
+                        ColumnDef::new(WebauthnCredential::CredentialId)
+                            .string()
+                            .not_null(),
+                    )
+                    // COSE_Key 形式の公開鍵
+                    .col(
+                        ColumnDef::new(WebauthnCredential::PublicKey)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredential::SignCount)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WebauthnCredential::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(WebauthnCredential::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(WebauthnCredential::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ユーザーが削除された場合はクレデンシャルも削除する
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(WebauthnCredential::Table, WebauthnCredential::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 認証時に credential_id で検索するため、また同じクレデンシャルの二重登録を防ぐためのユニークインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(UniqueConstraints::WebauthnCredentialCredentialIdKey.to_string())
+                    .table(WebauthnCredential::Table)
+                    .col(WebauthnCredential::CredentialId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // ユーザーごとのパスキーの一覧を取得するためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::WebauthnCredentialUserId.into())
+                    .table(WebauthnCredential::Table)
+                    .col(WebauthnCredential::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::WebauthnCredentialUserId.into())
+                    .table(WebauthnCredential::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(UniqueConstraints::WebauthnCredentialCredentialIdKey.to_string())
+                    .table(WebauthnCredential::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(WebauthnCredential::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebauthnCredential {
+    Table,
+    Id,
+    UserId,
+    CredentialId,
+    PublicKey,
+    SignCount,
+    Name,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::{Indices, UniqueConstraints};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FederatedIdentity::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FederatedIdentity::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(FederatedIdentity::UserId).uuid().not_null())
+                    // 設定で定義した ID プロバイダーの識別子
+                    .col(
+                        ColumnDef::new(FederatedIdentity::Provider)
+                            .string()
+                            .not_null(),
+                    )
+                    // ID トークンの sub クレーム
+                    .col(
+                        ColumnDef::new(FederatedIdentity::Subject)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(FederatedIdentity::Email).string().not_null())
+                    .col(
+                        ColumnDef::new(FederatedIdentity::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FederatedIdentity::LastLoginAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ユーザーが削除された場合は紐づけも削除する
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(FederatedIdentity::Table, FederatedIdentity::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // ログイン時に (provider, subject) で検索するため、また同じ外部アカウントの二重登録を防ぐためのユニークインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(UniqueConstraints::FederatedIdentityProviderSubjectKey.to_string())
+                    .table(FederatedIdentity::Table)
+                    .col(FederatedIdentity::Provider)
+                    .col(FederatedIdentity::Subject)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // ユーザーごとの紐づけを取得するためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::FederatedIdentityUserId.into())
+                    .table(FederatedIdentity::Table)
+                    .col(FederatedIdentity::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::FederatedIdentityUserId.into())
+                    .table(FederatedIdentity::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(UniqueConstraints::FederatedIdentityProviderSubjectKey.to_string())
+                    .table(FederatedIdentity::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FederatedIdentity::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FederatedIdentity {
+    Table,
+    Id,
+    UserId,
+    Provider,
+    Subject,
+    Email,
+    CreatedAt,
+    LastLoginAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+use crate::constants::{Indices, UniqueConstraints};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApiKey::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ApiKey::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(ApiKey::UserId).uuid().not_null())
+                    .col(ColumnDef::new(ApiKey::Name).string().not_null())
+                    // キーの平文に含まれる検索用の識別子
+                    .col(ColumnDef::new(ApiKey::Prefix).string().not_null())
+                    // キーの秘密の部分のハッシュ値 (平文は保存しない)
+                    .col(ColumnDef::new(ApiKey::SecretHash).string().not_null())
+                    // 許可するスコープの配列
+                    .col(ColumnDef::new(ApiKey::Scopes).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(ApiKey::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ApiKey::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ApiKey::LastUsedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    // ユーザーが削除された場合はキーも削除する
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(ApiKey::Table, ApiKey::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 認証時に識別子で検索するためのユニークインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(UniqueConstraints::ApiKeyPrefixKey.to_string())
+                    .table(ApiKey::Table)
+                    .col(ApiKey::Prefix)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // ユーザーごとのキーの一覧を取得するためのインデックス
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name::<&'static str>(Indices::ApiKeyUserId.into())
+                    .table(ApiKey::Table)
+                    .col(ApiKey::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name::<&'static str>(Indices::ApiKeyUserId.into())
+                    .table(ApiKey::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(UniqueConstraints::ApiKeyPrefixKey.to_string())
+                    .table(ApiKey::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ApiKey::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApiKey {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Prefix,
+    SecretHash,
+    Scopes,
+    ExpiresAt,
+    CreatedAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ServiceAccount::Table)
+                    .if_not_exists()
+                    // クライアントクレデンシャルグラントのクライアント ID としても使用する
+                    .col(
+                        ColumnDef::new(ServiceAccount::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ServiceAccount::Name).string().not_null())
+                    .col(ColumnDef::new(ServiceAccount::Role).string().not_null())
+                    // クライアントシークレットのハッシュ値 (平文は保存しない)
+                    .col(
+                        ColumnDef::new(ServiceAccount::SecretHash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccount::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccount::LastAuthenticatedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ServiceAccount::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ServiceAccount {
+    Table,
+    Id,
+    Name,
+    Role,
+    SecretHash,
+    CreatedAt,
+    LastAuthenticatedAt,
+}
@@ -1,15 +1,34 @@
 use std::sync::Arc;
 
 use actix_web::{App, HttpServer, web};
+use api::session_cookie::AuthCookieConfig;
 use app::telemetry;
+use domain::auth::session::SessionLimit;
+use domain::user::{CharacterClass, LoginLockoutConfig, PasswordPolicyConfig};
 use dotenvy::dotenv;
 use relay::{RelayConfig, RelayWorker};
 use sea_orm::Database;
 use tokio_util::sync::CancellationToken;
 use tracing_actix_web::TracingLogger;
+use usecase::auth::api_key_config::ApiKeyConfig;
+use usecase::auth::email_verification_config::EmailVerificationConfig;
+use usecase::auth::login_throttle_config::LoginThrottleConfig;
+use usecase::auth::magic_link_config::MagicLinkConfig;
+use usecase::auth::mfa_config::MfaConfig;
+use usecase::auth::oidc_config::{OidcConfig, OidcProviderConfig};
+use usecase::auth::password_reset_config::PasswordResetConfig;
+use usecase::auth::token_config::TokenConfig;
+use usecase::auth::webauthn_config::WebAuthnConfig;
 
 use infrastructure::{
-    AppRegistry, RepoRegistry, email_service::stub_email_service::email_service::StubEmailService,
+    AppRegistry, RepoRegistry,
+    auth::{
+        argon2::{password_service::Argon2Config, pepper::PepperSet},
+        breached_password::hibp_file::HibpBreachedPasswordList,
+        jwt::pem_key_files::PemKeyFiles,
+        oidc::reqwest_client::ReqwestOidcClient,
+    },
+    email_service::stub_email_service::email_service::StubEmailService,
     relay::next_attempt_calculator::backoff_next_attempt_calculator::BackoffCalculatorConfig,
 };
 
@@ -22,7 +41,302 @@ async fn main() -> std::io::Result<()> {
     telemetry::init_telemetry();
 
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+    let jwt_algorithm = std::env::var("JWT_ALGORITHM").expect("JWT_ALGORITHM must be set");
+    let jwt_signing_key_id =
+        std::env::var("JWT_SIGNING_KEY_ID").expect("JWT_SIGNING_KEY_ID must be set");
+    let jwt_signing_key_path =
+        std::env::var("JWT_SIGNING_KEY_PATH").expect("JWT_SIGNING_KEY_PATH must be set");
+    let jwt_verification_keys =
+        std::env::var("JWT_VERIFICATION_KEYS").expect("JWT_VERIFICATION_KEYS must be set");
+
+    let jwt_key_set = PemKeyFiles::new(
+        &jwt_algorithm,
+        jwt_signing_key_id,
+        jwt_signing_key_path,
+        &jwt_verification_keys,
+    )
+    .and_then(|files| files.load())
+    .unwrap_or_else(|e| panic!("Failed to load JWT keys: {e}"));
+
+    // 未設定の場合は iss/aud を付与・検証しない
+    let jwt_issuer = std::env::var("JWT_ISSUER").ok().filter(|s| !s.is_empty());
+    let jwt_audience = std::env::var("JWT_AUDIENCE").ok().filter(|s| !s.is_empty());
+    let access_token_ttl_secs = std::env::var("ACCESS_TOKEN_TTL_SECS")
+        .expect("ACCESS_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("ACCESS_TOKEN_TTL_SECS must be a valid number");
+    let refresh_token_ttl_secs = std::env::var("REFRESH_TOKEN_TTL_SECS")
+        .expect("REFRESH_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("REFRESH_TOKEN_TTL_SECS must be a valid number");
+
+    let token_epoch_cache_ttl_secs = std::env::var("TOKEN_EPOCH_CACHE_TTL_SECS")
+        .expect("TOKEN_EPOCH_CACHE_TTL_SECS must be set")
+        .parse()
+        .expect("TOKEN_EPOCH_CACHE_TTL_SECS must be a valid number");
+
+    let impersonation_token_ttl_secs = std::env::var("IMPERSONATION_TOKEN_TTL_SECS")
+        .expect("IMPERSONATION_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("IMPERSONATION_TOKEN_TTL_SECS must be a valid number");
+
+    let token_config = TokenConfig::new(
+        access_token_ttl_secs,
+        refresh_token_ttl_secs,
+        jwt_issuer,
+        jwt_audience,
+        token_epoch_cache_ttl_secs,
+        impersonation_token_ttl_secs,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create TokenConfig: {e}"));
+
+    let email_verification_token_ttl_secs = std::env::var("EMAIL_VERIFICATION_TOKEN_TTL_SECS")
+        .expect("EMAIL_VERIFICATION_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("EMAIL_VERIFICATION_TOKEN_TTL_SECS must be a valid number");
+    let email_verification_url =
+        std::env::var("EMAIL_VERIFICATION_URL").expect("EMAIL_VERIFICATION_URL must be set");
+    let email_verification_resend_max_attempts =
+        std::env::var("EMAIL_VERIFICATION_RESEND_MAX_ATTEMPTS")
+            .expect("EMAIL_VERIFICATION_RESEND_MAX_ATTEMPTS must be set")
+            .parse()
+            .expect("EMAIL_VERIFICATION_RESEND_MAX_ATTEMPTS must be a valid number");
+    let email_verification_resend_window_secs =
+        std::env::var("EMAIL_VERIFICATION_RESEND_WINDOW_SECS")
+            .expect("EMAIL_VERIFICATION_RESEND_WINDOW_SECS must be set")
+            .parse()
+            .expect("EMAIL_VERIFICATION_RESEND_WINDOW_SECS must be a valid number");
+
+    let email_verification_config = EmailVerificationConfig::new(
+        email_verification_token_ttl_secs,
+        email_verification_url,
+        email_verification_resend_max_attempts,
+        email_verification_resend_window_secs,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create EmailVerificationConfig: {e}"));
+
+    let password_reset_token_ttl_secs = std::env::var("PASSWORD_RESET_TOKEN_TTL_SECS")
+        .expect("PASSWORD_RESET_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("PASSWORD_RESET_TOKEN_TTL_SECS must be a valid number");
+    let password_reset_url =
+        std::env::var("PASSWORD_RESET_URL").expect("PASSWORD_RESET_URL must be set");
+    let password_reset_token_secret = std::env::var("PASSWORD_RESET_TOKEN_SECRET")
+        .expect("PASSWORD_RESET_TOKEN_SECRET must be set");
+
+    let password_reset_config = PasswordResetConfig::new(
+        password_reset_token_ttl_secs,
+        password_reset_url,
+        password_reset_token_secret.into_bytes(),
+    )
+    .unwrap_or_else(|e| panic!("Failed to create PasswordResetConfig: {e}"));
+
+    let magic_link_token_ttl_secs = std::env::var("MAGIC_LINK_TOKEN_TTL_SECS")
+        .expect("MAGIC_LINK_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("MAGIC_LINK_TOKEN_TTL_SECS must be a valid number");
+    let magic_link_url = std::env::var("MAGIC_LINK_URL").expect("MAGIC_LINK_URL must be set");
+
+    let magic_link_config = MagicLinkConfig::new(magic_link_token_ttl_secs, magic_link_url)
+        .unwrap_or_else(|e| panic!("Failed to create MagicLinkConfig: {e}"));
+
+    let password_min_length = std::env::var("PASSWORD_MIN_LENGTH")
+        .expect("PASSWORD_MIN_LENGTH must be set")
+        .parse()
+        .expect("PASSWORD_MIN_LENGTH must be a valid number");
+    let password_max_length = std::env::var("PASSWORD_MAX_LENGTH")
+        .expect("PASSWORD_MAX_LENGTH must be set")
+        .parse()
+        .expect("PASSWORD_MAX_LENGTH must be a valid number");
+    let password_required_character_classes = std::env::var("PASSWORD_REQUIRED_CHARACTER_CLASSES")
+        .expect("PASSWORD_REQUIRED_CHARACTER_CLASSES must be set")
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<CharacterClass>().unwrap_or_else(|_| {
+                panic!("PASSWORD_REQUIRED_CHARACTER_CLASSES contains an unknown class: {s}")
+            })
+        })
+        .collect();
+    let password_forbid_user_info = std::env::var("PASSWORD_FORBID_USER_INFO")
+        .expect("PASSWORD_FORBID_USER_INFO must be set")
+        .parse()
+        .expect("PASSWORD_FORBID_USER_INFO must be 'true' or 'false'");
+
+    let password_policy_config = PasswordPolicyConfig::new(
+        password_min_length,
+        password_max_length,
+        password_required_character_classes,
+        password_forbid_user_info,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create PasswordPolicyConfig: {e}"));
+
+    // 未設定の場合は漏洩済みパスワードとの照合を行わない
+    let breached_password_list = match std::env::var("BREACHED_PASSWORDS_FILE")
+        .ok()
+        .filter(|s| !s.is_empty())
+    {
+        Some(path) => HibpBreachedPasswordList::load(path)
+            .unwrap_or_else(|e| panic!("Failed to load breached password list: {e}")),
+        None => HibpBreachedPasswordList::empty(),
+    };
+
+    let argon2_memory_kib = std::env::var("ARGON2_MEMORY_KIB")
+        .expect("ARGON2_MEMORY_KIB must be set")
+        .parse()
+        .expect("ARGON2_MEMORY_KIB must be a valid number");
+    let argon2_iterations = std::env::var("ARGON2_ITERATIONS")
+        .expect("ARGON2_ITERATIONS must be set")
+        .parse()
+        .expect("ARGON2_ITERATIONS must be a valid number");
+    let argon2_parallelism = std::env::var("ARGON2_PARALLELISM")
+        .expect("ARGON2_PARALLELISM must be set")
+        .parse()
+        .expect("ARGON2_PARALLELISM must be a valid number");
+
+    let argon2_config = Argon2Config::new(argon2_memory_kib, argon2_iterations, argon2_parallelism)
+        .unwrap_or_else(|e| panic!("Failed to create Argon2Config: {e}"));
+
+    // ペッパーはファイルでの指定を優先し、どちらも未設定の場合はペッパーを使用しない
+    let password_pepper_id = std::env::var("PASSWORD_PEPPER_ID").unwrap_or_default();
+    let password_peppers = match std::env::var("PASSWORD_PEPPERS_FILE")
+        .ok()
+        .filter(|s| !s.is_empty())
+    {
+        Some(path) => PepperSet::load(&password_pepper_id, path),
+        None => PepperSet::new(
+            &password_pepper_id,
+            &std::env::var("PASSWORD_PEPPERS").unwrap_or_default(),
+        ),
+    }
+    .unwrap_or_else(|e| panic!("Failed to load password peppers: {e}"));
+
+    let login_max_failed_attempts = std::env::var("LOGIN_MAX_FAILED_ATTEMPTS")
+        .expect("LOGIN_MAX_FAILED_ATTEMPTS must be set")
+        .parse()
+        .expect("LOGIN_MAX_FAILED_ATTEMPTS must be a valid number");
+    let login_failure_window_secs = std::env::var("LOGIN_FAILURE_WINDOW_SECS")
+        .expect("LOGIN_FAILURE_WINDOW_SECS must be set")
+        .parse()
+        .expect("LOGIN_FAILURE_WINDOW_SECS must be a valid number");
+    let login_lockout_secs = std::env::var("LOGIN_LOCKOUT_SECS")
+        .expect("LOGIN_LOCKOUT_SECS must be set")
+        .parse()
+        .expect("LOGIN_LOCKOUT_SECS must be a valid number");
+
+    let login_lockout_config = LoginLockoutConfig::new(
+        login_max_failed_attempts,
+        login_failure_window_secs,
+        login_lockout_secs,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create LoginLockoutConfig: {e}"));
+
+    let login_ip_max_failed_attempts = std::env::var("LOGIN_IP_MAX_FAILED_ATTEMPTS")
+        .expect("LOGIN_IP_MAX_FAILED_ATTEMPTS must be set")
+        .parse()
+        .expect("LOGIN_IP_MAX_FAILED_ATTEMPTS must be a valid number");
+    let login_ip_failure_window_secs = std::env::var("LOGIN_IP_FAILURE_WINDOW_SECS")
+        .expect("LOGIN_IP_FAILURE_WINDOW_SECS must be set")
+        .parse()
+        .expect("LOGIN_IP_FAILURE_WINDOW_SECS must be a valid number");
+
+    let login_throttle_config =
+        LoginThrottleConfig::new(login_ip_max_failed_attempts, login_ip_failure_window_secs)
+            .unwrap_or_else(|e| panic!("Failed to create LoginThrottleConfig: {e}"));
+
+    // 未設定の場合は同時にログインできるセッション数を制限しない
+    let max_sessions_per_user = std::env::var("MAX_SESSIONS_PER_USER")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse()
+                .expect("MAX_SESSIONS_PER_USER must be a valid number")
+        });
+
+    let session_limit = SessionLimit::new(max_sessions_per_user)
+        .unwrap_or_else(|e| panic!("Failed to create SessionLimit: {e}"));
+
+    let mfa_totp_issuer = std::env::var("MFA_TOTP_ISSUER").expect("MFA_TOTP_ISSUER must be set");
+    let mfa_challenge_token_ttl_secs = std::env::var("MFA_CHALLENGE_TOKEN_TTL_SECS")
+        .expect("MFA_CHALLENGE_TOKEN_TTL_SECS must be set")
+        .parse()
+        .expect("MFA_CHALLENGE_TOKEN_TTL_SECS must be a valid number");
+
+    let mfa_config = MfaConfig::new(mfa_totp_issuer, mfa_challenge_token_ttl_secs)
+        .unwrap_or_else(|e| panic!("Failed to create MfaConfig: {e}"));
+
+    let webauthn_rp_id = std::env::var("WEBAUTHN_RP_ID").expect("WEBAUTHN_RP_ID must be set");
+    let webauthn_rp_name = std::env::var("WEBAUTHN_RP_NAME").expect("WEBAUTHN_RP_NAME must be set");
+    let webauthn_origins = std::env::var("WEBAUTHN_ORIGINS")
+        .expect("WEBAUTHN_ORIGINS must be set")
+        .split(',')
+        .map(|origin| origin.trim().to_string())
+        .filter(|origin| !origin.is_empty())
+        .collect();
+    let webauthn_challenge_ttl_secs = std::env::var("WEBAUTHN_CHALLENGE_TTL_SECS")
+        .expect("WEBAUTHN_CHALLENGE_TTL_SECS must be set")
+        .parse()
+        .expect("WEBAUTHN_CHALLENGE_TTL_SECS must be a valid number");
+
+    let webauthn_config = WebAuthnConfig::new(
+        webauthn_rp_id,
+        webauthn_rp_name,
+        webauthn_origins,
+        webauthn_challenge_ttl_secs,
+    )
+    .unwrap_or_else(|e| panic!("Failed to create WebAuthnConfig: {e}"));
+
+    // プロバイダーごとの設定は `OIDC_<ID>_*` から読み込む (ID は大文字にし、`-` を `_` に置き換える)
+    let oidc_providers = std::env::var("OIDC_PROVIDERS")
+        .unwrap_or_default()
+        .split(',')
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            let prefix = format!("OIDC_{}", id.to_uppercase().replace('-', "_"));
+            let var = |name: &str| {
+                let key = format!("{prefix}_{name}");
+                std::env::var(&key).unwrap_or_else(|_| panic!("{key} must be set"))
+            };
+            let scopes = std::env::var(format!("{prefix}_SCOPES"))
+                .ok()
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| "openid email profile".to_string())
+                .split_whitespace()
+                .map(str::to_string)
+                .collect();
+
+            OidcProviderConfig::new(
+                id.clone(),
+                var("ISSUER"),
+                var("CLIENT_ID"),
+                var("CLIENT_SECRET"),
+                var("REDIRECT_URI"),
+                scopes,
+            )
+            .unwrap_or_else(|e| panic!("Failed to create OidcProviderConfig: {e}"))
+        })
+        .collect();
+    let oidc_state_ttl_secs = std::env::var("OIDC_STATE_TTL_SECS")
+        .expect("OIDC_STATE_TTL_SECS must be set")
+        .parse()
+        .expect("OIDC_STATE_TTL_SECS must be a valid number");
+
+    let oidc_config = OidcConfig::new(oidc_providers, oidc_state_ttl_secs)
+        .unwrap_or_else(|e| panic!("Failed to create OidcConfig: {e}"));
+
+    let oidc_client =
+        ReqwestOidcClient::new().unwrap_or_else(|e| panic!("Failed to create OIDC client: {e}"));
+
+    let api_key_max_ttl_days = std::env::var("API_KEY_MAX_TTL_DAYS")
+        .expect("API_KEY_MAX_TTL_DAYS must be set")
+        .parse()
+        .expect("API_KEY_MAX_TTL_DAYS must be a valid number");
+
+    let api_key_config = ApiKeyConfig::new(api_key_max_ttl_days)
+        .unwrap_or_else(|e| panic!("Failed to create ApiKeyConfig: {e}"));
+
     let relay_batch_size = std::env::var("RELAY_BATCH_SIZE")
         .expect("RELAY_BATCH_SIZE must be set")
         .parse()
@@ -65,6 +379,22 @@ async fn main() -> std::io::Result<()> {
     )
     .unwrap_or_else(|e| panic!("Failed to create BackoffCalculatorConfig: {}", e));
 
+    let token_revocation_backend = std::env::var("TOKEN_REVOCATION_BACKEND")
+        .expect("TOKEN_REVOCATION_BACKEND must be set")
+        .parse()
+        .expect("TOKEN_REVOCATION_BACKEND must be either 'in_memory' or 'database'");
+
+    let auth_mode = std::env::var("AUTH_MODE")
+        .expect("AUTH_MODE must be set")
+        .parse()
+        .expect("AUTH_MODE must be either 'header' or 'cookie'");
+    let auth_cookie_same_site = std::env::var("AUTH_COOKIE_SAME_SITE")
+        .expect("AUTH_COOKIE_SAME_SITE must be set")
+        .parse()
+        .expect("AUTH_COOKIE_SAME_SITE must be either 'strict' or 'lax'");
+
+    let auth_cookie_config = AuthCookieConfig::new(auth_mode, auth_cookie_same_site);
+
     let db_conn = Database::connect(database_url)
         .await
         .expect("Failed to connect DB");
@@ -73,17 +403,41 @@ async fn main() -> std::io::Result<()> {
 
     // 2. 依存関係の構築 (DI Containerとしての役割)
     // リポジトリ群を一括生成
-    let repos = RepoRegistry::new_seaorm(db_conn);
+    let repos = RepoRegistry::new_seaorm(db_conn, token_revocation_backend);
 
     let email_service = Arc::new(StubEmailService::new());
 
     // DIコンテナ（Registry）の初期化
-    let registry = AppRegistry::new(repos, email_service, jwt_secret, backoff_calculator_config);
+    let registry = AppRegistry::new(
+        repos,
+        email_service,
+        jwt_key_set,
+        token_config,
+        email_verification_config,
+        password_reset_config,
+        magic_link_config,
+        argon2_config,
+        password_peppers,
+        password_policy_config,
+        Arc::new(breached_password_list),
+        login_lockout_config,
+        login_throttle_config,
+        session_limit,
+        mfa_config,
+        webauthn_config,
+        oidc_config,
+        Arc::new(oidc_client),
+        api_key_config,
+        backoff_calculator_config,
+    );
 
     // Actix-web 内で共有するために web::Data にラップ
     let auth_service = web::Data::from(registry.auth_service.clone());
     let user_service = web::Data::from(registry.user_service.clone());
+    let service_account_service = web::Data::from(registry.service_account_service.clone());
     let token_service = web::Data::from(registry.token_service.clone());
+    let token_revocation_store = web::Data::from(registry.token_revocation_store.clone());
+    let auth_cookie_config = web::Data::new(auth_cookie_config);
 
     println!("Starting outbox relay worker... ");
 
@@ -103,7 +457,10 @@ async fn main() -> std::io::Result<()> {
             .wrap(TracingLogger::default()) // ログ・追跡用ミドルウェア
             .app_data(auth_service.clone())
             .app_data(user_service.clone())
+            .app_data(service_account_service.clone())
             .app_data(token_service.clone())
+            .app_data(token_revocation_store.clone())
+            .app_data(auth_cookie_config.clone())
             .configure(api::routes_config);
 
         // Swagger UI の設定